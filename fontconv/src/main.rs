@@ -0,0 +1,416 @@
+//! Convert TTF and BDF fonts to Rust source for `pinetime_rtic::font`:
+//!
+//! ```text
+//! $ cargo run --release -- --size 48 --bpp 4 --chars 0123456789:- \
+//!       DejaVuSans-Bold.ttf DIGITS_48 ../pinetime-rtic/src/font/digits_48.rs
+//! ```
+//!
+//! TTF fonts are rendered at `--size` pixels with anti-aliasing. BDF fonts
+//! are bitmap fonts, their pixels are either fully covered or not at all.
+//! Characters are selected with `--chars` and `--range`, which can both be
+//! given several times. Without them, the printable ASCII characters are
+//! converted.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs, process};
+
+const USAGE: &str = "Usage: fontconv [--size PX] [--bpp 1|2|4|8] [--chars CHARS] \
+                     [--range FIRST[-LAST]]... <font.ttf|font.bdf> <NAME> <output.rs>";
+
+/// A glyph with the coverage of its pixels (0–255), row by row.
+#[derive(Debug, PartialEq)]
+struct Glyph {
+    c: char,
+    advance: i32,
+    left: i32,
+    top: i32,
+    width: usize,
+    height: usize,
+    coverage: Vec<u8>,
+}
+
+/// A font with the selected glyphs, sorted by character.
+#[derive(Debug, PartialEq)]
+struct Font {
+    line_height: i32,
+    baseline: i32,
+    glyphs: Vec<Glyph>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut size = 16.0;
+    let mut bpp = 4;
+    let mut chars = BTreeSet::new();
+    let mut options = Vec::new();
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| USAGE.to_string());
+        match arg.as_str() {
+            "--size" => {
+                let value = value()?;
+                size = value
+                    .parse()
+                    .map_err(|_| format!("Invalid size: {}", value))?;
+                options.push(format!("--size {}", value));
+            }
+            "--bpp" => {
+                let value = value()?;
+                bpp = match value.as_str() {
+                    "1" | "2" | "4" | "8" => value.parse().unwrap(),
+                    _ => return Err(format!("Invalid bits per pixel: {}", value)),
+                };
+                options.push(format!("--bpp {}", value));
+            }
+            "--chars" => {
+                let value = value()?;
+                chars.extend(value.chars());
+                options.push(format!("--chars {}", value));
+            }
+            "--range" => {
+                let value = value()?;
+                chars
+                    .extend(parse_range(value).ok_or_else(|| format!("Invalid range: {}", value))?);
+                options.push(format!("--range {}", value));
+            }
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => paths.push(arg),
+        }
+    }
+    let (input, name, output) = match paths.as_slice() {
+        [input, name, output] => (input, name, output),
+        _ => return Err(USAGE.into()),
+    };
+    if chars.is_empty() {
+        chars.extend(' '..='~');
+    }
+
+    let data = fs::read(input).map_err(|e| format!("Could not read {}: {}", input, e))?;
+    let mut font = if input.ends_with(".bdf") {
+        let text = String::from_utf8(data).map_err(|_| format!("{} is not a BDF font", input))?;
+        parse_bdf(&text, &chars)?
+    } else {
+        render_ttf(&data, size, &chars)?
+    };
+    fit(&mut font);
+    let missing: String = chars
+        .iter()
+        .filter(|c| font.glyphs.iter().all(|glyph| glyph.c != **c))
+        .collect();
+    if !missing.is_empty() {
+        eprintln!("Missing characters: {}", missing);
+    }
+
+    let file_name = Path::new(input).file_name().unwrap().to_string_lossy();
+    let source = generate(
+        &font,
+        name,
+        bpp,
+        &format!("{} {}", options.join(" "), file_name),
+    )?;
+    fs::write(output, source).map_err(|e| format!("Could not write {}: {}", output, e))?;
+    println!("{}: {} glyphs", output, font.glyphs.len());
+    Ok(())
+}
+
+/// Parse a range of characters like `20-7e` or a single character like
+/// `20ac` (hexadecimal code points).
+fn parse_range(range: &str) -> Option<impl Iterator<Item = char>> {
+    let mut parts = range.splitn(2, '-');
+    let first = u32::from_str_radix(parts.next()?, 16).ok()?;
+    let last = match parts.next() {
+        Some(last) => u32::from_str_radix(last, 16).ok()?,
+        None => first,
+    };
+    if first > last {
+        return None;
+    }
+    Some((first..=last).filter_map(std::char::from_u32))
+}
+
+/// Render the glyphs of a TTF font.
+fn render_ttf(data: &[u8], size: f32, chars: &BTreeSet<char>) -> Result<Font, String> {
+    let font = fontdue::Font::from_bytes(data, fontdue::FontSettings::default())?;
+    let metrics = font
+        .horizontal_line_metrics(size)
+        .ok_or("The font has no horizontal metrics")?;
+    let glyphs = chars
+        .iter()
+        .filter(|c| font.lookup_glyph_index(**c) != 0)
+        .map(|c| {
+            let (glyph, coverage) = font.rasterize(*c, size);
+            Glyph {
+                c: *c,
+                advance: glyph.advance_width.round() as i32,
+                left: glyph.xmin,
+                top: glyph.ymin + glyph.height as i32,
+                width: glyph.width,
+                height: glyph.height,
+                coverage,
+            }
+        })
+        .collect();
+    Ok(Font {
+        line_height: metrics.new_line_size.ceil() as i32,
+        baseline: metrics.ascent.ceil() as i32,
+        glyphs,
+    })
+}
+
+/// Read the glyphs of a BDF font.
+fn parse_bdf(text: &str, chars: &BTreeSet<char>) -> Result<Font, String> {
+    let error = |line: &str| format!("Invalid BDF line: {}", line);
+    let numbers = |line: &str| -> Result<Vec<i32>, String> {
+        line.split_whitespace()
+            .skip(1)
+            .map(|n| n.parse().map_err(|_| error(line)))
+            .collect()
+    };
+
+    let (mut ascent, mut descent) = (None, None);
+    let mut glyphs = Vec::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let keyword = line.split_whitespace().next().unwrap_or("");
+        match keyword {
+            "FONT_ASCENT" => ascent = numbers(line)?.first().copied(),
+            "FONT_DESCENT" => descent = numbers(line)?.first().copied(),
+            "STARTCHAR" => {
+                let (mut c, mut advance, mut bbx) = (None, 0, None);
+                for line in &mut lines {
+                    match line.split_whitespace().next().unwrap_or("") {
+                        "ENCODING" => {
+                            c = numbers(line)?
+                                .first()
+                                .and_then(|n| std::char::from_u32(*n as u32))
+                        }
+                        "DWIDTH" => advance = *numbers(line)?.first().ok_or_else(|| error(line))?,
+                        "BBX" => bbx = Some(numbers(line)?),
+                        "BITMAP" => break,
+                        _ => {}
+                    }
+                }
+                let bbx = bbx
+                    .filter(|bbx| bbx.len() == 4)
+                    .ok_or("Glyph without BBX")?;
+                let (width, height) = (bbx[0] as usize, bbx[1] as usize);
+                let mut coverage = Vec::with_capacity(width * height);
+                for line in (&mut lines).take_while(|line| line.trim() != "ENDCHAR") {
+                    let row = line.trim();
+                    for x in 0..width {
+                        let nibble = row
+                            .get(x / 4..x / 4 + 1)
+                            .and_then(|nibble| u8::from_str_radix(nibble, 16).ok())
+                            .ok_or_else(|| error(line))?;
+                        let set = nibble & (0x8 >> (x % 4)) != 0;
+                        coverage.push(if set { 255 } else { 0 });
+                    }
+                }
+                if coverage.len() != width * height {
+                    return Err("Glyph with wrong bitmap size".into());
+                }
+                match c {
+                    Some(c) if chars.contains(&c) => glyphs.push(Glyph {
+                        c,
+                        advance,
+                        left: bbx[2],
+                        top: bbx[3] + height as i32,
+                        width,
+                        height,
+                        coverage,
+                    }),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    let (ascent, descent) = match (ascent, descent) {
+        (Some(ascent), Some(descent)) => (ascent, descent),
+        _ => return Err("FONT_ASCENT or FONT_DESCENT missing".into()),
+    };
+    glyphs.sort_by_key(|glyph| glyph.c);
+    Ok(Font {
+        line_height: ascent + descent,
+        baseline: ascent,
+        glyphs,
+    })
+}
+
+/// Move the baseline down if glyphs extend above the top of the line, e.g.
+/// accented capitals.
+fn fit(font: &mut Font) {
+    let top = font.glyphs.iter().map(|glyph| glyph.top).max().unwrap_or(0);
+    if top > font.baseline {
+        font.line_height += top - font.baseline;
+        font.baseline = top;
+    }
+}
+
+/// Pack the coverage of the pixels with `bpp` bits per pixel, the first
+/// pixel in the highest bits.
+fn pack(coverage: &[u8], bpp: u32) -> Vec<u8> {
+    let max = (1 << bpp) - 1;
+    let mut bytes = vec![0u8; (coverage.len() * bpp as usize).div_ceil(8)];
+    for (i, alpha) in coverage.iter().enumerate() {
+        let value = ((*alpha as u32 * max + 127) / 255) as u8;
+        let bit = i * bpp as usize;
+        bytes[bit / 8] |= value << (8 - bpp as usize - bit % 8);
+    }
+    bytes
+}
+
+/// Generate the Rust source of a font.
+fn generate(font: &Font, name: &str, bpp: u32, command: &str) -> Result<String, String> {
+    let check = |value: i32, min: i32, max: i32, what: &str, c: char| {
+        if value < min || value > max {
+            Err(format!(
+                "The {} of {:?} ({}) is out of range",
+                what, c, value
+            ))
+        } else {
+            Ok(value)
+        }
+    };
+
+    let mut glyphs = String::new();
+    let mut bitmaps = Vec::new();
+    for glyph in &font.glyphs {
+        let c = glyph.c;
+        writeln!(
+            glyphs,
+            "        Glyph {{\n            c: {:?},\n            advance: {},\n            \
+             left: {},\n            top: {},\n            width: {},\n            \
+             height: {},\n            offset: {},\n        }},",
+            c,
+            check(glyph.advance, 0, 255, "advance", c)?,
+            check(glyph.left, -128, 127, "left side", c)?,
+            check(glyph.top, -128, 127, "top", c)?,
+            check(glyph.width as i32, 0, 255, "width", c)?,
+            check(glyph.height as i32, 0, 255, "height", c)?,
+            bitmaps.len(),
+        )
+        .unwrap();
+        bitmaps.extend(pack(&glyph.coverage, bpp));
+    }
+
+    let mut source = String::new();
+    writeln!(source, "//! Generated with `fontconv {}`.", command).unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "use super::{{Font, Glyph}};").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "pub static {}: Font = Font {{", name).unwrap();
+    writeln!(
+        source,
+        "    line_height: {},",
+        check(font.line_height, 0, 255, "line height", ' ')?
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "    baseline: {},",
+        check(font.baseline, 0, 255, "baseline", ' ')?
+    )
+    .unwrap();
+    writeln!(source, "    bpp: {},", bpp).unwrap();
+    writeln!(source, "    glyphs: &[").unwrap();
+    source.push_str(&glyphs);
+    writeln!(source, "    ],").unwrap();
+    writeln!(source, "    bitmaps: &[").unwrap();
+    for row in bitmaps.chunks(12) {
+        let row: Vec<String> = row.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+        writeln!(source, "        {},", row.join(", ")).unwrap();
+    }
+    writeln!(source, "    ],").unwrap();
+    writeln!(source, "}};").unwrap();
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BDF: &str = "STARTFONT 2.1
+FONT -test-
+SIZE 8 75 75
+FONTBOUNDINGBOX 4 6 0 -1
+FONT_ASCENT 5
+FONT_DESCENT 1
+CHARS 2
+STARTCHAR A
+ENCODING 65
+DWIDTH 5 0
+BBX 4 5 0 0
+BITMAP
+60
+90
+F0
+90
+90
+ENDCHAR
+STARTCHAR ae
+ENCODING 228
+DWIDTH 4 0
+BBX 3 2 1 -1
+BITMAP
+A0
+40
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn bdf() {
+        let chars = parse_range("20-ff").unwrap().collect();
+        let font = parse_bdf(BDF, &chars).unwrap();
+        assert_eq!((font.line_height, font.baseline), (6, 5));
+        assert_eq!(font.glyphs.len(), 2);
+        assert_eq!(
+            font.glyphs[1],
+            Glyph {
+                c: 'ä',
+                advance: 4,
+                left: 1,
+                top: 1,
+                width: 3,
+                height: 2,
+                coverage: vec![255, 0, 255, 0, 255, 0],
+            }
+        );
+
+        // Only the selected characters
+        let chars = "A".chars().collect();
+        let mut font = parse_bdf(BDF, &chars).unwrap();
+        assert_eq!(font.glyphs.len(), 1);
+
+        font.glyphs[0].top = 7;
+        fit(&mut font);
+        assert_eq!((font.line_height, font.baseline), (8, 7));
+    }
+
+    #[test]
+    fn packing() {
+        assert_eq!(pack(&[255, 0, 128, 255, 255], 1), [0b1011_1000]);
+        assert_eq!(pack(&[255, 170, 85, 0, 255], 2), [0b1110_0100, 0b1100_0000]);
+        assert_eq!(pack(&[255, 17, 0], 4), [0xf1, 0x00]);
+        assert_eq!(pack(&[255, 17], 8), [255, 17]);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse_range("41-43").unwrap().collect::<String>(), "ABC");
+        assert!(parse_range("43-41").is_none());
+        assert_eq!(parse_range("20ac").unwrap().collect::<String>(), "€");
+        assert!(parse_range("41-").is_none());
+    }
+}
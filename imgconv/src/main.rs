@@ -0,0 +1,139 @@
+//! Convert PNG images to the image format of the firmware (see
+//! `pinetime_rtic::image`):
+//!
+//! ```text
+//! $ cargo run --release -- ferris.png ../pinetime-rtic/ferris.img
+//! ```
+//!
+//! The format that takes the least space is chosen. Transparent pixels are
+//! blended with a background color, which can be set with
+//! `--background RRGGBB` (black by default).
+
+use std::{env, fs, process};
+
+use pinetime_rtic::image;
+
+const USAGE: &str = "Usage: imgconv [--background RRGGBB] <input.png> <output.img>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut background = [0; 3];
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--background" => {
+                let color = args.next().ok_or(USAGE)?;
+                background = parse_color(color)
+                    .ok_or_else(|| format!("Invalid background color: {}", color))?;
+            }
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => paths.push(arg),
+        }
+    }
+    let (input, output) = match paths.as_slice() {
+        [input, output] => (input, output),
+        _ => return Err(USAGE.into()),
+    };
+
+    let (width, height, pixels) = load(input, background)?;
+    let mut data = Vec::new();
+    let header = image::encode(width, height, &pixels, |bytes| {
+        data.extend_from_slice(bytes)
+    });
+    fs::write(output, &data).map_err(|e| format!("Could not write {}: {}", output, e))?;
+    println!(
+        "{}: {}x{}, {:?}, {:?}, {} bytes ({} bytes uncompressed)",
+        output,
+        width,
+        height,
+        header.format,
+        header.compression,
+        data.len(),
+        pixels.len() * 2
+    );
+    Ok(())
+}
+
+/// Read a PNG and convert its pixels to Rgb565.
+fn load(path: &str, background: [u8; 3]) -> Result<(u16, u16, Vec<u16>), String> {
+    let error = |e: &dyn std::fmt::Display| format!("Could not read {}: {}", path, e);
+    let file = fs::File::open(path).map_err(|e| error(&e))?;
+    let mut decoder = png::Decoder::new(file);
+    // Always get 8 bit grayscale or RGB, with or without alpha
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|e| error(&e))?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).map_err(|e| error(&e))?;
+    if info.width > u16::MAX as u32 || info.height > u16::MAX as u32 {
+        return Err(format!("{} is too large", path));
+    }
+
+    let channels = info.color_type.samples();
+    let pixels = buf[..info.buffer_size()]
+        .chunks(channels)
+        .map(|pixel| {
+            let (color, alpha) = match *pixel {
+                [gray] => ([gray; 3], 255),
+                [gray, alpha] => ([gray; 3], alpha),
+                [r, g, b] => ([r, g, b], 255),
+                [r, g, b, alpha] => ([r, g, b], alpha),
+                _ => unreachable!(),
+            };
+            rgb565(blend(color, alpha, background))
+        })
+        .collect();
+    Ok((info.width as u16, info.height as u16, pixels))
+}
+
+/// Parse a color like `1a2b3c`.
+fn parse_color(color: &str) -> Option<[u8; 3]> {
+    let color = u32::from_str_radix(color.trim_start_matches('#'), 16).ok()?;
+    if color > 0xFF_FFFF {
+        return None;
+    }
+    Some([(color >> 16) as u8, (color >> 8) as u8, color as u8])
+}
+
+/// Blend a color with the background.
+fn blend(color: [u8; 3], alpha: u8, background: [u8; 3]) -> [u8; 3] {
+    let mut blended = [0; 3];
+    for i in 0..3 {
+        let mixed = color[i] as u32 * alpha as u32 + background[i] as u32 * (255 - alpha as u32);
+        blended[i] = ((mixed + 127) / 255) as u8;
+    }
+    blended
+}
+
+fn rgb565([r, g, b]: [u8; 3]) -> u16 {
+    (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors() {
+        assert_eq!(parse_color("ff8000"), Some([0xFF, 0x80, 0x00]));
+        assert_eq!(parse_color("#00ff00"), Some([0, 0xFF, 0]));
+        assert_eq!(parse_color("1000000"), None);
+        assert_eq!(parse_color("red"), None);
+
+        assert_eq!(rgb565([0xFF, 0xFF, 0xFF]), 0xFFFF);
+        assert_eq!(rgb565([0xFF, 0, 0]), 0xF800);
+        assert_eq!(rgb565([0, 0xFF, 0]), 0x07E0);
+        assert_eq!(rgb565([0, 0, 0xFF]), 0x001F);
+
+        assert_eq!(blend([200, 100, 0], 255, [0, 0, 255]), [200, 100, 0]);
+        assert_eq!(blend([200, 100, 0], 0, [0, 0, 255]), [0, 0, 255]);
+        assert_eq!(blend([200, 100, 0], 128, [0, 0, 0]), [100, 50, 0]);
+    }
+}
@@ -0,0 +1,264 @@
+//! Recurring alarms.
+//!
+//! Alarms are driven by the wall clock: once per minute, `Alarms::due` is
+//! asked whether an alarm should ring. This way alarms work for arbitrarily
+//! long time spans and follow changes of the wall clock.
+
+use crate::clock::DateTime;
+
+/// Number of alarms that can be configured.
+pub const MAX_ALARMS: usize = 4;
+
+/// Size of a single encoded alarm in bytes.
+pub const ENCODED_ALARM_LEN: usize = 4;
+
+/// Size of all encoded alarms in bytes.
+pub const ENCODED_LEN: usize = MAX_ALARMS * ENCODED_ALARM_LEN;
+
+/// Snooze duration in minutes.
+pub const SNOOZE_MINUTES: u64 = 9;
+
+/// Weekday masks (bit 0 = Monday, bit 6 = Sunday) that can be cycled through
+/// on the watch. A mask of 0 means that the alarm rings only once.
+pub const WEEKDAY_PRESETS: [u8; 4] = [0, 0b111_1111, 0b001_1111, 0b110_0000];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alarm {
+    pub hour: u8,
+    pub minute: u8,
+    /// Days of the week on which the alarm rings (bit 0 = Monday, bit 6 =
+    /// Sunday). If no bit is set, the alarm rings once and is then disabled.
+    pub weekdays: u8,
+    pub enabled: bool,
+    /// If snoozed, the minute (since 1970-01-01 00:00) at which the alarm
+    /// rings again.
+    snoozed_until: Option<u64>,
+}
+
+impl Alarm {
+    pub const fn new(hour: u8, minute: u8, weekdays: u8) -> Self {
+        Self {
+            hour,
+            minute,
+            weekdays,
+            enabled: false,
+            snoozed_until: None,
+        }
+    }
+
+    /// Return whether the alarm is set to ring at the specified time.
+    fn rings_at(&self, datetime: &DateTime) -> bool {
+        self.enabled
+            && self.hour == datetime.hour
+            && self.minute == datetime.minute
+            && (self.weekdays == 0 || self.weekdays & (1 << datetime.weekday) != 0)
+    }
+
+    /// Move the alarm time by the specified number of minutes, wrapping
+    /// around at midnight.
+    pub fn adjust(&mut self, minutes: i32) {
+        let time = (self.hour as i32 * 60 + self.minute as i32 + minutes).rem_euclid(24 * 60);
+        self.hour = (time / 60) as u8;
+        self.minute = (time % 60) as u8;
+        self.snoozed_until = None;
+    }
+
+    /// Switch to the next weekday preset.
+    pub fn cycle_weekdays(&mut self) {
+        let index = WEEKDAY_PRESETS
+            .iter()
+            .position(|preset| *preset == self.weekdays)
+            .map(|i| (i + 1) % WEEKDAY_PRESETS.len())
+            .unwrap_or(0);
+        self.weekdays = WEEKDAY_PRESETS[index];
+    }
+
+    /// Format the weekday mask as e.g. `MTWTF--`.
+    pub fn format_weekdays<'a>(&self, buf: &'a mut [u8; 7]) -> &'a str {
+        if self.weekdays == 0 {
+            buf.copy_from_slice(b"Once   ");
+        } else {
+            for (i, c) in b"MTWTFSS".iter().enumerate() {
+                buf[i] = if self.weekdays & (1 << i) != 0 {
+                    *c
+                } else {
+                    b'-'
+                };
+            }
+        }
+        core::str::from_utf8(buf).unwrap()
+    }
+}
+
+/// The set of configured alarms.
+pub struct Alarms {
+    alarms: [Alarm; MAX_ALARMS],
+}
+
+impl Alarms {
+    pub const fn new() -> Self {
+        Self {
+            alarms: [
+                Alarm::new(7, 0, 0b001_1111),
+                Alarm::new(9, 0, 0b110_0000),
+                Alarm::new(12, 0, 0),
+                Alarm::new(18, 0, 0),
+            ],
+        }
+    }
+
+    /// Return the alarm with the specified index.
+    pub fn get(&self, index: usize) -> &Alarm {
+        &self.alarms[index]
+    }
+
+    /// Return the alarm with the specified index for modification.
+    pub fn get_mut(&mut self, index: usize) -> &mut Alarm {
+        &mut self.alarms[index]
+    }
+
+    /// Return the index of an alarm that is due at the specified time, if
+    /// any. This should be called once per minute.
+    pub fn due(&self, datetime: &DateTime) -> Option<usize> {
+        let minute = datetime.to_secs().map(|secs| secs / 60);
+        self.alarms.iter().position(|alarm| {
+            alarm.rings_at(datetime) || (minute.is_some() && alarm.snoozed_until == minute)
+        })
+    }
+
+    /// Snooze the ringing alarm with the specified index.
+    pub fn snooze(&mut self, index: usize, datetime: &DateTime) {
+        if let Some(secs) = datetime.to_secs() {
+            self.alarms[index].snoozed_until = Some(secs / 60 + SNOOZE_MINUTES);
+        }
+    }
+
+    /// Dismiss the ringing alarm with the specified index. Alarms that only
+    /// ring once are disabled.
+    pub fn dismiss(&mut self, index: usize) {
+        let alarm = &mut self.alarms[index];
+        alarm.snoozed_until = None;
+        if alarm.weekdays == 0 {
+            alarm.enabled = false;
+        }
+    }
+
+    /// Encode all alarms into bytes, 4 bytes per alarm: hour, minute,
+    /// weekday mask and flags (bit 0 = enabled).
+    pub fn encode(&self) -> [u8; ENCODED_LEN] {
+        let mut buf = [0; ENCODED_LEN];
+        for (alarm, chunk) in self.alarms.iter().zip(buf.chunks_mut(ENCODED_ALARM_LEN)) {
+            chunk[0] = alarm.hour;
+            chunk[1] = alarm.minute;
+            chunk[2] = alarm.weekdays;
+            chunk[3] = alarm.enabled as u8;
+        }
+        buf
+    }
+
+    /// Decode alarms encoded with `encode`. If fewer bytes than
+    /// `ENCODED_LEN` are passed, only the first alarms are replaced. Return
+    /// `false` without changing anything if the data is invalid.
+    pub fn decode(&mut self, data: &[u8]) -> bool {
+        let chunks = data.chunks_exact(ENCODED_ALARM_LEN);
+        if !chunks.remainder().is_empty() || data.len() > ENCODED_LEN {
+            return false;
+        }
+        let valid = data
+            .chunks(ENCODED_ALARM_LEN)
+            .all(|chunk| chunk[0] < 24 && chunk[1] < 60 && chunk[2] < 0x80);
+        if !valid {
+            return false;
+        }
+        for (alarm, chunk) in self.alarms.iter_mut().zip(data.chunks(ENCODED_ALARM_LEN)) {
+            *alarm = Alarm {
+                hour: chunk[0],
+                minute: chunk[1],
+                weekdays: chunk[2],
+                enabled: chunk[3] & 0x01 != 0,
+                snoozed_until: None,
+            };
+        }
+        true
+    }
+}
+
+impl Default for Alarms {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2020-06-15 was a Monday
+    const MONDAY: u64 = 1_592_179_200;
+    const SATURDAY: u64 = MONDAY + 5 * 86_400;
+
+    fn at(day: u64, hour: u64, minute: u64) -> DateTime {
+        DateTime::from_secs(day + hour * 3600 + minute * 60)
+    }
+
+    #[test]
+    fn weekday_recurrence() {
+        let mut alarms = Alarms::new();
+        alarms.get_mut(0).enabled = true; // 07:00 on weekdays
+        assert_eq!(alarms.due(&at(MONDAY, 7, 0)), Some(0));
+        assert_eq!(alarms.due(&at(MONDAY, 7, 1)), None);
+        assert_eq!(alarms.due(&at(SATURDAY, 7, 0)), None);
+        alarms.get_mut(0).enabled = false;
+        assert_eq!(alarms.due(&at(MONDAY, 7, 0)), None);
+    }
+
+    #[test]
+    fn snooze_and_dismiss() {
+        let mut alarms = Alarms::new();
+        alarms.get_mut(2).enabled = true; // 12:00 once
+        assert_eq!(alarms.due(&at(SATURDAY, 12, 0)), Some(2));
+        alarms.snooze(2, &at(SATURDAY, 12, 0));
+        assert_eq!(alarms.due(&at(SATURDAY, 12, 5)), None);
+        assert_eq!(alarms.due(&at(SATURDAY, 12, 9)), Some(2));
+        alarms.dismiss(2);
+        assert!(!alarms.get(2).enabled);
+        assert_eq!(alarms.due(&at(SATURDAY + 86_400, 12, 0)), None);
+    }
+
+    #[test]
+    fn adjust_wraps_around() {
+        let mut alarm = Alarm::new(23, 55, 0);
+        alarm.adjust(10);
+        assert_eq!((alarm.hour, alarm.minute), (0, 5));
+        alarm.adjust(-60);
+        assert_eq!((alarm.hour, alarm.minute), (23, 5));
+    }
+
+    #[test]
+    fn encode_decode() {
+        let mut alarms = Alarms::new();
+        alarms.get_mut(1).enabled = true;
+        let encoded = alarms.encode();
+        assert_eq!(&encoded[4..8], &[9, 0, 0b110_0000, 1]);
+
+        let mut decoded = Alarms::new();
+        assert!(decoded.decode(&encoded));
+        assert_eq!(decoded.get(1), alarms.get(1));
+
+        assert!(decoded.decode(&[6, 30, 0b001_1111, 1]));
+        assert_eq!(decoded.get(0).hour, 6);
+        assert!(decoded.get(0).enabled);
+        assert!(!decoded.decode(&[24, 0, 0, 1]));
+        assert!(!decoded.decode(&[6, 30, 0]));
+    }
+
+    #[test]
+    fn format_weekdays() {
+        let mut buf = [0; 7];
+        assert_eq!(
+            Alarm::new(0, 0, 0b001_1111).format_weekdays(&mut buf),
+            "MTWTF--"
+        );
+        assert_eq!(Alarm::new(0, 0, 0).format_weekdays(&mut buf), "Once   ");
+    }
+}
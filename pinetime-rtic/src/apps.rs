@@ -0,0 +1,59 @@
+//! The apps of the user interface. See `ui` for how they are shown.
+
+use crate::ui::{App, AppId};
+
+pub mod alarms;
+pub mod alert;
+pub mod countdown;
+pub mod launcher;
+pub mod notifications;
+pub mod settings;
+pub mod stopwatch;
+pub mod watch_face;
+
+/// All apps. They keep their state while they aren't shown, e.g. the
+/// stopwatch keeps running.
+pub struct Apps {
+    pub watch_face: watch_face::WatchFace,
+    pub launcher: launcher::Launcher,
+    pub stopwatch: stopwatch::StopwatchApp,
+    pub countdown: countdown::CountdownApp,
+    pub alarms: alarms::AlarmsApp,
+    pub settings: settings::SettingsApp,
+    pub notifications: notifications::NotificationsApp,
+    pub alert: alert::AlertApp,
+}
+
+impl Apps {
+    pub const fn new() -> Self {
+        Self {
+            watch_face: watch_face::WatchFace::new(),
+            launcher: launcher::Launcher::new(),
+            stopwatch: stopwatch::StopwatchApp::new(),
+            countdown: countdown::CountdownApp::new(),
+            alarms: alarms::AlarmsApp::new(),
+            settings: settings::SettingsApp::new(),
+            notifications: notifications::NotificationsApp::new(),
+            alert: alert::AlertApp::new(),
+        }
+    }
+
+    pub fn get(&mut self, id: AppId) -> &mut dyn App {
+        match id {
+            AppId::WatchFace => &mut self.watch_face,
+            AppId::Launcher => &mut self.launcher,
+            AppId::Stopwatch => &mut self.stopwatch,
+            AppId::Countdown => &mut self.countdown,
+            AppId::Alarms => &mut self.alarms,
+            AppId::Settings => &mut self.settings,
+            AppId::Notifications => &mut self.notifications,
+            AppId::Alert => &mut self.alert,
+        }
+    }
+}
+
+impl Default for Apps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,115 @@
+//! The alarms app. A press selects the next alarm, a long press or a tap
+//! switches it on or off. Swiping up and down moves the selected alarm by
+//! five minutes, swiping right and left by an hour. A long touch cycles
+//! through the weekday presets.
+
+use embedded_graphics::fonts::Text;
+use embedded_graphics::prelude::*;
+
+use crate::alarm::{self, Alarm};
+use crate::button::Gesture;
+use crate::clock;
+use crate::touch::TouchGesture;
+use crate::ui::{self, Action, App, Context, Input, BACKGROUND_COLOR};
+
+pub struct AlarmsApp {
+    /// Index of the selected alarm.
+    pub selected: usize,
+    /// The alarms that are shown, and whether they are selected.
+    rows_shown: [Option<(Alarm, bool)>; alarm::MAX_ALARMS],
+    /// Whether the clock was set when the hint was shown.
+    clock_set_shown: Option<bool>,
+}
+
+impl AlarmsApp {
+    pub const fn new() -> Self {
+        Self {
+            selected: 0,
+            rows_shown: [None; alarm::MAX_ALARMS],
+            clock_set_shown: None,
+        }
+    }
+}
+
+impl Default for AlarmsApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for AlarmsApp {
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        let alarm = cx.env.alarms.get_mut(self.selected);
+        match input {
+            Input::Button(event) => match event.gesture {
+                Gesture::Press => {
+                    self.selected = (self.selected + 1) % alarm::MAX_ALARMS;
+                    return Action::None;
+                }
+                Gesture::LongPress => alarm.enabled = !alarm.enabled,
+                Gesture::DoublePress => return Action::None,
+            },
+            Input::Touch(gesture) => match gesture {
+                TouchGesture::SlideUp => alarm.adjust(5),
+                TouchGesture::SlideDown => alarm.adjust(-5),
+                TouchGesture::SlideRight => alarm.adjust(60),
+                TouchGesture::SlideLeft => alarm.adjust(-60),
+                TouchGesture::SingleTap => alarm.enabled = !alarm.enabled,
+                TouchGesture::LongPress => alarm.cycle_weekdays(),
+                _ => return Action::None,
+            },
+        }
+        cx.effects.alarms_changed = true;
+        Action::None
+    }
+
+    /// Redraw the alarms that changed, e.g. over BLE. Alarms only ring once
+    /// the wall clock is set, which is pointed out until it is.
+    fn tick(&mut self, cx: &mut Context) {
+        let text_style = ui::text_style(BACKGROUND_COLOR);
+        for index in 0..alarm::MAX_ALARMS {
+            let alarm = *cx.env.alarms.get(index);
+            let row = (alarm, index == self.selected);
+            if self.rows_shown[index] == Some(row) {
+                continue;
+            }
+
+            // Format as "> HH:MM MTWTF-- on "
+            let mut line = [b' '; 19];
+            if row.1 {
+                line[0] = b'>';
+            }
+            let mut time_buf = [0u8; 5];
+            let time = clock::format_hm(alarm.hour, alarm.minute, &mut time_buf);
+            line[2..7].copy_from_slice(time.as_bytes());
+            let mut weekdays_buf = [0u8; 7];
+            line[8..15].copy_from_slice(alarm.format_weekdays(&mut weekdays_buf).as_bytes());
+            line[16..19].copy_from_slice(if alarm.enabled { b"on " } else { b"off" });
+            cx.draw_text(
+                Text::new(
+                    core::str::from_utf8(&line).unwrap(),
+                    Point::new(6, 50 + 36 * index as i32),
+                )
+                .into_styled(text_style),
+            );
+            self.rows_shown[index] = Some(row);
+        }
+
+        let clock_set = cx.env.wall_clock.is_set();
+        if self.clock_set_shown != Some(clock_set) {
+            let hint = if clock_set {
+                "             "
+            } else {
+                "Clock not set"
+            };
+            cx.draw_text(Text::new(hint, Point::new(42, 200)).into_styled(text_style));
+            self.clock_set_shown = Some(clock_set);
+        }
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Alarms");
+        self.rows_shown = [None; alarm::MAX_ALARMS];
+        self.clock_set_shown = None;
+    }
+}
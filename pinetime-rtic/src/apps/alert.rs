@@ -0,0 +1,107 @@
+//! Alerts, shown as a banner on top of the app below. Any gesture ends an
+//! alert. A ringing alarm is dismissed by a long press (or long touch) and
+//! snoozed by any other gesture. Received notifications pop up full screen.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{
+    fonts::Text, pixelcolor::Rgb565, primitives::Rectangle, style::PrimitiveStyleBuilder,
+};
+
+use crate::apps::notifications;
+use crate::button::Gesture;
+use crate::clock;
+use crate::damage::Rect;
+use crate::touch::TouchGesture;
+use crate::ui::{self, Action, Alert, App, Context, Input, LCD_W};
+
+pub struct AlertApp {
+    /// The alert that is shown.
+    pub alert: Option<Alert>,
+}
+
+impl AlertApp {
+    pub const fn new() -> Self {
+        Self { alert: None }
+    }
+}
+
+impl Default for AlertApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for AlertApp {
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        let dismiss = match input {
+            Input::Button(event) => event.gesture == Gesture::LongPress,
+            Input::Touch(gesture) => gesture == TouchGesture::LongPress,
+        };
+        if let Some(Alert::Alarm(index)) = self.alert.take() {
+            let alarms = &mut *cx.env.alarms;
+            if dismiss {
+                alarms.dismiss(index);
+            } else {
+                alarms.snooze(index, &cx.env.wall_clock.datetime(cx.env.now));
+            }
+            cx.effects.alarms_changed = true;
+        }
+        cx.effects.stop_vibration = true;
+        Action::Pop
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        // "ALARM HH:MM" or "ALARM H:MM AM"
+        let mut title = *b"ALARM         ";
+        let (top, bottom, lines) = match self.alert {
+            Some(Alert::Alarm(index)) => {
+                let alarm = cx.env.alarms.get(index);
+                let mut time_buf = [0u8; 5];
+                let (time, suffix) = clock::format_time(
+                    alarm.hour,
+                    alarm.minute,
+                    cx.env.settings.clock_12h,
+                    &mut time_buf,
+                );
+                let mut len = 6 + time.len();
+                title[6..len].copy_from_slice(time.as_bytes());
+                if let Some(suffix) = suffix {
+                    title[len + 1..len + 3].copy_from_slice(suffix.as_bytes());
+                    len += 3;
+                }
+                let title = core::str::from_utf8(&title[..len]).unwrap();
+                (
+                    70,
+                    170,
+                    [(title, 82), ("Press: snooze", 114), ("Hold: dismiss", 138)],
+                )
+            }
+            Some(Alert::Countdown) => (90, 150, [("TIME'S UP", 112), ("", 0), ("", 0)]),
+            Some(Alert::Notification) => {
+                if let Some(&notification) = cx.env.notifications.get(0) {
+                    notifications::draw_notification(cx, &notification);
+                }
+                return;
+            }
+            None => return,
+        };
+
+        let banner_style = PrimitiveStyleBuilder::new().fill_color(Rgb565::RED).build();
+        let text_style = ui::text_style(Rgb565::RED);
+        let banner = Rect::new(0, top, LCD_W, bottom - top + 1);
+        cx.draw_area(banner, |strip| {
+            Rectangle::new(
+                Point::new(0, top as i32),
+                Point::new(LCD_W as i32, bottom as i32),
+            )
+            .into_styled(banner_style)
+            .draw(strip)
+            .unwrap();
+            for (line, y) in lines.iter().filter(|(line, _)| !line.is_empty()) {
+                let text = Text::new(line, Point::zero()).into_styled(text_style);
+                let translation = Point::new((LCD_W as i32 - text.size().width as i32) / 2, *y);
+                text.translate(translation).draw(strip).unwrap();
+            }
+        });
+    }
+}
@@ -0,0 +1,118 @@
+//! The countdown app. A press or tap starts and pauses the countdown. While
+//! it's stopped, the duration is adjusted by swiping up and down (minutes)
+//! or right and left (seconds), or with long presses, which step through the
+//! durations one minute at a time. A long press resets a started countdown.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{
+    fonts::{Font24x32, Text},
+    pixelcolor::Rgb565,
+    style::TextStyleBuilder,
+};
+
+use crate::button::Gesture;
+use crate::countdown::{self, Countdown};
+use crate::touch::TouchGesture;
+use crate::ui::{self, Action, App, Context, Input, BACKGROUND_COLOR};
+
+/// Initial countdown duration in seconds.
+const DEFAULT_SECS: u32 = 5 * 60;
+
+pub struct CountdownApp {
+    pub countdown: Countdown,
+    /// The remaining seconds that are shown.
+    secs_shown: Option<u32>,
+    state_shown: Option<&'static str>,
+}
+
+impl CountdownApp {
+    pub const fn new() -> Self {
+        Self {
+            countdown: Countdown::new(DEFAULT_SECS),
+            secs_shown: None,
+            state_shown: None,
+        }
+    }
+}
+
+impl Default for CountdownApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for CountdownApp {
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        let countdown = &mut self.countdown;
+        match input {
+            Input::Button(event) => match event.gesture {
+                Gesture::Press => cx.effects.countdown_expiry = countdown.toggle(event.at),
+                Gesture::LongPress if countdown.is_stopped() => {
+                    // Step through the durations one minute at a time
+                    if !countdown.adjust(60) {
+                        countdown.adjust(60 - countdown::MAX_SECS as i32);
+                    }
+                }
+                Gesture::LongPress => countdown.reset(),
+                Gesture::DoublePress => {}
+            },
+            Input::Touch(gesture) => match gesture {
+                TouchGesture::SingleTap => {
+                    cx.effects.countdown_expiry = countdown.toggle(cx.env.now)
+                }
+                TouchGesture::SlideUp => {
+                    countdown.adjust(60);
+                }
+                TouchGesture::SlideDown => {
+                    countdown.adjust(-60);
+                }
+                TouchGesture::SlideRight => {
+                    countdown.adjust(10);
+                }
+                TouchGesture::SlideLeft => {
+                    countdown.adjust(-10);
+                }
+                _ => {}
+            },
+        }
+        Action::None
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        let countdown = &self.countdown;
+
+        // Show remaining time
+        let secs = countdown.remaining_secs(cx.now());
+        if self.secs_shown != Some(secs) {
+            let mut buf = [0u8; 5];
+            let time = countdown::format_secs(secs, &mut buf);
+            let time_style = TextStyleBuilder::new(Font24x32)
+                .text_color(Rgb565::WHITE)
+                .background_color(BACKGROUND_COLOR)
+                .build();
+            cx.draw_text(Text::new(time, Point::new(60, 80)).into_styled(time_style));
+            self.secs_shown = Some(secs);
+        }
+
+        // Show state
+        let state = if countdown.is_running() {
+            "Running"
+        } else if countdown.is_stopped() {
+            "Stopped"
+        } else {
+            "Paused "
+        };
+        if self.state_shown != Some(state) {
+            cx.draw_text(
+                Text::new(state, Point::new(78, 140)).into_styled(ui::text_style(BACKGROUND_COLOR)),
+            );
+            self.state_shown = Some(state);
+        }
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Timer");
+        self.secs_shown = None;
+        self.state_shown = None;
+    }
+}
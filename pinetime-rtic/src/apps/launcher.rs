@@ -0,0 +1,107 @@
+//! The launcher, opened with a double press on the watch face. The apps are
+//! shown in a list: a press selects the next app, a long press or a tap
+//! opens it.
+
+use crate::list::{Items, List};
+use crate::ui::{Action, App, AppId, Context, Input, MARGIN};
+
+/// The apps that can be opened, with their icons and names.
+const ENTRIES: &[(char, &str, AppId)] = &[
+    ('↑', "Stopwatch", AppId::Stopwatch),
+    ('↓', "Timer", AppId::Countdown),
+    ('☎', "Alarms", AppId::Alarms),
+    ('★', "Settings", AppId::Settings),
+    ('☺', "Notifications", AppId::Notifications),
+];
+
+struct Entries;
+
+impl Items for Entries {
+    fn count(&self) -> usize {
+        ENTRIES.len()
+    }
+
+    fn label(&self, index: usize) -> &str {
+        ENTRIES[index].1
+    }
+
+    fn icon(&self, index: usize) -> Option<char> {
+        Some(ENTRIES[index].0)
+    }
+}
+
+pub struct Launcher {
+    list: List,
+}
+
+impl Launcher {
+    pub const fn new() -> Self {
+        Self {
+            list: List::new(3 * MARGIN),
+        }
+    }
+}
+
+impl Default for Launcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for Launcher {
+    fn input(&mut self, input: Input, _cx: &mut Context) -> Action {
+        match self.list.input(input, ENTRIES.len()) {
+            Some(index) => Action::Push(ENTRIES[index].2),
+            None => Action::None,
+        }
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        self.list.tick(cx, &Entries);
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Apps");
+        self.list.invalidate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Gesture;
+    use crate::damage::Rect;
+    use crate::list::ROW_H;
+    use crate::ui::tests::TestEnv;
+    use crate::ui::{Event, Ui, LCD_W};
+
+    #[test]
+    fn redraw_selection() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::Launcher);
+
+        // Only the rows whose selection changed are redrawn
+        env.press(&mut ui, Gesture::Press);
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        let rows = Rect::new(0, 3 * MARGIN, LCD_W, 2 * ROW_H);
+        assert!(env
+            .panel
+            .0
+            .iter()
+            .all(|area| rows.intersection(area) == Some(*area)));
+        let pixels: u32 = env.panel.0.iter().map(Rect::area).sum();
+        assert_eq!(pixels, rows.area());
+
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        assert!(env.panel.0.is_empty());
+
+        // The third app is opened
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Alarms);
+    }
+}
@@ -0,0 +1,375 @@
+//! The notifications app: the notifications that were received, newest
+//! first. A press selects the next one, a long press or a tap opens it, and
+//! any gesture closes it again.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{
+    fonts::Text, pixelcolor::Rgb565, primitives::Rectangle, style::PrimitiveStyleBuilder,
+};
+
+use crate::clock::{self, DateTime};
+use crate::damage::Rect;
+use crate::font;
+use crate::list::{Items, List, HIGHLIGHT_COLOR, VALUE_LEN};
+use crate::notification::{self, Category, Notification, MAX_NOTIFICATIONS};
+use crate::ui::{self, Action, App, Context, Input, BACKGROUND_COLOR, LCD_H, LCD_W, MARGIN};
+
+/// Height of the header with the app name and the time.
+const HEADER_H: u16 = 40;
+
+/// Maximum number of characters of a title in the list. Longer ones are
+/// shortened with "...".
+const LABEL_CHARS: usize = 16;
+
+/// Return the icon for a category, a character of `font::SYMBOLS_16`.
+pub fn icon(category: Category) -> Option<char> {
+    match category {
+        Category::IncomingCall | Category::MissedCall | Category::Voicemail => Some('☎'),
+        Category::Social => Some('☺'),
+        Category::Schedule => Some('★'),
+        Category::HealthAndFitness => Some('♥'),
+        Category::Entertainment => Some('😀'),
+        _ => None,
+    }
+}
+
+/// Format the time of a notification, `None` if it is unknown.
+fn format_timestamp(timestamp: u32, clock_12h: bool, buf: &mut [u8; VALUE_LEN]) -> Option<&str> {
+    if timestamp == 0 {
+        return None;
+    }
+    let datetime = DateTime::from_secs(timestamp as u64);
+    let mut time_buf = [0u8; 5];
+    let (time, suffix) =
+        clock::format_time(datetime.hour, datetime.minute, clock_12h, &mut time_buf);
+    let mut len = time.len();
+    buf[..len].copy_from_slice(time.as_bytes());
+    if let Some(suffix) = suffix {
+        buf[len] = b' ';
+        buf[len + 1..len + 3].copy_from_slice(suffix.as_bytes());
+        len += 3;
+    }
+    Some(core::str::from_utf8(&buf[..len]).unwrap())
+}
+
+/// Draw a notification full screen: the app name and the time in the
+/// header, then the title and the body, wrapped.
+pub fn draw_notification(cx: &mut Context, notification: &Notification) {
+    let header_style = font::TextStyle {
+        fonts: &[&font::TEXT_16, &font::SYMBOLS_16],
+        color: Rgb565::WHITE,
+        background: HIGHLIGHT_COLOR,
+    };
+    let style = font::TextStyle {
+        background: BACKGROUND_COLOR,
+        ..header_style
+    };
+    let line_height = style.line_height() as i32;
+    let header_y = (HEADER_H as i32 - line_height) / 2;
+
+    let mut icon_buf = [0u8; 4];
+    let icon = icon(notification.category).map(|icon| &*icon.encode_utf8(&mut icon_buf));
+    let app_x = MARGIN as i32 + if icon.is_some() { 24 } else { 0 };
+    let mut time_buf = [0u8; VALUE_LEN];
+    let time = format_timestamp(
+        notification.timestamp,
+        cx.env.settings.clock_12h,
+        &mut time_buf,
+    );
+
+    let width = (LCD_W - 2 * MARGIN) as u32;
+    let title = font::TextBox::new(
+        notification.title(),
+        Point::new(MARGIN as i32, HEADER_H as i32 + 8),
+        Size::new(width, 2 * line_height as u32),
+        style,
+    );
+    let title_rows = title.lines().take(2).count() as i32;
+    let body_y = HEADER_H as i32 + 8 + title_rows * line_height + 8;
+    let body = font::TextBox::new(
+        notification.body(),
+        Point::new(MARGIN as i32, body_y),
+        Size::new(width, (LCD_H as i32 - MARGIN as i32 - body_y) as u32),
+        style,
+    );
+
+    let header = PrimitiveStyleBuilder::new()
+        .fill_color(HIGHLIGHT_COLOR)
+        .build();
+    cx.draw_area(Rect::new(0, 0, LCD_W, LCD_H), |strip| {
+        Rectangle::new(
+            Point::zero(),
+            Point::new(LCD_W as i32 - 1, HEADER_H as i32 - 1),
+        )
+        .into_styled(header)
+        .draw(strip)
+        .unwrap();
+        if let Some(icon) = icon {
+            let icon_style = font::TextStyle {
+                fonts: &[&font::SYMBOLS_16],
+                ..header_style
+            };
+            font::Text::new(icon, Point::new(MARGIN as i32, header_y), icon_style)
+                .draw(strip)
+                .unwrap();
+        }
+        font::Text::new(
+            notification.app(),
+            Point::new(app_x, header_y),
+            header_style,
+        )
+        .draw(strip)
+        .unwrap();
+        if let Some(time) = time {
+            let x = (LCD_W - MARGIN) as i32 - header_style.text_width(time) as i32;
+            font::Text::new(time, Point::new(x, header_y), header_style)
+                .draw(strip)
+                .unwrap();
+        }
+        title.draw(strip).unwrap();
+        body.draw(strip).unwrap();
+    });
+}
+
+/// Copy `text` into `buf`, shortened to `LABEL_CHARS` characters with
+/// "..." if it is longer. Return the length in bytes.
+fn shorten(text: &str, buf: &mut [u8; 4 * LABEL_CHARS]) -> usize {
+    let (text, ellipsis) = match text.char_indices().nth(LABEL_CHARS) {
+        Some(_) => {
+            let (end, _) = text.char_indices().nth(LABEL_CHARS - 3).unwrap();
+            (&text[..end], "...")
+        }
+        None => (text, ""),
+    };
+    let len = text.len() + ellipsis.len();
+    buf[..text.len()].copy_from_slice(text.as_bytes());
+    buf[text.len()..len].copy_from_slice(ellipsis.as_bytes());
+    len
+}
+
+/// A row of the list.
+#[derive(Clone, Copy)]
+struct Row {
+    icon: Option<char>,
+    /// The title, or the app name if there is no title.
+    label: [u8; 4 * LABEL_CHARS],
+    label_len: usize,
+    timestamp: u32,
+}
+
+/// The rows of the list. They are copied from the notifications, which
+/// can't be borrowed while the list is drawn.
+struct Rows {
+    rows: [Row; MAX_NOTIFICATIONS],
+    count: usize,
+    clock_12h: bool,
+}
+
+impl Rows {
+    fn new(notifications: &notification::Notifications, clock_12h: bool) -> Self {
+        let mut rows = Self {
+            rows: [Row {
+                icon: None,
+                label: [0; 4 * LABEL_CHARS],
+                label_len: 0,
+                timestamp: 0,
+            }; MAX_NOTIFICATIONS],
+            count: notifications.len(),
+            clock_12h,
+        };
+        for (index, row) in rows.rows.iter_mut().enumerate() {
+            let notification = match notifications.get(index) {
+                Some(notification) => notification,
+                None => break,
+            };
+            let label = match notification.title() {
+                "" => notification.app(),
+                title => title,
+            };
+            row.label_len = shorten(label, &mut row.label);
+            row.icon = icon(notification.category);
+            row.timestamp = notification.timestamp;
+        }
+        rows
+    }
+}
+
+impl Items for Rows {
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn label(&self, index: usize) -> &str {
+        let row = &self.rows[index];
+        core::str::from_utf8(&row.label[..row.label_len]).unwrap()
+    }
+
+    fn icon(&self, index: usize) -> Option<char> {
+        self.rows[index].icon
+    }
+
+    fn value<'a>(&'a self, index: usize, buf: &'a mut [u8; VALUE_LEN]) -> Option<&'a str> {
+        format_timestamp(self.rows[index].timestamp, self.clock_12h, buf)
+    }
+}
+
+pub struct NotificationsApp {
+    list: List,
+    /// The notification that is open, 0 being the newest one.
+    open: Option<usize>,
+    /// Number of notifications in the list.
+    count_shown: Option<usize>,
+}
+
+impl NotificationsApp {
+    pub const fn new() -> Self {
+        Self {
+            list: List::new(3 * MARGIN),
+            open: None,
+            count_shown: None,
+        }
+    }
+}
+
+impl Default for NotificationsApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for NotificationsApp {
+    fn enter(&mut self, _cx: &mut Context) {
+        self.open = None;
+    }
+
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        if self.open.take().is_some() {
+            self.draw(cx);
+            return Action::None;
+        }
+        let count = cx.env.notifications.len();
+        if let Some(index) = self.list.input(input, count) {
+            if let Some(&notification) = cx.env.notifications.get(index) {
+                self.open = Some(index);
+                draw_notification(cx, &notification);
+            }
+        }
+        Action::None
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        if self.open.is_some() {
+            return;
+        }
+        let rows = Rows::new(cx.env.notifications, cx.env.settings.clock_12h);
+        self.list.tick(cx, &rows);
+        // Drawn after the list, which clears its area when the count changes
+        if rows.count == 0 && self.count_shown != Some(0) {
+            cx.draw_text(
+                Text::new("No notifications", Point::new(24, 110))
+                    .into_styled(ui::text_style(BACKGROUND_COLOR)),
+            );
+        }
+        self.count_shown = Some(rows.count);
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Notifications");
+        self.list.invalidate();
+        self.count_shown = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Gesture;
+    use crate::touch::TouchGesture;
+    use crate::ui::tests::TestEnv;
+    use crate::ui::{Alert, AppId, Event, Ui};
+
+    fn notify(env: &mut TestEnv, ui: &mut Ui, title: &str) -> ui::Effects {
+        let notification = Notification::new(Category::Social, 0, "Chat", title, "Hi");
+        env.notifications.push(notification);
+        env.handle(ui, Event::Notification)
+    }
+
+    #[test]
+    fn labels() {
+        let mut buf = [0; 4 * LABEL_CHARS];
+        let len = shorten("Meeting at the usual place", &mut buf);
+        assert_eq!(&buf[..len], b"Meeting at th...");
+        let len = shorten("Grüße", &mut buf);
+        assert_eq!(&buf[..len], "Grüße".as_bytes());
+
+        let mut buf = [0; VALUE_LEN];
+        assert_eq!(format_timestamp(0, false, &mut buf), None);
+        // 2020-09-13 12:26:40
+        assert_eq!(
+            format_timestamp(1_600_000_000, true, &mut buf),
+            Some("12:26 PM")
+        );
+    }
+
+    #[test]
+    fn pop_up() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.handle(&mut ui, Event::Tick);
+
+        let effects = notify(&mut env, &mut ui, "Ferris");
+        assert_eq!(effects.alert, Some(Alert::Notification));
+        assert_eq!(ui.alert(), Some(Alert::Notification));
+        // A second notification replaces the first one
+        notify(&mut env, &mut ui, "Ferris again");
+        let effects = env.press(&mut ui, Gesture::Press);
+        assert!(effects.stop_vibration);
+        assert_eq!(ui.top(), AppId::WatchFace);
+
+        // A ringing alarm isn't covered
+        env.handle(&mut ui, Event::AlarmRinging(0));
+        let effects = notify(&mut env, &mut ui, "Ferris");
+        assert_eq!(effects.alert, None);
+        assert_eq!(ui.alert(), Some(Alert::Alarm(0)));
+        assert_eq!(env.notifications.len(), 3);
+    }
+
+    #[test]
+    fn open_and_close() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.press(&mut ui, Gesture::DoublePress);
+        for _ in 0..4 {
+            env.press(&mut ui, Gesture::Press);
+        }
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Notifications);
+
+        // Nothing to open yet
+        env.panel.0.clear();
+        env.press(&mut ui, Gesture::LongPress);
+        assert!(env.panel.0.is_empty());
+
+        env.notifications
+            .push(Notification::new(Category::Email, 0, "Mail", "Old", ""));
+        env.notifications
+            .push(Notification::new(Category::Email, 0, "Mail", "New", ""));
+        env.handle(&mut ui, Event::Tick);
+        env.press(&mut ui, Gesture::Press);
+        env.panel.0.clear();
+        env.touch(&mut ui, TouchGesture::SingleTap);
+        let pixels: u32 = env.panel.0.iter().map(Rect::area).sum();
+        assert_eq!(pixels, LCD_W as u32 * LCD_H as u32);
+        // The list isn't drawn over it
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        assert!(env.panel.0.is_empty());
+
+        // Any gesture goes back to the list
+        env.touch(&mut ui, TouchGesture::SlideUp);
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        assert!(!env.panel.0.is_empty());
+        assert_eq!(ui.top(), AppId::Notifications);
+    }
+}
@@ -0,0 +1,225 @@
+//! The settings app. A press selects the next row, a long press or a tap
+//! changes the selected setting to its next value. Swiping scrolls. The last
+//! rows show information about the watch.
+
+use numtoa::NumToA;
+
+use crate::list::{Items, List, VALUE_LEN};
+use crate::settings::{Settings, SCREEN_TIMEOUTS, VIBRATION_LEVELS};
+use crate::ui::{Action, App, Battery, Context, Input, MARGIN};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Row {
+    Brightness,
+    ScreenTimeout,
+    TimeFormat,
+    Vibration,
+    Firmware,
+    Address,
+    Battery,
+}
+
+const ROWS: &[(Row, &str)] = &[
+    (Row::Brightness, "Brightness"),
+    (Row::ScreenTimeout, "Screen timeout"),
+    (Row::TimeFormat, "Time format"),
+    (Row::Vibration, "Vibration"),
+    (Row::Firmware, "Firmware"),
+    (Row::Address, "Address"),
+    (Row::Battery, "Battery"),
+];
+
+/// The rows with the values that are shown.
+struct Rows {
+    settings: Settings,
+    ble_address: [u8; 6],
+    battery: Battery,
+}
+
+impl Items for Rows {
+    fn count(&self) -> usize {
+        ROWS.len()
+    }
+
+    fn label(&self, index: usize) -> &str {
+        ROWS[index].1
+    }
+
+    fn value<'a>(&'a self, index: usize, buf: &'a mut [u8; VALUE_LEN]) -> Option<&'a str> {
+        let settings = &self.settings;
+        let value = match ROWS[index].0 {
+            Row::Brightness => format_number(settings.brightness, "", buf),
+            Row::ScreenTimeout if settings.screen_timeout == 0 => "Never",
+            Row::ScreenTimeout => format_number(settings.screen_timeout, " s", buf),
+            Row::TimeFormat if settings.clock_12h => "12h",
+            Row::TimeFormat => "24h",
+            Row::Vibration => format_number(settings.vibration, "%", buf),
+            Row::Firmware => env!("CARGO_PKG_VERSION"),
+            Row::Address => format_address(&self.ble_address, buf),
+            Row::Battery => format_battery(self.battery, buf),
+        };
+        Some(value)
+    }
+}
+
+/// Format a number followed by a unit.
+fn format_number<'a>(number: u8, unit: &str, buf: &'a mut [u8; VALUE_LEN]) -> &'a str {
+    let mut digits = [0u8; 3];
+    let digits = number.numtoa(10, &mut digits);
+    let len = digits.len() + unit.len();
+    buf[..digits.len()].copy_from_slice(digits);
+    buf[digits.len()..len].copy_from_slice(unit.as_bytes());
+    core::str::from_utf8(&buf[..len]).unwrap()
+}
+
+/// Format a BLE device address as `AB:CD:EF:01:23:45`, most significant byte
+/// first.
+fn format_address<'a>(address: &[u8; 6], buf: &'a mut [u8; VALUE_LEN]) -> &'a str {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for (i, byte) in address.iter().rev().enumerate() {
+        buf[3 * i] = HEX[(byte >> 4) as usize];
+        buf[3 * i + 1] = HEX[(byte & 0xF) as usize];
+        buf[3 * i + 2] = b':';
+    }
+    core::str::from_utf8(&buf[..17]).unwrap()
+}
+
+/// Format the battery status as `3.9 V` or `3.9 V, charging`.
+fn format_battery(battery: Battery, buf: &mut [u8; VALUE_LEN]) -> &str {
+    buf[0] = b'0' + battery.voltage / 10 % 10;
+    buf[1] = b'.';
+    buf[2] = b'0' + battery.voltage % 10;
+    buf[3..5].copy_from_slice(b" V");
+    let mut len = 5;
+    if battery.charging {
+        buf[5..15].copy_from_slice(b", charging");
+        len = 15;
+    }
+    core::str::from_utf8(&buf[..len]).unwrap()
+}
+
+/// Return the value that follows `current`, or the first one if `current`
+/// isn't one of them.
+fn next<T: Copy + PartialEq>(values: &[T], current: T) -> T {
+    let index = values
+        .iter()
+        .position(|value| *value == current)
+        .map_or(0, |index| (index + 1) % values.len());
+    values[index]
+}
+
+pub struct SettingsApp {
+    list: List,
+    battery_shown: Option<Battery>,
+}
+
+impl SettingsApp {
+    pub const fn new() -> Self {
+        Self {
+            list: List::new(3 * MARGIN),
+            battery_shown: None,
+        }
+    }
+}
+
+impl Default for SettingsApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for SettingsApp {
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        let index = match self.list.input(input, ROWS.len()) {
+            Some(index) => index,
+            None => return Action::None,
+        };
+        let settings = &mut *cx.env.settings;
+        match ROWS[index].0 {
+            Row::Brightness => settings.brightness = settings.brightness % 7 + 1,
+            Row::ScreenTimeout => {
+                settings.screen_timeout = next(SCREEN_TIMEOUTS, settings.screen_timeout)
+            }
+            Row::TimeFormat => settings.clock_12h = !settings.clock_12h,
+            Row::Vibration => settings.vibration = next(VIBRATION_LEVELS, settings.vibration),
+            Row::Firmware | Row::Address | Row::Battery => return Action::None,
+        }
+        self.list.changed(index);
+        cx.effects.settings_changed = true;
+        Action::None
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        let battery = cx.env.battery;
+        if self.battery_shown != Some(battery) {
+            let index = ROWS.iter().position(|(row, _)| *row == Row::Battery);
+            self.list.changed(index.unwrap());
+            self.battery_shown = Some(battery);
+        }
+        let rows = Rows {
+            settings: *cx.env.settings,
+            ble_address: cx.env.ble_address,
+            battery,
+        };
+        self.list.tick(cx, &rows);
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Settings");
+        self.list.invalidate();
+        self.battery_shown = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Gesture;
+    use crate::ui::tests::TestEnv;
+    use crate::ui::{AppId, Ui};
+
+    #[test]
+    fn values() {
+        let mut buf = [0; VALUE_LEN];
+        assert_eq!(format_number(15, " s", &mut buf), "15 s");
+        let address = [0x45, 0x23, 0x01, 0xEF, 0xCD, 0xAB];
+        assert_eq!(format_address(&address, &mut buf), "AB:CD:EF:01:23:45");
+        let battery = Battery {
+            voltage: 39,
+            charging: true,
+        };
+        assert_eq!(format_battery(battery, &mut buf), "3.9 V, charging");
+        assert_eq!(next(SCREEN_TIMEOUTS, 60), 0);
+        assert_eq!(next(VIBRATION_LEVELS, 33), VIBRATION_LEVELS[0]);
+    }
+
+    #[test]
+    fn change_settings() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.press(&mut ui, Gesture::DoublePress);
+        for _ in 0..3 {
+            env.press(&mut ui, Gesture::Press);
+        }
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Settings);
+
+        let effects = env.press(&mut ui, Gesture::LongPress);
+        assert!(effects.settings_changed);
+        assert_eq!(env.settings.brightness, 2);
+
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(env.settings.screen_timeout, 10);
+
+        // The information rows can't be changed
+        for _ in 0..5 {
+            env.press(&mut ui, Gesture::Press);
+        }
+        let settings = env.settings;
+        let effects = env.press(&mut ui, Gesture::LongPress);
+        assert!(!effects.settings_changed);
+        assert_eq!(env.settings, settings);
+    }
+}
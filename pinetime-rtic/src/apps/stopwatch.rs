@@ -0,0 +1,174 @@
+//! The stopwatch app. A press starts and stops the stopwatch, a long press
+//! takes a lap while it's running and resets it otherwise. The laps are
+//! shown in a list below the elapsed time, newest first, and scrolled by
+//! swiping.
+
+use core::convert::TryInto;
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{
+    fonts::{Font24x32, Text},
+    pixelcolor::Rgb565,
+    style::TextStyleBuilder,
+};
+
+use crate::button::Gesture;
+use crate::list::{Items, List, VALUE_LEN};
+use crate::stopwatch::{self, Stopwatch, MAX_LAPS};
+use crate::ui::{Action, App, Context, Input, BACKGROUND_COLOR};
+
+/// Top of the lap list, below the elapsed time.
+const LAPS_TOP: u16 = 100;
+
+/// The labels of the laps, `#01` to `#99`.
+static LAP_LABELS: [[u8; 3]; MAX_LAPS] = lap_labels();
+
+const fn lap_labels() -> [[u8; 3]; MAX_LAPS] {
+    let mut labels = [[0; 3]; MAX_LAPS];
+    let mut lap = 0;
+    while lap < MAX_LAPS {
+        let number = lap + 1;
+        labels[lap] = [b'#', b'0' + (number / 10) as u8, b'0' + (number % 10) as u8];
+        lap += 1;
+    }
+    labels
+}
+
+/// The laps, newest first.
+struct Laps<'a>(&'a Stopwatch);
+
+impl Laps<'_> {
+    fn lap(&self, index: usize) -> usize {
+        self.0.lap_count() - 1 - index
+    }
+}
+
+impl Items for Laps<'_> {
+    fn count(&self) -> usize {
+        self.0.lap_count()
+    }
+
+    fn label(&self, index: usize) -> &str {
+        core::str::from_utf8(&LAP_LABELS[self.lap(index)]).unwrap()
+    }
+
+    fn value<'a>(&'a self, index: usize, buf: &'a mut [u8; VALUE_LEN]) -> Option<&'a str> {
+        let centis = self.0.lap_centis(self.lap(index)).unwrap_or(0);
+        let buf = (&mut buf[..8]).try_into().unwrap();
+        Some(stopwatch::format_centis(centis, buf))
+    }
+}
+
+pub struct StopwatchApp {
+    pub stopwatch: Stopwatch,
+    /// The elapsed time that is shown, in centiseconds.
+    centis_shown: Option<u64>,
+    laps: List,
+}
+
+impl StopwatchApp {
+    pub const fn new() -> Self {
+        Self {
+            stopwatch: Stopwatch::new(),
+            centis_shown: None,
+            laps: List::new(LAPS_TOP),
+        }
+    }
+}
+
+impl Default for StopwatchApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for StopwatchApp {
+    fn input(&mut self, input: Input, _cx: &mut Context) -> Action {
+        match input {
+            Input::Button(event) => match event.gesture {
+                Gesture::Press => self.stopwatch.toggle(event.at),
+                Gesture::LongPress if self.stopwatch.is_running() => {
+                    self.stopwatch.lap(event.at);
+                    // Scroll back to the new lap
+                    self.laps.select(0, self.stopwatch.lap_count());
+                }
+                Gesture::LongPress => self.stopwatch.reset(),
+                Gesture::DoublePress => {}
+            },
+            // Swipes scroll the laps. Nothing happens when one is tapped.
+            Input::Touch(_) => {
+                self.laps.input(input, self.stopwatch.lap_count());
+            }
+        }
+        Action::None
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        // Show elapsed time
+        let centis = self.stopwatch.elapsed_centis(cx.now());
+        if self.centis_shown != Some(centis) {
+            let mut buf = [0u8; 8];
+            let time = stopwatch::format_centis(centis, &mut buf);
+            let time_style = TextStyleBuilder::new(Font24x32)
+                .text_color(Rgb565::WHITE)
+                .background_color(BACKGROUND_COLOR)
+                .build();
+            cx.draw_text(Text::new(time, Point::new(24, 50)).into_styled(time_style));
+            self.centis_shown = Some(centis);
+        }
+
+        self.laps.tick(cx, &Laps(&self.stopwatch));
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("Stopwatch");
+        self.centis_shown = None;
+        self.laps.invalidate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monotonic_nrf52::Duration;
+    use crate::touch::TouchGesture;
+    use crate::ui::tests::TestEnv;
+    use crate::ui::{AppId, Event, Ui};
+
+    #[test]
+    fn scroll_laps() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.press(&mut ui, Gesture::DoublePress);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Stopwatch);
+
+        // Take 12 laps, the n-th one takes n seconds
+        env.press(&mut ui, Gesture::Press);
+        for lap in 1..=12 {
+            env.now += Duration::from_secs(lap);
+            env.press(&mut ui, Gesture::LongPress);
+        }
+        let laps = Laps(&ui.apps.stopwatch.stopwatch);
+        let mut buf = [0; VALUE_LEN];
+        assert_eq!(laps.count(), 12);
+        assert_eq!(laps.label(0), "#12");
+        assert_eq!(laps.value(0, &mut buf), Some("00:12.00"));
+        assert_eq!(laps.label(11), "#01");
+        assert_eq!(laps.value(11, &mut buf), Some("00:01.00"));
+
+        // Swiping scrolls to the end, where the first lap is on the last of
+        // the three and a half visible rows
+        for _ in 0..3 {
+            env.touch(&mut ui, TouchGesture::SlideUp);
+        }
+        while ui.apps.stopwatch.laps.is_scrolling() {
+            env.handle(&mut ui, Event::Tick);
+        }
+        assert_eq!(ui.apps.stopwatch.laps.selected(), 9);
+
+        // A new lap scrolls back to the top
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.apps.stopwatch.laps.selected(), 0);
+    }
+}
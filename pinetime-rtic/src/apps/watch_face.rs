@@ -0,0 +1,256 @@
+//! The watch face: the time, the battery status and a bouncing ferris.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{fonts::Text, pixelcolor::Rgb565};
+use numtoa::NumToA;
+
+use crate::button::Gesture;
+use crate::clock;
+use crate::damage::Rect;
+use crate::font;
+use crate::monotonic_nrf52::TICKS_PER_SECOND;
+use crate::ui::{
+    self, Action, App, Battery, Context, Image, Input, BACKGROUND_COLOR, LCD_H, LCD_W, MARGIN,
+};
+
+pub const FERRIS_W: u16 = 86;
+pub const FERRIS_H: u16 = 64;
+
+/// The bouncing ferris.
+struct Ferris {
+    x: i32,
+    y: i32,
+    step: i32,
+}
+
+impl Ferris {
+    const fn new() -> Self {
+        Self {
+            x: 10,
+            y: 80,
+            step: 2,
+        }
+    }
+
+    /// Move ferris by one step, turning around at the screen edges.
+    fn advance(&mut self) {
+        if self.x as u16 > LCD_W - FERRIS_W - MARGIN || (self.x as u16) < MARGIN {
+            self.step = -self.step;
+        }
+        self.x += self.step;
+    }
+}
+
+pub struct WatchFace {
+    ferris: Ferris,
+    /// Whether ferris is shown at its current position.
+    ferris_shown: bool,
+    /// The time that is shown, `None` if the clock isn't set, and whether it
+    /// is shown on a 12-hour clock.
+    time_shown: Option<(Option<(u8, u8)>, bool)>,
+    battery_shown: Option<Battery>,
+    /// The seconds since boot that are shown.
+    counter_shown: Option<u64>,
+}
+
+impl WatchFace {
+    pub const fn new() -> Self {
+        Self {
+            ferris: Ferris::new(),
+            ferris_shown: false,
+            time_shown: None,
+            battery_shown: None,
+            counter_shown: None,
+        }
+    }
+
+    /// Move ferris by one step. The area ferris no longer covers is cleared.
+    fn step_ferris(&mut self, cx: &mut Context) {
+        let ferris = &mut self.ferris;
+        if self.ferris_shown {
+            let old_x = ferris.x;
+            ferris.advance();
+            let (x, width) = if ferris.x > old_x {
+                (old_x, ferris.x - old_x)
+            } else {
+                (ferris.x + FERRIS_W as i32, old_x - ferris.x)
+            };
+            let uncovered = Rect::new(
+                x as u16,
+                ferris.y as u16,
+                (width as u16).min(FERRIS_W),
+                FERRIS_H,
+            );
+            cx.draw_area(uncovered, |_| {});
+        }
+        cx.draw_image(Image::Ferris, ferris.x as u16, ferris.y as u16);
+        self.ferris_shown = true;
+    }
+
+    /// Show the wall clock, `None` if it isn't set. On a 12-hour clock, AM
+    /// or PM is shown next to the time.
+    fn show_clock(&mut self, cx: &mut Context, time: Option<(u8, u8)>, clock_12h: bool) {
+        let mut buf = [0u8; 5];
+        let (time, suffix) = match time {
+            Some((hour, minute)) => clock::format_time(hour, minute, clock_12h, &mut buf),
+            None => ("--:--", None),
+        };
+        let style = font::TextStyle {
+            fonts: &[&font::DIGITS_48],
+            color: Rgb565::WHITE,
+            background: BACKGROUND_COLOR,
+        };
+        let suffix_style = font::TextStyle {
+            fonts: &[&font::TEXT_16],
+            ..style
+        };
+        let width = style.text_width(time) as i32;
+        let suffix_width = suffix.map_or(0, |suffix| 4 + suffix_style.text_width(suffix) as i32);
+        let x = (LCD_W as i32 - width - suffix_width) / 2;
+        let text = font::Text::new(time, Point::new(x, 22), style);
+        let suffix = suffix.map(|suffix| {
+            // On the baseline of the time
+            let y = 22 + style.baseline() as i32 - suffix_style.baseline() as i32;
+            font::Text::new(suffix, Point::new(x + width + 4, y), suffix_style)
+        });
+        // Clear everything between the battery status and ferris, the width
+        // of the time changes with its digits
+        let area = Rect::new(0, 28, LCD_W, 50);
+        cx.draw_area(area, |strip| {
+            text.draw(strip).unwrap();
+            if let Some(suffix) = &suffix {
+                suffix.draw(strip).unwrap();
+            }
+        });
+    }
+
+    /// Show the battery status in the top right corner.
+    fn show_battery(&mut self, cx: &mut Context, battery: Battery) {
+        let buf = [
+            b'0' + battery.voltage / 10,
+            b'.',
+            b'0' + battery.voltage % 10,
+            b'V',
+            b'/',
+            if battery.charging { b'C' } else { b'D' },
+        ];
+        let status = core::str::from_utf8(&buf).unwrap();
+        let text = Text::new(status, Point::zero()).into_styled(ui::text_style(BACKGROUND_COLOR));
+        let translation = Point::new(
+            LCD_W as i32 - text.size().width as i32 - MARGIN as i32,
+            MARGIN as i32,
+        );
+        cx.draw_text(text.translate(translation));
+    }
+
+    /// Show the seconds since boot in the bottom left corner.
+    fn show_counter(&mut self, cx: &mut Context, counter: u64) {
+        let mut buf = [0u8; 20];
+        let text = counter.numtoa_str(10, &mut buf);
+        cx.draw_text(
+            Text::new(
+                text,
+                Point::new(MARGIN as i32, (LCD_H - MARGIN - 16) as i32),
+            )
+            .into_styled(ui::text_style(BACKGROUND_COLOR)),
+        );
+    }
+}
+
+impl Default for WatchFace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App for WatchFace {
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action {
+        if let Input::Button(event) = input {
+            if event.gesture == Gesture::Press {
+                cx.effects.cycle_brightness = true;
+            }
+        }
+        Action::None
+    }
+
+    fn tick(&mut self, cx: &mut Context) {
+        self.step_ferris(cx);
+
+        let wall_clock = cx.env.wall_clock;
+        let time = if wall_clock.is_set() {
+            let datetime = wall_clock.datetime(cx.now());
+            Some((datetime.hour, datetime.minute))
+        } else {
+            None
+        };
+        let clock_12h = cx.env.settings.clock_12h;
+        if self.time_shown != Some((time, clock_12h)) {
+            self.show_clock(cx, time, clock_12h);
+            self.time_shown = Some((time, clock_12h));
+        }
+
+        let battery = cx.env.battery;
+        if self.battery_shown != Some(battery) {
+            self.show_battery(cx, battery);
+            self.battery_shown = Some(battery);
+        }
+
+        let counter = cx.now().counts() / TICKS_PER_SECOND;
+        if self.counter_shown != Some(counter) {
+            self.show_counter(cx, counter);
+            self.counter_shown = Some(counter);
+        }
+    }
+
+    fn draw(&mut self, cx: &mut Context) {
+        cx.clear("PineTime");
+        self.ferris_shown = false;
+        self.time_shown = None;
+        self.battery_shown = None;
+        self.counter_shown = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::tests::TestEnv;
+    use crate::ui::{AppId, Event, Ui};
+
+    #[test]
+    fn ferris() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        // Drawn when the watch face is entered, then moved by the tick
+        env.handle(&mut ui, Event::Tick);
+        assert_eq!(
+            env.images.0,
+            [(Image::Ferris, 10, 80), (Image::Ferris, 12, 80)]
+        );
+
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        assert_eq!(env.images.0.last(), Some(&(Image::Ferris, 14, 80)));
+        // The two columns ferris left are cleared
+        assert_eq!(env.panel.0, [Rect::new(12, 80, 2, FERRIS_H)]);
+
+        // Ferris turns around at the right edge
+        let mut x = 14;
+        loop {
+            env.handle(&mut ui, Event::Tick);
+            let (_, next, _) = *env.images.0.last().unwrap();
+            if next < x {
+                break;
+            }
+            x = next;
+        }
+        assert_eq!(x, LCD_W - FERRIS_W - MARGIN + 2);
+
+        // Nothing is drawn in the background
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::Launcher);
+        let drawn = env.images.0.len();
+        env.handle(&mut ui, Event::Tick);
+        assert_eq!(env.images.0.len(), drawn);
+    }
+}
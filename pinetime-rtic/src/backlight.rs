@@ -1,6 +1,6 @@
+use core::fmt::Debug;
+
 use embedded_hal::digital::v2::OutputPin;
-use nrf52832_hal::gpio::{Output, Pin, PushPull};
-use rtt_target::rprintln;
 
 /// Control the backlight.
 ///
@@ -13,23 +13,22 @@ use rtt_target::rprintln;
 ///
 /// Through combinations of these pins, 7 brightness levels (+ off) can be
 /// configured.
-pub struct Backlight {
-    low: Pin<Output<PushPull>>,
-    mid: Pin<Output<PushPull>>,
-    high: Pin<Output<PushPull>>,
+pub struct Backlight<P> {
+    low: P,
+    mid: P,
+    high: P,
 
     /// The current brightness level (value between 0 and 7).
     brightness: u8,
 }
 
-impl Backlight {
+impl<P> Backlight<P>
+where
+    P: OutputPin,
+    P::Error: Debug,
+{
     /// Initialize the backlight with the specified level (0–7).
-    pub fn init(
-        low: Pin<Output<PushPull>>,
-        mid: Pin<Output<PushPull>>,
-        high: Pin<Output<PushPull>>,
-        brightness: u8,
-    ) -> Self {
+    pub fn init(low: P, mid: P, high: P, brightness: u8) -> Self {
         let mut backlight = Self {
             low,
             mid,
@@ -46,7 +45,6 @@ impl Backlight {
         if brightness > 7 {
             brightness = 7;
         }
-        rprintln!("Setting backlight brightness to {}", brightness);
         if brightness & 0x01 > 0 {
             self.low.set_low().unwrap();
         } else {
@@ -77,7 +75,7 @@ impl Backlight {
 
     /// Decrease backlight brightness.
     pub fn darker(&mut self) {
-        self.set(self.brightness - 1);
+        self.set(self.brightness.saturating_sub(1));
     }
 
     /// Return the current brightness level (value between 0 and 7).
@@ -85,3 +83,55 @@ impl Backlight {
         self.brightness
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::convert::Infallible;
+
+    /// A pin that stores its level in a shared cell.
+    struct MockPin<'a>(&'a Cell<bool>);
+
+    impl OutputPin for MockPin<'_> {
+        type Error = Infallible;
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.0.set(false);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.0.set(true);
+            Ok(())
+        }
+    }
+
+    /// Return which of the low, mid and high pins are active (low).
+    fn active(pins: &[Cell<bool>; 3]) -> [bool; 3] {
+        [!pins[0].get(), !pins[1].get(), !pins[2].get()]
+    }
+
+    #[test]
+    fn levels() {
+        let pins = [Cell::new(false), Cell::new(false), Cell::new(false)];
+        let mut backlight =
+            Backlight::init(MockPin(&pins[0]), MockPin(&pins[1]), MockPin(&pins[2]), 3);
+        assert_eq!(active(&pins), [true, true, false]);
+
+        backlight.brighter();
+        assert_eq!(backlight.get_brightness(), 4);
+        assert_eq!(active(&pins), [false, false, true]);
+
+        backlight.set(9);
+        assert_eq!(backlight.get_brightness(), 7);
+        assert_eq!(active(&pins), [true, true, true]);
+        backlight.brighter();
+        assert_eq!(backlight.get_brightness(), 7);
+
+        backlight.off();
+        assert_eq!(active(&pins), [false, false, false]);
+        backlight.darker();
+        assert_eq!(backlight.get_brightness(), 0);
+    }
+}
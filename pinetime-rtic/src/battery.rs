@@ -1,18 +1,17 @@
-use embedded_hal::adc::OneShot;
+use core::fmt::Debug;
+
+use embedded_hal::adc::{Channel, OneShot};
 use embedded_hal::digital::v2::InputPin;
-use nrf52832_hal::gpio::{p0, Floating, Input};
-use nrf52832_hal::saadc::{Saadc, SaadcConfig};
-use nrf52832_hal::target::SAADC;
 
-pub struct BatteryStatus {
-    /// Pin P0.12: High = battery, Low = charging.
-    pin_charge_indication: p0::P0_12<Input<Floating>>,
+pub struct BatteryStatus<ADC, CHARGE, VOLTAGE> {
+    /// Charge indication pin: High = battery, Low = charging.
+    pin_charge_indication: CHARGE,
 
-    /// Pin P0.31: Voltage level
-    pin_voltage: p0::P0_31<Input<Floating>>,
+    /// Voltage level pin
+    pin_voltage: VOLTAGE,
 
-    /// SAADC peripheral
-    saadc: Saadc,
+    /// ADC that measures the voltage level
+    adc: ADC,
 
     /// Charging state
     charging: bool,
@@ -21,25 +20,28 @@ pub struct BatteryStatus {
     voltage: u8,
 }
 
-impl BatteryStatus {
-    /// Initialize the battery status.
-    pub fn init(
-        pin_charge_indication: p0::P0_12<Input<Floating>>,
-        mut pin_voltage: p0::P0_31<Input<Floating>>,
-        #[allow(non_snake_case)] SAADC: SAADC,
-    ) -> Self {
+impl<ADC, CHARGE, VOLTAGE> BatteryStatus<ADC, CHARGE, VOLTAGE>
+where
+    ADC: OneShot<ADC, i16, VOLTAGE>,
+    ADC::Error: Debug,
+    CHARGE: InputPin,
+    CHARGE::Error: Debug,
+    VOLTAGE: Channel<ADC>,
+{
+    /// Initialize the battery status. The ADC must measure with a 14 bit
+    /// resolution against a 3.3 V reference.
+    pub fn init(pin_charge_indication: CHARGE, mut pin_voltage: VOLTAGE, mut adc: ADC) -> Self {
         // Get initial charging state
         let charging = pin_charge_indication.is_low().unwrap();
 
         // Get initial voltage
-        let mut saadc = Saadc::new(SAADC, SaadcConfig::default());
         let voltage =
-            Self::convert_adc_measurement(saadc.read(&mut pin_voltage).unwrap()).unwrap_or(0);
+            Self::convert_adc_measurement(adc.read(&mut pin_voltage).unwrap()).unwrap_or(0);
 
         Self {
             pin_charge_indication,
             pin_voltage,
-            saadc,
+            adc,
             charging,
             voltage,
         }
@@ -90,9 +92,8 @@ impl BatteryStatus {
         }
 
         // Check voltage
-        let voltage =
-            Self::convert_adc_measurement(self.saadc.read(&mut self.pin_voltage).unwrap())
-                .unwrap_or(0);
+        let voltage = Self::convert_adc_measurement(self.adc.read(&mut self.pin_voltage).unwrap())
+            .unwrap_or(0);
         if voltage != self.voltage {
             self.voltage = voltage;
             changed = true;
@@ -101,3 +102,82 @@ impl BatteryStatus {
         changed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::convert::Infallible;
+
+    /// An ADC that returns the value of a shared cell.
+    struct MockAdc<'a>(&'a Cell<i16>);
+
+    struct MockChannel;
+
+    impl Channel<MockAdc<'_>> for MockChannel {
+        type ID = u8;
+
+        fn channel() -> u8 {
+            7
+        }
+    }
+
+    impl OneShot<MockAdc<'_>, i16, MockChannel> for MockAdc<'_> {
+        type Error = Infallible;
+
+        fn read(&mut self, _pin: &mut MockChannel) -> nb::Result<i16, Self::Error> {
+            Ok(self.0.get())
+        }
+    }
+
+    /// An input pin with the level of a shared cell.
+    struct MockPin<'a>(&'a Cell<bool>);
+
+    impl InputPin for MockPin<'_> {
+        type Error = Infallible;
+
+        fn is_high(&self) -> Result<bool, Self::Error> {
+            Ok(self.0.get())
+        }
+
+        fn is_low(&self) -> Result<bool, Self::Error> {
+            Ok(!self.0.get())
+        }
+    }
+
+    #[test]
+    fn conversion() {
+        let convert = BatteryStatus::<MockAdc, MockPin, MockChannel>::convert_adc_measurement;
+        assert_eq!(convert(-1), None);
+        assert_eq!(convert(0), Some(0));
+        // 3.699 V and 3.705 V
+        assert_eq!(convert(9185), Some(36));
+        assert_eq!(convert(9200), Some(37));
+        // Full scale: 6.6 V
+        assert_eq!(convert(16383), Some(65));
+    }
+
+    #[test]
+    fn update() {
+        let raw = Cell::new(9200);
+        let charge_indication = Cell::new(true);
+        let mut battery =
+            BatteryStatus::init(MockPin(&charge_indication), MockChannel, MockAdc(&raw));
+        assert_eq!(battery.voltage(), 37);
+        assert!(!battery.is_charging());
+        assert!(!battery.update());
+
+        charge_indication.set(false);
+        assert!(battery.update());
+        assert!(battery.is_charging());
+
+        raw.set(10_000);
+        assert!(battery.update());
+        assert_eq!(battery.voltage(), 40);
+
+        // Invalid measurements read as 0 V
+        raw.set(-5);
+        assert!(battery.update());
+        assert_eq!(battery.voltage(), 0);
+    }
+}
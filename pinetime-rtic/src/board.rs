@@ -0,0 +1,53 @@
+//! The wiring of the PineTime: which pins and peripherals the drivers use.
+//!
+//! The drivers in the library are generic over the embedded-hal traits, so
+//! that they can be tested on the host. This module fixes them to the
+//! concrete nRF52832 types.
+
+use nrf52832_hal::gpio::{p0, Floating, Input, Level, Output, Pin, PushPull};
+use nrf52832_hal::pac::{SAADC, TWIM0};
+use nrf52832_hal::saadc::{Saadc, SaadcConfig};
+use nrf52832_hal::twim::Twim;
+
+use pinetime_rtic::{backlight, battery, touch};
+
+/// Backlight, on P0.14 (low), P0.22 (mid) and P0.23 (high).
+pub type Backlight = backlight::Backlight<Pin<Output<PushPull>>>;
+
+/// Battery status: charge indication on P0.12, voltage on P0.31 (AIN7).
+pub type BatteryStatus =
+    battery::BatteryStatus<Saadc, p0::P0_12<Input<Floating>>, p0::P0_31<Input<Floating>>>;
+
+/// The side button on P0.13.
+pub type Button = Pin<Input<Floating>>;
+
+/// Touch controller on TWIM0.
+pub type Touch = touch::Cst816s<Twim<TWIM0>>;
+
+/// Initialize the backlight with the specified level (0–7).
+pub fn backlight<L, M, H>(
+    low: p0::P0_14<L>,
+    mid: p0::P0_22<M>,
+    high: p0::P0_23<H>,
+    brightness: u8,
+) -> Backlight {
+    Backlight::init(
+        low.into_push_pull_output(Level::High).degrade(),
+        mid.into_push_pull_output(Level::High).degrade(),
+        high.into_push_pull_output(Level::High).degrade(),
+        brightness,
+    )
+}
+
+/// Initialize the battery status.
+pub fn battery<C, V>(
+    charge_indication: p0::P0_12<C>,
+    voltage: p0::P0_31<V>,
+    saadc: SAADC,
+) -> BatteryStatus {
+    BatteryStatus::init(
+        charge_indication.into_floating_input(),
+        voltage.into_floating_input(),
+        Saadc::new(saadc, SaadcConfig::default()),
+    )
+}
@@ -0,0 +1,202 @@
+//! Button gesture detection.
+//!
+//! The side button is polled and debounced in the firmware. The resulting
+//! edges are fed into a `GestureDetector`, which turns them into presses,
+//! double presses and long presses.
+
+use debouncr::Edge;
+
+use crate::monotonic_nrf52::{Duration, Instant};
+
+/// Maximum time between releasing the button and pressing it again for the
+/// two presses to count as a double press.
+const DOUBLE_PRESS_WINDOW: Duration = Duration::from_millis(300);
+
+/// Minimum time the button must be held down for a long press.
+const LONG_PRESS_THRESHOLD: Duration = Duration::from_millis(700);
+
+/// A button gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gesture {
+    /// The button was pressed and released once.
+    Press,
+    /// The button was pressed twice in quick succession.
+    DoublePress,
+    /// The button was held down.
+    LongPress,
+}
+
+/// A detected gesture, together with the instant the button was pressed.
+///
+/// Because a single press can only be reported once the double press window
+/// has expired, `at` should be used for anything that needs accurate timing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButtonEvent {
+    pub gesture: Gesture,
+    pub at: Instant,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    /// The button is not pressed.
+    Idle,
+    /// The button is held down. `second` is set if this is the second press
+    /// of a potential double press.
+    Pressed { since: Instant, second: bool },
+    /// The button was pressed once and released. Waiting for a second press.
+    Released { pressed_at: Instant, since: Instant },
+    /// A long press was reported, waiting for the button to be released.
+    LongPressed,
+}
+
+/// Turns debounced button edges into gestures.
+pub struct GestureDetector {
+    state: State,
+}
+
+impl GestureDetector {
+    pub const fn new() -> Self {
+        Self { state: State::Idle }
+    }
+
+    /// Update the detector with the debounced edge (if any) from the latest
+    /// button poll. Must be called regularly, even if there was no edge, so
+    /// that timeouts are detected.
+    pub fn update(&mut self, edge: Option<Edge>, now: Instant) -> Option<ButtonEvent> {
+        let (state, gesture) = match (self.state, edge) {
+            (State::Idle, Some(Edge::Rising)) => (
+                State::Pressed {
+                    since: now,
+                    second: false,
+                },
+                None,
+            ),
+            (State::Pressed { since, second }, Some(Edge::Falling)) => {
+                if second {
+                    (State::Idle, Some((Gesture::DoublePress, since)))
+                } else {
+                    (
+                        State::Released {
+                            pressed_at: since,
+                            since: now,
+                        },
+                        None,
+                    )
+                }
+            }
+            (
+                State::Pressed {
+                    since,
+                    second: false,
+                },
+                None,
+            ) if now.duration_since(since) >= LONG_PRESS_THRESHOLD => {
+                (State::LongPressed, Some((Gesture::LongPress, since)))
+            }
+            (State::Released { pressed_at, .. }, Some(Edge::Rising)) => (
+                State::Pressed {
+                    since: pressed_at,
+                    second: true,
+                },
+                None,
+            ),
+            (State::Released { pressed_at, since }, None)
+                if now.duration_since(since) >= DOUBLE_PRESS_WINDOW =>
+            {
+                (State::Idle, Some((Gesture::Press, pressed_at)))
+            }
+            (State::LongPressed, Some(Edge::Falling)) => (State::Idle, None),
+            (state, _) => (state, None),
+        };
+        self.state = state;
+        gesture.map(|(gesture, at)| ButtonEvent { gesture, at })
+    }
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Instant {
+        Instant::from_counts(millis * 1_000)
+    }
+
+    /// Feed a sequence of `(time, edge)` pairs into a detector, polling every
+    /// 2 ms like the firmware does, and collect the resulting events.
+    fn run(edges: Vec<(u64, Edge)>, until: u64) -> Vec<ButtonEvent> {
+        let mut edges = edges.into_iter().peekable();
+        let mut detector = GestureDetector::new();
+        let mut events = Vec::new();
+        for t in (0..=until).step_by(2) {
+            let edge = edges.next_if(|(at, _)| *at == t).map(|(_, edge)| edge);
+            events.extend(detector.update(edge, ms(t)));
+        }
+        events
+    }
+
+    #[test]
+    fn single_press() {
+        let events = run(vec![(10, Edge::Rising), (100, Edge::Falling)], 1000);
+        assert_eq!(
+            events,
+            vec![ButtonEvent {
+                gesture: Gesture::Press,
+                at: ms(10)
+            }]
+        );
+    }
+
+    #[test]
+    fn double_press() {
+        let events = run(
+            vec![
+                (10, Edge::Rising),
+                (100, Edge::Falling),
+                (200, Edge::Rising),
+                (300, Edge::Falling),
+            ],
+            1000,
+        );
+        assert_eq!(
+            events,
+            vec![ButtonEvent {
+                gesture: Gesture::DoublePress,
+                at: ms(10)
+            }]
+        );
+    }
+
+    #[test]
+    fn two_slow_presses() {
+        let events = run(
+            vec![
+                (10, Edge::Rising),
+                (100, Edge::Falling),
+                (600, Edge::Rising),
+                (700, Edge::Falling),
+            ],
+            2000,
+        );
+        let gestures: Vec<_> = events.iter().map(|e| e.gesture).collect();
+        assert_eq!(gestures, vec![Gesture::Press, Gesture::Press]);
+        assert_eq!(events[1].at, ms(600));
+    }
+
+    #[test]
+    fn long_press() {
+        let events = run(vec![(10, Edge::Rising), (2000, Edge::Falling)], 3000);
+        assert_eq!(
+            events,
+            vec![ButtonEvent {
+                gesture: Gesture::LongPress,
+                at: ms(10)
+            }]
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Wall clock.
+//!
+//! There is no battery backed RTC, so the wall clock is derived from the
+//! monotonic timer: once the time is set (e.g. over BLE), the current time is
+//! the time that was set plus the time elapsed since then. All times are in
+//! local time, as seconds since 1970-01-01 00:00:00.
+
+use crate::monotonic_nrf52::{Instant, TICKS_PER_SECOND};
+
+/// A calendar date and time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: u16,
+    /// Month (1–12)
+    pub month: u8,
+    /// Day of the month (1–31)
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// Day of the week (0 = Monday, 6 = Sunday)
+    pub weekday: u8,
+}
+
+impl DateTime {
+    /// Convert seconds since 1970-01-01 00:00:00 into a calendar date and
+    /// time.
+    pub fn from_secs(secs: u64) -> Self {
+        let days = (secs / 86_400) as i64;
+        let secs_of_day = secs % 86_400;
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year: year as u16,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: ((secs_of_day / 60) % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+            // 1970-01-01 was a Thursday
+            weekday: ((days + 3) % 7) as u8,
+        }
+    }
+
+    /// Convert the calendar date and time into seconds since 1970-01-01
+    /// 00:00:00. The weekday is ignored. Return `None` for dates before 1970.
+    pub fn to_secs(&self) -> Option<u64> {
+        let days = days_from_civil(self.year as i64, self.month, self.day);
+        if days < 0 {
+            return None;
+        }
+        Some(
+            days as u64 * 86_400
+                + self.hour as u64 * 3600
+                + self.minute as u64 * 60
+                + self.second as u64,
+        )
+    }
+
+    /// Parse a value of the BLE "Current Time" characteristic (0x2A2B).
+    pub fn from_current_time(data: &[u8]) -> Option<Self> {
+        if data.len() < 7 {
+            return None;
+        }
+        let datetime = Self {
+            year: u16::from_le_bytes([data[0], data[1]]),
+            month: data[2],
+            day: data[3],
+            hour: data[4],
+            minute: data[5],
+            second: data[6],
+            weekday: 0,
+        };
+        let valid = datetime.year >= 1970
+            && (1..=12).contains(&datetime.month)
+            && (1..=days_in_month(datetime.year, datetime.month)).contains(&datetime.day)
+            && datetime.hour < 24
+            && datetime.minute < 60
+            && datetime.second < 60;
+        if valid {
+            Some(datetime)
+        } else {
+            None
+        }
+    }
+}
+
+/// Format a time of day as `HH:MM`.
+pub fn format_hm(hour: u8, minute: u8, buf: &mut [u8; 5]) -> &str {
+    buf[0] = b'0' + hour / 10;
+    buf[1] = b'0' + hour % 10;
+    buf[2] = b':';
+    buf[3] = b'0' + minute / 10;
+    buf[4] = b'0' + minute % 10;
+    core::str::from_utf8(buf).unwrap()
+}
+
+/// Format a time of day as `HH:MM`, or on a 12-hour clock as `H:MM` and
+/// the `AM` or `PM` that goes with it.
+pub fn format_time(
+    hour: u8,
+    minute: u8,
+    clock_12h: bool,
+    buf: &mut [u8; 5],
+) -> (&str, Option<&'static str>) {
+    if !clock_12h {
+        return (format_hm(hour, minute, buf), None);
+    }
+    let (hour, pm) = hour_12(hour);
+    let time = format_hm(hour, minute, buf);
+    let suffix = if pm { "PM" } else { "AM" };
+    (time.strip_prefix('0').unwrap_or(time), Some(suffix))
+}
+
+/// Convert an hour of the day (0–23) to the 12-hour clock. Return the hour
+/// (1–12) and whether it is after noon.
+pub fn hour_12(hour: u8) -> (u8, bool) {
+    let pm = hour >= 12;
+    match hour % 12 {
+        0 => (12, pm),
+        hour => (hour, pm),
+    }
+}
+
+/// Return the number of days of a month (1–12) in the Gregorian calendar.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Convert days since 1970-01-01 into a (year, month, day) triple.
+///
+/// Algorithm by Howard Hinnant, see
+/// http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) triple into days since 1970-01-01.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// The wall clock.
+pub struct WallClock {
+    /// Local time in seconds at `base_instant`.
+    base_secs: u64,
+    base_instant: Instant,
+    set: bool,
+}
+
+impl WallClock {
+    /// Create a wall clock that starts at 1970-01-01 00:00:00 at the
+    /// specified instant.
+    pub const fn new(now: Instant) -> Self {
+        Self {
+            base_secs: 0,
+            base_instant: now,
+            set: false,
+        }
+    }
+
+    /// Set the local time in seconds since 1970-01-01 00:00:00.
+    pub fn set(&mut self, now: Instant, secs: u64) {
+        self.base_secs = secs;
+        self.base_instant = now;
+        self.set = true;
+    }
+
+    /// Return whether the time was set since boot.
+    pub fn is_set(&self) -> bool {
+        self.set
+    }
+
+    /// Return the local time in seconds since 1970-01-01 00:00:00.
+    pub fn secs(&self, now: Instant) -> u64 {
+        self.base_secs + now.ticks_since(self.base_instant) / TICKS_PER_SECOND
+    }
+
+    /// Return the local time as calendar date and time.
+    pub fn datetime(&self, now: Instant) -> DateTime {
+        DateTime::from_secs(self.secs(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        let datetime = DateTime::from_secs(0);
+        assert_eq!(
+            datetime,
+            DateTime {
+                year: 1970,
+                month: 1,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                weekday: 3,
+            }
+        );
+        assert_eq!(datetime.to_secs(), Some(0));
+    }
+
+    #[test]
+    fn roundtrip() {
+        // 2020-02-29 13:37:42, a Saturday
+        let secs = 1_582_983_462;
+        let datetime = DateTime::from_secs(secs);
+        assert_eq!((datetime.year, datetime.month, datetime.day), (2020, 2, 29));
+        assert_eq!(
+            (datetime.hour, datetime.minute, datetime.second),
+            (13, 37, 42)
+        );
+        assert_eq!(datetime.weekday, 5);
+        assert_eq!(datetime.to_secs(), Some(secs));
+    }
+
+    #[test]
+    fn current_time_characteristic() {
+        let data = [0xE4, 0x07, 6, 15, 8, 30, 5, 1, 0, 0];
+        let datetime = DateTime::from_current_time(&data).unwrap();
+        assert_eq!((datetime.year, datetime.month, datetime.day), (2020, 6, 15));
+        assert_eq!(
+            (datetime.hour, datetime.minute, datetime.second),
+            (8, 30, 5)
+        );
+        assert_eq!(DateTime::from_current_time(&data[..6]), None);
+        assert_eq!(
+            DateTime::from_current_time(&[0xE4, 0x07, 13, 1, 0, 0, 0]),
+            None
+        );
+
+        // Days past the end of the month
+        let date = |year: u16, month, day| {
+            let [low, high] = year.to_le_bytes();
+            DateTime::from_current_time(&[low, high, month, day, 0, 0, 0]).map(|d| d.day)
+        };
+        assert_eq!(date(2021, 2, 31), None);
+        assert_eq!(date(2021, 2, 29), None);
+        assert_eq!(date(2020, 2, 29), Some(29));
+        assert_eq!(date(2100, 2, 29), None);
+        assert_eq!(date(2000, 2, 29), Some(29));
+        assert_eq!(date(2021, 4, 31), None);
+        assert_eq!(date(2021, 12, 31), Some(31));
+    }
+
+    #[test]
+    fn format() {
+        let mut buf = [0; 5];
+        assert_eq!(format_hm(7, 5, &mut buf), "07:05");
+        assert_eq!(format_hm(23, 59, &mut buf), "23:59");
+        assert_eq!(hour_12(0), (12, false));
+        assert_eq!(hour_12(11), (11, false));
+        assert_eq!(hour_12(12), (12, true));
+        assert_eq!(hour_12(23), (11, true));
+        assert_eq!(format_time(7, 5, false, &mut buf), ("07:05", None));
+        assert_eq!(format_time(7, 5, true, &mut buf), ("7:05", Some("AM")));
+        assert_eq!(format_time(0, 30, true, &mut buf), ("12:30", Some("AM")));
+        assert_eq!(format_time(22, 0, true, &mut buf), ("10:00", Some("PM")));
+    }
+
+    #[test]
+    fn wall_clock() {
+        let mut clock = WallClock::new(Instant::from_counts(0));
+        assert!(!clock.is_set());
+        clock.set(Instant::from_counts(5_000_000), 1_000);
+        assert!(clock.is_set());
+        assert_eq!(clock.secs(Instant::from_counts(7_500_000)), 1_002);
+    }
+}
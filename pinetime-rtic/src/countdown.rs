@@ -0,0 +1,232 @@
+//! Countdown timer.
+//!
+//! The countdown doesn't tick by itself. Starting it returns the instant at
+//! which it expires, and the firmware schedules a task for exactly that
+//! instant. Since scheduled RTIC tasks can't be cancelled, every start, pause
+//! and reset bumps a generation counter, and expiry notifications from an
+//! older generation are ignored.
+
+use crate::monotonic_nrf52::{Duration, Instant, TICKS_PER_SECOND};
+
+/// Maximum countdown duration in seconds. This must fit into a `Duration`.
+pub const MAX_SECS: u32 = 60 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    Stopped,
+    Running { ends: Instant },
+    Paused { remaining: Duration },
+}
+
+pub struct Countdown {
+    /// The configured duration in seconds.
+    duration: u32,
+
+    state: State,
+
+    /// Generation of the currently scheduled expiry.
+    generation: u32,
+}
+
+impl Countdown {
+    /// Create a stopped countdown with the specified duration in seconds.
+    pub const fn new(duration: u32) -> Self {
+        Self {
+            duration,
+            state: State::Stopped,
+            generation: 0,
+        }
+    }
+
+    /// Return the configured duration in seconds.
+    pub fn duration_secs(&self) -> u32 {
+        self.duration
+    }
+
+    /// Adjust the configured duration by the specified number of seconds,
+    /// clamped to `0..=MAX_SECS`. Only possible while the countdown is
+    /// stopped. Return whether the duration changed.
+    pub fn adjust(&mut self, delta: i32) -> bool {
+        if self.state != State::Stopped {
+            return false;
+        }
+        let duration = (self.duration as i64 + delta as i64)
+            .max(0)
+            .min(MAX_SECS as i64) as u32;
+        let changed = duration != self.duration;
+        self.duration = duration;
+        changed
+    }
+
+    /// Return whether the countdown is currently running.
+    pub fn is_running(&self) -> bool {
+        matches!(self.state, State::Running { .. })
+    }
+
+    /// Return whether the countdown is stopped (as opposed to running or
+    /// paused).
+    pub fn is_stopped(&self) -> bool {
+        self.state == State::Stopped
+    }
+
+    /// Start or resume the countdown.
+    ///
+    /// Return the instant at which the countdown expires together with the
+    /// generation that must be passed to `expire`, or `None` if the countdown
+    /// is already running or the duration is zero.
+    pub fn start(&mut self, now: Instant) -> Option<(Instant, u32)> {
+        let remaining = match self.state {
+            State::Running { .. } => return None,
+            State::Paused { remaining } => remaining,
+            State::Stopped => Duration::from_secs(self.duration),
+        };
+        if remaining == Duration::ZERO {
+            return None;
+        }
+        let ends = now + remaining;
+        self.state = State::Running { ends };
+        self.generation = self.generation.wrapping_add(1);
+        Some((ends, self.generation))
+    }
+
+    /// Pause the countdown. Does nothing if it's not running.
+    pub fn pause(&mut self, now: Instant) {
+        if let State::Running { ends } = self.state {
+            self.state = State::Paused {
+                remaining: ends - now,
+            };
+            self.generation = self.generation.wrapping_add(1);
+        }
+    }
+
+    /// Pause the countdown if it's running, start or resume it otherwise.
+    /// See `start` for the return value.
+    pub fn toggle(&mut self, now: Instant) -> Option<(Instant, u32)> {
+        if self.is_running() {
+            self.pause(now);
+            None
+        } else {
+            self.start(now)
+        }
+    }
+
+    /// Stop the countdown and reset it to the configured duration.
+    pub fn reset(&mut self) {
+        self.state = State::Stopped;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Return the instant at which the countdown expires together with the
+    /// current generation, if it's running.
+    pub fn expiry(&self) -> Option<(Instant, u32)> {
+        match self.state {
+            State::Running { ends } => Some((ends, self.generation)),
+            _ => None,
+        }
+    }
+
+    /// Handle the expiry notification of the specified generation. Return
+    /// whether the countdown actually expired, i.e. whether the alert should
+    /// be raised.
+    pub fn expire(&mut self, generation: u32) -> bool {
+        if generation != self.generation || !self.is_running() {
+            return false;
+        }
+        self.state = State::Stopped;
+        true
+    }
+
+    /// Return the remaining time.
+    pub fn remaining(&self, now: Instant) -> Duration {
+        match self.state {
+            State::Stopped => Duration::from_secs(self.duration),
+            State::Running { ends } => ends - now,
+            State::Paused { remaining } => remaining,
+        }
+    }
+
+    /// Return the remaining time in whole seconds, rounded up.
+    pub fn remaining_secs(&self, now: Instant) -> u32 {
+        let ticks = self.remaining(now).as_cycles() as u64;
+        let secs = ticks / TICKS_PER_SECOND;
+        if ticks > secs * TICKS_PER_SECOND {
+            secs as u32 + 1
+        } else {
+            secs as u32
+        }
+    }
+}
+
+/// Format a number of seconds as `MM:SS`.
+pub fn format_secs(secs: u32, buf: &mut [u8; 5]) -> &str {
+    let (minutes, secs) = ((secs / 60) % 100, secs % 60);
+    buf[0] = b'0' + (minutes / 10) as u8;
+    buf[1] = b'0' + (minutes % 10) as u8;
+    buf[2] = b':';
+    buf[3] = b'0' + (secs / 10) as u8;
+    buf[4] = b'0' + (secs % 10) as u8;
+    core::str::from_utf8(buf).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Instant {
+        Instant::from_counts(millis * 1_000)
+    }
+
+    #[test]
+    fn run_to_expiry() {
+        let mut countdown = Countdown::new(10);
+        let (ends, generation) = countdown.start(ms(1_000)).unwrap();
+        assert_eq!(ends, ms(11_000));
+        assert_eq!(countdown.remaining_secs(ms(1_500)), 10);
+        assert_eq!(countdown.remaining_secs(ms(2_000)), 9);
+        assert!(countdown.expire(generation));
+        assert!(countdown.is_stopped());
+        assert_eq!(countdown.remaining_secs(ms(20_000)), 10);
+    }
+
+    #[test]
+    fn pause_invalidates_expiry() {
+        let mut countdown = Countdown::new(10);
+        let (_, first) = countdown.toggle(ms(0)).unwrap();
+        assert_eq!(countdown.toggle(ms(4_000)), None);
+        assert_eq!(countdown.remaining_secs(ms(8_000)), 6);
+        let (ends, second) = countdown.toggle(ms(8_000)).unwrap();
+        assert_eq!(ends, ms(14_000));
+        assert!(!countdown.expire(first));
+        assert!(countdown.is_running());
+        assert_eq!(countdown.expiry(), Some((ends, second)));
+        assert!(countdown.expire(second));
+    }
+
+    #[test]
+    fn reset_invalidates_expiry() {
+        let mut countdown = Countdown::new(10);
+        let (_, generation) = countdown.start(ms(0)).unwrap();
+        countdown.reset();
+        assert!(!countdown.expire(generation));
+    }
+
+    #[test]
+    fn adjust() {
+        let mut countdown = Countdown::new(30);
+        assert!(countdown.adjust(-60));
+        assert_eq!(countdown.duration_secs(), 0);
+        assert_eq!(countdown.start(ms(0)), None);
+        assert!(countdown.adjust(MAX_SECS as i32 * 2));
+        assert_eq!(countdown.duration_secs(), MAX_SECS);
+        countdown.start(ms(0));
+        assert!(!countdown.adjust(-10));
+    }
+
+    #[test]
+    fn format() {
+        let mut buf = [0; 5];
+        assert_eq!(format_secs(0, &mut buf), "00:00");
+        assert_eq!(format_secs(605, &mut buf), "10:05");
+        assert_eq!(format_secs(MAX_SECS, &mut buf), "60:00");
+    }
+}
@@ -0,0 +1,218 @@
+//! Tracking of damaged screen regions.
+//!
+//! Every change on the screen is recorded as a damaged rectangle. Rectangles
+//! that overlap or touch are merged if their bounding box doesn't cover much
+//! more than the rectangles themselves, so that every pixel is only sent to
+//! the display once in the common case.
+
+/// Maximum number of separate damaged regions. When more regions are added,
+/// they are merged with the region that grows the least.
+pub const MAX_REGIONS: usize = 8;
+
+/// A rectangle in screen coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Return whether the rectangle doesn't contain any pixels.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Return the x coordinate right of the rectangle (exclusive).
+    pub fn right(&self) -> u16 {
+        self.x + self.width
+    }
+
+    /// Return the y coordinate below the rectangle (exclusive).
+    pub fn bottom(&self) -> u16 {
+        self.y + self.height
+    }
+
+    /// Return the number of pixels in the rectangle.
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    /// Return whether the rectangle contains the specified point.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Return the bounding box of both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    /// Return the area covered by both rectangles, if any.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if x < right && y < bottom {
+            Some(Rect::new(x, y, right - x, bottom - y))
+        } else {
+            None
+        }
+    }
+
+    /// Return whether the rectangles overlap or share an edge.
+    fn touches(&self, other: &Rect) -> bool {
+        self.x <= other.right()
+            && other.x <= self.right()
+            && self.y <= other.bottom()
+            && other.y <= self.bottom()
+    }
+}
+
+/// The damaged regions of the screen.
+pub struct Damage {
+    regions: [Rect; MAX_REGIONS],
+    count: usize,
+}
+
+impl Damage {
+    pub const fn new() -> Self {
+        Self {
+            regions: [Rect::new(0, 0, 0, 0); MAX_REGIONS],
+            count: 0,
+        }
+    }
+
+    /// Mark a rectangle as damaged.
+    pub fn add(&mut self, rect: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+
+        // Merge with all regions it can be merged with. A merged region may
+        // in turn be mergeable with regions that were checked before.
+        let mut rect = rect;
+        while let Some(index) = self.regions().iter().position(|region| {
+            region.touches(&rect) && region.union(&rect).area() <= region.area() + rect.area()
+        }) {
+            rect = rect.union(&self.regions[index]);
+            self.count -= 1;
+            self.regions[index] = self.regions[self.count];
+        }
+
+        if self.count < MAX_REGIONS {
+            self.regions[self.count] = rect;
+            self.count += 1;
+        } else {
+            let growth = |region: &Rect| region.union(&rect).area() - region.area();
+            let (index, _) = self
+                .regions
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, region)| growth(region))
+                .unwrap();
+            self.regions[index] = self.regions[index].union(&rect);
+        }
+    }
+
+    /// Return whether nothing is damaged.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Return the damaged regions.
+    pub fn regions(&self) -> &[Rect] {
+        &self.regions[..self.count]
+    }
+
+    /// Mark everything as clean.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
+
+impl Default for Damage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_operations() {
+        let a = Rect::new(10, 10, 20, 10);
+        let b = Rect::new(25, 15, 10, 10);
+        assert_eq!(a.union(&b), Rect::new(10, 10, 25, 15));
+        assert_eq!(a.intersection(&b), Some(Rect::new(25, 15, 5, 5)));
+        assert_eq!(a.intersection(&Rect::new(30, 10, 5, 5)), None);
+        assert!(a.contains(29, 19));
+        assert!(!a.contains(30, 19));
+    }
+
+    #[test]
+    fn merge_moving_sprite() {
+        // A sprite that moved 2 pixels to the right
+        let mut damage = Damage::new();
+        damage.add(Rect::new(10, 80, 86, 64));
+        damage.add(Rect::new(12, 80, 86, 64));
+        assert_eq!(damage.regions(), &[Rect::new(10, 80, 88, 64)]);
+    }
+
+    #[test]
+    fn keep_distant_regions_separate() {
+        let mut damage = Damage::new();
+        damage.add(Rect::new(0, 0, 10, 10));
+        damage.add(Rect::new(100, 100, 10, 10));
+        // Touches the first one diagonally, but the bounding box would be
+        // twice as large
+        damage.add(Rect::new(10, 10, 10, 10));
+        assert_eq!(damage.regions().len(), 3);
+        damage.add(Rect::new(0, 0, 0, 10));
+        assert_eq!(damage.regions().len(), 3);
+        damage.clear();
+        assert!(damage.is_empty());
+    }
+
+    #[test]
+    fn chained_merge() {
+        let mut damage = Damage::new();
+        damage.add(Rect::new(0, 0, 10, 10));
+        damage.add(Rect::new(20, 0, 10, 10));
+        // Bridges the gap between both regions
+        damage.add(Rect::new(10, 0, 10, 10));
+        assert_eq!(damage.regions(), &[Rect::new(0, 0, 30, 10)]);
+    }
+
+    #[test]
+    fn overflow_merges_closest_region() {
+        let mut damage = Damage::new();
+        for i in 0..MAX_REGIONS as u16 {
+            damage.add(Rect::new(i * 20, i * 20, 10, 10));
+        }
+        assert_eq!(damage.regions().len(), MAX_REGIONS);
+        damage.add(Rect::new(0, 15, 10, 2));
+        assert_eq!(damage.regions().len(), MAX_REGIONS);
+        assert_eq!(damage.regions()[0], Rect::new(0, 0, 10, 17));
+    }
+}
@@ -0,0 +1,426 @@
+//! Driver for the XT25F32B SPI NOR flash (4 MiB).
+//!
+//! Like all NOR flash, erased memory reads as 0xFF and programming can only
+//! clear bits. Memory must therefore be erased (in sectors of 4 KiB or blocks
+//! of 64 KiB) before it can be programmed again.
+//!
+//! Program and erase operations return as soon as they were started, which
+//! keeps the SPI bus free while the flash is busy (an erase takes up to
+//! several hundred milliseconds). Every operation first waits for the
+//! previous one to complete; `is_busy` can be used to wait without blocking.
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+
+use crate::{fs, kvstore};
+
+#[cfg(test)]
+pub mod sim;
+
+/// Size of the flash in bytes.
+pub const CAPACITY: u32 = 4 * 1024 * 1024;
+
+/// Maximum number of bytes that can be programmed at once. Programming
+/// wraps around at page boundaries.
+pub const PAGE_SIZE: u32 = 256;
+
+/// Smallest unit that can be erased.
+pub const SECTOR_SIZE: u32 = 4 * 1024;
+
+/// Size of a block for `erase_block`.
+pub const BLOCK_SIZE: u32 = 64 * 1024;
+
+/// JEDEC ID of the XT25F32B: manufacturer, memory type, capacity.
+pub const JEDEC_ID: [u8; 3] = [0x0B, 0x40, 0x16];
+
+/// Time the flash needs to wake up from deep power-down, in microseconds.
+const WAKE_UP_US: u32 = 20;
+
+// Commands
+const WRITE_ENABLE: u8 = 0x06;
+const READ_STATUS: u8 = 0x05;
+const READ_DATA: u8 = 0x03;
+const PAGE_PROGRAM: u8 = 0x02;
+const SECTOR_ERASE: u8 = 0x20;
+const BLOCK_ERASE: u8 = 0xD8;
+const DEEP_POWER_DOWN: u8 = 0xB9;
+const RELEASE_POWER_DOWN: u8 = 0xAB;
+const READ_JEDEC_ID: u8 = 0x9F;
+
+/// Status register: write in progress.
+const STATUS_BUSY: u8 = 0x01;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// SPI transfer failed.
+    Spi(E),
+    /// The chip select pin could not be set.
+    ChipSelect,
+    /// The address range exceeds the flash.
+    OutOfRange,
+    /// The address is not aligned to the sector, block or page, or the data
+    /// crosses a page boundary.
+    Unaligned,
+    /// The flash is in deep power-down.
+    PoweredDown,
+}
+
+pub struct Xt25f32b<SPI, CS> {
+    spi: SPI,
+    cs: CS,
+    powered_down: bool,
+}
+
+impl<SPI, CS, E> Xt25f32b<SPI, CS>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+{
+    /// Create the driver. The chip select pin must be high and the flash
+    /// must not be in deep power-down.
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Self {
+            spi,
+            cs,
+            powered_down: false,
+        }
+    }
+
+    /// Return the SPI bus and the chip select pin.
+    pub fn release(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    /// Read the JEDEC ID (manufacturer, memory type, capacity).
+    pub fn read_jedec_id(&mut self) -> Result<[u8; 3], Error<E>> {
+        self.wait_idle()?;
+        let mut id = [0; 3];
+        self.command(&[READ_JEDEC_ID], Some(&mut id))?;
+        Ok(id)
+    }
+
+    /// Return whether a program or erase operation is in progress.
+    pub fn is_busy(&mut self) -> Result<bool, Error<E>> {
+        if self.powered_down {
+            // The status can't be read
+            return Err(Error::PoweredDown);
+        }
+        let mut status = [0];
+        self.command(&[READ_STATUS], Some(&mut status))?;
+        Ok(status[0] & STATUS_BUSY != 0)
+    }
+
+    /// Read data starting at `address`.
+    pub fn read(&mut self, address: u32, buf: &mut [u8]) -> Result<(), Error<E>> {
+        check_range(address, buf.len())?;
+        self.wait_idle()?;
+        self.command(&address_command(READ_DATA, address), Some(buf))
+    }
+
+    /// Program data within a single page. The memory must have been erased
+    /// before, bits that are already cleared stay cleared.
+    pub fn program_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        check_range(address, data.len())?;
+        if address % PAGE_SIZE + data.len() as u32 > PAGE_SIZE {
+            return Err(Error::Unaligned);
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.wait_idle()?;
+        self.command(&[WRITE_ENABLE], None)?;
+        self.select()?;
+        let result = self
+            .spi
+            .write(&address_command(PAGE_PROGRAM, address))
+            .and_then(|_| self.spi.write(data));
+        self.deselect()?;
+        result.map_err(Error::Spi)
+    }
+
+    /// Program data of any length, splitting it at page boundaries. Waits
+    /// for every page to be programmed except for the last one.
+    pub fn program(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        check_range(address, data.len())?;
+        let mut address = address;
+        let mut data = data;
+        while !data.is_empty() {
+            let len = data.len().min((PAGE_SIZE - address % PAGE_SIZE) as usize);
+            self.program_page(address, &data[..len])?;
+            address += len as u32;
+            data = &data[len..];
+        }
+        Ok(())
+    }
+
+    /// Erase the sector starting at `address`.
+    pub fn erase_sector(&mut self, address: u32) -> Result<(), Error<E>> {
+        self.erase(SECTOR_ERASE, address, SECTOR_SIZE)
+    }
+
+    /// Erase the block starting at `address`.
+    pub fn erase_block(&mut self, address: u32) -> Result<(), Error<E>> {
+        self.erase(BLOCK_ERASE, address, BLOCK_SIZE)
+    }
+
+    /// Enter deep power-down. The flash ignores all commands until
+    /// `release_power_down` is called.
+    pub fn power_down(&mut self) -> Result<(), Error<E>> {
+        self.wait_idle()?;
+        self.command(&[DEEP_POWER_DOWN], None)?;
+        self.powered_down = true;
+        Ok(())
+    }
+
+    /// Leave deep power-down.
+    pub fn release_power_down(&mut self, delay: &mut impl DelayUs<u32>) -> Result<(), Error<E>> {
+        self.command(&[RELEASE_POWER_DOWN], None)?;
+        delay.delay_us(WAKE_UP_US);
+        self.powered_down = false;
+        Ok(())
+    }
+
+    /// Return whether the flash is in deep power-down.
+    pub fn is_powered_down(&self) -> bool {
+        self.powered_down
+    }
+
+    /// Use `sectors` sectors starting at `first_sector` as a block device.
+    pub fn partition(&mut self, first_sector: u32, sectors: u32) -> Partition<'_, SPI, CS> {
+        Partition {
+            flash: self,
+            first_sector,
+            sectors,
+        }
+    }
+
+    fn erase(&mut self, command: u8, address: u32, size: u32) -> Result<(), Error<E>> {
+        check_range(address, size as usize)?;
+        // Sizes are powers of two
+        if address & (size - 1) != 0 {
+            return Err(Error::Unaligned);
+        }
+        self.wait_idle()?;
+        self.command(&[WRITE_ENABLE], None)?;
+        self.command(&address_command(command, address), None)
+    }
+
+    /// Wait for a program or erase operation to complete.
+    fn wait_idle(&mut self) -> Result<(), Error<E>> {
+        while self.is_busy()? {}
+        Ok(())
+    }
+
+    /// Send a command, then read the response into `response`, if any.
+    fn command(&mut self, command: &[u8], response: Option<&mut [u8]>) -> Result<(), Error<E>> {
+        self.select()?;
+        let mut result = self.spi.write(command);
+        if let Some(response) = response {
+            if result.is_ok() {
+                for byte in response.iter_mut() {
+                    *byte = 0xFF;
+                }
+                result = self.spi.transfer(response).map(|_| ());
+            }
+        }
+        // Commands are only executed once the chip select is high again
+        self.deselect()?;
+        result.map_err(Error::Spi)
+    }
+
+    fn select(&mut self) -> Result<(), Error<E>> {
+        self.cs.set_low().map_err(|_| Error::ChipSelect)
+    }
+
+    fn deselect(&mut self) -> Result<(), Error<E>> {
+        self.cs.set_high().map_err(|_| Error::ChipSelect)
+    }
+}
+
+impl<SPI, CS, E> kvstore::Storage for Xt25f32b<SPI, CS>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+{
+    type Error = Error<E>;
+
+    fn read(&mut self, address: u32, buf: &mut [u8]) -> Result<(), Self::Error> {
+        Xt25f32b::read(self, address, buf)
+    }
+
+    fn program(&mut self, address: u32, data: &[u8]) -> Result<(), Self::Error> {
+        Xt25f32b::program(self, address, data)
+    }
+
+    fn erase_sector(&mut self, address: u32) -> Result<(), Self::Error> {
+        Xt25f32b::erase_sector(self, address)
+    }
+}
+
+/// Consecutive sectors of the flash, used as blocks of a file system.
+pub struct Partition<'a, SPI, CS> {
+    flash: &'a mut Xt25f32b<SPI, CS>,
+    first_sector: u32,
+    sectors: u32,
+}
+
+impl<'a, SPI, CS> Partition<'a, SPI, CS> {
+    fn address<E>(&self, block: u32, offset: u32) -> Result<u32, Error<E>> {
+        if block >= self.sectors {
+            return Err(Error::OutOfRange);
+        }
+        Ok((self.first_sector + block) * SECTOR_SIZE + offset)
+    }
+}
+
+impl<'a, SPI, CS, E> fs::BlockDevice for Partition<'a, SPI, CS>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+{
+    type Error = Error<E>;
+
+    fn block_count(&self) -> u32 {
+        self.sectors
+    }
+
+    fn read(&mut self, block: u32, offset: u32, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let address = self.address(block, offset)?;
+        self.flash.read(address, buf)
+    }
+
+    fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), Self::Error> {
+        let address = self.address(block, offset)?;
+        self.flash.program(address, data)
+    }
+
+    fn erase(&mut self, block: u32) -> Result<(), Self::Error> {
+        let address = self.address(block, 0)?;
+        self.flash.erase_sector(address)
+    }
+}
+
+/// Check that `len` bytes starting at `address` are within the flash.
+fn check_range<E>(address: u32, len: usize) -> Result<(), Error<E>> {
+    if address as u64 + len as u64 > CAPACITY as u64 {
+        Err(Error::OutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// Encode a command followed by a 24 bit address.
+fn address_command(command: u8, address: u32) -> [u8; 4] {
+    let [_, a2, a1, a0] = address.to_be_bytes();
+    [command, a2, a1, a0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sim::SimFlash;
+    use super::*;
+
+    struct NoDelay;
+
+    impl DelayUs<u32> for NoDelay {
+        fn delay_us(&mut self, _us: u32) {}
+    }
+
+    fn driver(sim: &SimFlash) -> Xt25f32b<sim::SimSpi, sim::SimCs> {
+        Xt25f32b::new(sim.spi(), sim.cs())
+    }
+
+    #[test]
+    fn jedec_id() {
+        let sim = SimFlash::new();
+        assert_eq!(driver(&sim).read_jedec_id(), Ok(JEDEC_ID));
+    }
+
+    #[test]
+    fn program_and_read() {
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        let mut buf = [0; 4];
+        flash.read(0x1000, &mut buf).unwrap();
+        assert_eq!(buf, [0xFF; 4]);
+
+        // Spans three pages
+        let data: Vec<u8> = (0..600).map(|i| i as u8).collect();
+        flash.program(0x10F0, &data).unwrap();
+        let mut buf = vec![0; 600];
+        flash.read(0x10F0, &mut buf).unwrap();
+        assert_eq!(buf, data);
+        assert_eq!(sim.memory(0x10EF, 1), [0xFF]);
+    }
+
+    #[test]
+    fn program_only_clears_bits() {
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        flash.program_page(0, &[0b1100_1100]).unwrap();
+        flash.program_page(0, &[0b1010_1010]).unwrap();
+        assert_eq!(sim.memory(0, 1), [0b1000_1000]);
+
+        flash.erase_sector(0).unwrap();
+        flash.program_page(0, &[0b1010_1010]).unwrap();
+        assert_eq!(sim.memory(0, 1), [0b1010_1010]);
+    }
+
+    #[test]
+    fn erase() {
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        flash.program(SECTOR_SIZE - 1, &[0, 0]).unwrap();
+        flash.erase_sector(0).unwrap();
+        assert_eq!(sim.memory(SECTOR_SIZE - 1, 2), [0xFF, 0]);
+        assert_eq!(sim.erase_count(0), 1);
+        assert_eq!(sim.erase_count(1), 0);
+
+        flash.erase_block(0).unwrap();
+        assert_eq!(sim.memory(SECTOR_SIZE, 1), [0xFF]);
+        assert_eq!(sim.erase_count(1), 1);
+    }
+
+    #[test]
+    fn reject_invalid_ranges() {
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        assert_eq!(flash.erase_sector(100), Err(Error::Unaligned));
+        assert_eq!(flash.erase_block(SECTOR_SIZE), Err(Error::Unaligned));
+        assert_eq!(flash.program_page(250, &[0; 10]), Err(Error::Unaligned));
+        assert_eq!(
+            flash.read(CAPACITY - 1, &mut [0; 2]),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(flash.erase_sector(CAPACITY), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn partition() {
+        use crate::fs::BlockDevice;
+
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        let mut partition = flash.partition(2, 3);
+        assert_eq!(partition.block_count(), 3);
+        partition.program(1, 10, &[1, 2]).unwrap();
+        assert_eq!(sim.memory(3 * SECTOR_SIZE + 10, 2), [1, 2]);
+        partition.erase(1).unwrap();
+        assert_eq!(sim.erase_count(3), 1);
+        assert_eq!(partition.erase(3), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn deep_power_down() {
+        let sim = SimFlash::new();
+        let mut flash = driver(&sim);
+        flash.power_down().unwrap();
+        assert!(sim.is_powered_down());
+        assert_eq!(flash.program_page(0, &[0]), Err(Error::PoweredDown));
+        assert_eq!(flash.read_jedec_id(), Err(Error::PoweredDown));
+        flash.release_power_down(&mut NoDelay).unwrap();
+        assert!(!sim.is_powered_down());
+        assert_eq!(flash.read_jedec_id(), Ok(JEDEC_ID));
+    }
+}
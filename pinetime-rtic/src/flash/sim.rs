@@ -0,0 +1,260 @@
+//! In-memory simulation of the XT25F32B for host tests.
+//!
+//! The simulator decodes the commands sent through its SPI bus and chip
+//! select pin like the real flash: commands only take effect once the chip
+//! select goes high, programming and erasing require a preceding "write
+//! enable", programming can only clear bits and wraps around at page
+//! boundaries, and nothing but "release power-down" is accepted in deep
+//! power-down. Program and erase operations complete immediately.
+
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+
+use super::{CAPACITY, JEDEC_ID, PAGE_SIZE, SECTOR_SIZE};
+
+struct State {
+    memory: Vec<u8>,
+    erase_counts: Vec<u32>,
+    /// Bytes received since the chip select went low, if it is low.
+    command: Option<Vec<u8>>,
+    write_enabled: bool,
+    powered_down: bool,
+}
+
+impl State {
+    /// Exchange a byte with the flash.
+    fn exchange(&mut self, byte: u8) -> u8 {
+        let command = match &mut self.command {
+            Some(command) => command,
+            // Not selected, the data line floats
+            None => return 0xFF,
+        };
+        command.push(byte);
+        let position = command.len() - 1;
+        if self.powered_down || position == 0 {
+            return 0xFF;
+        }
+        match command[0] {
+            0x9F => JEDEC_ID.get(position - 1).copied().unwrap_or(0xFF),
+            0x05 => {
+                // Never busy, write enable latch in bit 1
+                (self.write_enabled as u8) << 1
+            }
+            0x03 if position >= 4 => {
+                let address = address(&command[1..4]) as usize + position - 4;
+                self.memory[address % CAPACITY as usize]
+            }
+            _ => 0xFF,
+        }
+    }
+
+    /// Execute the command when the chip select goes high.
+    fn execute(&mut self, command: &[u8]) {
+        if command.is_empty() {
+            return;
+        }
+        if self.powered_down {
+            if command[0] == 0xAB {
+                self.powered_down = false;
+            }
+            return;
+        }
+        match command[0] {
+            0x06 => self.write_enabled = true,
+            0x04 => self.write_enabled = false,
+            0x02 if self.write_enabled && command.len() > 4 => {
+                let start = address(&command[1..4]);
+                let page = start - start % PAGE_SIZE;
+                for (offset, byte) in command[4..].iter().enumerate() {
+                    let address = page + (start % PAGE_SIZE + offset as u32) % PAGE_SIZE;
+                    self.memory[address as usize] &= byte;
+                }
+                self.write_enabled = false;
+            }
+            0x20 if self.write_enabled && command.len() == 4 => {
+                self.erase(address(&command[1..4]), SECTOR_SIZE);
+            }
+            0x52 if self.write_enabled && command.len() == 4 => {
+                self.erase(address(&command[1..4]), 32 * 1024);
+            }
+            0xD8 if self.write_enabled && command.len() == 4 => {
+                self.erase(address(&command[1..4]), 64 * 1024);
+            }
+            0xB9 => self.powered_down = true,
+            _ => {}
+        }
+    }
+
+    fn erase(&mut self, address: u32, size: u32) {
+        let start = (address - address % size) as usize;
+        for byte in &mut self.memory[start..start + size as usize] {
+            *byte = 0xFF;
+        }
+        let first_sector = start / SECTOR_SIZE as usize;
+        for count in &mut self.erase_counts[first_sector..][..(size / SECTOR_SIZE) as usize] {
+            *count += 1;
+        }
+        self.write_enabled = false;
+    }
+}
+
+fn address(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]) % CAPACITY
+}
+
+/// A simulated flash, initially erased.
+pub struct SimFlash {
+    state: Rc<RefCell<State>>,
+}
+
+impl SimFlash {
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(State {
+                memory: vec![0xFF; CAPACITY as usize],
+                erase_counts: vec![0; (CAPACITY / SECTOR_SIZE) as usize],
+                command: None,
+                write_enabled: false,
+                powered_down: false,
+            })),
+        }
+    }
+
+    /// Return the SPI bus of the flash.
+    pub fn spi(&self) -> SimSpi {
+        SimSpi {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Return the chip select pin of the flash.
+    pub fn cs(&self) -> SimCs {
+        SimCs {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Return a copy of the memory contents.
+    pub fn memory(&self, address: u32, len: usize) -> Vec<u8> {
+        let start = address as usize;
+        self.state.borrow().memory[start..start + len].to_vec()
+    }
+
+    /// Return how many times the sector with the specified index was erased.
+    pub fn erase_count(&self, sector: usize) -> u32 {
+        self.state.borrow().erase_counts[sector]
+    }
+
+    /// Return whether the flash is in deep power-down.
+    pub fn is_powered_down(&self) -> bool {
+        self.state.borrow().powered_down
+    }
+}
+
+impl Default for SimFlash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct SimSpi {
+    state: Rc<RefCell<State>>,
+}
+
+impl Transfer<u8> for SimSpi {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        let mut state = self.state.borrow_mut();
+        for word in words.iter_mut() {
+            *word = state.exchange(*word);
+        }
+        Ok(words)
+    }
+}
+
+impl Write<u8> for SimSpi {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        let mut state = self.state.borrow_mut();
+        for word in words {
+            state.exchange(*word);
+        }
+        Ok(())
+    }
+}
+
+pub struct SimCs {
+    state: Rc<RefCell<State>>,
+}
+
+impl OutputPin for SimCs {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let mut state = self.state.borrow_mut();
+        if state.command.is_none() {
+            state.command = Some(Vec::new());
+        }
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let mut state = self.state.borrow_mut();
+        if let Some(command) = state.command.take() {
+            state.execute(&command);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Send a raw command.
+    fn command(sim: &SimFlash, bytes: &[u8]) {
+        sim.cs().set_low().unwrap();
+        sim.spi().write(bytes).unwrap();
+        sim.cs().set_high().unwrap();
+    }
+
+    #[test]
+    fn program_requires_write_enable() {
+        let sim = SimFlash::new();
+        command(&sim, &[0x02, 0, 0, 0, 0x00]);
+        assert_eq!(sim.memory(0, 1), [0xFF]);
+        command(&sim, &[0x06]);
+        command(&sim, &[0x02, 0, 0, 0, 0x00]);
+        assert_eq!(sim.memory(0, 1), [0x00]);
+        // The write enable latch is reset afterwards
+        command(&sim, &[0x02, 0, 0, 1, 0x00]);
+        assert_eq!(sim.memory(1, 1), [0xFF]);
+    }
+
+    #[test]
+    fn program_wraps_at_page_boundary() {
+        let sim = SimFlash::new();
+        command(&sim, &[0x06]);
+        command(&sim, &[0x02, 0, 0x01, 0xFF, 0x11, 0x22]);
+        assert_eq!(sim.memory(0x1FF, 1), [0x11]);
+        assert_eq!(sim.memory(0x100, 1), [0x22]);
+        assert_eq!(sim.memory(0x200, 1), [0xFF]);
+    }
+
+    #[test]
+    fn ignore_commands_in_power_down() {
+        let sim = SimFlash::new();
+        command(&sim, &[0xB9]);
+        command(&sim, &[0x06]);
+        command(&sim, &[0x02, 0, 0, 0, 0x00]);
+        assert_eq!(sim.memory(0, 1), [0xFF]);
+        command(&sim, &[0xAB]);
+        assert!(!sim.is_powered_down());
+    }
+}
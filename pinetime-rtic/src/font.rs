@@ -0,0 +1,559 @@
+//! Proportional fonts with anti-aliased glyphs.
+//!
+//! Every glyph has its own width and a bitmap with the coverage of its
+//! pixels, using 1, 2, 4 or 8 bits per pixel. Without a framebuffer, the
+//! pixels behind the text can't be read back. The coverage is therefore
+//! blended with the background color of the text instead, which must be the
+//! color of the area the text is drawn on.
+//!
+//! Text is drawn with a list of fonts, e.g. one for Latin script and one for
+//! symbols. Every character is taken from the first font that has it.
+//! Characters that no font has are shown as the replacement character, so
+//! that missing glyphs are noticeable instead of silently skipped.
+//!
+//! Fonts are converted from TTF or BDF files to Rust source with the
+//! `fontconv` tool.
+
+use embedded_graphics::{
+    drawable::{Drawable, Pixel},
+    geometry::{Point, Size},
+    pixelcolor::{Rgb565, RgbColor},
+    DrawTarget,
+};
+
+pub mod digits_48;
+pub mod symbols_16;
+pub mod text_16;
+
+pub use digits_48::DIGITS_48;
+pub use symbols_16::SYMBOLS_16;
+pub use text_16::TEXT_16;
+
+/// Shown for characters that none of the fonts has.
+pub const REPLACEMENT: char = '\u{FFFD}';
+
+/// A proportional font.
+pub struct Font {
+    /// Distance between the tops of two lines.
+    pub line_height: u8,
+    /// Distance from the top of a line to the baseline.
+    pub baseline: u8,
+    /// Bits per pixel of the bitmaps: 1, 2, 4 or 8.
+    pub bpp: u8,
+    /// The glyphs, sorted by character.
+    pub glyphs: &'static [Glyph],
+    /// The bitmaps of the glyphs. Every bitmap starts at a byte boundary and
+    /// contains the rows of the glyph without padding, with the first pixel
+    /// in the highest bits.
+    pub bitmaps: &'static [u8],
+}
+
+/// A character of a font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph {
+    pub c: char,
+    /// Horizontal distance from the start of the glyph to the next one.
+    pub advance: u8,
+    /// Distance from the start of the glyph to the left of the bitmap.
+    pub left: i8,
+    /// Distance from the baseline up to the top of the bitmap.
+    pub top: i8,
+    pub width: u8,
+    pub height: u8,
+    /// Offset of the bitmap in `Font::bitmaps`.
+    pub offset: u32,
+}
+
+impl Font {
+    /// Return the glyph of a character, if the font has one.
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs
+            .binary_search_by_key(&c, |glyph| glyph.c)
+            .ok()
+            .map(|index| &self.glyphs[index])
+    }
+
+    /// Return the width of a line of text. Characters without a glyph are
+    /// skipped.
+    pub fn text_width(&self, text: &str) -> u32 {
+        text.chars()
+            .filter_map(|c| self.glyph(c))
+            .map(|glyph| glyph.advance as u32)
+            .sum()
+    }
+
+    /// Return the coverage of the pixels of a glyph (0–255), row by row.
+    pub fn coverage<'a>(&'a self, glyph: &Glyph) -> impl Iterator<Item = u8> + 'a {
+        let bpp = self.bpp as usize;
+        let max = (1u16 << bpp) - 1;
+        let bitmap = &self.bitmaps[glyph.offset as usize..];
+        (0..glyph.width as usize * glyph.height as usize).map(move |i| {
+            let bit = i * bpp;
+            let value = (bitmap[bit / 8] >> (8 - bpp - bit % 8)) as u16 & max;
+            (value * 255 / max) as u8
+        })
+    }
+}
+
+/// Blend `color` with `background`. `alpha` is the coverage of `color`
+/// (0–255).
+pub fn blend(color: Rgb565, background: Rgb565, alpha: u8) -> Rgb565 {
+    let mix = |color: u8, background: u8| {
+        let mixed = color as u16 * alpha as u16 + background as u16 * (255 - alpha as u16);
+        ((mixed + 127) / 255) as u8
+    };
+    Rgb565::new(
+        mix(color.r(), background.r()),
+        mix(color.g(), background.g()),
+        mix(color.b(), background.b()),
+    )
+}
+
+#[derive(Clone, Copy)]
+pub struct TextStyle<'a> {
+    /// The fonts, in the order in which they are searched for a glyph.
+    pub fonts: &'a [&'a Font],
+    pub color: Rgb565,
+    /// The color of the area the text is drawn on.
+    pub background: Rgb565,
+}
+
+impl<'a> TextStyle<'a> {
+    /// Return the glyph of a character and the font it belongs to. Missing
+    /// characters are replaced by `REPLACEMENT`, or `?` if no font has that
+    /// either. Control characters have no glyph.
+    pub fn glyph(&self, c: char) -> Option<(&'a Font, &'a Glyph)> {
+        if c.is_control() {
+            return None;
+        }
+        let find = |c| {
+            self.fonts
+                .iter()
+                .find_map(|font| font.glyph(c).map(|glyph| (*font, glyph)))
+        };
+        find(c).or_else(|| find(REPLACEMENT)).or_else(|| find('?'))
+    }
+
+    /// Return the width of a line of text.
+    pub fn text_width(&self, text: &str) -> u32 {
+        text.chars()
+            .filter_map(|c| self.glyph(c))
+            .map(|(_, glyph)| glyph.advance as u32)
+            .sum()
+    }
+
+    /// Distance from the top of a line to the baseline, which is shared by
+    /// all fonts.
+    pub fn baseline(&self) -> u32 {
+        self.fonts
+            .iter()
+            .map(|font| font.baseline as u32)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Distance between the tops of two lines.
+    pub fn line_height(&self) -> u32 {
+        let baseline = self.baseline();
+        self.fonts
+            .iter()
+            .map(|font| baseline + font.line_height as u32 - font.baseline as u32)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Draw a line of text with its top left corner at `position`.
+    fn draw_line<D: DrawTarget<Rgb565>>(
+        &self,
+        text: &str,
+        position: Point,
+        display: &mut D,
+    ) -> Result<(), D::Error> {
+        let baseline = position.y + self.baseline() as i32;
+        let mut x = position.x;
+        for (font, glyph) in text.chars().filter_map(|c| self.glyph(c)) {
+            let left = x + glyph.left as i32;
+            let top = baseline - glyph.top as i32;
+            let width = glyph.width as usize;
+            for (i, alpha) in font.coverage(glyph).enumerate() {
+                if alpha == 0 {
+                    continue;
+                }
+                let point = Point::new(left + (i % width) as i32, top + (i / width) as i32);
+                let color = blend(self.color, self.background, alpha);
+                display.draw_pixel(Pixel(point, color))?;
+            }
+            x += glyph.advance as i32;
+        }
+        Ok(())
+    }
+}
+
+/// A line of text.
+#[derive(Clone, Copy)]
+pub struct Text<'a> {
+    text: &'a str,
+    /// Top left corner of the line.
+    position: Point,
+    style: TextStyle<'a>,
+}
+
+impl<'a> Text<'a> {
+    pub fn new(text: &'a str, position: Point, style: TextStyle<'a>) -> Self {
+        Self {
+            text,
+            position,
+            style,
+        }
+    }
+
+    /// Return the size of the line. Glyphs may extend beyond it, e.g. in
+    /// italic fonts.
+    pub fn size(&self) -> Size {
+        Size::new(self.style.text_width(self.text), self.style.line_height())
+    }
+}
+
+impl<'a> Drawable<Rgb565> for &Text<'a> {
+    fn draw<D: DrawTarget<Rgb565>>(self, display: &mut D) -> Result<(), D::Error> {
+        self.style.draw_line(self.text, self.position, display)
+    }
+}
+
+/// Text that is wrapped into a box.
+///
+/// Lines are broken at spaces, words that are wider than the box are broken
+/// anywhere. Line breaks in the text are kept. Lines below the box are not
+/// drawn.
+#[derive(Clone, Copy)]
+pub struct TextBox<'a> {
+    text: &'a str,
+    /// Top left corner of the box.
+    position: Point,
+    size: Size,
+    style: TextStyle<'a>,
+}
+
+impl<'a> TextBox<'a> {
+    pub fn new(text: &'a str, position: Point, size: Size, style: TextStyle<'a>) -> Self {
+        Self {
+            text,
+            position,
+            size,
+            style,
+        }
+    }
+
+    /// Return the wrapped lines, including the ones below the box.
+    pub fn lines(&self) -> Lines<'a> {
+        Lines {
+            text: self.text,
+            width: self.size.width,
+            style: self.style,
+        }
+    }
+}
+
+impl<'a> Drawable<Rgb565> for &TextBox<'a> {
+    fn draw<D: DrawTarget<Rgb565>>(self, display: &mut D) -> Result<(), D::Error> {
+        let line_height = self.style.line_height();
+        let rows = self.size.height / line_height.max(1);
+        for (row, line) in self.lines().take(rows as usize).enumerate() {
+            let y = self.position.y + (row as u32 * line_height) as i32;
+            self.style
+                .draw_line(line, Point::new(self.position.x, y), display)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the lines of wrapped text.
+pub struct Lines<'a> {
+    text: &'a str,
+    width: u32,
+    style: TextStyle<'a>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.text.is_empty() {
+            return None;
+        }
+        let text = self.text;
+        let mut width = 0;
+        // Where the line can be broken: the end of the line and the start of
+        // the next one
+        let mut space = None;
+        for (i, c) in text.char_indices() {
+            if c == '\n' {
+                self.text = &text[i + 1..];
+                return Some(text[..i].trim_end_matches(' '));
+            }
+            if c == ' ' && i > 0 {
+                space = Some(i);
+            }
+            let advance = self.style.glyph(c).map_or(0, |(_, glyph)| glyph.advance);
+            width += advance as u32;
+            if width > self.width && c != ' ' {
+                let (end, next) = match space {
+                    Some(space) => (space, space + 1),
+                    // At least one character per line
+                    None if i == 0 => (c.len_utf8(), c.len_utf8()),
+                    None => (i, i),
+                };
+                self.text = text[next..].trim_start_matches(' ');
+                return Some(text[..end].trim_end_matches(' '));
+            }
+        }
+        self.text = "";
+        Some(text.trim_end_matches(' '))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    /// A font with 2 bits per pixel.
+    static FONT: Font = Font {
+        line_height: 6,
+        baseline: 4,
+        bpp: 2,
+        glyphs: &[
+            Glyph {
+                c: '-',
+                advance: 3,
+                left: 0,
+                top: 2,
+                width: 2,
+                height: 1,
+                offset: 0,
+            },
+            Glyph {
+                c: 'l',
+                advance: 2,
+                left: 1,
+                top: 4,
+                width: 1,
+                height: 4,
+                offset: 1,
+            },
+            Glyph {
+                c: 'ä',
+                advance: 4,
+                left: 0,
+                top: 3,
+                width: 3,
+                height: 3,
+                offset: 2,
+            },
+        ],
+        bitmaps: &[
+            // '-': full, two thirds
+            0b1110_0000,
+            // 'l': full, full, one third, none
+            0b1111_0100,
+            // 'ä': full, none, full / none, full, none / full, full, full
+            0b1100_1100,
+            0b1100_1111,
+            0b1100_0000,
+        ],
+    };
+
+    /// A font with 1 bit per pixel and a lower baseline.
+    static FALLBACK: Font = Font {
+        line_height: 5,
+        baseline: 5,
+        bpp: 1,
+        glyphs: &[
+            Glyph {
+                c: ' ',
+                advance: 1,
+                left: 0,
+                top: 0,
+                width: 0,
+                height: 0,
+                offset: 0,
+            },
+            Glyph {
+                c: 'x',
+                advance: 2,
+                left: 0,
+                top: 1,
+                width: 1,
+                height: 1,
+                offset: 0,
+            },
+            Glyph {
+                c: REPLACEMENT,
+                advance: 3,
+                left: 1,
+                top: 5,
+                width: 1,
+                height: 1,
+                offset: 1,
+            },
+        ],
+        bitmaps: &[0b1000_0000, 0b1000_0000],
+    };
+
+    const STYLE: TextStyle = TextStyle {
+        fonts: &[&FONT, &FALLBACK],
+        color: Rgb565::WHITE,
+        background: Rgb565::BLACK,
+    };
+
+    /// Records the drawn pixels.
+    struct Display(Vec<Pixel<Rgb565>>);
+
+    impl DrawTarget<Rgb565> for Display {
+        type Error = Infallible;
+
+        fn draw_pixel(&mut self, pixel: Pixel<Rgb565>) -> Result<(), Self::Error> {
+            self.0.push(pixel);
+            Ok(())
+        }
+
+        fn size(&self) -> Size {
+            Size::new(240, 240)
+        }
+    }
+
+    #[test]
+    fn glyphs() {
+        assert_eq!(FONT.glyph('l').unwrap().offset, 1);
+        assert_eq!(FONT.glyph('ä').unwrap().width, 3);
+        assert_eq!(FONT.glyph('a'), None);
+        assert_eq!(FONT.text_width("-lä"), 9);
+        assert_eq!(FONT.text_width("-x-"), 6);
+
+        let coverage: Vec<_> = FONT.coverage(FONT.glyph('-').unwrap()).collect();
+        assert_eq!(coverage, [255, 170]);
+        let coverage: Vec<_> = FONT.coverage(FONT.glyph('l').unwrap()).collect();
+        assert_eq!(coverage, [255, 255, 85, 0]);
+    }
+
+    #[test]
+    fn generated() {
+        for font in [&DIGITS_48, &SYMBOLS_16, &TEXT_16] {
+            assert!(font.glyphs.windows(2).all(|pair| pair[0].c < pair[1].c));
+            for glyph in font.glyphs {
+                let bits = glyph.width as usize * glyph.height as usize * font.bpp as usize;
+                assert!(glyph.offset as usize + (bits + 7) / 8 <= font.bitmaps.len());
+                assert!(glyph.top as i32 <= font.baseline as i32);
+            }
+        }
+        assert_eq!(DIGITS_48.text_width("12:34"), 4 * 33 + 19);
+        assert!(TEXT_16.glyph(REPLACEMENT).is_some());
+        assert!("Grüße, Ærøskøbing – Łódź, 5 €"
+            .chars()
+            .all(|c| TEXT_16.glyph(c).is_some()));
+    }
+
+    #[test]
+    fn blending() {
+        let background = Rgb565::new(0, 8, 0);
+        assert_eq!(blend(Rgb565::WHITE, background, 255), Rgb565::WHITE);
+        assert_eq!(blend(Rgb565::WHITE, background, 0), background);
+        assert_eq!(
+            blend(Rgb565::WHITE, background, 128),
+            Rgb565::new(16, 36, 16)
+        );
+    }
+
+    #[test]
+    fn draw() {
+        let style = TextStyle {
+            fonts: &[&FONT],
+            color: Rgb565::WHITE,
+            background: Rgb565::BLACK,
+        };
+        let text = Text::new("l-?", Point::new(10, 20), style);
+        assert_eq!(text.size(), Size::new(5, 6));
+
+        let mut display = Display(Vec::new());
+        text.draw(&mut display).unwrap();
+        let gray = |alpha| blend(Rgb565::WHITE, Rgb565::BLACK, alpha);
+        assert_eq!(
+            display.0,
+            [
+                // 'l' starts one pixel to the right, at the top of the line
+                Pixel(Point::new(11, 20), Rgb565::WHITE),
+                Pixel(Point::new(11, 21), Rgb565::WHITE),
+                Pixel(Point::new(11, 22), gray(85)),
+                // '-' is two pixels above the baseline
+                Pixel(Point::new(12, 22), Rgb565::WHITE),
+                Pixel(Point::new(13, 22), gray(170)),
+            ]
+        );
+    }
+
+    #[test]
+    fn fallback() {
+        let (font, glyph) = STYLE.glyph('l').unwrap();
+        assert!(core::ptr::eq(font, &FONT));
+        assert_eq!(glyph.c, 'l');
+        let (font, glyph) = STYLE.glyph('x').unwrap();
+        assert!(core::ptr::eq(font, &FALLBACK));
+        assert_eq!(glyph.c, 'x');
+        assert_eq!(STYLE.glyph('€').unwrap().1.c, REPLACEMENT);
+        assert!(STYLE.glyph('\r').is_none());
+        assert_eq!(STYLE.text_width("lx€\r"), 7);
+
+        // Without a replacement glyph, missing characters are skipped
+        let style = TextStyle {
+            fonts: &[&FONT],
+            ..STYLE
+        };
+        assert!(style.glyph('€').is_none());
+
+        // The fonts share the lowest baseline
+        assert_eq!(STYLE.baseline(), 5);
+        assert_eq!(STYLE.line_height(), 7);
+        let mut display = Display(Vec::new());
+        Text::new("-x", Point::new(0, 0), STYLE)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(
+            display.0,
+            [
+                Pixel(Point::new(0, 3), Rgb565::WHITE),
+                Pixel(Point::new(1, 3), blend(Rgb565::WHITE, Rgb565::BLACK, 170)),
+                Pixel(Point::new(3, 4), Rgb565::WHITE),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap() {
+        let lines = |text, width| -> Vec<_> {
+            TextBox::new(text, Point::new(0, 0), Size::new(width, 100), STYLE)
+                .lines()
+                .collect()
+        };
+        assert_eq!(
+            lines("ll ll ll\n\nxxxxxxxx", 6),
+            ["ll", "ll", "ll", "", "xxx", "xxx", "xx"]
+        );
+        assert_eq!(lines("ll    ll  ", 6), ["ll", "ll"]);
+        assert_eq!(lines(" xxxx", 6), [" xx", "xx"]);
+        assert_eq!(lines("äl", 1), ["ä", "l"]);
+        assert_eq!(lines("", 6), [] as [&str; 0]);
+
+        // Only the lines that fit into the box are drawn
+        let mut display = Display(Vec::new());
+        TextBox::new("x x x", Point::new(0, 0), Size::new(3, 15), STYLE)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(
+            display.0,
+            [
+                Pixel(Point::new(0, 4), Rgb565::WHITE),
+                Pixel(Point::new(0, 11), Rgb565::WHITE),
+            ]
+        );
+    }
+}
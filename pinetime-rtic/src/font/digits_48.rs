@@ -0,0 +1,474 @@
+//! Generated with `fontconv --size 48 --bpp 4 --chars 0123456789:- DejaVuSans-Bold.ttf`.
+
+use super::{Font, Glyph};
+
+pub static DIGITS_48: Font = Font {
+    line_height: 56,
+    baseline: 45,
+    bpp: 4,
+    glyphs: &[
+        Glyph {
+            c: '-',
+            advance: 20,
+            left: 2,
+            top: 18,
+            width: 16,
+            height: 8,
+            offset: 0,
+        },
+        Glyph {
+            c: '0',
+            advance: 33,
+            left: 2,
+            top: 36,
+            width: 30,
+            height: 37,
+            offset: 64,
+        },
+        Glyph {
+            c: '1',
+            advance: 33,
+            left: 5,
+            top: 35,
+            width: 26,
+            height: 35,
+            offset: 619,
+        },
+        Glyph {
+            c: '2',
+            advance: 33,
+            left: 3,
+            top: 36,
+            width: 27,
+            height: 36,
+            offset: 1074,
+        },
+        Glyph {
+            c: '3',
+            advance: 33,
+            left: 3,
+            top: 36,
+            width: 27,
+            height: 37,
+            offset: 1560,
+        },
+        Glyph {
+            c: '4',
+            advance: 33,
+            left: 2,
+            top: 35,
+            width: 30,
+            height: 35,
+            offset: 2060,
+        },
+        Glyph {
+            c: '5',
+            advance: 33,
+            left: 3,
+            top: 35,
+            width: 28,
+            height: 36,
+            offset: 2585,
+        },
+        Glyph {
+            c: '6',
+            advance: 33,
+            left: 2,
+            top: 36,
+            width: 29,
+            height: 37,
+            offset: 3089,
+        },
+        Glyph {
+            c: '7',
+            advance: 33,
+            left: 3,
+            top: 35,
+            width: 27,
+            height: 35,
+            offset: 3626,
+        },
+        Glyph {
+            c: '8',
+            advance: 33,
+            left: 2,
+            top: 36,
+            width: 29,
+            height: 37,
+            offset: 4099,
+        },
+        Glyph {
+            c: '9',
+            advance: 33,
+            left: 2,
+            top: 36,
+            width: 29,
+            height: 37,
+            offset: 4636,
+        },
+        Glyph {
+            c: ':',
+            advance: 19,
+            left: 5,
+            top: 27,
+            width: 9,
+            height: 27,
+            offset: 5173,
+        },
+    ],
+    bitmaps: &[
+        0x13, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x31, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xf5, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf5, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xf5, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf5, 0x6f, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xf5, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf5, 0x49, 0x99, 0x99, 0x99,
+        0x99, 0x99, 0x99, 0x93, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x79, 0x99, 0x86, 0x30, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6c, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x82,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfb, 0x10, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xc1, 0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfa, 0x00, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x9a, 0xdf, 0xff, 0xff,
+        0xff, 0xff, 0x50, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xfc, 0x20, 0x00, 0x07, 0xff, 0xff,
+        0xff, 0xff, 0xd0, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xe1, 0x00, 0x00, 0x00, 0x9f, 0xff,
+        0xff, 0xff, 0xf5, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00, 0x1f, 0xff,
+        0xff, 0xff, 0xfb, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x0b, 0xff,
+        0xff, 0xff, 0xff, 0x10, 0x0e, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x07, 0xff,
+        0xff, 0xff, 0xff, 0x50, 0x2f, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x04, 0xff,
+        0xff, 0xff, 0xff, 0x80, 0x5f, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff,
+        0xff, 0xff, 0xff, 0xb0, 0x7f, 0xff, 0xff, 0xff, 0xf7, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff,
+        0xff, 0xff, 0xff, 0xd0, 0x8f, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xe0, 0xaf, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xf1, 0xaf, 0xff, 0xff, 0xff, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef,
+        0xff, 0xff, 0xff, 0xf1, 0xaf, 0xff, 0xff, 0xff, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef,
+        0xff, 0xff, 0xff, 0xf1, 0xaf, 0xff, 0xff, 0xff, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef,
+        0xff, 0xff, 0xff, 0xf1, 0xaf, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xf1, 0x9f, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xf0, 0x7f, 0xff, 0xff, 0xff, 0xf7, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff,
+        0xff, 0xff, 0xff, 0xd0, 0x5f, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff,
+        0xff, 0xff, 0xff, 0xb0, 0x2f, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff,
+        0xff, 0xff, 0xff, 0x80, 0x0e, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x07, 0xff,
+        0xff, 0xff, 0xff, 0x50, 0x0a, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x0b, 0xff,
+        0xff, 0xff, 0xff, 0x10, 0x05, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00, 0x1f, 0xff,
+        0xff, 0xff, 0xfb, 0x00, 0x01, 0xef, 0xff, 0xff, 0xff, 0xe1, 0x00, 0x00, 0x00, 0x9f, 0xff,
+        0xff, 0xff, 0xf5, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xff, 0xfb, 0x10, 0x00, 0x07, 0xff, 0xff,
+        0xff, 0xff, 0xd0, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xff, 0xff, 0xea, 0x78, 0xcf, 0xff, 0xff,
+        0xff, 0xff, 0x50, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xc1, 0x00, 0x00, 0x00, 0x00, 0x07, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfc, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x7d, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x92,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x26, 0x89, 0xaa, 0x97, 0x40, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25, 0x9c, 0xef, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00,
+        0x00, 0x00, 0x38, 0xbe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00,
+        0x9f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xfc, 0x96, 0x8f, 0xff, 0xff, 0xff,
+        0xf2, 0x00, 0x00, 0x00, 0x00, 0x79, 0x63, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f,
+        0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff,
+        0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff,
+        0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff,
+        0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x6f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f,
+        0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x13, 0x33, 0x33, 0x33, 0x8f, 0xff, 0xff,
+        0xff, 0xf5, 0x33, 0x33, 0x33, 0x30, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xf1, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xf1, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf1,
+        0x6f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf1, 0x6f, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf1, 0x6f, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf1, 0x00, 0x00, 0x00, 0x03, 0x57, 0x89,
+        0x99, 0x87, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x59, 0xcf, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfd, 0x72, 0x00, 0x00, 0x00, 0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xf7, 0x00, 0x00, 0x02, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfc, 0x10, 0x00, 0x2f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+        0x10, 0x02, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf8, 0x00,
+        0x2f, 0xff, 0xff, 0xff, 0xca, 0x99, 0xbe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf2, 0x02, 0xff,
+        0xff, 0xc6, 0x10, 0x00, 0x00, 0x17, 0xff, 0xff, 0xff, 0xff, 0xff, 0x70, 0x2f, 0xfa, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x02, 0xc3, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xff, 0xd0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0xff, 0xff, 0xff, 0xff, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2f,
+        0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaf, 0xff, 0xff, 0xff,
+        0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xff, 0xff, 0xff, 0xfd, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0xff, 0xff, 0xff, 0xff, 0x90, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x1c, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x2d, 0xff, 0xff, 0xff, 0xff, 0xb1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3e,
+        0xff, 0xff, 0xff, 0xff, 0xb1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5f, 0xff, 0xff,
+        0xff, 0xff, 0xb1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xff,
+        0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaf, 0xff, 0xff, 0xff, 0xff, 0x60, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0xbf, 0xff, 0xff, 0xff, 0xfe, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0xdf, 0xff, 0xff, 0xff, 0xfe, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0xdf, 0xff, 0xff, 0xff, 0xfd, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xef, 0xff,
+        0xff, 0xff, 0xfb, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xa9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x92, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x33, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xf3, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x33, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xf3, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x33, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf3,
+        0x00, 0x00, 0x00, 0x25, 0x68, 0x89, 0x98, 0x87, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15,
+        0x8c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xa5, 0x00, 0x00, 0x00, 0x04, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc, 0x40, 0x00, 0x00, 0x4f, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x60, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x04, 0xff, 0xff, 0xec, 0xa9, 0x89, 0xbe, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xf4, 0x00, 0x4f, 0xc7, 0x30, 0x00, 0x00, 0x00, 0x05, 0xdf, 0xff, 0xff,
+        0xff, 0xff, 0x80, 0x02, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xef, 0xff, 0xff, 0xff,
+        0xfa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xff, 0xff, 0xff, 0xff, 0xb0,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xfa, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xaf, 0xff, 0xff, 0xff, 0xf3, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x5f, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x25, 0xbf, 0xff, 0xff, 0xff, 0xfd, 0x20, 0x00, 0x00, 0x00, 0x0b, 0xee, 0xee, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfc, 0x20, 0x00, 0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xc5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd7,
+        0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x91,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd1, 0x00,
+        0x00, 0x00, 0x00, 0xad, 0xdd, 0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc0, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x49, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0xcf, 0xff, 0xff, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0xef, 0xff, 0xff, 0xff, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x09, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x7f, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+        0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcf, 0xff,
+        0xff, 0xff, 0xf6, 0xa7, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff,
+        0xff, 0x4c, 0xfe, 0xa5, 0x10, 0x00, 0x00, 0x00, 0x02, 0xaf, 0xff, 0xff, 0xff, 0xff, 0xe0,
+        0xcf, 0xff, 0xff, 0xda, 0x98, 0x78, 0xad, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf9, 0x0c, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x10, 0xcf, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x40, 0x0c, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x30, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf8, 0x10, 0x00, 0x01, 0x59, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfb, 0x62, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x57, 0x99, 0xaa, 0x98, 0x85, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xfd, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xd6, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0x45, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0xff, 0xff, 0xf9, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xd1, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0x40, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xf9, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xd1, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0x40, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xd0, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0x30, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x1d, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xc0, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0xdf, 0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0xdf, 0xff, 0xff, 0xee, 0xee, 0xee, 0xee, 0xee, 0xff, 0xff,
+        0xff, 0xff, 0xee, 0xee, 0xe3, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf3, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf3, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf3, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf3, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf3, 0x89, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9b, 0xff, 0xff,
+        0xff, 0xff, 0xb9, 0x99, 0x92, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff,
+        0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x80, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x80, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x80, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80,
+        0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00,
+        0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00,
+        0xef, 0xff, 0xff, 0xfb, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x50, 0x00, 0x00, 0xef,
+        0xff, 0xff, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff,
+        0xff, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff,
+        0xf4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xf4,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xf5, 0x36,
+        0x78, 0x76, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xd9, 0x30, 0x00, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xfb, 0x20, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xe4, 0x00, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x40, 0x00, 0x00, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xe2, 0x00, 0x00, 0xef, 0xff, 0xff, 0xdb, 0xaa, 0xbe, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xf9, 0x00, 0x00, 0xef, 0xc8, 0x30, 0x00, 0x00, 0x00, 0x4c, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x20, 0x00, 0x72, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff, 0xff, 0x70,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0xd0, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff, 0xe0, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xf0, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0xe0, 0x35, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x0b, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x4f, 0xc5, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x7f, 0xff, 0xff, 0xff, 0xff, 0x70, 0x4f, 0xff, 0xe8, 0x30, 0x00, 0x00, 0x00, 0x2a,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0x20, 0x4f, 0xff, 0xff, 0xfe, 0xb9, 0x87, 0x9c, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfa, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xe1, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfe, 0x30, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd3,
+        0x00, 0x00, 0x2a, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf9, 0x10, 0x00,
+        0x00, 0x00, 0x15, 0x9d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd8, 0x20, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x14, 0x68, 0x9a, 0xaa, 0x97, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x67, 0x89, 0x87, 0x64, 0x20, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0xae, 0xff, 0xff, 0xff, 0xff, 0xff, 0xda, 0x61, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x4d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x9f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x01,
+        0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0xbf,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x8f, 0xff,
+        0xff, 0xff, 0xff, 0xfb, 0x86, 0x56, 0x7a, 0xdf, 0xff, 0x80, 0x00, 0x00, 0x3f, 0xff, 0xff,
+        0xff, 0xff, 0x92, 0x00, 0x00, 0x00, 0x00, 0x38, 0xe8, 0x00, 0x00, 0x0b, 0xff, 0xff, 0xff,
+        0xff, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff,
+        0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaf, 0xff, 0xff, 0xff, 0xd0,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xff, 0xff, 0xff, 0xf6, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0x10, 0x00,
+        0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xc0, 0x05, 0xad,
+        0xff, 0xfe, 0xd9, 0x50, 0x00, 0x00, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xfa, 0x5d, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xd6, 0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfb, 0x10, 0x00, 0x0e, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfd, 0x10, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfb, 0x00, 0x0f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xcb, 0xdf, 0xff,
+        0xff, 0xff, 0xff, 0xf6, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf9, 0x10, 0x00, 0x2c, 0xff,
+        0xff, 0xff, 0xff, 0xd0, 0x0f, 0xff, 0xff, 0xff, 0xff, 0xfa, 0x00, 0x00, 0x00, 0x1d, 0xff,
+        0xff, 0xff, 0xff, 0x30, 0xef, 0xff, 0xff, 0xff, 0xff, 0x30, 0x00, 0x00, 0x00, 0x6f, 0xff,
+        0xff, 0xff, 0xf8, 0x0d, 0xff, 0xff, 0xff, 0xff, 0xd0, 0x00, 0x00, 0x00, 0x02, 0xff, 0xff,
+        0xff, 0xff, 0xa0, 0xbf, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xff, 0xff,
+        0xff, 0xfb, 0x08, 0xff, 0xff, 0xff, 0xff, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xff, 0xff,
+        0xff, 0xc0, 0x5f, 0xff, 0xff, 0xff, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xff, 0xff, 0xff,
+        0xfb, 0x01, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x90, 0x0b, 0xff, 0xff, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xff, 0xff, 0xff, 0xf6,
+        0x00, 0x6f, 0xff, 0xff, 0xff, 0xf4, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0x20,
+        0x00, 0xdf, 0xff, 0xff, 0xff, 0xc1, 0x00, 0x00, 0x03, 0xef, 0xff, 0xff, 0xff, 0xc0, 0x00,
+        0x05, 0xff, 0xff, 0xff, 0xff, 0xd5, 0x10, 0x26, 0xef, 0xff, 0xff, 0xff, 0xf4, 0x00, 0x00,
+        0x0a, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfa, 0x00, 0x00, 0x00,
+        0x1c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc, 0x10, 0x00, 0x00, 0x00,
+        0x1c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x19, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x9e, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x79, 0xaa, 0x98, 0x62, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x9c, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf9, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x9c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf9, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0x9c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xf6, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaf, 0xff, 0xff, 0xff, 0xfe,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xe1, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0xff, 0xff, 0xff, 0xff, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x9f, 0xff, 0xff, 0xff, 0xf3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0xff,
+        0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff,
+        0xff, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xef, 0xff, 0xff, 0xff, 0xc0,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x4f, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b,
+        0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xff,
+        0xff, 0xff, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff,
+        0xf3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0xff, 0xff, 0xff, 0xfb, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xef, 0xff, 0xff, 0xff, 0xc0, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x0d, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xcf,
+        0xff, 0xff, 0xff, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4f, 0xff, 0xff,
+        0xff, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0xff, 0xff, 0xff, 0xff,
+        0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0xff, 0x90, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff, 0xf3, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x47, 0x88, 0x99, 0x87, 0x53, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x8d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xa5,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+        0x30, 0x00, 0x00, 0x00, 0x1c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x50, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x30, 0x00, 0x06, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+        0x00, 0x00, 0xcf, 0xff, 0xff, 0xff, 0xff, 0x83, 0x12, 0x5d, 0xff, 0xff, 0xff, 0xff, 0xf3,
+        0x00, 0x1f, 0xff, 0xff, 0xff, 0xff, 0x30, 0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0x70,
+        0x03, 0xff, 0xff, 0xff, 0xff, 0x90, 0x00, 0x00, 0x00, 0x4f, 0xff, 0xff, 0xff, 0xf9, 0x00,
+        0x4f, 0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xa0, 0x03,
+        0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x00, 0x00, 0x0e, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x1f,
+        0xff, 0xff, 0xff, 0xf6, 0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff, 0x60, 0x00, 0xbf,
+        0xff, 0xff, 0xff, 0xb0, 0x00, 0x00, 0x00, 0x5f, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x04, 0xff,
+        0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x2e, 0xff, 0xff, 0xff, 0xfa, 0x00, 0x00, 0x09, 0xff,
+        0xff, 0xff, 0xff, 0xc7, 0x56, 0x9f, 0xff, 0xff, 0xff, 0xfd, 0x10, 0x00, 0x00, 0x08, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc, 0x20, 0x00, 0x00, 0x00, 0x03, 0xbf,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x8f,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x30, 0x00, 0x00, 0x00, 0x4e, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x3e, 0xff, 0xff, 0xff, 0xff,
+        0xb7, 0x56, 0x9e, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x0d, 0xff, 0xff, 0xff, 0xfe, 0x30,
+        0x00, 0x00, 0x1a, 0xff, 0xff, 0xff, 0xff, 0x30, 0x06, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00,
+        0x00, 0x00, 0x0d, 0xff, 0xff, 0xff, 0xfb, 0x00, 0xbf, 0xff, 0xff, 0xff, 0xc0, 0x00, 0x00,
+        0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xf1, 0x0e, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0xff, 0xff, 0xff, 0xff, 0x40, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x00, 0x00,
+        0x00, 0x2f, 0xff, 0xff, 0xff, 0xf6, 0x1f, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0xff, 0xff, 0xff, 0xff, 0x60, 0xef, 0xff, 0xff, 0xff, 0xa0, 0x00, 0x00, 0x00, 0x00,
+        0x5f, 0xff, 0xff, 0xff, 0xf5, 0x0d, 0xff, 0xff, 0xff, 0xfe, 0x10, 0x00, 0x00, 0x00, 0x0a,
+        0xff, 0xff, 0xff, 0xff, 0x30, 0x9f, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x00, 0x06, 0xff,
+        0xff, 0xff, 0xff, 0xe0, 0x04, 0xff, 0xff, 0xff, 0xff, 0xfc, 0x52, 0x01, 0x39, 0xff, 0xff,
+        0xff, 0xff, 0xf9, 0x00, 0x0b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfe, 0x20, 0x00, 0x2e, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x70, 0x00, 0x00, 0x3e, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x70, 0x00, 0x00, 0x00, 0x2a, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfd, 0x40,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x9e, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xb6, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x57, 0x89, 0xaa, 0x98, 0x63, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x36, 0x78, 0x88, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x4b, 0xef, 0xff, 0xff, 0xff, 0xfe, 0x93, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x03, 0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfa, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x07, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfd, 0x30, 0x00, 0x00, 0x00,
+        0x07, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x20, 0x00, 0x00, 0x05,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfd, 0x10, 0x00, 0x00, 0xdf,
+        0xff, 0xff, 0xff, 0xfa, 0x42, 0x25, 0xcf, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x7f, 0xff,
+        0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0xaf, 0xff, 0xff, 0xff, 0xf3, 0x00, 0x0c, 0xff, 0xff,
+        0xff, 0xfd, 0x00, 0x00, 0x00, 0x01, 0xef, 0xff, 0xff, 0xff, 0x90, 0x01, 0xff, 0xff, 0xff,
+        0xff, 0x80, 0x00, 0x00, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xff, 0x10, 0x5f, 0xff, 0xff, 0xff,
+        0xf5, 0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xf5, 0x06, 0xff, 0xff, 0xff, 0xff,
+        0x30, 0x00, 0x00, 0x00, 0x05, 0xff, 0xff, 0xff, 0xff, 0x90, 0x7f, 0xff, 0xff, 0xff, 0xf3,
+        0x00, 0x00, 0x00, 0x00, 0x5f, 0xff, 0xff, 0xff, 0xfc, 0x07, 0xff, 0xff, 0xff, 0xff, 0x40,
+        0x00, 0x00, 0x00, 0x06, 0xff, 0xff, 0xff, 0xff, 0xf0, 0x6f, 0xff, 0xff, 0xff, 0xf6, 0x00,
+        0x00, 0x00, 0x00, 0x8f, 0xff, 0xff, 0xff, 0xff, 0x24, 0xff, 0xff, 0xff, 0xff, 0xa0, 0x00,
+        0x00, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0xf3, 0x0e, 0xff, 0xff, 0xff, 0xff, 0x30, 0x00,
+        0x00, 0x04, 0xff, 0xff, 0xff, 0xff, 0xff, 0x40, 0xaf, 0xff, 0xff, 0xff, 0xfd, 0x30, 0x00,
+        0x05, 0xef, 0xff, 0xff, 0xff, 0xff, 0xf5, 0x02, 0xff, 0xff, 0xff, 0xff, 0xff, 0xca, 0xad,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x50, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xf4, 0x00, 0x0b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0x30, 0x00, 0x1a, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xf2, 0x00, 0x00, 0x06, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xa7,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x6a, 0xef, 0xff, 0xff, 0xd9, 0x30, 0x7f,
+        0xff, 0xff, 0xff, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x32, 0x10, 0x00, 0x0b, 0xff,
+        0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xff,
+        0xff, 0xff, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff,
+        0xff, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2e, 0xff, 0xff, 0xff,
+        0xf9, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0xff, 0xff, 0xff, 0xff,
+        0x20, 0x00, 0x03, 0xe8, 0x30, 0x00, 0x00, 0x00, 0x00, 0x5d, 0xff, 0xff, 0xff, 0xff, 0x80,
+        0x00, 0x00, 0x3f, 0xff, 0xd9, 0x65, 0x44, 0x58, 0xdf, 0xff, 0xff, 0xff, 0xff, 0xd1, 0x00,
+        0x00, 0x03, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe3, 0x00, 0x00,
+        0x00, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe3, 0x00, 0x00, 0x00,
+        0x03, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd3, 0x00, 0x00, 0x00, 0x00,
+        0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x81, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x6b, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd8, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x35, 0x79, 0x9a, 0xa9, 0x85, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x44,
+        0x44, 0x44, 0x39, 0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc9, 0xff, 0xff, 0xff,
+        0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc9, 0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc9,
+        0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc8, 0xcc, 0xcc, 0xcc, 0xca, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x11, 0x11, 0x11, 0x11, 0x9f, 0xff, 0xff, 0xff, 0xc9, 0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff,
+        0xff, 0xff, 0xc9, 0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc9, 0xff, 0xff, 0xff,
+        0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc9, 0xff, 0xff, 0xff, 0xfc, 0x9f, 0xff, 0xff, 0xff, 0xc0,
+    ],
+};
@@ -0,0 +1,318 @@
+//! Generated with `fontconv --size 16 --bpp 4 --chars ←↑→↓☀☁☂☎★☹☺♥✓✔✗❤😀😂😊😍😢 DejaVuSans.ttf`.
+
+use super::{Font, Glyph};
+
+pub static SYMBOLS_16: Font = Font {
+    line_height: 19,
+    baseline: 15,
+    bpp: 4,
+    glyphs: &[
+        Glyph {
+            c: '←',
+            advance: 13,
+            left: 0,
+            top: 9,
+            width: 13,
+            height: 8,
+            offset: 0,
+        },
+        Glyph {
+            c: '↑',
+            advance: 13,
+            left: 3,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 52,
+        },
+        Glyph {
+            c: '→',
+            advance: 13,
+            left: 0,
+            top: 9,
+            width: 13,
+            height: 8,
+            offset: 100,
+        },
+        Glyph {
+            c: '↓',
+            advance: 13,
+            left: 3,
+            top: 12,
+            width: 8,
+            height: 13,
+            offset: 152,
+        },
+        Glyph {
+            c: '☀',
+            advance: 14,
+            left: 1,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 204,
+        },
+        Glyph {
+            c: '☁',
+            advance: 16,
+            left: 0,
+            top: 6,
+            width: 16,
+            height: 7,
+            offset: 276,
+        },
+        Glyph {
+            c: '☂',
+            advance: 14,
+            left: 0,
+            top: 12,
+            width: 14,
+            height: 12,
+            offset: 332,
+        },
+        Glyph {
+            c: '★',
+            advance: 14,
+            left: 1,
+            top: 12,
+            width: 13,
+            height: 13,
+            offset: 416,
+        },
+        Glyph {
+            c: '☎',
+            advance: 20,
+            left: 1,
+            top: 12,
+            width: 18,
+            height: 12,
+            offset: 501,
+        },
+        Glyph {
+            c: '☹',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 609,
+        },
+        Glyph {
+            c: '☺',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 722,
+        },
+        Glyph {
+            c: '♥',
+            advance: 14,
+            left: 1,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 835,
+        },
+        Glyph {
+            c: '✓',
+            advance: 13,
+            left: 2,
+            top: 11,
+            width: 9,
+            height: 10,
+            offset: 907,
+        },
+        Glyph {
+            c: '✔',
+            advance: 13,
+            left: 1,
+            top: 11,
+            width: 11,
+            height: 10,
+            offset: 952,
+        },
+        Glyph {
+            c: '✗',
+            advance: 13,
+            left: 1,
+            top: 12,
+            width: 11,
+            height: 13,
+            offset: 1007,
+        },
+        Glyph {
+            c: '❤',
+            advance: 13,
+            left: 0,
+            top: 11,
+            width: 13,
+            height: 10,
+            offset: 1079,
+        },
+        Glyph {
+            c: '😀',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 1144,
+        },
+        Glyph {
+            c: '😂',
+            advance: 19,
+            left: 0,
+            top: 13,
+            width: 18,
+            height: 15,
+            offset: 1257,
+        },
+        Glyph {
+            c: '😊',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 1392,
+        },
+        Glyph {
+            c: '😍',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 1505,
+        },
+        Glyph {
+            c: '😢',
+            advance: 17,
+            left: 1,
+            top: 13,
+            width: 15,
+            height: 15,
+            offset: 1618,
+        },
+    ],
+    bitmaps: &[
+        0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8, 0x00, 0x00, 0x00, 0x00, 0x03, 0xeb,
+        0x10, 0x00, 0x00, 0x00, 0x02, 0xef, 0xba, 0xaa, 0xaa, 0xaa, 0xa5, 0x1e, 0xfb, 0xaa, 0xaa,
+        0xaa, 0xaa, 0x50, 0x2e, 0xc1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2e, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1a, 0x40, 0x00, 0x01, 0xcf, 0xf4, 0x00,
+        0x1c, 0xdf, 0xdf, 0x40, 0x7d, 0x2e, 0x69, 0xd0, 0x01, 0x0e, 0x50, 0x10, 0x00, 0x0e, 0x50,
+        0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e,
+        0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xf7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xf7, 0x01,
+        0xaa, 0xaa, 0xaa, 0xaa, 0xae, 0xf6, 0x1a, 0xaa, 0xaa, 0xaa, 0xaa, 0xef, 0x60, 0x00, 0x00,
+        0x00, 0x00, 0x6f, 0x70, 0x00, 0x00, 0x00, 0x00, 0x2f, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x0a, 0x40, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00,
+        0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x0e, 0x50, 0x00,
+        0x00, 0x0e, 0x50, 0x00, 0x49, 0x0e, 0x55, 0x90, 0x4e, 0xaf, 0xaf, 0x90, 0x04, 0xef, 0xf9,
+        0x00, 0x00, 0x4e, 0x90, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x50, 0x00, 0x00,
+        0x00, 0x10, 0x02, 0x80, 0x00, 0x20, 0x00, 0xb2, 0x02, 0x80, 0x09, 0x50, 0x00, 0x1b, 0x14,
+        0x61, 0x66, 0x00, 0x00, 0x01, 0x8f, 0xfe, 0x20, 0x00, 0x23, 0x31, 0xff, 0xff, 0x83, 0x33,
+        0x57, 0x72, 0xff, 0xff, 0x96, 0x77, 0x00, 0x00, 0xaf, 0xff, 0x30, 0x00, 0x00, 0x09, 0x16,
+        0x83, 0x83, 0x00, 0x00, 0xa4, 0x02, 0x70, 0x1b, 0x30, 0x01, 0x40, 0x02, 0x80, 0x01, 0x20,
+        0x00, 0x00, 0x02, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x5b, 0xb5, 0x00, 0x00, 0x00,
+        0x00, 0x06, 0xff, 0xff, 0xff, 0x50, 0x00, 0x00, 0x00, 0x4e, 0xff, 0xff, 0xff, 0xd7, 0x20,
+        0x00, 0x09, 0xff, 0xff, 0xff, 0xff, 0xff, 0x90, 0x00, 0x7e, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xb1, 0x07, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe1, 0x01, 0x11, 0x11, 0x11, 0x11, 0x11,
+        0x11, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0x9c, 0x84, 0x00,
+        0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xc2, 0x00, 0x00, 0x9f, 0xff, 0xff, 0xff, 0xfe, 0x20,
+        0x06, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc0, 0x06, 0x58, 0xed, 0xac, 0xbf, 0xc6, 0x53, 0x00,
+        0x00, 0x20, 0x06, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xcf, 0x30, 0x00, 0x00, 0x22, 0x22, 0x4f, 0xf8, 0x22, 0x22, 0x03, 0xdf,
+        0xff, 0xff, 0xff, 0xff, 0x70, 0x01, 0xaf, 0xff, 0xff, 0xfd, 0x30, 0x00, 0x00, 0x7f, 0xff,
+        0xfb, 0x10, 0x00, 0x00, 0x07, 0xff, 0xff, 0xc0, 0x00, 0x00, 0x00, 0xcf, 0xc9, 0xff, 0x20,
+        0x00, 0x00, 0x2f, 0x80, 0x04, 0xe7, 0x00, 0x00, 0x05, 0x40, 0x00, 0x01, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25, 0x79, 0xaa, 0x97, 0x52, 0x00, 0x00,
+        0x00, 0x7d, 0xff, 0xff, 0xff, 0xff, 0xff, 0xd6, 0x00, 0x1c, 0xff, 0xb8, 0x98, 0x88, 0x89,
+        0x8b, 0xff, 0xb1, 0xaf, 0xff, 0xb0, 0xf8, 0x77, 0x8e, 0x0c, 0xff, 0xf9, 0xcf, 0xfb, 0x55,
+        0xfe, 0xbc, 0xff, 0x45, 0xbf, 0xfb, 0x36, 0x17, 0xef, 0xc8, 0x44, 0x9d, 0xfd, 0x61, 0x62,
+        0x00, 0x5f, 0xff, 0x56, 0xdc, 0x47, 0xff, 0xf4, 0x00, 0x00, 0xaf, 0xfc, 0x5c, 0xff, 0xa5,
+        0xff, 0xf8, 0x00, 0x00, 0xaf, 0xfd, 0x8a, 0xff, 0x88, 0xff, 0xf9, 0x00, 0x00, 0xaf, 0xff,
+        0x48, 0x87, 0x77, 0xff, 0xf9, 0x00, 0x00, 0xaf, 0xff, 0xe8, 0x55, 0x9f, 0xff, 0xf9, 0x00,
+        0x00, 0xaf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x49, 0xcd, 0xb8, 0x20,
+        0x00, 0x00, 0x01, 0xbd, 0x74, 0x35, 0x9e, 0x70, 0x00, 0x01, 0xc9, 0x10, 0x00, 0x00, 0x2c,
+        0x90, 0x00, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x50, 0x2e, 0x10, 0xce, 0x10, 0x4f, 0x80,
+        0x5d, 0x07, 0x90, 0x0c, 0xe1, 0x04, 0xf8, 0x00, 0xe2, 0x96, 0x00, 0x01, 0x00, 0x01, 0x00,
+        0x0b, 0x5a, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb5, 0x88, 0x00, 0x17, 0xbc, 0xb5, 0x00,
+        0x0d, 0x34, 0xd0, 0x2d, 0xb5, 0x46, 0xda, 0x03, 0xe0, 0x0c, 0x67, 0x80, 0x00, 0x01, 0xb3,
+        0xb8, 0x00, 0x3e, 0x50, 0x00, 0x00, 0x00, 0x9c, 0x10, 0x00, 0x4e, 0x93, 0x00, 0x04, 0xcc,
+        0x10, 0x00, 0x00, 0x19, 0xee, 0xdf, 0xd7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x49, 0xcd, 0xb8, 0x20, 0x00, 0x00, 0x01, 0xbd, 0x74, 0x35, 0x9e,
+        0x70, 0x00, 0x01, 0xc9, 0x10, 0x00, 0x00, 0x2c, 0x90, 0x00, 0xaa, 0x00, 0x00, 0x00, 0x00,
+        0x1d, 0x50, 0x2e, 0x10, 0xce, 0x10, 0x4f, 0x80, 0x5d, 0x07, 0x90, 0x0c, 0xe1, 0x04, 0xf8,
+        0x00, 0xe2, 0x96, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0b, 0x5a, 0x60, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xb5, 0x88, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0d, 0x34, 0xd0, 0x8b, 0x10, 0x00, 0x2d,
+        0x33, 0xe0, 0x0c, 0x61, 0xad, 0x87, 0x9e, 0x70, 0xb8, 0x00, 0x3e, 0x50, 0x48, 0x97, 0x20,
+        0x9c, 0x10, 0x00, 0x4e, 0x93, 0x00, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x19, 0xee, 0xdf, 0xd7,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x00, 0x01, 0x79, 0x50, 0x02, 0x89,
+        0x30, 0x1d, 0xff, 0xf6, 0x2e, 0xff, 0xf4, 0x7f, 0xff, 0xfe, 0x9f, 0xff, 0xfc, 0x8f, 0xff,
+        0xff, 0xff, 0xff, 0xfe, 0x6f, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x1e, 0xff, 0xff, 0xff, 0xff,
+        0xf6, 0x05, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x00, 0x8f, 0xff, 0xff, 0xfd, 0x10, 0x00, 0x0b,
+        0xff, 0xff, 0xe2, 0x00, 0x00, 0x01, 0xdf, 0xff, 0x40, 0x00, 0x00, 0x00, 0x2e, 0xf6, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x04,
+        0xe6, 0x00, 0x00, 0x02, 0xe9, 0x00, 0x00, 0x01, 0xcc, 0x00, 0x00, 0x00, 0x9e, 0x20, 0x00,
+        0x10, 0x4f, 0x50, 0x00, 0x6e, 0x2d, 0x90, 0x00, 0x06, 0xfd, 0xd1, 0x00, 0x00, 0x1f, 0xf4,
+        0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x5e, 0xf5, 0x00, 0x00, 0x00, 0x7f, 0xf8, 0x00, 0x00, 0x00, 0x6f, 0xfa, 0x00, 0x00,
+        0x20, 0x3f, 0xfc, 0x00, 0x01, 0xdf, 0x2d, 0xfd, 0x10, 0x00, 0x1f, 0xfe, 0xff, 0x30, 0x00,
+        0x00, 0xdf, 0xff, 0x60, 0x00, 0x00, 0x07, 0xff, 0xb0, 0x00, 0x00, 0x00, 0x18, 0x81, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x58, 0x10, 0x00, 0xae, 0x10, 0x5f, 0xc0, 0x00, 0x07,
+        0xf6, 0x4f, 0xd1, 0x00, 0x00, 0x2f, 0xee, 0xe3, 0x00, 0x00, 0x00, 0xbf, 0xf5, 0x00, 0x00,
+        0x00, 0x0a, 0xff, 0x10, 0x00, 0x00, 0x06, 0xff, 0xf8, 0x00, 0x00, 0x03, 0xff, 0x6f, 0xe1,
+        0x00, 0x01, 0xdf, 0x70, 0x8f, 0x90, 0x00, 0x9f, 0xc0, 0x00, 0xc5, 0x00, 0x1f, 0xf3, 0x00,
+        0x00, 0x00, 0x01, 0xe8, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x30, 0x00, 0x14, 0x20, 0x00, 0x4e, 0xff, 0xb0, 0x5f, 0xff, 0x90, 0x0d, 0xff, 0xff,
+        0x8f, 0xff, 0xff, 0x42, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xf8, 0x0e, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x50, 0x4e, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x19, 0xff, 0xff, 0xfd, 0x30, 0x00,
+        0x00, 0x05, 0xef, 0xfa, 0x10, 0x00, 0x00, 0x00, 0x03, 0xe9, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49, 0xcd, 0xb8, 0x20, 0x00, 0x00, 0x01, 0xbd, 0x74,
+        0x35, 0x9e, 0x70, 0x00, 0x01, 0xc9, 0x10, 0x00, 0x00, 0x2c, 0x90, 0x00, 0xaa, 0x00, 0x00,
+        0x00, 0x10, 0x1d, 0x50, 0x2e, 0x10, 0xce, 0x10, 0x4f, 0x80, 0x5d, 0x07, 0x90, 0x0c, 0xe1,
+        0x04, 0xf8, 0x00, 0xe2, 0x96, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0b, 0x5a, 0x66, 0xbb, 0xbb,
+        0xbb, 0xbb, 0xb2, 0xb5, 0x88, 0x5d, 0xaa, 0x7e, 0x5d, 0x8f, 0x1d, 0x34, 0xd1, 0xec, 0x83,
+        0xd0, 0xdc, 0xa3, 0xe0, 0x0c, 0x64, 0xfa, 0x4d, 0x2e, 0xd1, 0xb8, 0x00, 0x3e, 0x53, 0xbf,
+        0xfe, 0x91, 0x9c, 0x10, 0x00, 0x4e, 0x93, 0x01, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x19, 0xee,
+        0xdf, 0xd7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x49, 0xcd, 0xb8, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1b, 0xd7, 0x43, 0x59, 0xe7, 0x00, 0x00,
+        0x00, 0x01, 0xc9, 0x10, 0x00, 0x00, 0x2c, 0x90, 0x00, 0x00, 0x0a, 0xa1, 0x99, 0x10, 0x3a,
+        0x61, 0xd5, 0x00, 0x00, 0x2e, 0x18, 0xba, 0x90, 0xd7, 0xe3, 0x5d, 0x00, 0x00, 0x7c, 0x9b,
+        0x43, 0xc2, 0xc0, 0x8a, 0x9f, 0x20, 0x00, 0x9f, 0x90, 0x00, 0x00, 0x00, 0x00, 0xdf, 0x50,
+        0x02, 0xc6, 0x6b, 0xbb, 0xbb, 0xbb, 0xbb, 0x2b, 0x81, 0x2f, 0xd8, 0x5d, 0xaa, 0x7e, 0x5d,
+        0x8f, 0x1d, 0xdc, 0x06, 0x4d, 0x1e, 0xc8, 0x3d, 0x0d, 0xca, 0x3e, 0x25, 0x00, 0x0c, 0x64,
+        0xfa, 0x4d, 0x2e, 0xd1, 0xb8, 0x00, 0x00, 0x03, 0xe5, 0x3b, 0xff, 0xe9, 0x19, 0xc1, 0x00,
+        0x00, 0x00, 0x4e, 0x93, 0x01, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x00, 0x01, 0x9e, 0xed, 0xfd,
+        0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49,
+        0xcd, 0xb8, 0x20, 0x00, 0x00, 0x01, 0xbd, 0x74, 0x35, 0x9e, 0x70, 0x00, 0x01, 0xc9, 0x10,
+        0x00, 0x00, 0x2c, 0x90, 0x00, 0xaa, 0x19, 0x91, 0x03, 0xa6, 0x1d, 0x50, 0x2e, 0x18, 0xba,
+        0x90, 0xd7, 0xe3, 0x5d, 0x07, 0x90, 0xb4, 0x3c, 0x2c, 0x08, 0x60, 0xe2, 0x96, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0b, 0x5a, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb5, 0x88, 0x00, 0x00,
+        0x00, 0x00, 0x10, 0x0d, 0x34, 0xd0, 0x8b, 0x10, 0x00, 0x2d, 0x33, 0xe0, 0x0c, 0x61, 0xad,
+        0x87, 0x9e, 0x70, 0xb8, 0x00, 0x3e, 0x50, 0x48, 0x97, 0x20, 0x9c, 0x10, 0x00, 0x4e, 0x93,
+        0x00, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x19, 0xee, 0xdf, 0xd7, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x22, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49, 0xcd, 0xb8, 0x20, 0x00, 0x00, 0x01, 0xbd,
+        0x74, 0x35, 0x9e, 0x70, 0x00, 0x01, 0xc9, 0x10, 0x00, 0x00, 0x2c, 0x90, 0x00, 0xaa, 0x16,
+        0x35, 0x06, 0x26, 0x1d, 0x50, 0x2e, 0x16, 0xfe, 0xe4, 0xfe, 0xf2, 0x5d, 0x07, 0x90, 0x1d,
+        0xf7, 0x0c, 0xfa, 0x00, 0xe2, 0x96, 0x00, 0x39, 0x00, 0x1b, 0x10, 0x0b, 0x5a, 0x60, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xb5, 0x88, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0d, 0x34, 0xd0, 0x8b,
+        0x10, 0x00, 0x2d, 0x33, 0xe0, 0x0c, 0x61, 0xad, 0x87, 0x9e, 0x70, 0xb8, 0x00, 0x3e, 0x50,
+        0x48, 0x97, 0x20, 0x9c, 0x10, 0x00, 0x4e, 0x93, 0x00, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x19,
+        0xee, 0xdf, 0xd7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x49, 0xcd, 0xb8, 0x20, 0x00, 0x00, 0x01, 0xbd, 0x74, 0x35, 0x9e, 0x70, 0x00, 0x01, 0xc9,
+        0x10, 0x00, 0x00, 0x2c, 0x90, 0x00, 0xaa, 0x10, 0x01, 0x01, 0x00, 0x2d, 0x50, 0x2e, 0x1c,
+        0x54, 0xc2, 0xe0, 0x97, 0x5d, 0x07, 0x90, 0x6c, 0xc7, 0x0c, 0xae, 0x20, 0xe2, 0x96, 0x00,
+        0x67, 0x00, 0x2d, 0x40, 0x0b, 0x5a, 0x60, 0x00, 0x00, 0x00, 0xf5, 0x00, 0xb5, 0x88, 0x00,
+        0x17, 0xbc, 0xbb, 0x10, 0x0d, 0x34, 0xd0, 0x2d, 0xa5, 0x46, 0xda, 0x03, 0xe0, 0x0c, 0x67,
+        0x80, 0x00, 0x01, 0xb3, 0xb8, 0x00, 0x3e, 0x50, 0x00, 0x00, 0x00, 0x9c, 0x10, 0x00, 0x4e,
+        0x93, 0x00, 0x04, 0xcc, 0x10, 0x00, 0x00, 0x19, 0xee, 0xdf, 0xd7, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x22, 0x10, 0x00, 0x00, 0x00,
+    ],
+};
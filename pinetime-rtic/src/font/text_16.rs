@@ -0,0 +1,4272 @@
+//! Generated with `fontconv --size 16 --bpp 4 --range 20-7e --range a0-17f --range 2013-2014 --range 2018-201e --range 2020-2022 --range 2026 --range 20ac --range fffd DejaVuSans.ttf`.
+
+use super::{Font, Glyph};
+
+pub static TEXT_16: Font = Font {
+    line_height: 20,
+    baseline: 16,
+    bpp: 4,
+    glyphs: &[
+        Glyph {
+            c: ' ',
+            advance: 5,
+            left: 0,
+            top: 0,
+            width: 0,
+            height: 0,
+            offset: 0,
+        },
+        Glyph {
+            c: '!',
+            advance: 6,
+            left: 2,
+            top: 12,
+            width: 2,
+            height: 12,
+            offset: 0,
+        },
+        Glyph {
+            c: '"',
+            advance: 7,
+            left: 1,
+            top: 12,
+            width: 5,
+            height: 5,
+            offset: 12,
+        },
+        Glyph {
+            c: '#',
+            advance: 13,
+            left: 1,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 25,
+        },
+        Glyph {
+            c: '$',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 16,
+            offset: 97,
+        },
+        Glyph {
+            c: '%',
+            advance: 15,
+            left: 0,
+            top: 12,
+            width: 15,
+            height: 13,
+            offset: 161,
+        },
+        Glyph {
+            c: '&',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 11,
+            height: 13,
+            offset: 259,
+        },
+        Glyph {
+            c: '\'',
+            advance: 4,
+            left: 1,
+            top: 12,
+            width: 2,
+            height: 5,
+            offset: 331,
+        },
+        Glyph {
+            c: '(',
+            advance: 6,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 16,
+            offset: 336,
+        },
+        Glyph {
+            c: ')',
+            advance: 6,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 16,
+            offset: 368,
+        },
+        Glyph {
+            c: '*',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 8,
+            height: 8,
+            offset: 400,
+        },
+        Glyph {
+            c: '+',
+            advance: 13,
+            left: 1,
+            top: 11,
+            width: 11,
+            height: 11,
+            offset: 432,
+        },
+        Glyph {
+            c: ',',
+            advance: 5,
+            left: 1,
+            top: 2,
+            width: 3,
+            height: 4,
+            offset: 493,
+        },
+        Glyph {
+            c: '-',
+            advance: 6,
+            left: 0,
+            top: 6,
+            width: 5,
+            height: 3,
+            offset: 499,
+        },
+        Glyph {
+            c: '.',
+            advance: 5,
+            left: 1,
+            top: 2,
+            width: 3,
+            height: 2,
+            offset: 507,
+        },
+        Glyph {
+            c: '/',
+            advance: 5,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 14,
+            offset: 510,
+        },
+        Glyph {
+            c: '0',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 552,
+        },
+        Glyph {
+            c: '1',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 611,
+        },
+        Glyph {
+            c: '2',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 659,
+        },
+        Glyph {
+            c: '3',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 13,
+            offset: 707,
+        },
+        Glyph {
+            c: '4',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 759,
+        },
+        Glyph {
+            c: '5',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 13,
+            offset: 819,
+        },
+        Glyph {
+            c: '6',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 871,
+        },
+        Glyph {
+            c: '7',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 930,
+        },
+        Glyph {
+            c: '8',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 978,
+        },
+        Glyph {
+            c: '9',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 1037,
+        },
+        Glyph {
+            c: ':',
+            advance: 5,
+            left: 1,
+            top: 9,
+            width: 3,
+            height: 9,
+            offset: 1096,
+        },
+        Glyph {
+            c: ';',
+            advance: 5,
+            left: 1,
+            top: 9,
+            width: 3,
+            height: 11,
+            offset: 1110,
+        },
+        Glyph {
+            c: '<',
+            advance: 13,
+            left: 1,
+            top: 10,
+            width: 11,
+            height: 10,
+            offset: 1127,
+        },
+        Glyph {
+            c: '=',
+            advance: 13,
+            left: 1,
+            top: 8,
+            width: 11,
+            height: 6,
+            offset: 1182,
+        },
+        Glyph {
+            c: '>',
+            advance: 13,
+            left: 1,
+            top: 10,
+            width: 11,
+            height: 10,
+            offset: 1215,
+        },
+        Glyph {
+            c: '?',
+            advance: 8,
+            left: 1,
+            top: 12,
+            width: 7,
+            height: 12,
+            offset: 1270,
+        },
+        Glyph {
+            c: '@',
+            advance: 16,
+            left: 1,
+            top: 12,
+            width: 14,
+            height: 15,
+            offset: 1312,
+        },
+        Glyph {
+            c: 'A',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 1417,
+        },
+        Glyph {
+            c: 'B',
+            advance: 11,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 12,
+            offset: 1483,
+        },
+        Glyph {
+            c: 'C',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 13,
+            offset: 1537,
+        },
+        Glyph {
+            c: 'D',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 1609,
+        },
+        Glyph {
+            c: 'E',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 12,
+            offset: 1675,
+        },
+        Glyph {
+            c: 'F',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 1729,
+        },
+        Glyph {
+            c: 'G',
+            advance: 12,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 13,
+            offset: 1777,
+        },
+        Glyph {
+            c: 'H',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 1855,
+        },
+        Glyph {
+            c: 'I',
+            advance: 5,
+            left: 1,
+            top: 12,
+            width: 3,
+            height: 12,
+            offset: 1915,
+        },
+        Glyph {
+            c: 'J',
+            advance: 5,
+            left: -1,
+            top: 12,
+            width: 5,
+            height: 16,
+            offset: 1933,
+        },
+        Glyph {
+            c: 'K',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 1973,
+        },
+        Glyph {
+            c: 'L',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 2033,
+        },
+        Glyph {
+            c: 'M',
+            advance: 14,
+            left: 1,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 2081,
+        },
+        Glyph {
+            c: 'N',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 2153,
+        },
+        Glyph {
+            c: 'O',
+            advance: 13,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 13,
+            offset: 2213,
+        },
+        Glyph {
+            c: 'P',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 12,
+            offset: 2291,
+        },
+        Glyph {
+            c: 'Q',
+            advance: 13,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 15,
+            offset: 2345,
+        },
+        Glyph {
+            c: 'R',
+            advance: 11,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 2435,
+        },
+        Glyph {
+            c: 'S',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 2495,
+        },
+        Glyph {
+            c: 'T',
+            advance: 10,
+            left: -1,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 2554,
+        },
+        Glyph {
+            c: 'U',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 13,
+            offset: 2620,
+        },
+        Glyph {
+            c: 'V',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 2685,
+        },
+        Glyph {
+            c: 'W',
+            advance: 16,
+            left: 0,
+            top: 12,
+            width: 16,
+            height: 12,
+            offset: 2751,
+        },
+        Glyph {
+            c: 'X',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 2847,
+        },
+        Glyph {
+            c: 'Y',
+            advance: 10,
+            left: -1,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 2913,
+        },
+        Glyph {
+            c: 'Z',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 2979,
+        },
+        Glyph {
+            c: '[',
+            advance: 6,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 16,
+            offset: 3045,
+        },
+        Glyph {
+            c: '\\',
+            advance: 5,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 14,
+            offset: 3077,
+        },
+        Glyph {
+            c: ']',
+            advance: 6,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 16,
+            offset: 3119,
+        },
+        Glyph {
+            c: '^',
+            advance: 13,
+            left: 1,
+            top: 12,
+            width: 11,
+            height: 5,
+            offset: 3151,
+        },
+        Glyph {
+            c: '_',
+            advance: 8,
+            left: -1,
+            top: -2,
+            width: 10,
+            height: 2,
+            offset: 3179,
+        },
+        Glyph {
+            c: '`',
+            advance: 8,
+            left: 1,
+            top: 13,
+            width: 5,
+            height: 4,
+            offset: 3189,
+        },
+        Glyph {
+            c: 'a',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 10,
+            offset: 3199,
+        },
+        Glyph {
+            c: 'b',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 3244,
+        },
+        Glyph {
+            c: 'c',
+            advance: 9,
+            left: 0,
+            top: 9,
+            width: 8,
+            height: 10,
+            offset: 3307,
+        },
+        Glyph {
+            c: 'd',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 3347,
+        },
+        Glyph {
+            c: 'e',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 10,
+            offset: 3410,
+        },
+        Glyph {
+            c: 'f',
+            advance: 6,
+            left: 0,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 3455,
+        },
+        Glyph {
+            c: 'g',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 3494,
+        },
+        Glyph {
+            c: 'h',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 3553,
+        },
+        Glyph {
+            c: 'i',
+            advance: 4,
+            left: 1,
+            top: 13,
+            width: 2,
+            height: 13,
+            offset: 3605,
+        },
+        Glyph {
+            c: 'j',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 4,
+            height: 17,
+            offset: 3618,
+        },
+        Glyph {
+            c: 'k',
+            advance: 9,
+            left: 1,
+            top: 13,
+            width: 9,
+            height: 13,
+            offset: 3652,
+        },
+        Glyph {
+            c: 'l',
+            advance: 4,
+            left: 1,
+            top: 13,
+            width: 2,
+            height: 13,
+            offset: 3711,
+        },
+        Glyph {
+            c: 'm',
+            advance: 16,
+            left: 1,
+            top: 9,
+            width: 14,
+            height: 9,
+            offset: 3724,
+        },
+        Glyph {
+            c: 'n',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 9,
+            offset: 3787,
+        },
+        Glyph {
+            c: 'o',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 10,
+            offset: 3823,
+        },
+        Glyph {
+            c: 'p',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 3868,
+        },
+        Glyph {
+            c: 'q',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 3927,
+        },
+        Glyph {
+            c: 'r',
+            advance: 7,
+            left: 1,
+            top: 9,
+            width: 6,
+            height: 9,
+            offset: 3986,
+        },
+        Glyph {
+            c: 's',
+            advance: 8,
+            left: 0,
+            top: 9,
+            width: 8,
+            height: 10,
+            offset: 4013,
+        },
+        Glyph {
+            c: 't',
+            advance: 6,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 12,
+            offset: 4053,
+        },
+        Glyph {
+            c: 'u',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 10,
+            offset: 4089,
+        },
+        Glyph {
+            c: 'v',
+            advance: 9,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 9,
+            offset: 4129,
+        },
+        Glyph {
+            c: 'w',
+            advance: 13,
+            left: 0,
+            top: 9,
+            width: 13,
+            height: 9,
+            offset: 4170,
+        },
+        Glyph {
+            c: 'x',
+            advance: 9,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 9,
+            offset: 4229,
+        },
+        Glyph {
+            c: 'y',
+            advance: 9,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 4270,
+        },
+        Glyph {
+            c: 'z',
+            advance: 8,
+            left: 0,
+            top: 9,
+            width: 8,
+            height: 9,
+            offset: 4329,
+        },
+        Glyph {
+            c: '{',
+            advance: 10,
+            left: 2,
+            top: 13,
+            width: 7,
+            height: 16,
+            offset: 4365,
+        },
+        Glyph {
+            c: '|',
+            advance: 5,
+            left: 2,
+            top: 13,
+            width: 2,
+            height: 17,
+            offset: 4421,
+        },
+        Glyph {
+            c: '}',
+            advance: 10,
+            left: 2,
+            top: 13,
+            width: 7,
+            height: 16,
+            offset: 4438,
+        },
+        Glyph {
+            c: '~',
+            advance: 13,
+            left: 1,
+            top: 7,
+            width: 11,
+            height: 4,
+            offset: 4494,
+        },
+        Glyph {
+            c: '\u{a0}',
+            advance: 5,
+            left: 0,
+            top: 0,
+            width: 0,
+            height: 0,
+            offset: 4516,
+        },
+        Glyph {
+            c: '¡',
+            advance: 6,
+            left: 2,
+            top: 9,
+            width: 2,
+            height: 12,
+            offset: 4516,
+        },
+        Glyph {
+            c: '¢',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 15,
+            offset: 4528,
+        },
+        Glyph {
+            c: '£',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 4588,
+        },
+        Glyph {
+            c: '¤',
+            advance: 10,
+            left: 0,
+            top: 10,
+            width: 10,
+            height: 10,
+            offset: 4636,
+        },
+        Glyph {
+            c: '¥',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 4686,
+        },
+        Glyph {
+            c: '¦',
+            advance: 5,
+            left: 2,
+            top: 12,
+            width: 2,
+            height: 15,
+            offset: 4746,
+        },
+        Glyph {
+            c: '§',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 8,
+            height: 14,
+            offset: 4761,
+        },
+        Glyph {
+            c: '¨',
+            advance: 8,
+            left: 1,
+            top: 13,
+            width: 6,
+            height: 3,
+            offset: 4817,
+        },
+        Glyph {
+            c: '©',
+            advance: 16,
+            left: 2,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 4826,
+        },
+        Glyph {
+            c: 'ª',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 7,
+            height: 9,
+            offset: 4898,
+        },
+        Glyph {
+            c: '«',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 8,
+            offset: 4930,
+        },
+        Glyph {
+            c: '¬',
+            advance: 13,
+            left: 1,
+            top: 7,
+            width: 11,
+            height: 5,
+            offset: 4962,
+        },
+        Glyph {
+            c: '\u{ad}',
+            advance: 6,
+            left: 0,
+            top: 6,
+            width: 5,
+            height: 3,
+            offset: 4990,
+        },
+        Glyph {
+            c: '®',
+            advance: 16,
+            left: 2,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 4998,
+        },
+        Glyph {
+            c: '¯',
+            advance: 8,
+            left: 1,
+            top: 12,
+            width: 6,
+            height: 2,
+            offset: 5070,
+        },
+        Glyph {
+            c: '°',
+            advance: 8,
+            left: 1,
+            top: 12,
+            width: 6,
+            height: 6,
+            offset: 5076,
+        },
+        Glyph {
+            c: '±',
+            advance: 13,
+            left: 1,
+            top: 11,
+            width: 11,
+            height: 11,
+            offset: 5094,
+        },
+        Glyph {
+            c: '²',
+            advance: 6,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 7,
+            offset: 5155,
+        },
+        Glyph {
+            c: '³',
+            advance: 6,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 7,
+            offset: 5176,
+        },
+        Glyph {
+            c: '´',
+            advance: 8,
+            left: 2,
+            top: 13,
+            width: 5,
+            height: 4,
+            offset: 5197,
+        },
+        Glyph {
+            c: 'µ',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 5207,
+        },
+        Glyph {
+            c: '¶',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 14,
+            offset: 5266,
+        },
+        Glyph {
+            c: '·',
+            advance: 5,
+            left: 1,
+            top: 7,
+            width: 3,
+            height: 3,
+            offset: 5322,
+        },
+        Glyph {
+            c: '¸',
+            advance: 8,
+            left: 2,
+            top: 0,
+            width: 4,
+            height: 4,
+            offset: 5327,
+        },
+        Glyph {
+            c: '¹',
+            advance: 6,
+            left: 1,
+            top: 12,
+            width: 5,
+            height: 7,
+            offset: 5335,
+        },
+        Glyph {
+            c: 'º',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 7,
+            height: 9,
+            offset: 5353,
+        },
+        Glyph {
+            c: '»',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 8,
+            offset: 5385,
+        },
+        Glyph {
+            c: '¼',
+            advance: 16,
+            left: 1,
+            top: 12,
+            width: 14,
+            height: 13,
+            offset: 5417,
+        },
+        Glyph {
+            c: '½',
+            advance: 16,
+            left: 1,
+            top: 12,
+            width: 14,
+            height: 13,
+            offset: 5508,
+        },
+        Glyph {
+            c: '¾',
+            advance: 16,
+            left: 0,
+            top: 12,
+            width: 15,
+            height: 13,
+            offset: 5599,
+        },
+        Glyph {
+            c: '¿',
+            advance: 8,
+            left: 1,
+            top: 9,
+            width: 7,
+            height: 13,
+            offset: 5697,
+        },
+        Glyph {
+            c: 'À',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 5743,
+        },
+        Glyph {
+            c: 'Á',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 5826,
+        },
+        Glyph {
+            c: 'Â',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 5909,
+        },
+        Glyph {
+            c: 'Ã',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 5992,
+        },
+        Glyph {
+            c: 'Ä',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 6075,
+        },
+        Glyph {
+            c: 'Å',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 6158,
+        },
+        Glyph {
+            c: 'Æ',
+            advance: 16,
+            left: 0,
+            top: 12,
+            width: 15,
+            height: 12,
+            offset: 6241,
+        },
+        Glyph {
+            c: 'Ç',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 11,
+            height: 16,
+            offset: 6331,
+        },
+        Glyph {
+            c: 'È',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 6419,
+        },
+        Glyph {
+            c: 'É',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 6487,
+        },
+        Glyph {
+            c: 'Ê',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 6555,
+        },
+        Glyph {
+            c: 'Ë',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 6623,
+        },
+        Glyph {
+            c: 'Ì',
+            advance: 5,
+            left: 0,
+            top: 15,
+            width: 4,
+            height: 15,
+            offset: 6691,
+        },
+        Glyph {
+            c: 'Í',
+            advance: 5,
+            left: 1,
+            top: 15,
+            width: 4,
+            height: 15,
+            offset: 6721,
+        },
+        Glyph {
+            c: 'Î',
+            advance: 5,
+            left: -1,
+            top: 15,
+            width: 6,
+            height: 15,
+            offset: 6751,
+        },
+        Glyph {
+            c: 'Ï',
+            advance: 5,
+            left: 0,
+            top: 15,
+            width: 5,
+            height: 15,
+            offset: 6796,
+        },
+        Glyph {
+            c: 'Ð',
+            advance: 12,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 6834,
+        },
+        Glyph {
+            c: 'Ñ',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 6906,
+        },
+        Glyph {
+            c: 'Ò',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 6981,
+        },
+        Glyph {
+            c: 'Ó',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 7077,
+        },
+        Glyph {
+            c: 'Ô',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 7173,
+        },
+        Glyph {
+            c: 'Õ',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 7269,
+        },
+        Glyph {
+            c: 'Ö',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 7365,
+        },
+        Glyph {
+            c: '×',
+            advance: 13,
+            left: 2,
+            top: 10,
+            width: 10,
+            height: 10,
+            offset: 7461,
+        },
+        Glyph {
+            c: 'Ø',
+            advance: 13,
+            left: 0,
+            top: 13,
+            width: 12,
+            height: 14,
+            offset: 7511,
+        },
+        Glyph {
+            c: 'Ù',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 7595,
+        },
+        Glyph {
+            c: 'Ú',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 7675,
+        },
+        Glyph {
+            c: 'Û',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 7755,
+        },
+        Glyph {
+            c: 'Ü',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 7835,
+        },
+        Glyph {
+            c: 'Ý',
+            advance: 10,
+            left: -1,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 7915,
+        },
+        Glyph {
+            c: 'Þ',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 12,
+            offset: 7998,
+        },
+        Glyph {
+            c: 'ß',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8052,
+        },
+        Glyph {
+            c: 'à',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8115,
+        },
+        Glyph {
+            c: 'á',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8178,
+        },
+        Glyph {
+            c: 'â',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8241,
+        },
+        Glyph {
+            c: 'ã',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8304,
+        },
+        Glyph {
+            c: 'ä',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8367,
+        },
+        Glyph {
+            c: 'å',
+            advance: 10,
+            left: 0,
+            top: 15,
+            width: 9,
+            height: 16,
+            offset: 8430,
+        },
+        Glyph {
+            c: 'æ',
+            advance: 16,
+            left: 0,
+            top: 9,
+            width: 15,
+            height: 10,
+            offset: 8502,
+        },
+        Glyph {
+            c: 'ç',
+            advance: 9,
+            left: 0,
+            top: 9,
+            width: 8,
+            height: 13,
+            offset: 8577,
+        },
+        Glyph {
+            c: 'è',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8629,
+        },
+        Glyph {
+            c: 'é',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8692,
+        },
+        Glyph {
+            c: 'ê',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8755,
+        },
+        Glyph {
+            c: 'ë',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 8818,
+        },
+        Glyph {
+            c: 'ì',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 5,
+            height: 13,
+            offset: 8881,
+        },
+        Glyph {
+            c: 'í',
+            advance: 4,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 13,
+            offset: 8914,
+        },
+        Glyph {
+            c: 'î',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 8940,
+        },
+        Glyph {
+            c: 'ï',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 8979,
+        },
+        Glyph {
+            c: 'ð',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9018,
+        },
+        Glyph {
+            c: 'ñ',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 9081,
+        },
+        Glyph {
+            c: 'ò',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9133,
+        },
+        Glyph {
+            c: 'ó',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9196,
+        },
+        Glyph {
+            c: 'ô',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9259,
+        },
+        Glyph {
+            c: 'õ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9322,
+        },
+        Glyph {
+            c: 'ö',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 9385,
+        },
+        Glyph {
+            c: '÷',
+            advance: 13,
+            left: 1,
+            top: 9,
+            width: 11,
+            height: 8,
+            offset: 9448,
+        },
+        Glyph {
+            c: 'ø',
+            advance: 10,
+            left: 0,
+            top: 10,
+            width: 10,
+            height: 11,
+            offset: 9492,
+        },
+        Glyph {
+            c: 'ù',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 9547,
+        },
+        Glyph {
+            c: 'ú',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 9603,
+        },
+        Glyph {
+            c: 'û',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 9659,
+        },
+        Glyph {
+            c: 'ü',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 9715,
+        },
+        Glyph {
+            c: 'ý',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 9771,
+        },
+        Glyph {
+            c: 'þ',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 9848,
+        },
+        Glyph {
+            c: 'ÿ',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 9925,
+        },
+        Glyph {
+            c: 'Ā',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 10002,
+        },
+        Glyph {
+            c: 'ā',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 10085,
+        },
+        Glyph {
+            c: 'Ă',
+            advance: 11,
+            left: 0,
+            top: 16,
+            width: 11,
+            height: 16,
+            offset: 10144,
+        },
+        Glyph {
+            c: 'ă',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 10232,
+        },
+        Glyph {
+            c: 'Ą',
+            advance: 11,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 16,
+            offset: 10295,
+        },
+        Glyph {
+            c: 'ą',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 10391,
+        },
+        Glyph {
+            c: 'Ć',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 16,
+            offset: 10450,
+        },
+        Glyph {
+            c: 'ć',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 10538,
+        },
+        Glyph {
+            c: 'Ĉ',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 16,
+            offset: 10594,
+        },
+        Glyph {
+            c: 'ĉ',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 10682,
+        },
+        Glyph {
+            c: 'Ċ',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 16,
+            offset: 10738,
+        },
+        Glyph {
+            c: 'ċ',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 10826,
+        },
+        Glyph {
+            c: 'Č',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 16,
+            offset: 10882,
+        },
+        Glyph {
+            c: 'č',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 10970,
+        },
+        Glyph {
+            c: 'Ď',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 11026,
+        },
+        Glyph {
+            c: 'ď',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 12,
+            height: 14,
+            offset: 11109,
+        },
+        Glyph {
+            c: 'Đ',
+            advance: 12,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 12,
+            offset: 11193,
+        },
+        Glyph {
+            c: 'đ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 10,
+            height: 14,
+            offset: 11265,
+        },
+        Glyph {
+            c: 'Ē',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 11335,
+        },
+        Glyph {
+            c: 'ē',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 11403,
+        },
+        Glyph {
+            c: 'Ĕ',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 11462,
+        },
+        Glyph {
+            c: 'ĕ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 11530,
+        },
+        Glyph {
+            c: 'Ė',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 11593,
+        },
+        Glyph {
+            c: 'ė',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 11661,
+        },
+        Glyph {
+            c: 'Ę',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 16,
+            offset: 11724,
+        },
+        Glyph {
+            c: 'ę',
+            advance: 10,
+            left: 0,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 11796,
+        },
+        Glyph {
+            c: 'Ě',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 15,
+            offset: 11855,
+        },
+        Glyph {
+            c: 'ě',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 11923,
+        },
+        Glyph {
+            c: 'Ĝ',
+            advance: 12,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 11986,
+        },
+        Glyph {
+            c: 'ĝ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 12082,
+        },
+        Glyph {
+            c: 'Ğ',
+            advance: 12,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 12159,
+        },
+        Glyph {
+            c: 'ğ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 12255,
+        },
+        Glyph {
+            c: 'Ġ',
+            advance: 12,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 12332,
+        },
+        Glyph {
+            c: 'ġ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 12428,
+        },
+        Glyph {
+            c: 'Ģ',
+            advance: 12,
+            left: 0,
+            top: 12,
+            width: 12,
+            height: 16,
+            offset: 12505,
+        },
+        Glyph {
+            c: 'ģ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 12601,
+        },
+        Glyph {
+            c: 'Ĥ',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 12678,
+        },
+        Glyph {
+            c: 'ĥ',
+            advance: 10,
+            left: -1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 12753,
+        },
+        Glyph {
+            c: 'Ħ',
+            advance: 15,
+            left: 1,
+            top: 12,
+            width: 13,
+            height: 12,
+            offset: 12828,
+        },
+        Glyph {
+            c: 'ħ',
+            advance: 11,
+            left: 0,
+            top: 13,
+            width: 10,
+            height: 13,
+            offset: 12906,
+        },
+        Glyph {
+            c: 'Ĩ',
+            advance: 5,
+            left: -1,
+            top: 15,
+            width: 6,
+            height: 15,
+            offset: 12971,
+        },
+        Glyph {
+            c: 'ĩ',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 13016,
+        },
+        Glyph {
+            c: 'Ī',
+            advance: 5,
+            left: 0,
+            top: 15,
+            width: 5,
+            height: 15,
+            offset: 13055,
+        },
+        Glyph {
+            c: 'ī',
+            advance: 4,
+            left: -1,
+            top: 12,
+            width: 6,
+            height: 12,
+            offset: 13093,
+        },
+        Glyph {
+            c: 'Ĭ',
+            advance: 5,
+            left: -1,
+            top: 15,
+            width: 6,
+            height: 15,
+            offset: 13129,
+        },
+        Glyph {
+            c: 'ĭ',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 13174,
+        },
+        Glyph {
+            c: 'Į',
+            advance: 5,
+            left: 1,
+            top: 12,
+            width: 4,
+            height: 16,
+            offset: 13213,
+        },
+        Glyph {
+            c: 'į',
+            advance: 4,
+            left: 1,
+            top: 13,
+            width: 4,
+            height: 17,
+            offset: 13245,
+        },
+        Glyph {
+            c: 'İ',
+            advance: 5,
+            left: 1,
+            top: 15,
+            width: 3,
+            height: 15,
+            offset: 13279,
+        },
+        Glyph {
+            c: 'ı',
+            advance: 4,
+            left: 1,
+            top: 9,
+            width: 2,
+            height: 9,
+            offset: 13302,
+        },
+        Glyph {
+            c: 'Ĳ',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 7,
+            height: 16,
+            offset: 13311,
+        },
+        Glyph {
+            c: 'ĳ',
+            advance: 9,
+            left: 1,
+            top: 13,
+            width: 7,
+            height: 17,
+            offset: 13367,
+        },
+        Glyph {
+            c: 'Ĵ',
+            advance: 5,
+            left: -1,
+            top: 15,
+            width: 6,
+            height: 19,
+            offset: 13427,
+        },
+        Glyph {
+            c: 'ĵ',
+            advance: 4,
+            left: -1,
+            top: 13,
+            width: 6,
+            height: 17,
+            offset: 13484,
+        },
+        Glyph {
+            c: 'Ķ',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 16,
+            offset: 13535,
+        },
+        Glyph {
+            c: 'ķ',
+            advance: 9,
+            left: 1,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 13615,
+        },
+        Glyph {
+            c: 'ĸ',
+            advance: 9,
+            left: 1,
+            top: 9,
+            width: 9,
+            height: 9,
+            offset: 13692,
+        },
+        Glyph {
+            c: 'Ĺ',
+            advance: 9,
+            left: 1,
+            top: 15,
+            width: 8,
+            height: 15,
+            offset: 13733,
+        },
+        Glyph {
+            c: 'ĺ',
+            advance: 4,
+            left: 1,
+            top: 15,
+            width: 4,
+            height: 15,
+            offset: 13793,
+        },
+        Glyph {
+            c: 'Ļ',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 16,
+            offset: 13823,
+        },
+        Glyph {
+            c: 'ļ',
+            advance: 4,
+            left: 1,
+            top: 13,
+            width: 3,
+            height: 17,
+            offset: 13887,
+        },
+        Glyph {
+            c: 'Ľ',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 13913,
+        },
+        Glyph {
+            c: 'ľ',
+            advance: 6,
+            left: 1,
+            top: 13,
+            width: 5,
+            height: 13,
+            offset: 13961,
+        },
+        Glyph {
+            c: 'Ŀ',
+            advance: 9,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 12,
+            offset: 13994,
+        },
+        Glyph {
+            c: 'ŀ',
+            advance: 5,
+            left: 1,
+            top: 13,
+            width: 5,
+            height: 13,
+            offset: 14042,
+        },
+        Glyph {
+            c: 'Ł',
+            advance: 9,
+            left: -1,
+            top: 12,
+            width: 10,
+            height: 12,
+            offset: 14075,
+        },
+        Glyph {
+            c: 'ł',
+            advance: 5,
+            left: 0,
+            top: 13,
+            width: 5,
+            height: 13,
+            offset: 14135,
+        },
+        Glyph {
+            c: 'Ń',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 14168,
+        },
+        Glyph {
+            c: 'ń',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 14243,
+        },
+        Glyph {
+            c: 'Ņ',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 16,
+            offset: 14295,
+        },
+        Glyph {
+            c: 'ņ',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 13,
+            offset: 14375,
+        },
+        Glyph {
+            c: 'Ň',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 14427,
+        },
+        Glyph {
+            c: 'ň',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 14502,
+        },
+        Glyph {
+            c: 'ŉ',
+            advance: 13,
+            left: 1,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 14554,
+        },
+        Glyph {
+            c: 'Ŋ',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 16,
+            offset: 14620,
+        },
+        Glyph {
+            c: 'ŋ',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 8,
+            height: 13,
+            offset: 14700,
+        },
+        Glyph {
+            c: 'Ō',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 14752,
+        },
+        Glyph {
+            c: 'ō',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 9,
+            height: 13,
+            offset: 14848,
+        },
+        Glyph {
+            c: 'Ŏ',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 14907,
+        },
+        Glyph {
+            c: 'ŏ',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 15003,
+        },
+        Glyph {
+            c: 'Ő',
+            advance: 13,
+            left: 0,
+            top: 15,
+            width: 12,
+            height: 16,
+            offset: 15066,
+        },
+        Glyph {
+            c: 'ő',
+            advance: 10,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 14,
+            offset: 15162,
+        },
+        Glyph {
+            c: 'Œ',
+            advance: 17,
+            left: 0,
+            top: 12,
+            width: 17,
+            height: 12,
+            offset: 15225,
+        },
+        Glyph {
+            c: 'œ',
+            advance: 16,
+            left: 0,
+            top: 9,
+            width: 16,
+            height: 10,
+            offset: 15327,
+        },
+        Glyph {
+            c: 'Ŕ',
+            advance: 11,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 15407,
+        },
+        Glyph {
+            c: 'ŕ',
+            advance: 7,
+            left: 1,
+            top: 13,
+            width: 7,
+            height: 13,
+            offset: 15482,
+        },
+        Glyph {
+            c: 'Ŗ',
+            advance: 11,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 16,
+            offset: 15528,
+        },
+        Glyph {
+            c: 'ŗ',
+            advance: 7,
+            left: 1,
+            top: 9,
+            width: 6,
+            height: 13,
+            offset: 15608,
+        },
+        Glyph {
+            c: 'Ř',
+            advance: 11,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 15,
+            offset: 15647,
+        },
+        Glyph {
+            c: 'ř',
+            advance: 7,
+            left: 1,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 15722,
+        },
+        Glyph {
+            c: 'Ś',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 16,
+            offset: 15761,
+        },
+        Glyph {
+            c: 'ś',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 15833,
+        },
+        Glyph {
+            c: 'Ŝ',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 16,
+            offset: 15889,
+        },
+        Glyph {
+            c: 'ŝ',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 15961,
+        },
+        Glyph {
+            c: 'Ş',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 9,
+            height: 16,
+            offset: 16017,
+        },
+        Glyph {
+            c: 'ş',
+            advance: 8,
+            left: 0,
+            top: 9,
+            width: 8,
+            height: 13,
+            offset: 16089,
+        },
+        Glyph {
+            c: 'Š',
+            advance: 10,
+            left: 1,
+            top: 15,
+            width: 9,
+            height: 16,
+            offset: 16141,
+        },
+        Glyph {
+            c: 'š',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 16213,
+        },
+        Glyph {
+            c: 'Ţ',
+            advance: 10,
+            left: -1,
+            top: 12,
+            width: 11,
+            height: 16,
+            offset: 16269,
+        },
+        Glyph {
+            c: 'ţ',
+            advance: 6,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 16,
+            offset: 16357,
+        },
+        Glyph {
+            c: 'Ť',
+            advance: 10,
+            left: -1,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 16405,
+        },
+        Glyph {
+            c: 'ť',
+            advance: 6,
+            left: 0,
+            top: 14,
+            width: 6,
+            height: 14,
+            offset: 16488,
+        },
+        Glyph {
+            c: 'Ŧ',
+            advance: 10,
+            left: -1,
+            top: 12,
+            width: 11,
+            height: 12,
+            offset: 16530,
+        },
+        Glyph {
+            c: 'ŧ',
+            advance: 6,
+            left: 0,
+            top: 12,
+            width: 6,
+            height: 12,
+            offset: 16596,
+        },
+        Glyph {
+            c: 'Ũ',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 16632,
+        },
+        Glyph {
+            c: 'ũ',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 16712,
+        },
+        Glyph {
+            c: 'Ū',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 16768,
+        },
+        Glyph {
+            c: 'ū',
+            advance: 10,
+            left: 1,
+            top: 12,
+            width: 8,
+            height: 13,
+            offset: 16848,
+        },
+        Glyph {
+            c: 'Ŭ',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 16900,
+        },
+        Glyph {
+            c: 'ŭ',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 16980,
+        },
+        Glyph {
+            c: 'Ů',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 17036,
+        },
+        Glyph {
+            c: 'ů',
+            advance: 10,
+            left: 1,
+            top: 14,
+            width: 8,
+            height: 15,
+            offset: 17116,
+        },
+        Glyph {
+            c: 'Ű',
+            advance: 12,
+            left: 1,
+            top: 15,
+            width: 10,
+            height: 16,
+            offset: 17176,
+        },
+        Glyph {
+            c: 'ű',
+            advance: 10,
+            left: 1,
+            top: 13,
+            width: 8,
+            height: 14,
+            offset: 17256,
+        },
+        Glyph {
+            c: 'Ų',
+            advance: 12,
+            left: 1,
+            top: 12,
+            width: 10,
+            height: 16,
+            offset: 17312,
+        },
+        Glyph {
+            c: 'ų',
+            advance: 10,
+            left: 1,
+            top: 9,
+            width: 9,
+            height: 13,
+            offset: 17392,
+        },
+        Glyph {
+            c: 'Ŵ',
+            advance: 16,
+            left: 0,
+            top: 15,
+            width: 16,
+            height: 15,
+            offset: 17451,
+        },
+        Glyph {
+            c: 'ŵ',
+            advance: 13,
+            left: 0,
+            top: 13,
+            width: 13,
+            height: 13,
+            offset: 17571,
+        },
+        Glyph {
+            c: 'Ŷ',
+            advance: 10,
+            left: -1,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 17656,
+        },
+        Glyph {
+            c: 'ŷ',
+            advance: 9,
+            left: 0,
+            top: 13,
+            width: 9,
+            height: 17,
+            offset: 17739,
+        },
+        Glyph {
+            c: 'Ÿ',
+            advance: 10,
+            left: -1,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 17816,
+        },
+        Glyph {
+            c: 'Ź',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 17899,
+        },
+        Glyph {
+            c: 'ź',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 17982,
+        },
+        Glyph {
+            c: 'Ż',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 18034,
+        },
+        Glyph {
+            c: 'ż',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 18117,
+        },
+        Glyph {
+            c: 'Ž',
+            advance: 11,
+            left: 0,
+            top: 15,
+            width: 11,
+            height: 15,
+            offset: 18169,
+        },
+        Glyph {
+            c: 'ž',
+            advance: 8,
+            left: 0,
+            top: 13,
+            width: 8,
+            height: 13,
+            offset: 18252,
+        },
+        Glyph {
+            c: 'ſ',
+            advance: 6,
+            left: 0,
+            top: 13,
+            width: 6,
+            height: 13,
+            offset: 18304,
+        },
+        Glyph {
+            c: '–',
+            advance: 8,
+            left: 0,
+            top: 5,
+            width: 8,
+            height: 2,
+            offset: 18343,
+        },
+        Glyph {
+            c: '—',
+            advance: 16,
+            left: 0,
+            top: 5,
+            width: 16,
+            height: 2,
+            offset: 18351,
+        },
+        Glyph {
+            c: '‘',
+            advance: 5,
+            left: 1,
+            top: 12,
+            width: 3,
+            height: 5,
+            offset: 18367,
+        },
+        Glyph {
+            c: '’',
+            advance: 5,
+            left: 1,
+            top: 12,
+            width: 3,
+            height: 5,
+            offset: 18375,
+        },
+        Glyph {
+            c: '‚',
+            advance: 5,
+            left: 1,
+            top: 2,
+            width: 3,
+            height: 4,
+            offset: 18383,
+        },
+        Glyph {
+            c: '‛',
+            advance: 5,
+            left: 1,
+            top: 12,
+            width: 3,
+            height: 5,
+            offset: 18389,
+        },
+        Glyph {
+            c: '“',
+            advance: 8,
+            left: 1,
+            top: 12,
+            width: 6,
+            height: 5,
+            offset: 18397,
+        },
+        Glyph {
+            c: '”',
+            advance: 8,
+            left: 1,
+            top: 12,
+            width: 6,
+            height: 5,
+            offset: 18412,
+        },
+        Glyph {
+            c: '„',
+            advance: 8,
+            left: 1,
+            top: 2,
+            width: 6,
+            height: 4,
+            offset: 18427,
+        },
+        Glyph {
+            c: '†',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 8,
+            height: 14,
+            offset: 18439,
+        },
+        Glyph {
+            c: '‡',
+            advance: 8,
+            left: 0,
+            top: 12,
+            width: 8,
+            height: 14,
+            offset: 18495,
+        },
+        Glyph {
+            c: '•',
+            advance: 9,
+            left: 2,
+            top: 9,
+            width: 6,
+            height: 6,
+            offset: 18551,
+        },
+        Glyph {
+            c: '…',
+            advance: 16,
+            left: 1,
+            top: 2,
+            width: 14,
+            height: 2,
+            offset: 18569,
+        },
+        Glyph {
+            c: '€',
+            advance: 10,
+            left: 0,
+            top: 12,
+            width: 10,
+            height: 13,
+            offset: 18583,
+        },
+        Glyph {
+            c: '�',
+            advance: 16,
+            left: 0,
+            top: 15,
+            width: 17,
+            height: 17,
+            offset: 18648,
+        },
+    ],
+    bitmaps: &[
+        0x6a, 0x9f, 0x9f, 0x9f, 0x9f, 0x9f, 0x8e, 0x7d, 0x23, 0x00, 0x9f, 0x9f, 0x59, 0x05, 0x87,
+        0xd0, 0x8c, 0x7d, 0x08, 0xc7, 0xd0, 0x8c, 0x59, 0x05, 0x80, 0x00, 0x00, 0x27, 0x00, 0x64,
+        0x00, 0x00, 0x00, 0x7b, 0x00, 0xe5, 0x00, 0x00, 0x00, 0xb8, 0x03, 0xf1, 0x00, 0x03, 0x44,
+        0xe7, 0x48, 0xd4, 0x41, 0x0d, 0xef, 0xfe, 0xef, 0xfe, 0xe3, 0x00, 0x07, 0xc0, 0x0e, 0x50,
+        0x00, 0x00, 0x0b, 0x80, 0x3f, 0x10, 0x00, 0x56, 0x6f, 0x96, 0xae, 0x66, 0x20, 0x9b, 0xdf,
+        0xbb, 0xed, 0xbb, 0x30, 0x00, 0x7b, 0x00, 0xe5, 0x00, 0x00, 0x00, 0xb8, 0x03, 0xf1, 0x00,
+        0x00, 0x00, 0xe4, 0x07, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0x00, 0x06, 0x60, 0x00,
+        0x00, 0x28, 0x93, 0x10, 0x1a, 0xfd, 0xde, 0xf2, 0x7e, 0x36, 0x60, 0x41, 0xac, 0x06, 0x60,
+        0x00, 0x7f, 0x67, 0x60, 0x00, 0x09, 0xff, 0xd9, 0x30, 0x00, 0x18, 0xbc, 0xf5, 0x00, 0x06,
+        0x60, 0xcc, 0x00, 0x06, 0x60, 0xac, 0x98, 0x47, 0x87, 0xf7, 0x4a, 0xdf, 0xfc, 0x60, 0x00,
+        0x06, 0x60, 0x00, 0x00, 0x06, 0x60, 0x00, 0x00, 0x02, 0x20, 0x00, 0x01, 0xad, 0xb2, 0x00,
+        0x00, 0x88, 0x00, 0x00, 0xbb, 0x29, 0xd0, 0x00, 0x3e, 0x10, 0x00, 0x1f, 0x30, 0x1f, 0x30,
+        0x0c, 0x70, 0x00, 0x01, 0xf2, 0x00, 0xf4, 0x06, 0xc0, 0x00, 0x00, 0x0e, 0x50, 0x3f, 0x21,
+        0xe4, 0x00, 0x00, 0x00, 0x7e, 0x8d, 0x90, 0x9a, 0x00, 0x10, 0x00, 0x00, 0x47, 0x50, 0x3e,
+        0x13, 0xde, 0xe5, 0x00, 0x00, 0x00, 0x0c, 0x60, 0xc9, 0x05, 0xe1, 0x00, 0x00, 0x06, 0xc0,
+        0x0f, 0x30, 0x0f, 0x40, 0x00, 0x01, 0xe3, 0x01, 0xf3, 0x00, 0xe4, 0x00, 0x00, 0x99, 0x00,
+        0x0c, 0x80, 0x4f, 0x10, 0x00, 0x4e, 0x10, 0x00, 0x3e, 0xce, 0x60, 0x00, 0x02, 0x20, 0x00,
+        0x00, 0x13, 0x10, 0x00, 0x00, 0x5b, 0xdc, 0x81, 0x00, 0x00, 0x4f, 0xb6, 0x7c, 0x20, 0x00,
+        0x09, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x9e, 0x10, 0x00, 0x00, 0x00, 0x02, 0xfa, 0x00, 0x00,
+        0x00, 0x00, 0x9e, 0xf9, 0x00, 0x00, 0x42, 0x7f, 0x35, 0xf9, 0x00, 0x2f, 0x5d, 0xa0, 0x06,
+        0xf9, 0x05, 0xf1, 0xf8, 0x00, 0x06, 0xf8, 0xc9, 0x0c, 0xc0, 0x00, 0x06, 0xfe, 0x10, 0x5f,
+        0xa3, 0x13, 0xaf, 0xf8, 0x00, 0x5d, 0xff, 0xfb, 0x37, 0xf7, 0x00, 0x02, 0x31, 0x00, 0x00,
+        0x00, 0x59, 0x7d, 0x7d, 0x7d, 0x59, 0x00, 0x12, 0x00, 0xa9, 0x03, 0xf2, 0x0a, 0xb0, 0x1f,
+        0x60, 0x5f, 0x20, 0x8f, 0x00, 0x9e, 0x00, 0x9e, 0x00, 0x8f, 0x00, 0x5f, 0x20, 0x1f, 0x60,
+        0x0a, 0xb0, 0x03, 0xf2, 0x00, 0xa9, 0x00, 0x12, 0x11, 0x00, 0x6d, 0x10, 0x0d, 0x70, 0x07,
+        0xd0, 0x02, 0xf4, 0x00, 0xe8, 0x00, 0xbb, 0x00, 0xad, 0x00, 0xad, 0x00, 0xbb, 0x00, 0xe8,
+        0x02, 0xf4, 0x07, 0xd0, 0x0d, 0x70, 0x6d, 0x00, 0x11, 0x00, 0x00, 0x06, 0x60, 0x00, 0x23,
+        0x07, 0x70, 0x32, 0x3c, 0x98, 0x89, 0xc3, 0x00, 0x5e, 0xe5, 0x00, 0x04, 0xcc, 0xcc, 0x40,
+        0x4b, 0x27, 0x72, 0xb4, 0x00, 0x07, 0x70, 0x00, 0x00, 0x03, 0x30, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x00,
+        0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x03, 0xaa, 0xaa, 0xfc, 0xaa, 0xa7,
+        0x3a, 0xaa, 0xaf, 0xca, 0xaa, 0x70, 0x00, 0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x50,
+        0x00, 0x00, 0x00, 0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x2f, 0x83,
+        0xf6, 0x6e, 0x18, 0x60, 0x00, 0x00, 0x03, 0xff, 0xff, 0x14, 0x44, 0x40, 0x4f, 0x54, 0xf5,
+        0x00, 0x00, 0xa3, 0x00, 0x04, 0xf1, 0x00, 0x09, 0xb0, 0x00, 0x0e, 0x60, 0x00, 0x3f, 0x20,
+        0x00, 0x8c, 0x00, 0x00, 0xd7, 0x00, 0x02, 0xf3, 0x00, 0x07, 0xd0, 0x00, 0x0c, 0x80, 0x00,
+        0x1f, 0x40, 0x00, 0x6e, 0x00, 0x00, 0xa9, 0x00, 0x00, 0x73, 0x00, 0x00, 0x01, 0x8c, 0xd9,
+        0x10, 0x00, 0xbe, 0x77, 0xdd, 0x10, 0x5f, 0x50, 0x02, 0xf8, 0x0a, 0xe0, 0x00, 0x0b, 0xc0,
+        0xdb, 0x00, 0x00, 0x8f, 0x0e, 0xa0, 0x00, 0x07, 0xf1, 0xea, 0x00, 0x00, 0x7f, 0x2d, 0xb0,
+        0x00, 0x08, 0xf1, 0xbd, 0x00, 0x00, 0xae, 0x07, 0xf2, 0x00, 0x1e, 0xa0, 0x1e, 0xc2, 0x1a,
+        0xf3, 0x00, 0x3d, 0xff, 0xe5, 0x00, 0x00, 0x03, 0x30, 0x00, 0x00, 0x04, 0x7a, 0xa1, 0x00,
+        0x4f, 0xfe, 0xf2, 0x00, 0x13, 0x07, 0xf2, 0x00, 0x00, 0x07, 0xf2, 0x00, 0x00, 0x07, 0xf2,
+        0x00, 0x00, 0x07, 0xf2, 0x00, 0x00, 0x07, 0xf2, 0x00, 0x00, 0x07, 0xf2, 0x00, 0x00, 0x07,
+        0xf2, 0x00, 0x00, 0x07, 0xf2, 0x00, 0x05, 0x59, 0xf6, 0x53, 0x0f, 0xff, 0xff, 0xfb, 0x38,
+        0xbd, 0xc8, 0x10, 0xbd, 0x97, 0x9f, 0xc0, 0x30, 0x00, 0x06, 0xf5, 0x00, 0x00, 0x01, 0xf7,
+        0x00, 0x00, 0x04, 0xf5, 0x00, 0x00, 0x1d, 0xd0, 0x00, 0x00, 0xbe, 0x20, 0x00, 0x0a, 0xe3,
+        0x00, 0x00, 0xae, 0x40, 0x00, 0x0a, 0xe4, 0x00, 0x00, 0x9f, 0x95, 0x55, 0x53, 0xcf, 0xff,
+        0xff, 0xf9, 0x39, 0xcd, 0xc9, 0x20, 0x6b, 0x87, 0x8e, 0xe2, 0x00, 0x00, 0x02, 0xf8, 0x00,
+        0x00, 0x00, 0xf9, 0x00, 0x00, 0x07, 0xf4, 0x00, 0xad, 0xee, 0x40, 0x00, 0x56, 0x8d, 0xd2,
+        0x00, 0x00, 0x01, 0xea, 0x00, 0x00, 0x00, 0xad, 0x00, 0x00, 0x00, 0xdc, 0x85, 0x22, 0x4a,
+        0xf6, 0x9f, 0xff, 0xfd, 0x60, 0x00, 0x33, 0x20, 0x00, 0x00, 0x00, 0x06, 0xa6, 0x00, 0x00,
+        0x00, 0x2e, 0xf9, 0x00, 0x00, 0x00, 0xc8, 0xe9, 0x00, 0x00, 0x07, 0xd1, 0xe9, 0x00, 0x00,
+        0x2e, 0x40, 0xe9, 0x00, 0x00, 0xb9, 0x00, 0xe9, 0x00, 0x06, 0xe1, 0x00, 0xe9, 0x00, 0x1e,
+        0x61, 0x11, 0xea, 0x10, 0x3f, 0xff, 0xff, 0xff, 0xf4, 0x14, 0x44, 0x44, 0xeb, 0x41, 0x00,
+        0x00, 0x00, 0xe9, 0x00, 0x00, 0x00, 0x00, 0xe9, 0x00, 0x3a, 0xaa, 0xaa, 0x90, 0x4f, 0xba,
+        0xaa, 0x90, 0x4f, 0x30, 0x00, 0x00, 0x4f, 0x30, 0x00, 0x00, 0x4f, 0xaa, 0x95, 0x00, 0x4e,
+        0xaa, 0xcf, 0xb0, 0x10, 0x00, 0x07, 0xf6, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0xcb,
+        0x00, 0x00, 0x02, 0xf9, 0x85, 0x22, 0x5d, 0xf3, 0xaf, 0xff, 0xfd, 0x40, 0x01, 0x33, 0x20,
+        0x00, 0x00, 0x3a, 0xdd, 0xa3, 0x00, 0x5f, 0xc8, 0x7a, 0x60, 0x1e, 0xa0, 0x00, 0x00, 0x07,
+        0xf2, 0x00, 0x00, 0x00, 0xbd, 0x29, 0xa8, 0x20, 0x0d, 0xde, 0xa9, 0xdf, 0x40, 0xdf, 0x70,
+        0x01, 0xdd, 0x0c, 0xf1, 0x00, 0x07, 0xf2, 0xaf, 0x00, 0x00, 0x7f, 0x26, 0xf3, 0x00, 0x0a,
+        0xf0, 0x1d, 0xc3, 0x16, 0xf9, 0x00, 0x2c, 0xff, 0xf9, 0x00, 0x00, 0x02, 0x31, 0x00, 0x00,
+        0x7a, 0xaa, 0xaa, 0xa8, 0x7a, 0xaa, 0xab, 0xf9, 0x00, 0x00, 0x06, 0xf4, 0x00, 0x00, 0x0c,
+        0xd0, 0x00, 0x00, 0x3f, 0x70, 0x00, 0x00, 0x8f, 0x10, 0x00, 0x00, 0xea, 0x00, 0x00, 0x05,
+        0xf5, 0x00, 0x00, 0x0b, 0xe0, 0x00, 0x00, 0x2f, 0x80, 0x00, 0x00, 0x7f, 0x20, 0x00, 0x00,
+        0xdc, 0x00, 0x00, 0x03, 0xad, 0xda, 0x40, 0x02, 0xed, 0x76, 0xcf, 0x50, 0x8f, 0x20, 0x00,
+        0xeb, 0x09, 0xe0, 0x00, 0x0c, 0xc0, 0x4f, 0x50, 0x03, 0xf7, 0x00, 0x5e, 0xdc, 0xe7, 0x00,
+        0x1c, 0xd8, 0x8c, 0xd3, 0x0a, 0xe1, 0x00, 0x0c, 0xc0, 0xdb, 0x00, 0x00, 0x8f, 0x1c, 0xc0,
+        0x00, 0x09, 0xf0, 0x7f, 0x81, 0x16, 0xfa, 0x00, 0x8f, 0xff, 0xfa, 0x10, 0x00, 0x13, 0x31,
+        0x00, 0x00, 0x03, 0xad, 0xc8, 0x10, 0x03, 0xec, 0x67, 0xec, 0x00, 0xbe, 0x10, 0x03, 0xf6,
+        0x0e, 0xa0, 0x00, 0x0d, 0xb0, 0xe9, 0x00, 0x00, 0xce, 0x0c, 0xd0, 0x00, 0x2f, 0xf0, 0x5f,
+        0xa4, 0x4c, 0xef, 0x10, 0x5d, 0xfe, 0x99, 0xe0, 0x00, 0x00, 0x00, 0xcb, 0x00, 0x00, 0x00,
+        0x4f, 0x60, 0x26, 0x22, 0x6e, 0xc0, 0x03, 0xef, 0xff, 0xa1, 0x00, 0x00, 0x33, 0x10, 0x00,
+        0x00, 0x04, 0x22, 0xf8, 0x1b, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xf8, 0x2f, 0x80,
+        0x04, 0x22, 0xf8, 0x1b, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xf8, 0x3f, 0x66, 0xe1,
+        0x86, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x27, 0xdb, 0x00, 0x00,
+        0x16, 0xcf, 0xe8, 0x30, 0x05, 0xaf, 0xe9, 0x40, 0x00, 0x3e, 0xfa, 0x50, 0x00, 0x00, 0x03,
+        0xef, 0xb5, 0x10, 0x00, 0x00, 0x00, 0x4a, 0xee, 0xa4, 0x00, 0x00, 0x00, 0x01, 0x6b, 0xfe,
+        0x93, 0x00, 0x00, 0x00, 0x02, 0x7c, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x44, 0x44,
+        0x44, 0x44, 0x35, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x01, 0x11, 0x11, 0x11, 0x11, 0x00, 0x11,
+        0x11, 0x11, 0x11, 0x11, 0x5f, 0xff, 0xff, 0xff, 0xff, 0xb1, 0x44, 0x44, 0x44, 0x44, 0x43,
+        0x11, 0x00, 0x00, 0x00, 0x00, 0x05, 0xea, 0x40, 0x00, 0x00, 0x00, 0x16, 0xcf, 0xe8, 0x30,
+        0x00, 0x00, 0x00, 0x27, 0xdf, 0xc7, 0x20, 0x00, 0x00, 0x00, 0x38, 0xdf, 0x80, 0x00, 0x00,
+        0x03, 0x9e, 0xf8, 0x00, 0x02, 0x8d, 0xfc, 0x71, 0x01, 0x7c, 0xfd, 0x82, 0x00, 0x00, 0x5e,
+        0x94, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x39, 0xcd, 0xa2, 0x0d, 0xb7,
+        0x7e, 0xe1, 0x30, 0x00, 0x5f, 0x50, 0x00, 0x05, 0xf4, 0x00, 0x02, 0xeb, 0x00, 0x02, 0xec,
+        0x10, 0x00, 0xad, 0x10, 0x00, 0x0d, 0x90, 0x00, 0x00, 0xc8, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xe9, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00, 0x00,
+        0x04, 0xbf, 0xed, 0xfd, 0x70, 0x00, 0x00, 0x8e, 0x72, 0x00, 0x04, 0xcc, 0x10, 0x08, 0xd2,
+        0x00, 0x00, 0x00, 0x0a, 0xb0, 0x2e, 0x20, 0x06, 0xa9, 0x37, 0x30, 0xd5, 0x99, 0x00, 0x9e,
+        0x87, 0xde, 0x50, 0x7a, 0xc4, 0x02, 0xf4, 0x00, 0x3f, 0x50, 0x4d, 0xe3, 0x04, 0xf0, 0x00,
+        0x0e, 0x50, 0x4d, 0xe3, 0x03, 0xf1, 0x00, 0x0f, 0x50, 0x89, 0xb6, 0x00, 0xe8, 0x00, 0x7f,
+        0x54, 0xe3, 0x6c, 0x00, 0x4e, 0xdd, 0xcd, 0xed, 0x40, 0x1d, 0x70, 0x01, 0x43, 0x04, 0x30,
+        0x00, 0x03, 0xe8, 0x00, 0x00, 0x00, 0x61, 0x00, 0x00, 0x3c, 0xd8, 0x65, 0x8d, 0xd3, 0x00,
+        0x00, 0x00, 0x59, 0xbb, 0x95, 0x00, 0x00, 0x00, 0x00, 0x5a, 0x50, 0x00, 0x00, 0x00, 0x0d,
+        0xfc, 0x00, 0x00, 0x00, 0x04, 0xf8, 0xf3, 0x00, 0x00, 0x00, 0x9d, 0x0e, 0x90, 0x00, 0x00,
+        0x1e, 0x80, 0x8e, 0x00, 0x00, 0x06, 0xf2, 0x03, 0xf5, 0x00, 0x00, 0xcc, 0x00, 0x0c, 0xb0,
+        0x00, 0x2f, 0x95, 0x55, 0xaf, 0x20, 0x08, 0xff, 0xff, 0xff, 0xf7, 0x00, 0xea, 0x00, 0x00,
+        0x0b, 0xd0, 0x5f, 0x50, 0x00, 0x00, 0x5f, 0x4a, 0xe0, 0x00, 0x00, 0x01, 0xe9, 0x4a, 0xaa,
+        0xa9, 0x50, 0x06, 0xfa, 0x9a, 0xcf, 0xb0, 0x6f, 0x20, 0x00, 0x7f, 0x46, 0xf2, 0x00, 0x04,
+        0xf5, 0x6f, 0x20, 0x00, 0x9f, 0x26, 0xfd, 0xdd, 0xee, 0x40, 0x6f, 0x86, 0x68, 0xdc, 0x16,
+        0xf2, 0x00, 0x01, 0xf9, 0x6f, 0x20, 0x00, 0x0d, 0xc6, 0xf2, 0x00, 0x01, 0xeb, 0x6f, 0x64,
+        0x46, 0xcf, 0x56, 0xff, 0xff, 0xeb, 0x50, 0x00, 0x02, 0x8c, 0xdc, 0x93, 0x00, 0x05, 0xee,
+        0x87, 0x7b, 0xf4, 0x03, 0xfb, 0x10, 0x00, 0x04, 0x30, 0xaf, 0x20, 0x00, 0x00, 0x00, 0x0e,
+        0xb0, 0x00, 0x00, 0x00, 0x01, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x00,
+        0x00, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x5f, 0x70, 0x00,
+        0x00, 0x12, 0x00, 0xaf, 0x93, 0x12, 0x6d, 0x50, 0x00, 0x7e, 0xff, 0xfe, 0x81, 0x00, 0x00,
+        0x02, 0x32, 0x00, 0x00, 0x4a, 0xaa, 0xa8, 0x61, 0x00, 0x06, 0xfa, 0x9a, 0xbf, 0xe7, 0x00,
+        0x6f, 0x20, 0x00, 0x1a, 0xf6, 0x06, 0xf2, 0x00, 0x00, 0x0d, 0xe0, 0x6f, 0x20, 0x00, 0x00,
+        0x7f, 0x36, 0xf2, 0x00, 0x00, 0x05, 0xf5, 0x6f, 0x20, 0x00, 0x00, 0x5f, 0x56, 0xf2, 0x00,
+        0x00, 0x06, 0xf4, 0x6f, 0x20, 0x00, 0x00, 0xaf, 0x16, 0xf2, 0x00, 0x00, 0x5f, 0x90, 0x6f,
+        0x64, 0x56, 0xbf, 0xc1, 0x06, 0xff, 0xff, 0xdb, 0x50, 0x00, 0x4a, 0xaa, 0xaa, 0xa9, 0x06,
+        0xfb, 0xaa, 0xaa, 0x90, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20,
+        0x00, 0x00, 0x06, 0xfe, 0xdd, 0xdd, 0x90, 0x6f, 0x87, 0x77, 0x75, 0x06, 0xf2, 0x00, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x55, 0x06,
+        0xff, 0xff, 0xff, 0xf1, 0x4a, 0xaa, 0xaa, 0xa3, 0x6f, 0xba, 0xaa, 0xa3, 0x6f, 0x20, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xa0, 0x6f, 0x86,
+        0x66, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x00, 0x02, 0x8c, 0xdd, 0xa6, 0x10, 0x00, 0x5e,
+        0xe9, 0x77, 0xaf, 0xb0, 0x03, 0xfb, 0x10, 0x00, 0x01, 0x70, 0x0a, 0xf2, 0x00, 0x00, 0x00,
+        0x00, 0x0e, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x01, 0x11, 0x10, 0x1f, 0x90,
+        0x00, 0x1f, 0xff, 0xf1, 0x0f, 0xa0, 0x00, 0x03, 0x39, 0xf1, 0x0c, 0xe0, 0x00, 0x00, 0x07,
+        0xf1, 0x05, 0xf7, 0x00, 0x00, 0x07, 0xf1, 0x00, 0xaf, 0x93, 0x12, 0x4b, 0xf1, 0x00, 0x07,
+        0xdf, 0xff, 0xfb, 0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x4a, 0x10, 0x00, 0x01, 0xa5,
+        0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7,
+        0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0xed, 0xdd, 0xdd, 0xf7, 0x6f, 0x87, 0x77, 0x78, 0xf7,
+        0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7,
+        0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x4a, 0x16, 0xf2, 0x6f, 0x26,
+        0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x26, 0xf2, 0x00, 0x4a,
+        0x10, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f,
+        0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x07, 0xf2, 0x00, 0x8f,
+        0x12, 0x4e, 0xb0, 0xcf, 0xd2, 0x02, 0x20, 0x00, 0x4a, 0x10, 0x00, 0x19, 0x91, 0x6f, 0x20,
+        0x01, 0xce, 0x40, 0x6f, 0x20, 0x1c, 0xe3, 0x00, 0x6f, 0x22, 0xdd, 0x20, 0x00, 0x6f, 0x5d,
+        0xd2, 0x00, 0x00, 0x6f, 0xed, 0x10, 0x00, 0x00, 0x6f, 0xcf, 0x50, 0x00, 0x00, 0x6f, 0x3b,
+        0xf5, 0x00, 0x00, 0x6f, 0x20, 0xbf, 0x50, 0x00, 0x6f, 0x20, 0x0b, 0xf5, 0x00, 0x6f, 0x20,
+        0x00, 0xbf, 0x50, 0x6f, 0x20, 0x00, 0x0b, 0xf5, 0x4a, 0x10, 0x00, 0x00, 0x6f, 0x20, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x54, 0x6f, 0xff, 0xff, 0xfc, 0x4a, 0xa1, 0x00, 0x00,
+        0x2a, 0xa2, 0x6f, 0xf5, 0x00, 0x00, 0x8f, 0xf4, 0x6f, 0xbb, 0x00, 0x00, 0xeb, 0xf4, 0x6f,
+        0x5f, 0x20, 0x05, 0xf6, 0xf4, 0x6f, 0x2d, 0x70, 0x0a, 0xa4, 0xf4, 0x6f, 0x17, 0xd0, 0x1f,
+        0x44, 0xf4, 0x6f, 0x12, 0xf3, 0x6e, 0x04, 0xf4, 0x6f, 0x10, 0xb9, 0xc8, 0x04, 0xf4, 0x6f,
+        0x10, 0x5f, 0xf3, 0x04, 0xf4, 0x6f, 0x10, 0x1b, 0xa0, 0x04, 0xf4, 0x6f, 0x10, 0x00, 0x00,
+        0x04, 0xf4, 0x6f, 0x10, 0x00, 0x00, 0x04, 0xf4, 0x4a, 0x90, 0x00, 0x01, 0xa4, 0x6f, 0xf5,
+        0x00, 0x02, 0xf6, 0x6f, 0xdc, 0x00, 0x02, 0xf6, 0x6f, 0x5f, 0x60, 0x02, 0xf6, 0x6f, 0x1b,
+        0xd0, 0x02, 0xf6, 0x6f, 0x13, 0xf6, 0x02, 0xf6, 0x6f, 0x10, 0xae, 0x12, 0xf6, 0x6f, 0x10,
+        0x2f, 0x72, 0xf6, 0x6f, 0x10, 0x09, 0xe3, 0xf6, 0x6f, 0x10, 0x01, 0xea, 0xf6, 0x6f, 0x10,
+        0x00, 0x8f, 0xf6, 0x6f, 0x10, 0x00, 0x1e, 0xf6, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00,
+        0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00,
+        0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f,
+        0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00,
+        0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00,
+        0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x4a, 0xaa, 0xa8, 0x20,
+        0x06, 0xfa, 0x9a, 0xef, 0x50, 0x6f, 0x20, 0x01, 0xdd, 0x06, 0xf2, 0x00, 0x09, 0xf1, 0x6f,
+        0x20, 0x00, 0xaf, 0x16, 0xf2, 0x00, 0x5f, 0xb0, 0x6f, 0xff, 0xff, 0xc2, 0x06, 0xf6, 0x54,
+        0x20, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00,
+        0x06, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8, 0x79,
+        0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4, 0x0e,
+        0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00, 0x00,
+        0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5, 0x05,
+        0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00, 0x07, 0xef, 0xff,
+        0xd3, 0x00, 0x00, 0x00, 0x03, 0x3b, 0xe3, 0x00, 0x00, 0x00, 0x00, 0x01, 0xce, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x10, 0x4a, 0xaa, 0xa8, 0x30, 0x00, 0x6f, 0xa9, 0xae, 0xf6, 0x00,
+        0x6f, 0x20, 0x01, 0xde, 0x00, 0x6f, 0x20, 0x00, 0x9f, 0x10, 0x6f, 0x20, 0x00, 0xbf, 0x00,
+        0x6f, 0x53, 0x48, 0xf9, 0x00, 0x6f, 0xff, 0xff, 0x90, 0x00, 0x6f, 0x31, 0x29, 0xf4, 0x00,
+        0x6f, 0x20, 0x00, 0xcd, 0x00, 0x6f, 0x20, 0x00, 0x4f, 0x60, 0x6f, 0x20, 0x00, 0x0c, 0xd0,
+        0x6f, 0x20, 0x00, 0x04, 0xf6, 0x03, 0xac, 0xdc, 0x93, 0x04, 0xfc, 0x76, 0x8c, 0x80, 0xcd,
+        0x00, 0x00, 0x01, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0xce, 0x40, 0x00, 0x00, 0x03, 0xef, 0xeb,
+        0x72, 0x00, 0x01, 0x6a, 0xdf, 0xf6, 0x00, 0x00, 0x00, 0x3d, 0xe1, 0x00, 0x00, 0x00, 0x7f,
+        0x40, 0x00, 0x00, 0x07, 0xf3, 0xc8, 0x31, 0x26, 0xec, 0x09, 0xef, 0xff, 0xfb, 0x20, 0x00,
+        0x23, 0x31, 0x00, 0x00, 0x0a, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0xaa, 0xaa, 0xfd, 0xaa, 0xa8,
+        0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0,
+        0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00,
+        0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00,
+        0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1,
+        0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00,
+        0x79, 0x00, 0x00, 0x00, 0x09, 0x76, 0xf3, 0x00, 0x00, 0x04, 0xf6, 0x1f, 0x90, 0x00, 0x00,
+        0xae, 0x10, 0xae, 0x00, 0x00, 0x1e, 0x90, 0x04, 0xf5, 0x00, 0x06, 0xf3, 0x00, 0x0d, 0xb0,
+        0x00, 0xcd, 0x00, 0x00, 0x8f, 0x20, 0x2f, 0x70, 0x00, 0x02, 0xf7, 0x08, 0xf1, 0x00, 0x00,
+        0x0b, 0xd0, 0xdb, 0x00, 0x00, 0x00, 0x6f, 0x8f, 0x50, 0x00, 0x00, 0x01, 0xef, 0xe0, 0x00,
+        0x00, 0x00, 0x09, 0xf8, 0x00, 0x00, 0x4a, 0x20, 0x00, 0x1a, 0x90, 0x00, 0x04, 0xa2, 0x3f,
+        0x60, 0x00, 0x4f, 0xf1, 0x00, 0x09, 0xf0, 0x0e, 0xa0, 0x00, 0x8c, 0xe5, 0x00, 0x0d, 0xb0,
+        0x0a, 0xe0, 0x00, 0xb8, 0xa9, 0x00, 0x1f, 0x80, 0x06, 0xf2, 0x01, 0xf4, 0x7d, 0x00, 0x5f,
+        0x40, 0x03, 0xf6, 0x04, 0xf1, 0x3f, 0x10, 0x9f, 0x00, 0x00, 0xea, 0x08, 0xc0, 0x0e, 0x50,
+        0xdb, 0x00, 0x00, 0xae, 0x0b, 0x80, 0x0b, 0x91, 0xf7, 0x00, 0x00, 0x6f, 0x3f, 0x40, 0x07,
+        0xc5, 0xf4, 0x00, 0x00, 0x3f, 0xaf, 0x10, 0x03, 0xfa, 0xf0, 0x00, 0x00, 0x0e, 0xfc, 0x00,
+        0x00, 0xef, 0xb0, 0x00, 0x00, 0x0a, 0xf8, 0x00, 0x00, 0xbf, 0x70, 0x00, 0x08, 0x90, 0x00,
+        0x00, 0x79, 0x00, 0x3f, 0x70, 0x00, 0x4f, 0x60, 0x00, 0x8f, 0x30, 0x1d, 0xb0, 0x00, 0x00,
+        0xdc, 0x09, 0xe2, 0x00, 0x00, 0x03, 0xfb, 0xf6, 0x00, 0x00, 0x00, 0x09, 0xfb, 0x00, 0x00,
+        0x00, 0x00, 0xcf, 0xc0, 0x00, 0x00, 0x00, 0x8f, 0x6f, 0x70, 0x00, 0x00, 0x3f, 0x80, 0x8f,
+        0x30, 0x00, 0x0c, 0xc0, 0x01, 0xdc, 0x00, 0x08, 0xf3, 0x00, 0x04, 0xf7, 0x03, 0xf8, 0x00,
+        0x00, 0x08, 0xf2, 0x08, 0x90, 0x00, 0x00, 0x1a, 0x60, 0x4f, 0x70, 0x00, 0x0a, 0xe1, 0x00,
+        0x9f, 0x20, 0x05, 0xf5, 0x00, 0x01, 0xdc, 0x01, 0xea, 0x00, 0x00, 0x03, 0xf7, 0xae, 0x10,
+        0x00, 0x00, 0x08, 0xff, 0x50, 0x00, 0x00, 0x00, 0x0e, 0xb0, 0x00, 0x00, 0x00, 0x00, 0xea,
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00,
+        0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x1a, 0xaa, 0xaa, 0xaa, 0xaa, 0x11,
+        0xaa, 0xaa, 0xaa, 0xaf, 0xe1, 0x00, 0x00, 0x00, 0x09, 0xf4, 0x00, 0x00, 0x00, 0x06, 0xf7,
+        0x00, 0x00, 0x00, 0x03, 0xfa, 0x00, 0x00, 0x00, 0x02, 0xec, 0x10, 0x00, 0x00, 0x00, 0xce,
+        0x20, 0x00, 0x00, 0x00, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x6f, 0x70, 0x00, 0x00, 0x00, 0x4f,
+        0x90, 0x00, 0x00, 0x00, 0x2e, 0xe5, 0x55, 0x55, 0x55, 0x14, 0xff, 0xff, 0xff, 0xff, 0xf4,
+        0x12, 0x22, 0x9f, 0xea, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c,
+        0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9c, 0x00, 0x9f, 0xfa,
+        0x12, 0x21, 0x94, 0x00, 0x00, 0xaa, 0x00, 0x00, 0x5e, 0x10, 0x00, 0x1f, 0x50, 0x00, 0x0b,
+        0x90, 0x00, 0x06, 0xe0, 0x00, 0x01, 0xf3, 0x00, 0x00, 0xc8, 0x00, 0x00, 0x7d, 0x00, 0x00,
+        0x3f, 0x20, 0x00, 0x0d, 0x70, 0x00, 0x08, 0xc0, 0x00, 0x04, 0xf1, 0x00, 0x00, 0x72, 0x12,
+        0x22, 0x6e, 0xfd, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d,
+        0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x00, 0x9d, 0x7f, 0xfd, 0x12,
+        0x21, 0x00, 0x00, 0x4a, 0x80, 0x00, 0x00, 0x00, 0x4e, 0xdf, 0x90, 0x00, 0x00, 0x3e, 0xa0,
+        0x5f, 0x90, 0x00, 0x3e, 0x90, 0x00, 0x4e, 0x80, 0x19, 0x70, 0x00, 0x00, 0x3a, 0x40, 0x15,
+        0x55, 0x55, 0x55, 0x51, 0x2c, 0xcc, 0xcc, 0xcc, 0xc2, 0x4c, 0x20, 0x00, 0x8c, 0x00, 0x00,
+        0xa9, 0x00, 0x00, 0x20, 0x02, 0xad, 0xed, 0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00,
+        0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02,
+        0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50,
+        0x00, 0x33, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x8d, 0x00,
+        0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x8d, 0x3b, 0xed, 0x60, 0x08, 0xed, 0x64, 0x9f,
+        0x60, 0x8f, 0x50, 0x00, 0xae, 0x08, 0xf0, 0x00, 0x05, 0xf3, 0x8e, 0x00, 0x00, 0x3f, 0x48,
+        0xe0, 0x00, 0x04, 0xf3, 0x8f, 0x40, 0x00, 0x8e, 0x18, 0xfc, 0x20, 0x5f, 0x90, 0x8d, 0x6f,
+        0xff, 0xa0, 0x00, 0x00, 0x13, 0x20, 0x00, 0x00, 0x2a, 0xee, 0xc7, 0x03, 0xec, 0x64, 0x69,
+        0x0b, 0xd1, 0x00, 0x00, 0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60, 0x00, 0x00, 0x1f, 0x70, 0x00,
+        0x00, 0x0c, 0xc0, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x26, 0x00, 0x6e, 0xff, 0xfa, 0x00, 0x00,
+        0x33, 0x10, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0xbb, 0x00, 0x00, 0x00, 0x0b,
+        0xb0, 0x00, 0x00, 0x00, 0xbb, 0x00, 0x4c, 0xec, 0x5b, 0xb0, 0x4f, 0xa4, 0x6d, 0xeb, 0x0b,
+        0xc0, 0x00, 0x3f, 0xb0, 0xf7, 0x00, 0x00, 0xdb, 0x1f, 0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00,
+        0x00, 0xcb, 0x0d, 0xb0, 0x00, 0x1f, 0xb0, 0x6f, 0x70, 0x2b, 0xfb, 0x00, 0x8f, 0xff, 0x8b,
+        0xb0, 0x00, 0x13, 0x10, 0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3, 0x0b,
+        0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7, 0x00,
+        0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff, 0xfe,
+        0x70, 0x00, 0x02, 0x32, 0x00, 0x00, 0x01, 0x22, 0x00, 0x7f, 0xfe, 0x02, 0xf7, 0x11, 0x04,
+        0xf3, 0x00, 0x7c, 0xfc, 0xb6, 0x38, 0xf7, 0x53, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04,
+        0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x00,
+        0x5c, 0xec, 0x58, 0x80, 0x4f, 0xa4, 0x5d, 0xeb, 0x0c, 0xc0, 0x00, 0x3f, 0xb1, 0xf7, 0x00,
+        0x00, 0xdb, 0x1f, 0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00, 0xcb, 0x0c, 0xc0, 0x00, 0x2f,
+        0xb0, 0x5f, 0x94, 0x5d, 0xeb, 0x00, 0x5d, 0xfd, 0x5b, 0xa0, 0x00, 0x00, 0x00, 0xe8, 0x00,
+        0x40, 0x01, 0x9f, 0x30, 0x1f, 0xed, 0xfe, 0x60, 0x00, 0x14, 0x54, 0x10, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x8d,
+        0x2b, 0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5, 0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00, 0xac,
+        0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00,
+        0xac, 0x8d, 0x00, 0x00, 0xac, 0x12, 0x7e, 0x59, 0x00, 0x6b, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e,
+        0x7e, 0x7e, 0x7e, 0x00, 0x12, 0x00, 0x7e, 0x00, 0x59, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x7e,
+        0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00,
+        0x8e, 0x00, 0xbc, 0x4e, 0xf5, 0x15, 0x20, 0x12, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00,
+        0x00, 0x8d, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x03, 0xb7, 0x08,
+        0xd0, 0x04, 0xe9, 0x00, 0x8d, 0x06, 0xf7, 0x00, 0x08, 0xe8, 0xf5, 0x00, 0x00, 0x8f, 0xfa,
+        0x00, 0x00, 0x08, 0xd5, 0xf9, 0x00, 0x00, 0x8d, 0x04, 0xf9, 0x00, 0x08, 0xd0, 0x04, 0xea,
+        0x00, 0x8d, 0x00, 0x03, 0xea, 0x00, 0x12, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e,
+        0x7e, 0x7e, 0x7e, 0x7e, 0x6a, 0x3b, 0xed, 0x50, 0x6d, 0xeb, 0x20, 0x8e, 0xd7, 0x5b, 0xf8,
+        0xc5, 0x6e, 0xc0, 0x8f, 0x40, 0x01, 0xfd, 0x10, 0x06, 0xf2, 0x8e, 0x00, 0x00, 0xd9, 0x00,
+        0x03, 0xf3, 0x8d, 0x00, 0x00, 0xd8, 0x00, 0x03, 0xf3, 0x8d, 0x00, 0x00, 0xd8, 0x00, 0x03,
+        0xf3, 0x8d, 0x00, 0x00, 0xd8, 0x00, 0x03, 0xf3, 0x8d, 0x00, 0x00, 0xd8, 0x00, 0x03, 0xf3,
+        0x8d, 0x00, 0x00, 0xd8, 0x00, 0x03, 0xf3, 0x6a, 0x2b, 0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5,
+        0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00,
+        0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x3b,
+        0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00,
+        0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0,
+        0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00, 0x00, 0x13, 0x30, 0x00, 0x6a, 0x3b,
+        0xed, 0x60, 0x08, 0xed, 0x64, 0x9f, 0x60, 0x8f, 0x50, 0x00, 0xae, 0x08, 0xf0, 0x00, 0x05,
+        0xf3, 0x8e, 0x00, 0x00, 0x3f, 0x48, 0xe0, 0x00, 0x04, 0xf3, 0x8f, 0x40, 0x00, 0x8e, 0x18,
+        0xfc, 0x20, 0x5f, 0x90, 0x8d, 0x6f, 0xff, 0xa0, 0x08, 0xd0, 0x13, 0x20, 0x00, 0x8d, 0x00,
+        0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4c, 0xec,
+        0x58, 0x80, 0x4f, 0xa4, 0x6d, 0xeb, 0x0b, 0xc0, 0x00, 0x3f, 0xb0, 0xf7, 0x00, 0x00, 0xdb,
+        0x1f, 0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00, 0xcb, 0x0d, 0xb0, 0x00, 0x1f, 0xb0, 0x6f,
+        0x70, 0x2b, 0xfb, 0x00, 0x8f, 0xff, 0x8b, 0xb0, 0x00, 0x13, 0x10, 0xbb, 0x00, 0x00, 0x00,
+        0x0b, 0xb0, 0x00, 0x00, 0x00, 0xbb, 0x00, 0x00, 0x00, 0x04, 0x30, 0x6a, 0x3b, 0xe8, 0x8e,
+        0xd7, 0x54, 0x8f, 0x50, 0x00, 0x8f, 0x00, 0x00, 0x8e, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x02, 0xad, 0xed, 0xa1, 0x0c, 0xc5, 0x45,
+        0x91, 0x1f, 0x50, 0x00, 0x00, 0x0d, 0xc5, 0x10, 0x00, 0x03, 0xcf, 0xfc, 0x50, 0x00, 0x01,
+        0x5b, 0xf4, 0x00, 0x00, 0x00, 0xf8, 0x28, 0x30, 0x05, 0xf5, 0x1d, 0xff, 0xff, 0x80, 0x00,
+        0x13, 0x31, 0x00, 0x02, 0x30, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00, 0x6d, 0xfb, 0xba,
+        0x3a, 0xe5, 0x55, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00,
+        0x07, 0xe0, 0x00, 0x05, 0xf6, 0x33, 0x00, 0x9e, 0xfd, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00,
+        0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac,
+        0x00, 0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba,
+        0x00, 0x13, 0x10, 0x00, 0x4b, 0x20, 0x00, 0x08, 0xa1, 0xf7, 0x00, 0x01, 0xe8, 0x0a, 0xd0,
+        0x00, 0x6f, 0x20, 0x5f, 0x30, 0x0b, 0xc0, 0x00, 0xe9, 0x02, 0xf6, 0x00, 0x08, 0xe0, 0x7e,
+        0x10, 0x00, 0x3f, 0x5d, 0xa0, 0x00, 0x00, 0xcd, 0xf4, 0x00, 0x00, 0x06, 0xfd, 0x00, 0x00,
+        0x3b, 0x20, 0x05, 0xb5, 0x00, 0x1b, 0x40, 0xf7, 0x00, 0x9f, 0xb0, 0x05, 0xf1, 0x0b, 0xb0,
+        0x0d, 0xae, 0x00, 0x9c, 0x00, 0x7e, 0x02, 0xf2, 0xf4, 0x0d, 0x80, 0x03, 0xf3, 0x6d, 0x0b,
+        0x82, 0xf5, 0x00, 0x0e, 0x7a, 0x90, 0x7c, 0x6f, 0x10, 0x00, 0xab, 0xe5, 0x03, 0xfb, 0xc0,
+        0x00, 0x06, 0xff, 0x10, 0x0e, 0xf8, 0x00, 0x00, 0x3f, 0xc0, 0x00, 0xbf, 0x40, 0x00, 0x1a,
+        0x80, 0x00, 0x2b, 0x60, 0x5f, 0x60, 0x0c, 0xc0, 0x00, 0x9e, 0x29, 0xe2, 0x00, 0x00, 0xce,
+        0xf5, 0x00, 0x00, 0x05, 0xfc, 0x00, 0x00, 0x01, 0xdd, 0xf5, 0x00, 0x00, 0xae, 0x19, 0xe2,
+        0x00, 0x6f, 0x40, 0x1c, 0xc0, 0x3f, 0x80, 0x00, 0x2e, 0x90, 0x4b, 0x20, 0x00, 0x08, 0x91,
+        0xe7, 0x00, 0x01, 0xe7, 0x09, 0xd0, 0x00, 0x6f, 0x20, 0x3f, 0x40, 0x0c, 0xa0, 0x00, 0xca,
+        0x03, 0xf4, 0x00, 0x05, 0xf2, 0x9d, 0x00, 0x00, 0x0e, 0x8e, 0x70, 0x00, 0x00, 0x8f, 0xf1,
+        0x00, 0x00, 0x02, 0xfa, 0x00, 0x00, 0x00, 0x4f, 0x40, 0x00, 0x00, 0x0b, 0xd0, 0x00, 0x00,
+        0xae, 0xf4, 0x00, 0x00, 0x04, 0x52, 0x00, 0x00, 0x00, 0x1b, 0xbb, 0xbb, 0xb8, 0x16, 0x66,
+        0x68, 0xf9, 0x00, 0x00, 0x1c, 0xd1, 0x00, 0x00, 0xbe, 0x20, 0x00, 0x09, 0xf3, 0x00, 0x00,
+        0x6f, 0x50, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x2e, 0xb2, 0x22, 0x22, 0x5f, 0xff, 0xff, 0xfb,
+        0x00, 0x00, 0x12, 0x00, 0x01, 0xcf, 0xf3, 0x00, 0x7f, 0x30, 0x00, 0x08, 0xd0, 0x00, 0x00,
+        0x9d, 0x00, 0x00, 0x09, 0xd0, 0x00, 0x00, 0xac, 0x00, 0x05, 0x8f, 0x70, 0x00, 0xce, 0xd2,
+        0x00, 0x00, 0x0d, 0xb0, 0x00, 0x00, 0x9d, 0x00, 0x00, 0x09, 0xd0, 0x00, 0x00, 0x9d, 0x00,
+        0x00, 0x08, 0xe0, 0x00, 0x00, 0x4f, 0xb8, 0x10, 0x00, 0x48, 0x92, 0x31, 0xf5, 0xf5, 0xf5,
+        0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xb4, 0x21, 0x00,
+        0x00, 0x0f, 0xfd, 0x30, 0x00, 0x01, 0xd9, 0x00, 0x00, 0x0b, 0xb0, 0x00, 0x00, 0xab, 0x00,
+        0x00, 0x0a, 0xb0, 0x00, 0x00, 0x9d, 0x00, 0x00, 0x04, 0xf9, 0x51, 0x00, 0x1b, 0xec, 0x20,
+        0x08, 0xe1, 0x00, 0x00, 0xac, 0x00, 0x00, 0x0a, 0xb0, 0x00, 0x00, 0xab, 0x00, 0x00, 0x0c,
+        0xa0, 0x00, 0x8a, 0xf6, 0x00, 0x09, 0x85, 0x00, 0x00, 0x00, 0x13, 0x10, 0x00, 0x00, 0x11,
+        0x8e, 0xff, 0xc6, 0x33, 0x9a, 0x5b, 0x42, 0x49, 0xef, 0xfb, 0x31, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x7b, 0x9f, 0x23, 0x00, 0x7d, 0x8e, 0x9f, 0x9f, 0x9f, 0x9f, 0x9f, 0x8e, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0xb1, 0x00, 0x00, 0x00, 0xb1, 0x00, 0x00, 0x6c, 0xfe, 0xa2, 0x0a,
+        0xf8, 0xc6, 0x94, 0x4f, 0x60, 0xb1, 0x00, 0x8f, 0x00, 0xb1, 0x00, 0xad, 0x00, 0xb1, 0x00,
+        0x9e, 0x00, 0xb1, 0x00, 0x5f, 0x40, 0xb1, 0x00, 0x0c, 0xd4, 0xb2, 0x52, 0x01, 0xaf, 0xff,
+        0xe3, 0x00, 0x01, 0xb4, 0x00, 0x00, 0x00, 0xb1, 0x00, 0x00, 0x00, 0x51, 0x00, 0x00, 0x07,
+        0xcd, 0xb5, 0x00, 0x9f, 0x86, 0x88, 0x00, 0xea, 0x00, 0x00, 0x02, 0xf7, 0x00, 0x00, 0x02,
+        0xf6, 0x00, 0x00, 0x13, 0xf7, 0x11, 0x10, 0xbf, 0xff, 0xff, 0x70, 0x03, 0xf7, 0x11, 0x00,
+        0x02, 0xf6, 0x00, 0x00, 0x02, 0xf6, 0x00, 0x00, 0x56, 0xf9, 0x55, 0x54, 0xff, 0xff, 0xff,
+        0xfb, 0x02, 0x00, 0x00, 0x00, 0x20, 0x1e, 0x80, 0x44, 0x15, 0xf3, 0x05, 0xfe, 0xfe, 0xef,
+        0x70, 0x00, 0xda, 0x10, 0x7f, 0x10, 0x03, 0xf1, 0x00, 0x0d, 0x60, 0x02, 0xf1, 0x00, 0x0d,
+        0x50, 0x00, 0xda, 0x10, 0x7e, 0x10, 0x05, 0xfe, 0xfe, 0xef, 0x70, 0x1e, 0x70, 0x44, 0x14,
+        0xf3, 0x02, 0x00, 0x00, 0x00, 0x20, 0x2a, 0x30, 0x00, 0x01, 0xa3, 0x0b, 0xb0, 0x00, 0x09,
+        0xd0, 0x03, 0xf4, 0x00, 0x2f, 0x50, 0x00, 0xac, 0x00, 0xac, 0x00, 0x01, 0x3f, 0x63, 0xf5,
+        0x10, 0x0c, 0xde, 0xdb, 0xed, 0xd1, 0x00, 0x01, 0xef, 0x30, 0x00, 0x07, 0x88, 0xde, 0x88,
+        0x81, 0x06, 0x77, 0xce, 0x77, 0x71, 0x00, 0x00, 0xad, 0x00, 0x00, 0x00, 0x00, 0xad, 0x00,
+        0x00, 0x00, 0x00, 0xad, 0x00, 0x00, 0x31, 0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xb4, 0x00, 0x31,
+        0xf5, 0xf5, 0xf5, 0xf5, 0xf5, 0xb4, 0x00, 0x8c, 0xdb, 0x40, 0x08, 0xe6, 0x47, 0x50, 0x0b,
+        0xb0, 0x00, 0x00, 0x06, 0xf7, 0x00, 0x00, 0x05, 0xee, 0xc4, 0x00, 0x2f, 0x31, 0x9f, 0x70,
+        0x4f, 0x10, 0x06, 0xf2, 0x1d, 0xc2, 0x01, 0xf3, 0x02, 0xbe, 0x79, 0xb0, 0x00, 0x05, 0xef,
+        0x20, 0x00, 0x00, 0x2e, 0x90, 0x01, 0x00, 0x0c, 0xa0, 0x0a, 0xc9, 0xcf, 0x40, 0x02, 0x68,
+        0x72, 0x00, 0x12, 0x00, 0x21, 0x5f, 0x44, 0xf5, 0x27, 0x22, 0x72, 0x00, 0x01, 0x69, 0x96,
+        0x10, 0x00, 0x00, 0x6c, 0x74, 0x47, 0xc6, 0x00, 0x06, 0xa0, 0x14, 0x42, 0x0a, 0x60, 0x2c,
+        0x05, 0xeb, 0xac, 0x50, 0xc2, 0x85, 0x2f, 0x50, 0x00, 0x00, 0x58, 0xb1, 0x6e, 0x00, 0x00,
+        0x00, 0x1b, 0xc0, 0x6d, 0x00, 0x00, 0x00, 0x0c, 0xa3, 0x4f, 0x20, 0x00, 0x00, 0x3a, 0x59,
+        0x0a, 0xc5, 0x36, 0x30, 0x95, 0x0b, 0x40, 0x6a, 0xb9, 0x24, 0xb0, 0x01, 0xb7, 0x10, 0x01,
+        0x7b, 0x10, 0x00, 0x06, 0xcc, 0xcc, 0x60, 0x00, 0x05, 0xbd, 0xc5, 0x00, 0x42, 0x14, 0xe3,
+        0x00, 0x58, 0x8d, 0x60, 0xbb, 0x66, 0xc7, 0x1f, 0x10, 0x0d, 0x70, 0xda, 0x6b, 0xe7, 0x01,
+        0x77, 0x34, 0x30, 0x99, 0x99, 0x94, 0x05, 0x55, 0x55, 0x20, 0x00, 0x01, 0x00, 0x01, 0x00,
+        0x2b, 0x00, 0x94, 0x03, 0xe8, 0x0a, 0xd1, 0x4e, 0x71, 0xbc, 0x10, 0xac, 0x14, 0xf4, 0x00,
+        0x1b, 0xc1, 0x6e, 0x50, 0x00, 0xac, 0x05, 0xe3, 0x00, 0x07, 0x00, 0x33, 0x3b, 0xbb, 0xbb,
+        0xbb, 0xbb, 0x83, 0x99, 0x99, 0x99, 0x99, 0xdb, 0x00, 0x00, 0x00, 0x00, 0x09, 0xb0, 0x00,
+        0x00, 0x00, 0x00, 0x9b, 0x00, 0x00, 0x00, 0x00, 0x07, 0x80, 0x00, 0x00, 0x03, 0xff, 0xff,
+        0x14, 0x44, 0x40, 0x00, 0x01, 0x69, 0x96, 0x10, 0x00, 0x00, 0x6c, 0x74, 0x47, 0xc6, 0x00,
+        0x06, 0xa1, 0x33, 0x20, 0x0a, 0x60, 0x2c, 0x06, 0xea, 0xbd, 0x20, 0xc2, 0x85, 0x06, 0xc0,
+        0x0b, 0x80, 0x58, 0xb1, 0x06, 0xd3, 0x5e, 0x50, 0x1b, 0xc0, 0x06, 0xe9, 0xe7, 0x00, 0x0c,
+        0xa3, 0x06, 0xc0, 0x5e, 0x10, 0x3a, 0x59, 0x06, 0xc0, 0x0b, 0x90, 0x95, 0x0b, 0x42, 0x40,
+        0x02, 0x55, 0xb0, 0x01, 0xb7, 0x10, 0x01, 0x7b, 0x10, 0x00, 0x06, 0xcc, 0xcc, 0x60, 0x00,
+        0x5e, 0xee, 0xe5, 0x13, 0x33, 0x31, 0x05, 0xcc, 0x50, 0x3e, 0x44, 0xe3, 0x78, 0x00, 0x87,
+        0x5c, 0x11, 0xc4, 0x09, 0xee, 0x80, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x50, 0x00, 0x00, 0x00, 0x00, 0xe5, 0x00,
+        0x00, 0x5f, 0xff, 0xff, 0xff, 0xff, 0xb1, 0x55, 0x55, 0xf8, 0x55, 0x53, 0x00, 0x00, 0x0e,
+        0x50, 0x00, 0x00, 0x00, 0x00, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x05, 0x20, 0x00, 0x01, 0x55,
+        0x55, 0x55, 0x55, 0x53, 0x5f, 0xff, 0xff, 0xff, 0xff, 0xb0, 0x2a, 0xdc, 0x70, 0x23, 0x03,
+        0xe4, 0x00, 0x01, 0xe3, 0x00, 0x0a, 0x90, 0x00, 0xaa, 0x00, 0x1b, 0xa2, 0x21, 0x3c, 0xcc,
+        0xc5, 0x0a, 0xcd, 0x80, 0x03, 0x02, 0xd5, 0x00, 0x24, 0xd3, 0x00, 0xad, 0xb1, 0x00, 0x00,
+        0xb8, 0x12, 0x02, 0xc7, 0x2b, 0xdd, 0x80, 0x00, 0x3c, 0x40, 0x1d, 0x80, 0x09, 0xa0, 0x00,
+        0x20, 0x00, 0x79, 0x00, 0x00, 0x88, 0x0a, 0xc0, 0x00, 0x0b, 0xa0, 0xac, 0x00, 0x00, 0xba,
+        0x0a, 0xc0, 0x00, 0x0b, 0xa0, 0xac, 0x00, 0x00, 0xba, 0x0a, 0xc0, 0x00, 0x0b, 0xa0, 0xae,
+        0x00, 0x00, 0xea, 0x0a, 0xf8, 0x11, 0x9f, 0xc2, 0xac, 0xbf, 0xfc, 0x8f, 0xca, 0xc0, 0x23,
+        0x00, 0x31, 0xac, 0x00, 0x00, 0x00, 0x0a, 0xc0, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x79, 0xaa, 0xa4, 0x1d, 0xff, 0xe4, 0xb7, 0x8f, 0xff, 0xd0, 0xa7, 0xbf, 0xff,
+        0xd0, 0xa7, 0xaf, 0xff, 0xd0, 0xa7, 0x3f, 0xff, 0xd0, 0xa7, 0x03, 0xae, 0xd0, 0xa7, 0x00,
+        0x04, 0xd0, 0xa7, 0x00, 0x04, 0xd0, 0xa7, 0x00, 0x04, 0xd0, 0xa7, 0x00, 0x04, 0xd0, 0xa7,
+        0x00, 0x04, 0xd0, 0xa7, 0x00, 0x04, 0xd0, 0xa7, 0x00, 0x02, 0x70, 0x54, 0x28, 0x34, 0xf5,
+        0x27, 0x20, 0x01, 0xc2, 0x00, 0xa7, 0xae, 0xe3, 0x01, 0x00, 0x7a, 0xa0, 0x05, 0x6e, 0x00,
+        0x03, 0xe0, 0x00, 0x3e, 0x00, 0x03, 0xe0, 0x01, 0x4e, 0x11, 0x9c, 0xcc, 0x60, 0x01, 0x9d,
+        0xc6, 0x00, 0xca, 0x13, 0xe5, 0x2f, 0x10, 0x08, 0xa3, 0xf0, 0x00, 0x7b, 0x1f, 0x30, 0x0a,
+        0x90, 0x8d, 0x68, 0xe2, 0x00, 0x48, 0x72, 0x00, 0x99, 0x99, 0x94, 0x05, 0x55, 0x55, 0x20,
+        0x10, 0x00, 0x00, 0x00, 0x76, 0x01, 0xb1, 0x00, 0x3e, 0x70, 0xbc, 0x10, 0x03, 0xd9, 0x1a,
+        0xd2, 0x00, 0x7f, 0x22, 0xe7, 0x07, 0xe4, 0x3d, 0x90, 0x6d, 0x31, 0xe7, 0x00, 0x52, 0x01,
+        0x50, 0x00, 0x7a, 0xa0, 0x00, 0x00, 0x07, 0xa0, 0x00, 0x56, 0xe0, 0x00, 0x00, 0x2e, 0x30,
+        0x00, 0x03, 0xe0, 0x00, 0x00, 0xa9, 0x00, 0x00, 0x03, 0xe0, 0x00, 0x04, 0xe1, 0x00, 0x00,
+        0x03, 0xe0, 0x00, 0x0d, 0x60, 0x00, 0x00, 0x14, 0xe1, 0x10, 0x7c, 0x00, 0x04, 0x70, 0x9c,
+        0xcc, 0x62, 0xe3, 0x00, 0x2d, 0xe0, 0x00, 0x00, 0x0a, 0x90, 0x01, 0xb4, 0xe0, 0x00, 0x00,
+        0x4e, 0x10, 0x09, 0x43, 0xe0, 0x00, 0x00, 0xd6, 0x00, 0x4b, 0x57, 0xe5, 0x00, 0x07, 0xc0,
+        0x00, 0x38, 0x89, 0xf8, 0x00, 0x2e, 0x30, 0x00, 0x00, 0x03, 0xe0, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x7a, 0xa0, 0x00, 0x00, 0x07, 0xa0, 0x00, 0x56, 0xe0, 0x00, 0x00, 0x2e,
+        0x30, 0x00, 0x03, 0xe0, 0x00, 0x00, 0xa9, 0x00, 0x00, 0x03, 0xe0, 0x00, 0x04, 0xe1, 0x00,
+        0x00, 0x03, 0xe0, 0x00, 0x0d, 0x60, 0x00, 0x00, 0x14, 0xe1, 0x10, 0x7c, 0x16, 0x99, 0x40,
+        0x9c, 0xcc, 0x62, 0xe3, 0x17, 0x35, 0xe4, 0x00, 0x00, 0x0a, 0x90, 0x00, 0x00, 0xc6, 0x00,
+        0x00, 0x4e, 0x10, 0x00, 0x07, 0xc0, 0x00, 0x00, 0xd6, 0x00, 0x00, 0x6d, 0x20, 0x00, 0x07,
+        0xc0, 0x00, 0x07, 0xc1, 0x00, 0x00, 0x2e, 0x30, 0x00, 0x3f, 0xed, 0xd7, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0xcd, 0x80, 0x00, 0x00, 0x7a, 0x00, 0x00, 0x30, 0x2d, 0x50,
+        0x00, 0x2e, 0x30, 0x00, 0x00, 0x24, 0xd3, 0x00, 0x0a, 0x90, 0x00, 0x00, 0x0a, 0xdb, 0x10,
+        0x04, 0xe1, 0x00, 0x00, 0x00, 0x00, 0xb8, 0x00, 0xd6, 0x00, 0x00, 0x01, 0x20, 0x2c, 0x70,
+        0x7c, 0x00, 0x04, 0x70, 0x2b, 0xdd, 0x80, 0x2e, 0x30, 0x02, 0xde, 0x00, 0x00, 0x00, 0x0a,
+        0x90, 0x01, 0xb4, 0xe0, 0x00, 0x00, 0x04, 0xe1, 0x00, 0x94, 0x3e, 0x00, 0x00, 0x00, 0xd6,
+        0x00, 0x4b, 0x57, 0xe5, 0x00, 0x00, 0x7c, 0x00, 0x03, 0x88, 0x9f, 0x80, 0x00, 0x2e, 0x30,
+        0x00, 0x00, 0x03, 0xe0, 0x00, 0x01, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2b, 0x50,
+        0x00, 0x02, 0xf7, 0x00, 0x00, 0x03, 0x20, 0x00, 0x01, 0x94, 0x00, 0x00, 0x1f, 0x60, 0x00,
+        0x04, 0xf4, 0x00, 0x02, 0xeb, 0x00, 0x02, 0xec, 0x10, 0x00, 0xae, 0x10, 0x00, 0x0d, 0xb0,
+        0x00, 0x01, 0x9f, 0x62, 0x5b, 0x51, 0xbf, 0xff, 0xa2, 0x00, 0x12, 0x00, 0x00, 0x00, 0x02,
+        0xc4, 0x00, 0x00, 0x00, 0x00, 0x04, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00,
+        0x00, 0x05, 0xa5, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f, 0x30,
+        0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00, 0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00, 0x6f, 0x20,
+        0x3f, 0x50, 0x00, 0x0c, 0xc0, 0x00, 0xcb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x8f,
+        0xff, 0xff, 0xff, 0x70, 0x0e, 0xa0, 0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05, 0xf4,
+        0xae, 0x00, 0x00, 0x00, 0x1e, 0x90, 0x00, 0x00, 0x05, 0xb1, 0x00, 0x00, 0x00, 0x02, 0xe3,
+        0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x05, 0xa5, 0x00, 0x00, 0x00, 0x00,
+        0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f, 0x30, 0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00,
+        0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00, 0x6f, 0x20, 0x3f, 0x50, 0x00, 0x0c, 0xc0, 0x00, 0xcb,
+        0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x8f, 0xff, 0xff, 0xff, 0x70, 0x0e, 0xa0, 0x00,
+        0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05, 0xf4, 0xae, 0x00, 0x00, 0x00, 0x1e, 0x90, 0x00,
+        0x00, 0x8c, 0x70, 0x00, 0x00, 0x00, 0x5c, 0x2c, 0x40, 0x00, 0x00, 0x03, 0x10, 0x13, 0x00,
+        0x00, 0x00, 0x05, 0xa5, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f,
+        0x30, 0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00, 0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00, 0x6f,
+        0x20, 0x3f, 0x50, 0x00, 0x0c, 0xc0, 0x00, 0xcb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00,
+        0x8f, 0xff, 0xff, 0xff, 0x70, 0x0e, 0xa0, 0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05,
+        0xf4, 0xae, 0x00, 0x00, 0x00, 0x1e, 0x90, 0x00, 0x06, 0xb6, 0x1b, 0x00, 0x00, 0x01, 0xe4,
+        0xae, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xa5, 0x00, 0x00, 0x00,
+        0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f, 0x30, 0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00,
+        0x00, 0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00, 0x6f, 0x20, 0x3f, 0x50, 0x00, 0x0c, 0xc0, 0x00,
+        0xcb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x8f, 0xff, 0xff, 0xff, 0x70, 0x0e, 0xa0,
+        0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05, 0xf4, 0xae, 0x00, 0x00, 0x00, 0x1e, 0x90,
+        0x00, 0x08, 0x70, 0x77, 0x00, 0x00, 0x00, 0xcb, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0xa5, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f,
+        0x8f, 0x30, 0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00, 0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00,
+        0x6f, 0x20, 0x3f, 0x50, 0x00, 0x0c, 0xc0, 0x00, 0xcb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2,
+        0x00, 0x8f, 0xff, 0xff, 0xff, 0x70, 0x0e, 0xa0, 0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00,
+        0x05, 0xf4, 0xae, 0x00, 0x00, 0x00, 0x1e, 0x90, 0x00, 0x00, 0x9c, 0x80, 0x00, 0x00, 0x00,
+        0x7a, 0x2b, 0x70, 0x00, 0x00, 0x0a, 0x50, 0x69, 0x00, 0x00, 0x00, 0x4d, 0x9d, 0x30, 0x00,
+        0x00, 0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f, 0x30, 0x00, 0x00, 0x09, 0xd0, 0xe9,
+        0x00, 0x00, 0x01, 0xe8, 0x09, 0xe0, 0x00, 0x00, 0x6f, 0x20, 0x3f, 0x50, 0x00, 0x0c, 0xc0,
+        0x00, 0xdb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x8f, 0xff, 0xff, 0xff, 0x70, 0x0e,
+        0xa0, 0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05, 0xf4, 0xae, 0x00, 0x00, 0x00, 0x1e,
+        0x90, 0x00, 0x00, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x08, 0xf9, 0xfd, 0xaa, 0xaa,
+        0xa4, 0x00, 0x00, 0xea, 0x0e, 0x90, 0x00, 0x00, 0x00, 0x00, 0x6f, 0x30, 0xe9, 0x00, 0x00,
+        0x00, 0x00, 0x0c, 0xc0, 0x0e, 0x90, 0x00, 0x00, 0x00, 0x03, 0xf6, 0x00, 0xee, 0xdd, 0xdd,
+        0xd2, 0x00, 0xae, 0x00, 0x0e, 0xc7, 0x77, 0x77, 0x10, 0x1f, 0xb5, 0x55, 0xf9, 0x00, 0x00,
+        0x00, 0x07, 0xff, 0xff, 0xff, 0x90, 0x00, 0x00, 0x00, 0xdb, 0x00, 0x00, 0xe9, 0x00, 0x00,
+        0x00, 0x5f, 0x40, 0x00, 0x0e, 0xb5, 0x55, 0x55, 0x3b, 0xd0, 0x00, 0x00, 0xef, 0xff, 0xff,
+        0xf8, 0x00, 0x02, 0x8c, 0xdc, 0x93, 0x00, 0x05, 0xee, 0x87, 0x7b, 0xf4, 0x03, 0xfb, 0x10,
+        0x00, 0x04, 0x30, 0xaf, 0x20, 0x00, 0x00, 0x00, 0x0e, 0xb0, 0x00, 0x00, 0x00, 0x01, 0xf9,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x00, 0x00, 0xfa, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x5f, 0x70, 0x00, 0x00, 0x12, 0x00, 0xaf, 0x93, 0x12,
+        0x6d, 0x50, 0x00, 0x7e, 0xff, 0xfe, 0x81, 0x00, 0x00, 0x02, 0xc8, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0xc0, 0x00, 0x00, 0x00, 0x5e, 0xe8, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x4c, 0x20, 0x00, 0x00, 0x00, 0x8b, 0x00, 0x00, 0x00, 0x00, 0x31, 0x00, 0x04, 0xaa, 0xaa,
+        0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00,
+        0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f,
+        0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6, 0x55,
+        0x55, 0x50, 0x6f, 0xff, 0xff, 0xff, 0x10, 0x00, 0x00, 0x89, 0x00, 0x00, 0x00, 0x5d, 0x10,
+        0x00, 0x00, 0x03, 0x10, 0x00, 0x04, 0xaa, 0xaa, 0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06,
+        0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed,
+        0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6, 0x55, 0x55, 0x50, 0x6f, 0xff, 0xff, 0xff, 0x10,
+        0x00, 0x0a, 0xc4, 0x00, 0x00, 0x09, 0x93, 0xd2, 0x00, 0x00, 0x30, 0x02, 0x20, 0x04, 0xaa,
+        0xaa, 0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00,
+        0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50,
+        0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6,
+        0x55, 0x55, 0x50, 0x6f, 0xff, 0xff, 0xff, 0x10, 0x01, 0x95, 0x09, 0x50, 0x00, 0x1f, 0x70,
+        0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xaa, 0xaa, 0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9,
+        0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f,
+        0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6, 0x55, 0x55, 0x50, 0x6f, 0xff, 0xff, 0xff,
+        0x10, 0x2c, 0x30, 0x06, 0xd1, 0x00, 0x31, 0x04, 0xa1, 0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2,
+        0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2, 0x06, 0xf2, 0x06,
+        0xf2, 0x06, 0xb0, 0x3e, 0x20, 0x22, 0x00, 0x4a, 0x10, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20,
+        0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f,
+        0x20, 0x00, 0x9c, 0x60, 0x07, 0xb2, 0xd3, 0x03, 0x10, 0x12, 0x00, 0x4a, 0x10, 0x00, 0x6f,
+        0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f,
+        0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f,
+        0x20, 0x96, 0x08, 0x6e, 0x90, 0xda, 0x00, 0x00, 0x00, 0x4a, 0x10, 0x06, 0xf2, 0x00, 0x6f,
+        0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f,
+        0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x03, 0xaa, 0xaa, 0x86, 0x10, 0x00,
+        0x05, 0xfb, 0x9a, 0xbe, 0xf8, 0x00, 0x05, 0xf3, 0x00, 0x01, 0x9f, 0x80, 0x05, 0xf3, 0x00,
+        0x00, 0x0c, 0xe1, 0x05, 0xf3, 0x00, 0x00, 0x06, 0xf4, 0x8b, 0xfa, 0x98, 0x00, 0x04, 0xf6,
+        0x7a, 0xfa, 0x87, 0x00, 0x03, 0xf7, 0x05, 0xf3, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf3, 0x00,
+        0x00, 0x09, 0xf2, 0x05, 0xf3, 0x00, 0x00, 0x4f, 0xb0, 0x05, 0xf7, 0x45, 0x6a, 0xfc, 0x10,
+        0x05, 0xff, 0xff, 0xeb, 0x60, 0x00, 0x00, 0x1a, 0x92, 0x66, 0x00, 0x00, 0x89, 0x6d, 0xd2,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x90, 0x00, 0x01, 0xa4, 0x6f, 0xf5, 0x00, 0x02,
+        0xf6, 0x6f, 0xdc, 0x00, 0x02, 0xf6, 0x6f, 0x5f, 0x60, 0x02, 0xf6, 0x6f, 0x1b, 0xd0, 0x02,
+        0xf6, 0x6f, 0x13, 0xf6, 0x02, 0xf6, 0x6f, 0x10, 0xae, 0x12, 0xf6, 0x6f, 0x10, 0x2f, 0x72,
+        0xf6, 0x6f, 0x10, 0x09, 0xe3, 0xf6, 0x6f, 0x10, 0x01, 0xea, 0xf6, 0x6f, 0x10, 0x00, 0x8f,
+        0xf6, 0x6f, 0x10, 0x00, 0x1e, 0xf6, 0x00, 0x00, 0x3c, 0x20, 0x00, 0x00, 0x00, 0x00, 0x07,
+        0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00,
+        0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00,
+        0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa,
+        0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00,
+        0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40,
+        0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x7a, 0x00, 0x00, 0x00, 0x00, 0x04, 0xd2, 0x00, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00,
+        0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10,
+        0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8,
+        0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00,
+        0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1,
+        0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03,
+        0x32, 0x00, 0x00, 0x00, 0x00, 0x0a, 0xc5, 0x00, 0x00, 0x00, 0x00, 0x8a, 0x3d, 0x20, 0x00,
+        0x00, 0x00, 0x30, 0x02, 0x20, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8,
+        0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4,
+        0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00,
+        0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5,
+        0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00, 0x07, 0xef,
+        0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x00, 0x00, 0x8a, 0x52, 0x90, 0x00,
+        0x00, 0x03, 0xc4, 0xbe, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x9c,
+        0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0,
+        0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00,
+        0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9,
+        0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82,
+        0x14, 0xcf, 0x40, 0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00,
+        0x00, 0x00, 0x95, 0x09, 0x60, 0x00, 0x00, 0x00, 0xf8, 0x0e, 0x90, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10,
+        0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00,
+        0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa,
+        0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00,
+        0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00, 0x07, 0xef, 0xff, 0xc4, 0x00,
+        0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x40, 0x8e, 0x30, 0x00, 0x0b,
+        0xe1, 0x0a, 0xe3, 0x00, 0xbe, 0x30, 0x00, 0xbe, 0x4b, 0xe3, 0x00, 0x00, 0x0b, 0xfe, 0x30,
+        0x00, 0x00, 0x0b, 0xfe, 0x30, 0x00, 0x00, 0xbe, 0x3a, 0xe3, 0x00, 0x0b, 0xe3, 0x00, 0xae,
+        0x30, 0x8e, 0x30, 0x00, 0x0a, 0xe1, 0x13, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x70, 0x98, 0x00, 0x5f, 0xd8, 0x69, 0xfd, 0xd1, 0x03,
+        0xfb, 0x10, 0x00, 0x7f, 0xb0, 0x0a, 0xf2, 0x00, 0x03, 0xeb, 0xf3, 0x0e, 0xb0, 0x00, 0x1d,
+        0x62, 0xf8, 0x1f, 0x90, 0x00, 0xc9, 0x00, 0xfa, 0x1f, 0x90, 0x0a, 0xb0, 0x00, 0xfa, 0x0f,
+        0xa0, 0x8c, 0x10, 0x01, 0xf9, 0x0c, 0xe6, 0xe2, 0x00, 0x05, 0xf5, 0x05, 0xff, 0x30, 0x00,
+        0x1d, 0xe1, 0x02, 0xff, 0x92, 0x14, 0xcf, 0x40, 0x1d, 0x87, 0xef, 0xff, 0xc4, 0x00, 0x04,
+        0x00, 0x02, 0x32, 0x00, 0x00, 0x00, 0x09, 0x90, 0x00, 0x00, 0x00, 0x01, 0xc6, 0x00, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90,
+        0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x00, 0x1c, 0x50, 0x00,
+        0x00, 0x00, 0xa9, 0x00, 0x00, 0x00, 0x01, 0x30, 0x00, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1,
+        0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00,
+        0x00, 0x03, 0xcb, 0x10, 0x00, 0x00, 0x1d, 0x48, 0xa0, 0x00, 0x00, 0x12, 0x00, 0x30, 0x00,
+        0x6a, 0x00, 0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4,
+        0x5f, 0x50, 0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00,
+        0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x49, 0x14, 0x92, 0x00, 0x00, 0x7f, 0x26, 0xf3, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90,
+        0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x00, 0x01, 0xb5, 0x00,
+        0x00, 0x00, 0x00, 0xa9, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x89, 0x00, 0x00,
+        0x01, 0xa6, 0x04, 0xf7, 0x00, 0x00, 0xae, 0x10, 0x09, 0xf2, 0x00, 0x5f, 0x50, 0x00, 0x1d,
+        0xc0, 0x1e, 0xa0, 0x00, 0x00, 0x3f, 0x7a, 0xe1, 0x00, 0x00, 0x00, 0x8f, 0xf5, 0x00, 0x00,
+        0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00,
+        0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e,
+        0xa0, 0x00, 0x00, 0x4a, 0x10, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xa9, 0x97,
+        0x20, 0x06, 0xfb, 0xab, 0xef, 0x40, 0x6f, 0x20, 0x02, 0xed, 0x06, 0xf2, 0x00, 0x09, 0xf1,
+        0x6f, 0x20, 0x00, 0xaf, 0x16, 0xf2, 0x00, 0x4f, 0xc0, 0x6f, 0xee, 0xff, 0xd2, 0x06, 0xf7,
+        0x55, 0x30, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x02, 0x20,
+        0x00, 0x00, 0x5e, 0xff, 0xd4, 0x00, 0x3f, 0x91, 0x29, 0xe1, 0x07, 0xf0, 0x00, 0x3f, 0x40,
+        0x8e, 0x00, 0xae, 0x82, 0x08, 0xe0, 0x6f, 0x20, 0x00, 0x8e, 0x07, 0xe1, 0x00, 0x08, 0xe0,
+        0x3f, 0xc2, 0x00, 0x8e, 0x00, 0x3d, 0xf6, 0x08, 0xe0, 0x00, 0x09, 0xf2, 0x8e, 0x00, 0x00,
+        0x2f, 0x58, 0xe1, 0x30, 0x08, 0xf2, 0x8e, 0x3f, 0xff, 0xe6, 0x00, 0x00, 0x13, 0x31, 0x00,
+        0x00, 0x8a, 0x00, 0x00, 0x00, 0x01, 0xd7, 0x00, 0x00, 0x00, 0x02, 0xd4, 0x00, 0x00, 0x00,
+        0x01, 0x10, 0x00, 0x02, 0xad, 0xed, 0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00,
+        0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5,
+        0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00,
+        0x33, 0x00, 0x00, 0x00, 0x00, 0x07, 0xb1, 0x00, 0x00, 0x04, 0xe3, 0x00, 0x00, 0x01, 0xd5,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x02, 0xad, 0xed, 0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0,
+        0x00, 0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda,
+        0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd,
+        0x5f, 0x50, 0x00, 0x33, 0x00, 0x00, 0x00, 0x02, 0xc6, 0x00, 0x00, 0x00, 0xc9, 0xe2, 0x00,
+        0x00, 0x7b, 0x06, 0xb0, 0x00, 0x02, 0x10, 0x02, 0x00, 0x02, 0xad, 0xed, 0x91, 0x00, 0x59,
+        0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9,
+        0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5,
+        0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00, 0x33, 0x00, 0x00, 0x00, 0x16, 0x20, 0x51, 0x00, 0x0a,
+        0xbd, 0x5e, 0x10, 0x00, 0xb1, 0x3b, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xad, 0xed,
+        0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5,
+        0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc,
+        0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00, 0x33, 0x00, 0x00, 0x00, 0x12, 0x01,
+        0x20, 0x00, 0x0a, 0xe0, 0x9e, 0x00, 0x00, 0x56, 0x04, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0xad, 0xed, 0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00, 0x4f, 0x20, 0x02,
+        0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00,
+        0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00, 0x33, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xae, 0xd2, 0x00, 0x00, 0x6a, 0x06, 0xa0, 0x00, 0x07,
+        0x90, 0x4b, 0x00, 0x00, 0x1d, 0xce, 0x40, 0x00, 0x00, 0x03, 0x10, 0x00, 0x02, 0xad, 0xed,
+        0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5,
+        0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc,
+        0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00, 0x33, 0x00, 0x00, 0x02, 0xad, 0xed,
+        0x91, 0x3b, 0xee, 0xa2, 0x00, 0x59, 0x54, 0x6e, 0xdf, 0xa5, 0x5b, 0xe2, 0x00, 0x00, 0x00,
+        0x4f, 0xb0, 0x00, 0x0d, 0x90, 0x02, 0x68, 0x89, 0xf7, 0x22, 0x22, 0xac, 0x05, 0xfd, 0xa9,
+        0xaf, 0xff, 0xff, 0xff, 0xd0, 0xda, 0x00, 0x02, 0xf5, 0x00, 0x00, 0x00, 0x0f, 0x60, 0x00,
+        0x6f, 0xa0, 0x00, 0x00, 0x00, 0xdc, 0x20, 0x4e, 0xaf, 0x81, 0x01, 0x55, 0x03, 0xdf, 0xfe,
+        0x60, 0x6e, 0xff, 0xfe, 0x50, 0x00, 0x33, 0x00, 0x00, 0x02, 0x32, 0x00, 0x00, 0x2a, 0xee,
+        0xc7, 0x03, 0xec, 0x64, 0x69, 0x0b, 0xd1, 0x00, 0x00, 0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60,
+        0x00, 0x00, 0x1f, 0x70, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x26, 0x00,
+        0x6e, 0xff, 0xfa, 0x00, 0x00, 0x3f, 0x40, 0x00, 0x00, 0x08, 0x90, 0x00, 0x09, 0xee, 0x50,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x3c, 0x30, 0x00, 0x00, 0x00, 0x7d, 0x10, 0x00, 0x00, 0x00,
+        0x9a, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a,
+        0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1,
+        0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d,
+        0xff, 0xfe, 0x70, 0x00, 0x02, 0x32, 0x00, 0x00, 0x00, 0x02, 0xc5, 0x00, 0x00, 0x00, 0xc9,
+        0x00, 0x00, 0x00, 0x8b, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00,
+        0x2e, 0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff,
+        0xff, 0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x5f, 0x92, 0x01,
+        0x56, 0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32, 0x00, 0x00, 0x00, 0x9b, 0x00, 0x00,
+        0x00, 0x5d, 0xb8, 0x00, 0x00, 0x1d, 0x31, 0xd3, 0x00, 0x01, 0x20, 0x01, 0x10, 0x00, 0x2a,
+        0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22,
+        0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00,
+        0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32, 0x00, 0x00, 0x02,
+        0x10, 0x21, 0x00, 0x03, 0xf5, 0x3f, 0x60, 0x00, 0x27, 0x21, 0x73, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0,
+        0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0,
+        0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32,
+        0x00, 0x2b, 0x50, 0x00, 0x5e, 0x20, 0x00, 0x7c, 0x00, 0x00, 0x20, 0x00, 0x6b, 0x00, 0x07,
+        0xe0, 0x00, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07,
+        0xe0, 0x00, 0x7e, 0x00, 0x01, 0xb6, 0x0a, 0xb0, 0x6c, 0x10, 0x21, 0x00, 0x6b, 0x00, 0x7e,
+        0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00,
+        0x00, 0x7c, 0x10, 0x03, 0xea, 0xa0, 0x0c, 0x40, 0xc5, 0x02, 0x00, 0x11, 0x00, 0x6b, 0x00,
+        0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00,
+        0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x02, 0x10, 0x21, 0x1f, 0x71, 0xf8,
+        0x17, 0x30, 0x74, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00,
+        0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00,
+        0x00, 0x7e, 0x00, 0x00, 0x12, 0x10, 0x00, 0x00, 0x02, 0xdb, 0x27, 0xb0, 0x00, 0x29, 0xfe,
+        0x61, 0x00, 0x3b, 0x65, 0xf8, 0x00, 0x00, 0x05, 0x8c, 0xf6, 0x00, 0x1c, 0xea, 0x9d, 0xe1,
+        0x09, 0xe3, 0x00, 0x2f, 0x80, 0xe9, 0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7,
+        0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x0e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff,
+        0xd4, 0x00, 0x00, 0x03, 0x20, 0x00, 0x00, 0x45, 0x01, 0x50, 0x02, 0xeb, 0xa8, 0xa0, 0x04,
+        0x70, 0x8b, 0x20, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x2b, 0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5,
+        0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00,
+        0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x5b,
+        0x10, 0x00, 0x00, 0x00, 0xab, 0x00, 0x00, 0x00, 0x00, 0xb7, 0x00, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x3b, 0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80,
+        0xf8, 0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0,
+        0x00, 0x1e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00, 0x00, 0x13, 0x30,
+        0x00, 0x00, 0x00, 0x04, 0xc2, 0x00, 0x00, 0x01, 0xd6, 0x00, 0x00, 0x00, 0xb8, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x3b, 0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0,
+        0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00,
+        0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00,
+        0x00, 0x13, 0x30, 0x00, 0x00, 0x00, 0xb9, 0x00, 0x00, 0x00, 0x8b, 0xd5, 0x00, 0x00, 0x3d,
+        0x13, 0xd1, 0x00, 0x01, 0x10, 0x02, 0x10, 0x00, 0x3b, 0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d,
+        0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1,
+        0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e,
+        0xff, 0xe5, 0x00, 0x00, 0x13, 0x30, 0x00, 0x00, 0x05, 0x30, 0x33, 0x00, 0x06, 0xcd, 0x7b,
+        0x50, 0x00, 0x84, 0x1a, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3b, 0xee, 0xa2, 0x00,
+        0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00, 0xbc, 0x1f, 0x60,
+        0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0, 0x6f, 0x71, 0x1a,
+        0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00, 0x00, 0x13, 0x30, 0x00, 0x00, 0x12, 0x01, 0x20, 0x00,
+        0x06, 0xf2, 0x5f, 0x30, 0x00, 0x37, 0x12, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3b,
+        0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00,
+        0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0,
+        0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00, 0x00, 0x13, 0x30, 0x00, 0x00, 0x00,
+        0x3d, 0x90, 0x00, 0x00, 0x00, 0x04, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0x03,
+        0xaa, 0xaa, 0xaa, 0xaa, 0xa7, 0x3a, 0xaa, 0xaa, 0xaa, 0xaa, 0x70, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x4f, 0xa0, 0x00, 0x00, 0x00, 0x03, 0xc8, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x30, 0x00, 0x3b, 0xee, 0xa6, 0xd1, 0x03, 0xfb, 0x55, 0xdf, 0x40, 0x0b, 0xd0, 0x01,
+        0xdf, 0x80, 0x0f, 0x80, 0x0c, 0x7b, 0xc0, 0x1f, 0x60, 0x99, 0x09, 0xd0, 0x1f, 0x77, 0xc0,
+        0x0a, 0xd0, 0x0d, 0xdd, 0x10, 0x1e, 0xa0, 0x07, 0xf9, 0x11, 0xaf, 0x30, 0x1d, 0xae, 0xff,
+        0xe5, 0x00, 0x26, 0x01, 0x33, 0x00, 0x00, 0x04, 0xc2, 0x00, 0x00, 0x00, 0x9c, 0x00, 0x00,
+        0x00, 0x0a, 0x80, 0x00, 0x00, 0x00, 0x20, 0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00,
+        0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00,
+        0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00,
+        0x13, 0x10, 0x00, 0x00, 0x00, 0x3c, 0x30, 0x00, 0x01, 0xd7, 0x00, 0x00, 0x0a, 0x90, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00,
+        0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00,
+        0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x00,
+        0x0a, 0xa0, 0x00, 0x00, 0x7b, 0xc6, 0x00, 0x02, 0xd2, 0x2d, 0x10, 0x01, 0x10, 0x01, 0x10,
+        0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00,
+        0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71,
+        0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x01, 0x20, 0x12, 0x00, 0x05,
+        0xf3, 0x5f, 0x40, 0x02, 0x72, 0x27, 0x20, 0x00, 0x00, 0x00, 0x00, 0x79, 0x00, 0x00, 0x88,
+        0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00,
+        0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff,
+        0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x00, 0x00, 0x06, 0xb1, 0x00, 0x00, 0x03, 0xe4, 0x00,
+        0x00, 0x01, 0xd6, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x4b, 0x20, 0x00, 0x08, 0x91, 0xe7,
+        0x00, 0x01, 0xe7, 0x09, 0xd0, 0x00, 0x6f, 0x20, 0x3f, 0x40, 0x0c, 0xa0, 0x00, 0xca, 0x03,
+        0xf4, 0x00, 0x05, 0xf2, 0x9d, 0x00, 0x00, 0x0e, 0x8e, 0x70, 0x00, 0x00, 0x8f, 0xf1, 0x00,
+        0x00, 0x02, 0xfa, 0x00, 0x00, 0x00, 0x4f, 0x40, 0x00, 0x00, 0x0b, 0xd0, 0x00, 0x00, 0xae,
+        0xf4, 0x00, 0x00, 0x04, 0x52, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00,
+        0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x8d, 0x3b, 0xed, 0x60,
+        0x08, 0xed, 0x64, 0x9f, 0x60, 0x8f, 0x50, 0x00, 0xae, 0x08, 0xf0, 0x00, 0x05, 0xf3, 0x8e,
+        0x00, 0x00, 0x3f, 0x48, 0xe0, 0x00, 0x04, 0xf3, 0x8f, 0x40, 0x00, 0x8e, 0x18, 0xfc, 0x20,
+        0x5f, 0x90, 0x8d, 0x6f, 0xff, 0xa0, 0x08, 0xd0, 0x13, 0x20, 0x00, 0x8d, 0x00, 0x00, 0x00,
+        0x08, 0xd0, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x01, 0x20, 0x00,
+        0x09, 0xf0, 0x8f, 0x10, 0x00, 0x47, 0x04, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4b, 0x20,
+        0x00, 0x08, 0x91, 0xe7, 0x00, 0x01, 0xe7, 0x09, 0xd0, 0x00, 0x6f, 0x20, 0x3f, 0x40, 0x0c,
+        0xa0, 0x00, 0xca, 0x03, 0xf4, 0x00, 0x05, 0xf2, 0x9d, 0x00, 0x00, 0x0e, 0x8e, 0x70, 0x00,
+        0x00, 0x8f, 0xf1, 0x00, 0x00, 0x02, 0xfa, 0x00, 0x00, 0x00, 0x4f, 0x40, 0x00, 0x00, 0x0b,
+        0xd0, 0x00, 0x00, 0xae, 0xf4, 0x00, 0x00, 0x04, 0x52, 0x00, 0x00, 0x00, 0x00, 0x05, 0x66,
+        0x65, 0x00, 0x00, 0x00, 0xac, 0xcc, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0xa5, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xc0, 0x00, 0x00, 0x00, 0x4f, 0x8f, 0x30, 0x00,
+        0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00, 0x01, 0xe8, 0x08, 0xe0, 0x00, 0x00, 0x6f, 0x20, 0x3f,
+        0x50, 0x00, 0x0c, 0xc0, 0x00, 0xcb, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x8f, 0xff,
+        0xff, 0xff, 0x70, 0x0e, 0xa0, 0x00, 0x00, 0xbd, 0x05, 0xf5, 0x00, 0x00, 0x05, 0xf4, 0xae,
+        0x00, 0x00, 0x00, 0x1e, 0x90, 0x00, 0xae, 0xee, 0xd0, 0x00, 0x03, 0x33, 0x33, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x2a, 0xde, 0xd9, 0x10, 0x05, 0x95, 0x46, 0xeb, 0x00, 0x00, 0x00,
+        0x04, 0xf2, 0x00, 0x26, 0x88, 0x9f, 0x50, 0x5f, 0xda, 0x9a, 0xf5, 0x0d, 0xa0, 0x00, 0x2f,
+        0x50, 0xf6, 0x00, 0x06, 0xf5, 0x0d, 0xc2, 0x04, 0xef, 0x50, 0x3e, 0xff, 0xd5, 0xf5, 0x00,
+        0x03, 0x30, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0xb6, 0x03, 0xe0, 0x00,
+        0x00, 0x03, 0xdf, 0xe5, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x5a, 0x50,
+        0x00, 0x00, 0x00, 0x0d, 0xfc, 0x00, 0x00, 0x00, 0x04, 0xf8, 0xf3, 0x00, 0x00, 0x00, 0x9d,
+        0x0e, 0x90, 0x00, 0x00, 0x1e, 0x80, 0x8e, 0x00, 0x00, 0x06, 0xf2, 0x03, 0xf5, 0x00, 0x00,
+        0xcc, 0x00, 0x0c, 0xb0, 0x00, 0x2f, 0x95, 0x55, 0xaf, 0x20, 0x08, 0xff, 0xff, 0xff, 0xf7,
+        0x00, 0xea, 0x00, 0x00, 0x0b, 0xd0, 0x5f, 0x50, 0x00, 0x00, 0x5f, 0x4a, 0xe0, 0x00, 0x00,
+        0x01, 0xe9, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0a, 0x82, 0x5e, 0x00, 0x00, 0x2c, 0xfd, 0x40,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xad, 0xed, 0x91, 0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00,
+        0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05, 0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00,
+        0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20, 0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f,
+        0x50, 0x00, 0x33, 0x00, 0x00, 0x00, 0x00, 0x5a, 0x50, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xc0,
+        0x00, 0x00, 0x00, 0x04, 0xf8, 0xf3, 0x00, 0x00, 0x00, 0x09, 0xd0, 0xe9, 0x00, 0x00, 0x00,
+        0x1e, 0x80, 0x8e, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x3f, 0x50, 0x00, 0x00, 0xcc, 0x00, 0x0c,
+        0xb0, 0x00, 0x02, 0xf9, 0x55, 0x5a, 0xf2, 0x00, 0x08, 0xff, 0xff, 0xff, 0xf7, 0x00, 0x0e,
+        0xa0, 0x00, 0x00, 0xbd, 0x00, 0x5f, 0x50, 0x00, 0x00, 0x5f, 0x40, 0xae, 0x00, 0x00, 0x00,
+        0x1e, 0x90, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x98, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x5e, 0xe4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x02, 0xad, 0xed, 0x91,
+        0x00, 0x59, 0x54, 0x6e, 0xb0, 0x00, 0x00, 0x00, 0x4f, 0x20, 0x02, 0x68, 0x89, 0xf5, 0x05,
+        0xfd, 0xa9, 0xaf, 0x50, 0xda, 0x00, 0x02, 0xf5, 0x0f, 0x60, 0x00, 0x6f, 0x50, 0xdc, 0x20,
+        0x4e, 0xf5, 0x03, 0xef, 0xfd, 0x5f, 0x50, 0x00, 0x33, 0x07, 0x80, 0x00, 0x00, 0x00, 0xd4,
+        0x00, 0x00, 0x00, 0x09, 0xee, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x7a, 0x00,
+        0x00, 0x00, 0x00, 0x3e, 0x20, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0x28, 0xcd,
+        0xc9, 0x30, 0x00, 0x5e, 0xe8, 0x77, 0xbf, 0x40, 0x3f, 0xb1, 0x00, 0x00, 0x43, 0x0a, 0xf2,
+        0x00, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x00, 0x01,
+        0xf9, 0x00, 0x00, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xce, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0xf7, 0x00, 0x00, 0x01, 0x20, 0x0a, 0xf9, 0x31, 0x26, 0xd5, 0x00, 0x07, 0xef,
+        0xff, 0xe8, 0x10, 0x00, 0x00, 0x23, 0x20, 0x00, 0x00, 0x00, 0x02, 0xc4, 0x00, 0x00, 0x0c,
+        0x90, 0x00, 0x00, 0x8b, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x2a, 0xee, 0xc7, 0x03, 0xec,
+        0x64, 0x69, 0x0b, 0xd1, 0x00, 0x00, 0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60, 0x00, 0x00, 0x1f,
+        0x70, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x26, 0x00, 0x6e, 0xff, 0xfa,
+        0x00, 0x00, 0x33, 0x10, 0x00, 0x00, 0x06, 0xc9, 0x00, 0x00, 0x00, 0x03, 0xd2, 0xb6, 0x00,
+        0x00, 0x00, 0x21, 0x01, 0x30, 0x00, 0x00, 0x28, 0xcd, 0xc9, 0x30, 0x00, 0x5e, 0xe8, 0x77,
+        0xbf, 0x40, 0x3f, 0xb1, 0x00, 0x00, 0x43, 0x0a, 0xf2, 0x00, 0x00, 0x00, 0x00, 0xeb, 0x00,
+        0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x00, 0x01, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x0f,
+        0xa0, 0x00, 0x00, 0x00, 0x00, 0xce, 0x00, 0x00, 0x00, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x01,
+        0x20, 0x0a, 0xf9, 0x31, 0x26, 0xd5, 0x00, 0x07, 0xef, 0xff, 0xe8, 0x10, 0x00, 0x00, 0x23,
+        0x20, 0x00, 0x00, 0x00, 0x7c, 0x20, 0x00, 0x02, 0xe9, 0xb0, 0x00, 0x0c, 0x50, 0xc5, 0x00,
+        0x02, 0x00, 0x12, 0x00, 0x2a, 0xee, 0xc7, 0x03, 0xec, 0x64, 0x69, 0x0b, 0xd1, 0x00, 0x00,
+        0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60, 0x00, 0x00, 0x1f, 0x70, 0x00, 0x00, 0x0c, 0xc0, 0x00,
+        0x00, 0x05, 0xf9, 0x20, 0x26, 0x00, 0x6e, 0xff, 0xfa, 0x00, 0x00, 0x33, 0x10, 0x00, 0x00,
+        0x02, 0x94, 0x00, 0x00, 0x00, 0x00, 0x3f, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x28, 0xcd, 0xc9, 0x30, 0x00, 0x5e, 0xe8, 0x77, 0xbf, 0x40, 0x3f, 0xb1, 0x00, 0x00,
+        0x43, 0x0a, 0xf2, 0x00, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00,
+        0x00, 0x00, 0x01, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xce,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x01, 0x20, 0x0a, 0xf9, 0x31, 0x26, 0xd5,
+        0x00, 0x07, 0xef, 0xff, 0xe8, 0x10, 0x00, 0x00, 0x23, 0x20, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x7f, 0x00, 0x00, 0x00, 0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a, 0xee,
+        0xc7, 0x03, 0xec, 0x64, 0x69, 0x0b, 0xd1, 0x00, 0x00, 0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60,
+        0x00, 0x00, 0x1f, 0x70, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x26, 0x00,
+        0x6e, 0xff, 0xfa, 0x00, 0x00, 0x33, 0x10, 0x00, 0x00, 0x96, 0x09, 0x50, 0x00, 0x00, 0x01,
+        0xdb, 0xa0, 0x00, 0x00, 0x00, 0x02, 0x31, 0x00, 0x00, 0x00, 0x28, 0xcd, 0xc9, 0x30, 0x00,
+        0x5e, 0xe8, 0x77, 0xbf, 0x40, 0x3f, 0xb1, 0x00, 0x00, 0x43, 0x0a, 0xf2, 0x00, 0x00, 0x00,
+        0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x00, 0x01, 0xf9, 0x00, 0x00,
+        0x00, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xce, 0x00, 0x00, 0x00, 0x00, 0x05, 0xf7,
+        0x00, 0x00, 0x01, 0x20, 0x0a, 0xf9, 0x31, 0x26, 0xd5, 0x00, 0x07, 0xef, 0xff, 0xe8, 0x10,
+        0x00, 0x00, 0x23, 0x20, 0x00, 0x00, 0x2b, 0x10, 0xa4, 0x00, 0x09, 0x97, 0xb0, 0x00, 0x01,
+        0xde, 0x20, 0x00, 0x00, 0x11, 0x00, 0x00, 0x2a, 0xee, 0xc7, 0x03, 0xec, 0x64, 0x69, 0x0b,
+        0xd1, 0x00, 0x00, 0x0f, 0x80, 0x00, 0x00, 0x2f, 0x60, 0x00, 0x00, 0x1f, 0x70, 0x00, 0x00,
+        0x0c, 0xc0, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x26, 0x00, 0x6e, 0xff, 0xfa, 0x00, 0x00, 0x33,
+        0x10, 0x00, 0x3b, 0x13, 0xb0, 0x00, 0x00, 0x00, 0x7c, 0xd3, 0x00, 0x00, 0x00, 0x00, 0x32,
+        0x00, 0x00, 0x04, 0xaa, 0xaa, 0x86, 0x10, 0x00, 0x6f, 0xa9, 0xab, 0xfe, 0x70, 0x06, 0xf2,
+        0x00, 0x01, 0xaf, 0x60, 0x6f, 0x20, 0x00, 0x00, 0xde, 0x06, 0xf2, 0x00, 0x00, 0x07, 0xf3,
+        0x6f, 0x20, 0x00, 0x00, 0x5f, 0x56, 0xf2, 0x00, 0x00, 0x05, 0xf5, 0x6f, 0x20, 0x00, 0x00,
+        0x6f, 0x46, 0xf2, 0x00, 0x00, 0x0a, 0xf1, 0x6f, 0x20, 0x00, 0x05, 0xf9, 0x06, 0xf6, 0x45,
+        0x6b, 0xfc, 0x10, 0x6f, 0xff, 0xfd, 0xb5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x20, 0x21,
+        0x00, 0x00, 0x00, 0x0b, 0xb0, 0xe8, 0x00, 0x00, 0x00, 0x0b, 0xb2, 0xf3, 0x00, 0x00, 0x00,
+        0x0b, 0xb4, 0xb0, 0x00, 0x4c, 0xec, 0x5b, 0xb0, 0x00, 0x04, 0xfa, 0x46, 0xde, 0xb0, 0x00,
+        0x0b, 0xc0, 0x00, 0x3f, 0xb0, 0x00, 0x0f, 0x70, 0x00, 0x0d, 0xb0, 0x00, 0x1f, 0x60, 0x00,
+        0x0b, 0xb0, 0x00, 0x1f, 0x70, 0x00, 0x0c, 0xb0, 0x00, 0x0d, 0xb0, 0x00, 0x1f, 0xb0, 0x00,
+        0x06, 0xf7, 0x02, 0xbf, 0xb0, 0x00, 0x00, 0x8f, 0xff, 0x8b, 0xb0, 0x00, 0x00, 0x01, 0x31,
+        0x00, 0x00, 0x00, 0x03, 0xaa, 0xaa, 0x86, 0x10, 0x00, 0x05, 0xfb, 0x9a, 0xbe, 0xf8, 0x00,
+        0x05, 0xf3, 0x00, 0x01, 0x9f, 0x80, 0x05, 0xf3, 0x00, 0x00, 0x0c, 0xe1, 0x05, 0xf3, 0x00,
+        0x00, 0x06, 0xf4, 0x8b, 0xfa, 0x98, 0x00, 0x04, 0xf6, 0x7a, 0xfa, 0x87, 0x00, 0x03, 0xf7,
+        0x05, 0xf3, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf3, 0x00, 0x00, 0x09, 0xf2, 0x05, 0xf3, 0x00,
+        0x00, 0x4f, 0xb0, 0x05, 0xf7, 0x45, 0x6a, 0xfc, 0x10, 0x05, 0xff, 0xff, 0xeb, 0x60, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0b, 0xb0, 0x00, 0x00, 0x4f, 0xff, 0xfd,
+        0x00, 0x00, 0x00, 0x0b, 0xb0, 0x00, 0x4c, 0xec, 0x5b, 0xb0, 0x04, 0xfa, 0x46, 0xde, 0xb0,
+        0x0b, 0xc0, 0x00, 0x3f, 0xb0, 0x0f, 0x70, 0x00, 0x0d, 0xb0, 0x1f, 0x60, 0x00, 0x0b, 0xb0,
+        0x1f, 0x70, 0x00, 0x0c, 0xb0, 0x0d, 0xb0, 0x00, 0x1f, 0xb0, 0x06, 0xf7, 0x02, 0xbf, 0xb0,
+        0x00, 0x8f, 0xff, 0x8b, 0xb0, 0x00, 0x01, 0x31, 0x00, 0x00, 0x00, 0x66, 0x66, 0x40, 0x00,
+        0x1b, 0xbb, 0xb7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xaa, 0xaa, 0xaa, 0x90, 0x6f, 0xba,
+        0xaa, 0xa9, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00,
+        0x00, 0x6f, 0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x06,
+        0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6, 0x55, 0x55, 0x50, 0x6f, 0xff,
+        0xff, 0xff, 0x10, 0x00, 0x2e, 0xee, 0xe7, 0x00, 0x01, 0x33, 0x33, 0x20, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0xae, 0xeb, 0x30, 0x02, 0xeb, 0x54, 0xaf, 0x30, 0xbc, 0x00, 0x00, 0xcb,
+        0x0f, 0x92, 0x22, 0x29, 0xe2, 0xff, 0xff, 0xff, 0xfe, 0x1f, 0x70, 0x00, 0x00, 0x00, 0xcc,
+        0x00, 0x00, 0x00, 0x05, 0xf9, 0x20, 0x15, 0x60, 0x05, 0xdf, 0xff, 0xe7, 0x00, 0x00, 0x23,
+        0x20, 0x00, 0x01, 0xc2, 0x07, 0x80, 0x00, 0x07, 0xef, 0xc2, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0xaa, 0xaa, 0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77,
+        0x77, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00,
+        0x06, 0xf6, 0x55, 0x55, 0x50, 0x6f, 0xff, 0xff, 0xff, 0x10, 0x00, 0x26, 0x00, 0x35, 0x00,
+        0x01, 0xe8, 0x7d, 0x50, 0x00, 0x03, 0x9a, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a,
+        0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22,
+        0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00,
+        0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32, 0x00, 0x00, 0x05,
+        0x90, 0x00, 0x00, 0x00, 0x8f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xaa, 0xaa, 0xaa,
+        0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06,
+        0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xd9, 0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f, 0x20,
+        0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf6, 0x55, 0x55,
+        0x50, 0x6f, 0xff, 0xff, 0xff, 0x10, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00,
+        0x00, 0x00, 0x69, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e,
+        0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff,
+        0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56,
+        0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32, 0x00, 0x4a, 0xaa, 0xaa, 0xa9, 0x06, 0xfb,
+        0xaa, 0xaa, 0x90, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x20, 0x00,
+        0x00, 0x06, 0xfe, 0xdd, 0xdd, 0x90, 0x6f, 0x87, 0x77, 0x75, 0x06, 0xf2, 0x00, 0x00, 0x00,
+        0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x55, 0x06, 0xff,
+        0xff, 0xff, 0xf1, 0x00, 0x00, 0x05, 0xa0, 0x00, 0x00, 0x00, 0xc5, 0x00, 0x00, 0x00, 0x08,
+        0xee, 0x10, 0x00, 0x00, 0x01, 0x10, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3,
+        0x0b, 0xc0, 0x00, 0x0c, 0xb0, 0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7,
+        0x00, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff,
+        0xfe, 0x70, 0x00, 0x02, 0x4f, 0x10, 0x00, 0x00, 0x06, 0xa0, 0x00, 0x00, 0x00, 0x3e, 0xe6,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x95, 0x09, 0x40, 0x00, 0x02, 0xdb, 0xa0, 0x00, 0x00,
+        0x02, 0x41, 0x00, 0x04, 0xaa, 0xaa, 0xaa, 0x90, 0x6f, 0xba, 0xaa, 0xa9, 0x06, 0xf2, 0x00,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f, 0xed, 0xdd, 0xd9,
+        0x06, 0xf8, 0x77, 0x77, 0x50, 0x6f, 0x20, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x06, 0xf6, 0x55, 0x55, 0x50, 0x6f, 0xff, 0xff, 0xff, 0x10, 0x00, 0x1a,
+        0x10, 0x85, 0x00, 0x00, 0x8a, 0x5c, 0x00, 0x00, 0x00, 0xde, 0x30, 0x00, 0x00, 0x01, 0x20,
+        0x00, 0x00, 0x2a, 0xee, 0xb3, 0x00, 0x2e, 0xb5, 0x4a, 0xf3, 0x0b, 0xc0, 0x00, 0x0c, 0xb0,
+        0xf9, 0x22, 0x22, 0x9e, 0x2f, 0xff, 0xff, 0xff, 0xe1, 0xf7, 0x00, 0x00, 0x00, 0x0c, 0xc0,
+        0x00, 0x00, 0x00, 0x5f, 0x92, 0x01, 0x56, 0x00, 0x5d, 0xff, 0xfe, 0x70, 0x00, 0x02, 0x32,
+        0x00, 0x00, 0x00, 0x05, 0xca, 0x00, 0x00, 0x00, 0x00, 0x2d, 0x3a, 0x80, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x30, 0x00, 0x00, 0x02, 0x8c, 0xdd, 0xa6, 0x10, 0x00, 0x5e, 0xe9, 0x77, 0xaf,
+        0xb0, 0x03, 0xfb, 0x10, 0x00, 0x01, 0x70, 0x0a, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xb0,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x01, 0x11, 0x10, 0x1f, 0x90, 0x00, 0x1f, 0xff,
+        0xf1, 0x0f, 0xa0, 0x00, 0x03, 0x39, 0xf1, 0x0c, 0xe0, 0x00, 0x00, 0x07, 0xf1, 0x05, 0xf7,
+        0x00, 0x00, 0x07, 0xf1, 0x00, 0xaf, 0x93, 0x12, 0x4b, 0xf1, 0x00, 0x07, 0xdf, 0xff, 0xfb,
+        0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x00, 0x01, 0xb8, 0x00, 0x00, 0x00, 0x9a, 0xd4,
+        0x00, 0x00, 0x4d, 0x14, 0xd0, 0x00, 0x01, 0x10, 0x02, 0x10, 0x00, 0x5c, 0xec, 0x58, 0x80,
+        0x4f, 0xa4, 0x5d, 0xeb, 0x0c, 0xc0, 0x00, 0x3f, 0xb1, 0xf7, 0x00, 0x00, 0xdb, 0x1f, 0x60,
+        0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00, 0xcb, 0x0c, 0xc0, 0x00, 0x2f, 0xb0, 0x5f, 0x94, 0x5d,
+        0xeb, 0x00, 0x5d, 0xfd, 0x5b, 0xa0, 0x00, 0x00, 0x00, 0xe8, 0x00, 0x40, 0x01, 0x9f, 0x30,
+        0x1f, 0xed, 0xfe, 0x60, 0x00, 0x14, 0x54, 0x10, 0x00, 0x00, 0x02, 0xb2, 0x07, 0x70, 0x00,
+        0x00, 0x00, 0x8e, 0xfc, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x8c,
+        0xdd, 0xa6, 0x10, 0x00, 0x5e, 0xe9, 0x77, 0xaf, 0xb0, 0x03, 0xfb, 0x10, 0x00, 0x01, 0x70,
+        0x0a, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00,
+        0x01, 0x11, 0x10, 0x1f, 0x90, 0x00, 0x1f, 0xff, 0xf1, 0x0f, 0xa0, 0x00, 0x03, 0x39, 0xf1,
+        0x0c, 0xe0, 0x00, 0x00, 0x07, 0xf1, 0x05, 0xf7, 0x00, 0x00, 0x07, 0xf1, 0x00, 0xaf, 0x93,
+        0x12, 0x4b, 0xf1, 0x00, 0x07, 0xdf, 0xff, 0xfb, 0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00,
+        0x00, 0x35, 0x00, 0x44, 0x00, 0x02, 0xe8, 0x7e, 0x40, 0x00, 0x03, 0x9a, 0x50, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x5c, 0xec, 0x58, 0x80, 0x4f, 0xa4, 0x5d, 0xeb, 0x0c, 0xc0, 0x00,
+        0x3f, 0xb1, 0xf7, 0x00, 0x00, 0xdb, 0x1f, 0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00, 0xcb,
+        0x0c, 0xc0, 0x00, 0x2f, 0xb0, 0x5f, 0x94, 0x5d, 0xeb, 0x00, 0x5d, 0xfd, 0x5b, 0xa0, 0x00,
+        0x00, 0x00, 0xe8, 0x00, 0x40, 0x01, 0x9f, 0x30, 0x1f, 0xed, 0xfe, 0x60, 0x00, 0x14, 0x54,
+        0x10, 0x00, 0x00, 0x00, 0x01, 0x95, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf8, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x8c, 0xdd, 0xa6, 0x10, 0x00, 0x5e, 0xe9, 0x77,
+        0xaf, 0xb0, 0x03, 0xfb, 0x10, 0x00, 0x01, 0x70, 0x0a, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x0e,
+        0xb0, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x01, 0x11, 0x10, 0x1f, 0x90, 0x00, 0x1f,
+        0xff, 0xf1, 0x0f, 0xa0, 0x00, 0x03, 0x39, 0xf1, 0x0c, 0xe0, 0x00, 0x00, 0x07, 0xf1, 0x05,
+        0xf7, 0x00, 0x00, 0x07, 0xf1, 0x00, 0xaf, 0x93, 0x12, 0x4b, 0xf1, 0x00, 0x07, 0xdf, 0xff,
+        0xfb, 0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x0e,
+        0x80, 0x00, 0x00, 0x00, 0x95, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0xec, 0x58,
+        0x80, 0x4f, 0xa4, 0x5d, 0xeb, 0x0c, 0xc0, 0x00, 0x3f, 0xb1, 0xf7, 0x00, 0x00, 0xdb, 0x1f,
+        0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00, 0xcb, 0x0c, 0xc0, 0x00, 0x2f, 0xb0, 0x5f, 0x94,
+        0x5d, 0xeb, 0x00, 0x5d, 0xfd, 0x5b, 0xa0, 0x00, 0x00, 0x00, 0xe8, 0x00, 0x40, 0x01, 0x9f,
+        0x30, 0x1f, 0xed, 0xfe, 0x60, 0x00, 0x14, 0x54, 0x10, 0x00, 0x00, 0x02, 0x8c, 0xdd, 0xa6,
+        0x10, 0x00, 0x5e, 0xe9, 0x77, 0xaf, 0xb0, 0x03, 0xfb, 0x10, 0x00, 0x01, 0x70, 0x0a, 0xf2,
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x90, 0x00, 0x01, 0x11,
+        0x10, 0x1f, 0x90, 0x00, 0x1f, 0xff, 0xf1, 0x0f, 0xa0, 0x00, 0x03, 0x39, 0xf1, 0x0c, 0xe0,
+        0x00, 0x00, 0x07, 0xf1, 0x05, 0xf7, 0x00, 0x00, 0x07, 0xf1, 0x00, 0xaf, 0x93, 0x12, 0x4b,
+        0xf1, 0x00, 0x07, 0xdf, 0xff, 0xfb, 0x40, 0x00, 0x00, 0x02, 0x33, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x75, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x05, 0xc0, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x10, 0x00, 0x00, 0x03, 0xf1, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00,
+        0x00, 0x01, 0x10, 0x00, 0x00, 0x5c, 0xec, 0x58, 0x80, 0x4f, 0xa4, 0x5d, 0xeb, 0x0c, 0xc0,
+        0x00, 0x3f, 0xb1, 0xf7, 0x00, 0x00, 0xdb, 0x1f, 0x60, 0x00, 0x0b, 0xb1, 0xf7, 0x00, 0x00,
+        0xcb, 0x0c, 0xc0, 0x00, 0x2f, 0xb0, 0x5f, 0x94, 0x5d, 0xeb, 0x00, 0x5d, 0xfd, 0x5b, 0xa0,
+        0x00, 0x00, 0x00, 0xe8, 0x00, 0x40, 0x01, 0x9f, 0x30, 0x1f, 0xed, 0xfe, 0x60, 0x00, 0x14,
+        0x54, 0x10, 0x00, 0x00, 0x01, 0xcc, 0x20, 0x00, 0x00, 0x0c, 0x66, 0xc0, 0x00, 0x00, 0x13,
+        0x00, 0x31, 0x00, 0x4a, 0x10, 0x00, 0x01, 0xa5, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20,
+        0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0xed,
+        0xdd, 0xdd, 0xf7, 0x6f, 0x87, 0x77, 0x78, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20,
+        0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20, 0x00, 0x02, 0xf7, 0x6f, 0x20,
+        0x00, 0x02, 0xf7, 0x01, 0xbc, 0x30, 0x00, 0x00, 0x0a, 0x84, 0xd1, 0x00, 0x00, 0x03, 0x12,
+        0x22, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x00, 0x00, 0x8d, 0x2b, 0xed, 0x70, 0x00, 0x8e, 0xd7, 0x59, 0xf5, 0x00, 0x8f,
+        0x40, 0x00, 0xda, 0x00, 0x8e, 0x00, 0x00, 0xac, 0x00, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x8d,
+        0x00, 0x00, 0xac, 0x00, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x8d,
+        0x00, 0x00, 0xac, 0x01, 0xa5, 0x00, 0x00, 0x08, 0x80, 0x00, 0x2f, 0x70, 0x00, 0x00, 0xcc,
+        0x00, 0x6e, 0xfe, 0xee, 0xee, 0xef, 0xfe, 0x12, 0x7f, 0xa5, 0x55, 0x55, 0xdd, 0x50, 0x02,
+        0xf7, 0x00, 0x00, 0x0c, 0xc0, 0x00, 0x2f, 0xed, 0xdd, 0xdd, 0xfc, 0x00, 0x02, 0xfb, 0x77,
+        0x77, 0x7d, 0xc0, 0x00, 0x2f, 0x70, 0x00, 0x00, 0xcc, 0x00, 0x02, 0xf7, 0x00, 0x00, 0x0c,
+        0xc0, 0x00, 0x2f, 0x70, 0x00, 0x00, 0xcc, 0x00, 0x02, 0xf7, 0x00, 0x00, 0x0c, 0xc0, 0x00,
+        0x2f, 0x70, 0x00, 0x00, 0xcc, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x04, 0xf7, 0x33, 0x00,
+        0x00, 0x1f, 0xff, 0xff, 0x20, 0x00, 0x02, 0xf6, 0x11, 0x00, 0x00, 0x01, 0xf6, 0x7d, 0xea,
+        0x20, 0x01, 0xfd, 0xa5, 0x6e, 0xc0, 0x01, 0xfb, 0x00, 0x06, 0xf2, 0x01, 0xf6, 0x00, 0x03,
+        0xf3, 0x01, 0xf5, 0x00, 0x03, 0xf4, 0x01, 0xf5, 0x00, 0x03, 0xf4, 0x01, 0xf5, 0x00, 0x03,
+        0xf4, 0x01, 0xf5, 0x00, 0x03, 0xf4, 0x01, 0xf5, 0x00, 0x03, 0xf4, 0x07, 0xa5, 0x2a, 0x2d,
+        0x4b, 0xe7, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x10, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00,
+        0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00,
+        0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x03, 0x50, 0x15, 0x2e,
+        0xbb, 0x7a, 0x48, 0x07, 0xb3, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x00, 0x7e, 0x00, 0x00,
+        0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00,
+        0x7e, 0x00, 0x00, 0x7e, 0x00, 0x66, 0x66, 0x4b, 0xcc, 0xc8, 0x00, 0x00, 0x00, 0x4a, 0x10,
+        0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20,
+        0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x6f, 0x20, 0x06, 0xf2, 0x00, 0x1e, 0xee,
+        0xe8, 0x04, 0x44, 0x42, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e,
+        0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e,
+        0x00, 0x00, 0x7e, 0x00, 0x0c, 0x30, 0x69, 0x06, 0xef, 0xd3, 0x00, 0x00, 0x00, 0x00, 0x4a,
+        0x10, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f,
+        0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f,
+        0x20, 0x00, 0x6f, 0x20, 0x17, 0x00, 0x35, 0x0e, 0x87, 0xd6, 0x02, 0x9a, 0x60, 0x00, 0x00,
+        0x00, 0x00, 0x6b, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e,
+        0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x4a, 0x10,
+        0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f,
+        0x20, 0x6f, 0x20, 0x6f, 0x20, 0x6f, 0x20, 0x3c, 0x00, 0x98, 0x00, 0x5e, 0xe4, 0x00, 0x10,
+        0x12, 0x00, 0x7e, 0x00, 0x59, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e,
+        0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x59, 0x00, 0xc5, 0x00,
+        0x8e, 0xe1, 0x01, 0x10, 0x49, 0x26, 0xf2, 0x00, 0x04, 0xa1, 0x6f, 0x26, 0xf2, 0x6f, 0x26,
+        0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x26, 0xf2, 0x6f, 0x20, 0x6b, 0x7e, 0x7e,
+        0x7e, 0x7e, 0x7e, 0x7e, 0x7e, 0x7e, 0x4a, 0x10, 0x07, 0x96, 0xf2, 0x00, 0xbd, 0x6f, 0x20,
+        0x0b, 0xd6, 0xf2, 0x00, 0xbd, 0x6f, 0x20, 0x0b, 0xd6, 0xf2, 0x00, 0xbd, 0x6f, 0x20, 0x0b,
+        0xd6, 0xf2, 0x00, 0xbd, 0x6f, 0x20, 0x0b, 0xd6, 0xf2, 0x00, 0xbd, 0x6f, 0x20, 0x0b, 0xd6,
+        0xf2, 0x00, 0xbd, 0x00, 0x00, 0x0d, 0xb0, 0x00, 0x27, 0xf7, 0x00, 0x2f, 0xfa, 0x10, 0x00,
+        0x31, 0x00, 0x12, 0x00, 0x02, 0x17, 0xe0, 0x01, 0xf6, 0x59, 0x00, 0x1a, 0x40, 0x00, 0x00,
+        0x00, 0x6b, 0x00, 0x1b, 0x47, 0xe0, 0x01, 0xf6, 0x7e, 0x00, 0x1f, 0x67, 0xe0, 0x01, 0xf6,
+        0x7e, 0x00, 0x1f, 0x67, 0xe0, 0x01, 0xf6, 0x7e, 0x00, 0x1f, 0x67, 0xe0, 0x01, 0xf6, 0x7e,
+        0x00, 0x1f, 0x60, 0x00, 0x01, 0xf5, 0x00, 0x00, 0x4f, 0x30, 0x00, 0xcf, 0xb0, 0x00, 0x04,
+        0x30, 0x00, 0x00, 0x9c, 0x60, 0x07, 0xb2, 0xd3, 0x03, 0x10, 0x22, 0x00, 0x4a, 0x10, 0x00,
+        0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00,
+        0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00, 0x6f, 0x20, 0x00,
+        0x7f, 0x20, 0x00, 0x8f, 0x10, 0x24, 0xeb, 0x00, 0xcf, 0xd2, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x7c, 0x10, 0x03, 0xea, 0xa0, 0x0c, 0x40, 0xc5, 0x02, 0x00, 0x11, 0x00, 0x6b, 0x00, 0x00,
+        0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00,
+        0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x8e, 0x00, 0x00, 0xbc, 0x00, 0x4e,
+        0xf5, 0x00, 0x15, 0x20, 0x00, 0x4a, 0x10, 0x00, 0x19, 0x91, 0x6f, 0x20, 0x01, 0xce, 0x40,
+        0x6f, 0x20, 0x1c, 0xe3, 0x00, 0x6f, 0x22, 0xdd, 0x20, 0x00, 0x6f, 0x5d, 0xd2, 0x00, 0x00,
+        0x6f, 0xed, 0x10, 0x00, 0x00, 0x6f, 0xcf, 0x50, 0x00, 0x00, 0x6f, 0x3b, 0xf5, 0x00, 0x00,
+        0x6f, 0x20, 0xbf, 0x50, 0x00, 0x6f, 0x20, 0x0b, 0xf5, 0x00, 0x6f, 0x20, 0x00, 0xbf, 0x50,
+        0x6f, 0x20, 0x00, 0x0b, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5b, 0x20, 0x00,
+        0x00, 0x00, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xa3, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x08,
+        0xd0, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x00, 0x8d, 0x00,
+        0x03, 0xb7, 0x08, 0xd0, 0x04, 0xe9, 0x00, 0x8d, 0x06, 0xf7, 0x00, 0x08, 0xe8, 0xf5, 0x00,
+        0x00, 0x8f, 0xfa, 0x00, 0x00, 0x08, 0xd5, 0xf9, 0x00, 0x00, 0x8d, 0x04, 0xf9, 0x00, 0x08,
+        0xd0, 0x04, 0xea, 0x00, 0x8d, 0x00, 0x03, 0xea, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0xb3, 0x00, 0x00, 0x00, 0x8c, 0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0x6a, 0x00, 0x03,
+        0xb7, 0x08, 0xd0, 0x04, 0xe9, 0x00, 0x8d, 0x06, 0xf7, 0x00, 0x08, 0xe7, 0xf6, 0x00, 0x00,
+        0x8f, 0xfa, 0x00, 0x00, 0x08, 0xd5, 0xf9, 0x00, 0x00, 0x8d, 0x04, 0xf9, 0x00, 0x08, 0xd0,
+        0x04, 0xea, 0x00, 0x8d, 0x00, 0x04, 0xea, 0x00, 0x01, 0xc5, 0x00, 0x00, 0x0b, 0x90, 0x00,
+        0x00, 0x13, 0x00, 0x00, 0x00, 0x4a, 0x10, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00,
+        0x6f, 0x65, 0x55, 0x54, 0x6f, 0xff, 0xff, 0xfc, 0x02, 0xc3, 0x1c, 0x60, 0x25, 0x00, 0x7e,
+        0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00,
+        0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x4a, 0x10, 0x00, 0x00, 0x6f, 0x20, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x54, 0x6f, 0xff, 0xff, 0xfc, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0xb2, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0x30, 0x00, 0x12, 0x07, 0xe0,
+        0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0,
+        0x7e, 0x00, 0x00, 0x4b, 0x29, 0xb0, 0xa3, 0x00, 0x4a, 0x10, 0x87, 0x00, 0x6f, 0x20, 0xe7,
+        0x00, 0x6f, 0x22, 0xf2, 0x00, 0x6f, 0x21, 0x40, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20,
+        0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f,
+        0x20, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x54, 0x6f, 0xff, 0xff, 0xfc, 0x12, 0x01, 0x27, 0xe0,
+        0xac, 0x7e, 0x0d, 0x77, 0xe0, 0xc2, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07, 0xe0,
+        0x00, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x00, 0x4a,
+        0x10, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00,
+        0x6f, 0x20, 0x06, 0x50, 0x6f, 0x20, 0x0e, 0xb0, 0x6f, 0x20, 0x07, 0x60, 0x6f, 0x20, 0x00,
+        0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x6f, 0x65, 0x55, 0x54, 0x6f, 0xff,
+        0xff, 0xfc, 0x12, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07,
+        0xe4, 0x70, 0x7e, 0x9f, 0x07, 0xe5, 0x80, 0x7e, 0x00, 0x07, 0xe0, 0x00, 0x7e, 0x00, 0x07,
+        0xe0, 0x00, 0x7e, 0x00, 0x00, 0x00, 0x3a, 0x20, 0x00, 0x00, 0x00, 0x5f, 0x30, 0x00, 0x00,
+        0x00, 0x5f, 0x30, 0x00, 0x00, 0x00, 0x5f, 0x30, 0x50, 0x00, 0x00, 0x5f, 0x5b, 0xd1, 0x00,
+        0x00, 0x5f, 0xf8, 0x00, 0x00, 0x01, 0xaf, 0x60, 0x00, 0x00, 0x0c, 0xdf, 0x30, 0x00, 0x00,
+        0x02, 0x5f, 0x30, 0x00, 0x00, 0x00, 0x5f, 0x30, 0x00, 0x00, 0x00, 0x5f, 0x75, 0x55, 0x54,
+        0x00, 0x5f, 0xff, 0xff, 0xfe, 0x01, 0x20, 0x00, 0x7f, 0x00, 0x07, 0xf0, 0x00, 0x7f, 0x00,
+        0x07, 0xf0, 0x00, 0x7f, 0xc5, 0x09, 0xf6, 0x0a, 0xff, 0x00, 0x37, 0xf0, 0x00, 0x7f, 0x00,
+        0x07, 0xf0, 0x00, 0x7f, 0x00, 0x07, 0xf0, 0x00, 0x00, 0x00, 0x4c, 0x10, 0x00, 0x00, 0x02,
+        0xe4, 0x00, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0x4a, 0x90, 0x00, 0x01, 0xa4, 0x6f, 0xf5,
+        0x00, 0x02, 0xf6, 0x6f, 0xdc, 0x00, 0x02, 0xf6, 0x6f, 0x5f, 0x60, 0x02, 0xf6, 0x6f, 0x1b,
+        0xd0, 0x02, 0xf6, 0x6f, 0x13, 0xf6, 0x02, 0xf6, 0x6f, 0x10, 0xae, 0x12, 0xf6, 0x6f, 0x10,
+        0x2f, 0x72, 0xf6, 0x6f, 0x10, 0x09, 0xe3, 0xf6, 0x6f, 0x10, 0x01, 0xea, 0xf6, 0x6f, 0x10,
+        0x00, 0x8f, 0xf6, 0x6f, 0x10, 0x00, 0x1e, 0xf6, 0x00, 0x00, 0x9a, 0x00, 0x00, 0x06, 0xd1,
+        0x00, 0x00, 0x3e, 0x30, 0x00, 0x00, 0x11, 0x00, 0x00, 0x6a, 0x2b, 0xed, 0x70, 0x8e, 0xd7,
+        0x59, 0xf5, 0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d,
+        0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac,
+        0x4a, 0x90, 0x00, 0x01, 0xa4, 0x6f, 0xf5, 0x00, 0x02, 0xf6, 0x6f, 0xdc, 0x00, 0x02, 0xf6,
+        0x6f, 0x5f, 0x60, 0x02, 0xf6, 0x6f, 0x1b, 0xd0, 0x02, 0xf6, 0x6f, 0x13, 0xf6, 0x02, 0xf6,
+        0x6f, 0x10, 0xae, 0x12, 0xf6, 0x6f, 0x10, 0x2f, 0x72, 0xf6, 0x6f, 0x10, 0x09, 0xe3, 0xf6,
+        0x6f, 0x10, 0x01, 0xea, 0xf6, 0x6f, 0x10, 0x00, 0x8f, 0xf6, 0x6f, 0x10, 0x00, 0x1e, 0xf6,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7a, 0x00, 0x00, 0x00, 0x00, 0xd7, 0x00, 0x00,
+        0x00, 0x01, 0xb1, 0x00, 0x00, 0x6a, 0x2b, 0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5, 0x8f, 0x40,
+        0x00, 0xda, 0x8e, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d,
+        0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x06, 0xb1, 0x00, 0x00, 0x0b, 0x90, 0x00, 0x00, 0x0b, 0x20, 0x00, 0x00, 0x2a, 0x12,
+        0xa1, 0x00, 0x00, 0x07, 0xbd, 0x50, 0x00, 0x00, 0x00, 0x54, 0x00, 0x00, 0x4a, 0x90, 0x00,
+        0x01, 0xa4, 0x6f, 0xf5, 0x00, 0x02, 0xf6, 0x6f, 0xdc, 0x00, 0x02, 0xf6, 0x6f, 0x5f, 0x60,
+        0x02, 0xf6, 0x6f, 0x1b, 0xd0, 0x02, 0xf6, 0x6f, 0x13, 0xf6, 0x02, 0xf6, 0x6f, 0x10, 0xae,
+        0x12, 0xf6, 0x6f, 0x10, 0x2f, 0x72, 0xf6, 0x6f, 0x10, 0x09, 0xe3, 0xf6, 0x6f, 0x10, 0x01,
+        0xea, 0xf6, 0x6f, 0x10, 0x00, 0x8f, 0xf6, 0x6f, 0x10, 0x00, 0x1e, 0xf6, 0x02, 0xb1, 0x09,
+        0x40, 0x00, 0x8a, 0x7b, 0x00, 0x00, 0x1d, 0xe2, 0x00, 0x00, 0x01, 0x20, 0x00, 0x6a, 0x2b,
+        0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5, 0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00, 0xac, 0x8d,
+        0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac,
+        0x8d, 0x00, 0x00, 0xac, 0x08, 0x90, 0x00, 0x00, 0x00, 0x00, 0xcc, 0x00, 0x00, 0x00, 0x00,
+        0x1f, 0x60, 0x00, 0x00, 0x00, 0x04, 0xd0, 0xa6, 0x5d, 0xec, 0x30, 0x00, 0x0d, 0xdc, 0x55,
+        0xce, 0x10, 0x00, 0xdd, 0x10, 0x03, 0xf5, 0x00, 0x0d, 0x90, 0x00, 0x0f, 0x60, 0x00, 0xd8,
+        0x00, 0x00, 0xf7, 0x00, 0x0d, 0x80, 0x00, 0x0f, 0x70, 0x00, 0xd8, 0x00, 0x00, 0xf7, 0x00,
+        0x0d, 0x80, 0x00, 0x0f, 0x70, 0x00, 0xd8, 0x00, 0x00, 0xf7, 0x4a, 0x14, 0xbd, 0xb5, 0x00,
+        0x6f, 0x7f, 0xca, 0xef, 0x50, 0x6f, 0xe4, 0x00, 0x2e, 0xc0, 0x6f, 0x80, 0x00, 0x08, 0xf1,
+        0x6f, 0x30, 0x00, 0x06, 0xf3, 0x6f, 0x20, 0x00, 0x06, 0xf3, 0x6f, 0x20, 0x00, 0x06, 0xf3,
+        0x6f, 0x20, 0x00, 0x06, 0xf3, 0x6f, 0x20, 0x00, 0x06, 0xf3, 0x6f, 0x20, 0x00, 0x06, 0xf3,
+        0x6f, 0x20, 0x00, 0x06, 0xf3, 0x6f, 0x20, 0x00, 0x06, 0xf3, 0x00, 0x00, 0x00, 0x06, 0xf3,
+        0x00, 0x00, 0x00, 0x09, 0xf1, 0x00, 0x00, 0x02, 0xef, 0x80, 0x00, 0x00, 0x01, 0x53, 0x00,
+        0x6a, 0x2b, 0xed, 0x70, 0x8e, 0xd7, 0x59, 0xf5, 0x8f, 0x40, 0x00, 0xda, 0x8e, 0x00, 0x00,
+        0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x8d, 0x00,
+        0x00, 0xac, 0x8d, 0x00, 0x00, 0xac, 0x00, 0x00, 0x00, 0xab, 0x00, 0x00, 0x00, 0xd9, 0x00,
+        0x0c, 0xde, 0xe3, 0x00, 0x04, 0x54, 0x10, 0x00, 0x00, 0x66, 0x66, 0x40, 0x00, 0x00, 0x00,
+        0xcc, 0xcc, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71,
+        0x00, 0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2,
+        0x00, 0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00,
+        0xfa, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0,
+        0x00, 0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf,
+        0x40, 0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x00, 0x6e,
+        0xee, 0xe3, 0x00, 0x02, 0x44, 0x44, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xbe, 0xea,
+        0x20, 0x03, 0xfb, 0x55, 0xde, 0x10, 0xbd, 0x00, 0x02, 0xf8, 0x0f, 0x80, 0x00, 0x0b, 0xc1,
+        0xf6, 0x00, 0x00, 0x9d, 0x1f, 0x70, 0x00, 0x0a, 0xd0, 0xdb, 0x00, 0x01, 0xea, 0x06, 0xf7,
+        0x11, 0xaf, 0x30, 0x07, 0xef, 0xfe, 0x50, 0x00, 0x01, 0x33, 0x00, 0x00, 0x00, 0x01, 0xc2,
+        0x06, 0x80, 0x00, 0x00, 0x00, 0x7e, 0xfc, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00, 0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10,
+        0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00, 0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8,
+        0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00,
+        0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00, 0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1,
+        0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40, 0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03,
+        0x32, 0x00, 0x00, 0x00, 0x44, 0x00, 0x63, 0x00, 0x04, 0xe7, 0x8e, 0x10, 0x00, 0x05, 0xa9,
+        0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3b, 0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1,
+        0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8, 0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7,
+        0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00, 0x1e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff,
+        0xe5, 0x00, 0x00, 0x13, 0x30, 0x00, 0x00, 0x00, 0x08, 0x91, 0xc4, 0x00, 0x00, 0x00, 0x5d,
+        0x1b, 0x80, 0x00, 0x00, 0x00, 0x31, 0x13, 0x00, 0x00, 0x00, 0x03, 0x9c, 0xdb, 0x71, 0x00,
+        0x00, 0x5f, 0xd8, 0x79, 0xfd, 0x10, 0x03, 0xfb, 0x10, 0x00, 0x3e, 0xb0, 0x0a, 0xf2, 0x00,
+        0x00, 0x08, 0xf4, 0x0e, 0xb0, 0x00, 0x00, 0x02, 0xf8, 0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa,
+        0x1f, 0x90, 0x00, 0x00, 0x00, 0xfa, 0x0f, 0xa0, 0x00, 0x00, 0x01, 0xf9, 0x0c, 0xe0, 0x00,
+        0x00, 0x05, 0xf5, 0x05, 0xf7, 0x00, 0x00, 0x1d, 0xe1, 0x00, 0xaf, 0x82, 0x14, 0xcf, 0x40,
+        0x00, 0x07, 0xef, 0xff, 0xc4, 0x00, 0x00, 0x00, 0x03, 0x32, 0x00, 0x00, 0x00, 0x00, 0x78,
+        0x1b, 0x40, 0x00, 0x1e, 0x37, 0xb0, 0x00, 0x08, 0x91, 0xe2, 0x00, 0x00, 0x20, 0x11, 0x00,
+        0x00, 0x3b, 0xee, 0xa2, 0x00, 0x3f, 0xb5, 0x5d, 0xe1, 0x0b, 0xd0, 0x00, 0x2f, 0x80, 0xf8,
+        0x00, 0x00, 0xbc, 0x1f, 0x60, 0x00, 0x09, 0xd1, 0xf7, 0x00, 0x00, 0xad, 0x0d, 0xb0, 0x00,
+        0x1e, 0xa0, 0x6f, 0x71, 0x1a, 0xf3, 0x00, 0x7e, 0xff, 0xe5, 0x00, 0x00, 0x13, 0x30, 0x00,
+        0x00, 0x00, 0x37, 0x9a, 0xaa, 0xaa, 0xaa, 0xa9, 0x00, 0x02, 0xbf, 0xeb, 0xac, 0xfb, 0xaa,
+        0xaa, 0x90, 0x01, 0xde, 0x50, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x00, 0x9f, 0x40, 0x00, 0x06,
+        0xf2, 0x00, 0x00, 0x00, 0x0e, 0xc0, 0x00, 0x00, 0x6f, 0x20, 0x00, 0x00, 0x01, 0xf9, 0x00,
+        0x00, 0x06, 0xfe, 0xdd, 0xdd, 0x90, 0x1f, 0x90, 0x00, 0x00, 0x6f, 0x87, 0x77, 0x75, 0x00,
+        0xfb, 0x00, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x0b, 0xe1, 0x00, 0x00, 0x6f, 0x20, 0x00,
+        0x00, 0x00, 0x4f, 0xb1, 0x00, 0x06, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x6f, 0xe9, 0x65, 0x9f,
+        0x65, 0x55, 0x55, 0x00, 0x00, 0x28, 0xce, 0xff, 0xff, 0xff, 0xff, 0xf1, 0x00, 0x3b, 0xee,
+        0xa2, 0x06, 0xce, 0xd8, 0x10, 0x03, 0xfb, 0x55, 0xdd, 0xaf, 0x84, 0x6e, 0xb0, 0x0b, 0xd0,
+        0x00, 0x2f, 0xf6, 0x00, 0x04, 0xf3, 0x0f, 0x80, 0x00, 0x0b, 0xf3, 0x22, 0x22, 0xf7, 0x1f,
+        0x60, 0x00, 0x09, 0xff, 0xff, 0xff, 0xf8, 0x1f, 0x70, 0x00, 0x0a, 0xf0, 0x00, 0x00, 0x00,
+        0x0d, 0xb0, 0x00, 0x1e, 0xf5, 0x00, 0x00, 0x00, 0x06, 0xf7, 0x11, 0xaf, 0xde, 0x51, 0x03,
+        0x72, 0x00, 0x7e, 0xff, 0xe5, 0x1a, 0xff, 0xff, 0xc2, 0x00, 0x01, 0x33, 0x00, 0x00, 0x13,
+        0x31, 0x00, 0x00, 0x00, 0x99, 0x00, 0x00, 0x00, 0x06, 0xc1, 0x00, 0x00, 0x00, 0x03, 0x10,
+        0x00, 0x00, 0x4a, 0xaa, 0xa8, 0x30, 0x00, 0x6f, 0xa9, 0xae, 0xf6, 0x00, 0x6f, 0x20, 0x01,
+        0xde, 0x00, 0x6f, 0x20, 0x00, 0x9f, 0x10, 0x6f, 0x20, 0x00, 0xbf, 0x00, 0x6f, 0x53, 0x48,
+        0xf9, 0x00, 0x6f, 0xff, 0xff, 0x90, 0x00, 0x6f, 0x31, 0x29, 0xf4, 0x00, 0x6f, 0x20, 0x00,
+        0xcd, 0x00, 0x6f, 0x20, 0x00, 0x4f, 0x60, 0x6f, 0x20, 0x00, 0x0c, 0xd0, 0x6f, 0x20, 0x00,
+        0x04, 0xf6, 0x00, 0x00, 0x9a, 0x00, 0x00, 0x6d, 0x10, 0x00, 0x3e, 0x30, 0x00, 0x01, 0x10,
+        0x00, 0x6a, 0x3b, 0xe8, 0x08, 0xed, 0x75, 0x40, 0x8f, 0x50, 0x00, 0x08, 0xf0, 0x00, 0x00,
+        0x8e, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x00, 0x4a, 0xaa, 0xa8, 0x30, 0x00, 0x6f, 0xa9, 0xae, 0xf6, 0x00, 0x6f, 0x20,
+        0x01, 0xde, 0x00, 0x6f, 0x20, 0x00, 0x9f, 0x10, 0x6f, 0x20, 0x00, 0xbf, 0x00, 0x6f, 0x53,
+        0x48, 0xf9, 0x00, 0x6f, 0xff, 0xff, 0x90, 0x00, 0x6f, 0x31, 0x29, 0xf4, 0x00, 0x6f, 0x20,
+        0x00, 0xcd, 0x00, 0x6f, 0x20, 0x00, 0x4f, 0x60, 0x6f, 0x20, 0x00, 0x0c, 0xd0, 0x6f, 0x20,
+        0x00, 0x04, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x10, 0x00, 0x00, 0x00,
+        0xb9, 0x00, 0x00, 0x00, 0x00, 0xb2, 0x00, 0x00, 0x6a, 0x3b, 0xe8, 0x8e, 0xd7, 0x54, 0x8f,
+        0x50, 0x00, 0x8f, 0x00, 0x00, 0x8e, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5b, 0x10, 0x00, 0xaa, 0x00, 0x00, 0xa3,
+        0x00, 0x00, 0x02, 0xa1, 0x1a, 0x10, 0x00, 0x00, 0x6c, 0xc6, 0x00, 0x00, 0x00, 0x04, 0x40,
+        0x00, 0x00, 0x4a, 0xaa, 0xa8, 0x30, 0x00, 0x6f, 0xa9, 0xae, 0xf6, 0x00, 0x6f, 0x20, 0x01,
+        0xde, 0x00, 0x6f, 0x20, 0x00, 0x9f, 0x10, 0x6f, 0x20, 0x00, 0xbf, 0x00, 0x6f, 0x53, 0x48,
+        0xf9, 0x00, 0x6f, 0xff, 0xff, 0x90, 0x00, 0x6f, 0x31, 0x29, 0xf4, 0x00, 0x6f, 0x20, 0x00,
+        0xcd, 0x00, 0x6f, 0x20, 0x00, 0x4f, 0x60, 0x6f, 0x20, 0x00, 0x0c, 0xd0, 0x6f, 0x20, 0x00,
+        0x04, 0xf6, 0x1b, 0x20, 0x85, 0x07, 0xb5, 0xc0, 0x00, 0xcf, 0x30, 0x00, 0x12, 0x00, 0x6a,
+        0x3b, 0xe8, 0x8e, 0xd7, 0x54, 0x8f, 0x50, 0x00, 0x8f, 0x00, 0x00, 0x8e, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x00, 0x99, 0x00,
+        0x00, 0x00, 0x6c, 0x10, 0x00, 0x00, 0x03, 0x10, 0x00, 0x00, 0x3a, 0xcd, 0xc9, 0x30, 0x4f,
+        0xc7, 0x68, 0xc8, 0x0c, 0xd0, 0x00, 0x00, 0x10, 0xea, 0x00, 0x00, 0x00, 0x0c, 0xe4, 0x00,
+        0x00, 0x00, 0x3e, 0xfe, 0xb7, 0x20, 0x00, 0x16, 0xad, 0xff, 0x60, 0x00, 0x00, 0x03, 0xde,
+        0x10, 0x00, 0x00, 0x07, 0xf4, 0x00, 0x00, 0x00, 0x7f, 0x3c, 0x83, 0x12, 0x6e, 0xc0, 0x9e,
+        0xff, 0xff, 0xb2, 0x00, 0x02, 0x33, 0x10, 0x00, 0x00, 0x00, 0x09, 0xa0, 0x00, 0x00, 0x6d,
+        0x10, 0x00, 0x03, 0xe3, 0x00, 0x00, 0x01, 0x10, 0x00, 0x02, 0xad, 0xed, 0xa1, 0x0c, 0xc5,
+        0x45, 0x91, 0x1f, 0x50, 0x00, 0x00, 0x0d, 0xc5, 0x10, 0x00, 0x03, 0xcf, 0xfc, 0x50, 0x00,
+        0x01, 0x5b, 0xf4, 0x00, 0x00, 0x00, 0xf8, 0x28, 0x30, 0x05, 0xf5, 0x1d, 0xff, 0xff, 0x80,
+        0x00, 0x13, 0x31, 0x00, 0x00, 0x1b, 0xc3, 0x00, 0x00, 0x0a, 0x84, 0xd1, 0x00, 0x00, 0x30,
+        0x02, 0x10, 0x00, 0x3a, 0xcd, 0xc9, 0x30, 0x4f, 0xc7, 0x68, 0xc8, 0x0c, 0xd0, 0x00, 0x00,
+        0x10, 0xea, 0x00, 0x00, 0x00, 0x0c, 0xe4, 0x00, 0x00, 0x00, 0x3e, 0xfe, 0xb7, 0x20, 0x00,
+        0x16, 0xad, 0xff, 0x60, 0x00, 0x00, 0x03, 0xde, 0x10, 0x00, 0x00, 0x07, 0xf4, 0x00, 0x00,
+        0x00, 0x7f, 0x3c, 0x83, 0x12, 0x6e, 0xc0, 0x9e, 0xff, 0xff, 0xb2, 0x00, 0x02, 0x33, 0x10,
+        0x00, 0x00, 0x07, 0xc2, 0x00, 0x00, 0x2e, 0x9b, 0x00, 0x00, 0xc5, 0x0b, 0x50, 0x00, 0x20,
+        0x01, 0x20, 0x02, 0xad, 0xed, 0xa1, 0x0c, 0xc5, 0x45, 0x91, 0x1f, 0x50, 0x00, 0x00, 0x0d,
+        0xc5, 0x10, 0x00, 0x03, 0xcf, 0xfc, 0x50, 0x00, 0x01, 0x5b, 0xf4, 0x00, 0x00, 0x00, 0xf8,
+        0x28, 0x30, 0x05, 0xf5, 0x1d, 0xff, 0xff, 0x80, 0x00, 0x13, 0x31, 0x00, 0x03, 0xac, 0xdc,
+        0x93, 0x04, 0xfc, 0x76, 0x8c, 0x80, 0xcd, 0x00, 0x00, 0x01, 0x0e, 0xa0, 0x00, 0x00, 0x00,
+        0xce, 0x40, 0x00, 0x00, 0x03, 0xef, 0xeb, 0x72, 0x00, 0x01, 0x6a, 0xdf, 0xf6, 0x00, 0x00,
+        0x00, 0x3d, 0xe1, 0x00, 0x00, 0x00, 0x7f, 0x40, 0x00, 0x00, 0x07, 0xf3, 0xc8, 0x31, 0x26,
+        0xec, 0x09, 0xef, 0xff, 0xfb, 0x20, 0x00, 0x23, 0xf3, 0x00, 0x00, 0x00, 0x09, 0x80, 0x00,
+        0x00, 0x9e, 0xe4, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x02, 0xad, 0xed, 0xa1, 0x0c, 0xc5,
+        0x45, 0x91, 0x1f, 0x50, 0x00, 0x00, 0x0d, 0xc5, 0x10, 0x00, 0x03, 0xcf, 0xfc, 0x50, 0x00,
+        0x01, 0x5b, 0xf4, 0x00, 0x00, 0x00, 0xf8, 0x28, 0x30, 0x05, 0xf5, 0x1d, 0xff, 0xff, 0x80,
+        0x00, 0x13, 0xe4, 0x00, 0x00, 0x00, 0x7a, 0x00, 0x00, 0x8e, 0xe6, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x01, 0xb3, 0x1b, 0x20, 0x00, 0x04, 0xdc, 0x60, 0x00, 0x00, 0x02, 0x30, 0x00, 0x00,
+        0x3a, 0xcd, 0xc9, 0x30, 0x4f, 0xc7, 0x68, 0xc8, 0x0c, 0xd0, 0x00, 0x00, 0x10, 0xea, 0x00,
+        0x00, 0x00, 0x0c, 0xe4, 0x00, 0x00, 0x00, 0x3e, 0xfe, 0xb7, 0x20, 0x00, 0x16, 0xad, 0xff,
+        0x60, 0x00, 0x00, 0x03, 0xde, 0x10, 0x00, 0x00, 0x07, 0xf4, 0x00, 0x00, 0x00, 0x7f, 0x3c,
+        0x83, 0x12, 0x6e, 0xc0, 0x9e, 0xff, 0xff, 0xb2, 0x00, 0x02, 0x33, 0x10, 0x00, 0x00, 0xa3,
+        0x07, 0x70, 0x00, 0x5d, 0x4d, 0x10, 0x00, 0x0a, 0xf5, 0x00, 0x00, 0x01, 0x20, 0x00, 0x02,
+        0xad, 0xed, 0xa1, 0x0c, 0xc5, 0x45, 0x91, 0x1f, 0x50, 0x00, 0x00, 0x0d, 0xc5, 0x10, 0x00,
+        0x03, 0xcf, 0xfc, 0x50, 0x00, 0x01, 0x5b, 0xf4, 0x00, 0x00, 0x00, 0xf8, 0x28, 0x30, 0x05,
+        0xf5, 0x1d, 0xff, 0xff, 0x80, 0x00, 0x13, 0x31, 0x00, 0x0a, 0xaa, 0xaa, 0xaa, 0xaa, 0x80,
+        0xaa, 0xaa, 0xfd, 0xaa, 0xa8, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00,
+        0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e,
+        0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00,
+        0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00,
+        0x00, 0x00, 0x05, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x10, 0x00, 0x00, 0x01, 0xee, 0xc0,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x02, 0x30, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0,
+        0x00, 0x6d, 0xfb, 0xba, 0x3a, 0xe5, 0x55, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0,
+        0x00, 0x08, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x05, 0xf6, 0x33, 0x00, 0x9e, 0xfd, 0x00, 0x03,
+        0xc0, 0x00, 0x00, 0xd3, 0x00, 0xee, 0xd1, 0x00, 0x01, 0x00, 0x00, 0x02, 0xa1, 0x2a, 0x10,
+        0x00, 0x00, 0x07, 0xbd, 0x50, 0x00, 0x00, 0x00, 0x05, 0x40, 0x00, 0x00, 0xaa, 0xaa, 0xaa,
+        0xaa, 0xa8, 0x0a, 0xaa, 0xaf, 0xda, 0xaa, 0x80, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00,
+        0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00,
+        0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00,
+        0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e,
+        0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xac, 0x02, 0x30, 0xd8, 0x08, 0xe1, 0xe3,
+        0x08, 0xe0, 0x00, 0x6d, 0xfb, 0xba, 0x3a, 0xe5, 0x55, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00,
+        0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x05, 0xf6, 0x33, 0x00, 0x9e, 0xfd,
+        0x0a, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0xaa, 0xaa, 0xfd, 0xaa, 0xa8, 0x00, 0x00, 0x0e, 0xa0,
+        0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00,
+        0xea, 0x00, 0x00, 0x00, 0x0c, 0xdf, 0xed, 0x90, 0x00, 0x00, 0x77, 0xed, 0x76, 0x00, 0x00,
+        0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00,
+        0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x02, 0x30, 0x00, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x00,
+        0x6d, 0xfb, 0xba, 0x3a, 0xe5, 0x55, 0x08, 0xe0, 0x00, 0x7e, 0xfc, 0xcb, 0x3a, 0xe4, 0x44,
+        0x08, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x05, 0xf6, 0x33, 0x00, 0x9e, 0xfd, 0x00, 0x2a, 0x82,
+        0x74, 0x00, 0x00, 0xa7, 0x7d, 0xd1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x00, 0x00,
+        0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00,
+        0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00,
+        0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00,
+        0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33,
+        0x10, 0x00, 0x00, 0x54, 0x03, 0x40, 0x04, 0xdc, 0x8a, 0x70, 0x06, 0x51, 0x9a, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba,
+        0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00, 0x01,
+        0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x00, 0x36,
+        0x66, 0x61, 0x00, 0x00, 0x6c, 0xcc, 0xc2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x00,
+        0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00,
+        0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00,
+        0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50,
+        0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01,
+        0x33, 0x10, 0x00, 0x04, 0xee, 0xee, 0x50, 0x01, 0x44, 0x44, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00,
+        0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71,
+        0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x00, 0x68, 0x01, 0xb3, 0x00,
+        0x00, 0x1b, 0xff, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5,
+        0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1,
+        0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10, 0x00,
+        0x03, 0x50, 0x05, 0x40, 0x02, 0xe7, 0x7e, 0x30, 0x00, 0x49, 0x94, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00,
+        0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f,
+        0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10, 0x00, 0x00, 0x05, 0xcb, 0x30,
+        0x00, 0x00, 0x2e, 0x35, 0xd0, 0x00, 0x00, 0x3b, 0x00, 0xe0, 0x00, 0x6a, 0x0c, 0xab, 0x93,
+        0xa3, 0x9f, 0x01, 0x55, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04,
+        0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04,
+        0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09,
+        0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9, 0x00, 0x00, 0x01, 0x33, 0x10,
+        0x00, 0x00, 0x18, 0x71, 0x00, 0x00, 0xd8, 0x9c, 0x00, 0x02, 0xc0, 0x0d, 0x10, 0x00, 0xd6,
+        0x7d, 0x00, 0x00, 0x3a, 0x92, 0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba, 0xac,
+        0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xca,
+        0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13, 0x10,
+        0x00, 0x00, 0x02, 0xc3, 0x6b, 0x10, 0x00, 0x0c, 0x73, 0xe2, 0x00, 0x00, 0x13, 0x02, 0x20,
+        0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04,
+        0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04,
+        0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00, 0x05,
+        0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff, 0xf9,
+        0x00, 0x00, 0x01, 0x33, 0x10, 0x00, 0x00, 0x06, 0xa0, 0xa6, 0x00, 0x0d, 0x45, 0xd0, 0x00,
+        0x6b, 0x0d, 0x30, 0x00, 0x11, 0x02, 0x00, 0x79, 0x00, 0x00, 0x88, 0xac, 0x00, 0x00, 0xba,
+        0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00, 0xba, 0xac, 0x00, 0x00,
+        0xca, 0x8e, 0x00, 0x01, 0xea, 0x4f, 0x71, 0x2b, 0xea, 0x09, 0xff, 0xf7, 0xba, 0x00, 0x13,
+        0x10, 0x00, 0x6a, 0x00, 0x00, 0x03, 0xa3, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00,
+        0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00,
+        0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x9f, 0x00, 0x00, 0x04, 0xf5, 0x8f, 0x10, 0x00,
+        0x05, 0xf4, 0x5f, 0x50, 0x00, 0x09, 0xf1, 0x0d, 0xe5, 0x12, 0x7f, 0x90, 0x02, 0xbf, 0xff,
+        0xf9, 0x00, 0x00, 0x02, 0xf4, 0x10, 0x00, 0x00, 0x06, 0xb0, 0x00, 0x00, 0x00, 0x03, 0xee,
+        0x70, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x79, 0x00, 0x00, 0x88, 0x0a, 0xc0, 0x00, 0x0b,
+        0xa0, 0xac, 0x00, 0x00, 0xba, 0x0a, 0xc0, 0x00, 0x0b, 0xa0, 0xac, 0x00, 0x00, 0xba, 0x0a,
+        0xc0, 0x00, 0x0c, 0xa0, 0x8e, 0x00, 0x01, 0xea, 0x04, 0xf7, 0x12, 0xbe, 0xa0, 0x09, 0xff,
+        0xf7, 0xba, 0x00, 0x01, 0x31, 0x09, 0x50, 0x00, 0x00, 0x01, 0xf1, 0x00, 0x00, 0x00, 0x0c,
+        0xec, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x3d, 0xc1, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0xd4, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x02, 0x10, 0x00, 0x00,
+        0x4a, 0x20, 0x00, 0x1a, 0x90, 0x00, 0x04, 0xa2, 0x3f, 0x60, 0x00, 0x4f, 0xf1, 0x00, 0x09,
+        0xf0, 0x0e, 0xa0, 0x00, 0x8c, 0xe5, 0x00, 0x0d, 0xb0, 0x0a, 0xe0, 0x00, 0xb8, 0xa9, 0x00,
+        0x1f, 0x80, 0x06, 0xf2, 0x01, 0xf4, 0x7d, 0x00, 0x5f, 0x40, 0x03, 0xf6, 0x04, 0xf1, 0x3f,
+        0x10, 0x9f, 0x00, 0x00, 0xea, 0x08, 0xc0, 0x0e, 0x50, 0xdb, 0x00, 0x00, 0xae, 0x0b, 0x80,
+        0x0b, 0x91, 0xf7, 0x00, 0x00, 0x6f, 0x3f, 0x40, 0x07, 0xc5, 0xf4, 0x00, 0x00, 0x3f, 0xaf,
+        0x10, 0x03, 0xfa, 0xf0, 0x00, 0x00, 0x0e, 0xfc, 0x00, 0x00, 0xef, 0xb0, 0x00, 0x00, 0x0a,
+        0xf8, 0x00, 0x00, 0xbf, 0x70, 0x00, 0x00, 0x00, 0x04, 0xd5, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0xd8, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x98, 0x07, 0xa0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01,
+        0x00, 0x00, 0x3b, 0x20, 0x05, 0xb5, 0x00, 0x1b, 0x40, 0xf7, 0x00, 0x9f, 0xb0, 0x05, 0xf1,
+        0x0b, 0xb0, 0x0d, 0xae, 0x00, 0x9c, 0x00, 0x7e, 0x02, 0xf2, 0xf4, 0x0d, 0x80, 0x03, 0xf3,
+        0x6d, 0x0b, 0x82, 0xf5, 0x00, 0x0e, 0x7a, 0x90, 0x7c, 0x6f, 0x10, 0x00, 0xab, 0xe5, 0x03,
+        0xfb, 0xc0, 0x00, 0x06, 0xff, 0x10, 0x0e, 0xf8, 0x00, 0x00, 0x3f, 0xc0, 0x00, 0xbf, 0x40,
+        0x00, 0x00, 0x00, 0x3d, 0xc1, 0x00, 0x00, 0x00, 0x1d, 0x47, 0xb0, 0x00, 0x00, 0x01, 0x20,
+        0x02, 0x10, 0x00, 0x89, 0x00, 0x00, 0x01, 0xa6, 0x04, 0xf7, 0x00, 0x00, 0xae, 0x10, 0x09,
+        0xf2, 0x00, 0x5f, 0x50, 0x00, 0x1d, 0xc0, 0x1e, 0xa0, 0x00, 0x00, 0x3f, 0x7a, 0xe1, 0x00,
+        0x00, 0x00, 0x8f, 0xf5, 0x00, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0,
+        0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00,
+        0xea, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x02, 0xd8, 0x00, 0x00, 0x00,
+        0xb9, 0xe3, 0x00, 0x00, 0x6b, 0x04, 0xc0, 0x00, 0x01, 0x00, 0x01, 0x00, 0x4b, 0x20, 0x00,
+        0x08, 0x91, 0xe7, 0x00, 0x01, 0xe7, 0x09, 0xd0, 0x00, 0x6f, 0x20, 0x3f, 0x40, 0x0c, 0xa0,
+        0x00, 0xca, 0x03, 0xf4, 0x00, 0x05, 0xf2, 0x9d, 0x00, 0x00, 0x0e, 0x8e, 0x70, 0x00, 0x00,
+        0x8f, 0xf1, 0x00, 0x00, 0x02, 0xfa, 0x00, 0x00, 0x00, 0x4f, 0x40, 0x00, 0x00, 0x0b, 0xd0,
+        0x00, 0x00, 0xae, 0xf4, 0x00, 0x00, 0x04, 0x52, 0x00, 0x00, 0x00, 0x00, 0x04, 0x91, 0x39,
+        0x20, 0x00, 0x00, 0x6f, 0x25, 0xf3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x89, 0x00,
+        0x00, 0x01, 0xa6, 0x04, 0xf7, 0x00, 0x00, 0xae, 0x10, 0x09, 0xf2, 0x00, 0x5f, 0x50, 0x00,
+        0x1d, 0xc0, 0x1e, 0xa0, 0x00, 0x00, 0x3f, 0x7a, 0xe1, 0x00, 0x00, 0x00, 0x8f, 0xf5, 0x00,
+        0x00, 0x00, 0x00, 0xeb, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea,
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x00,
+        0x0e, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x09, 0x90, 0x00, 0x00, 0x00, 0x06, 0xc1, 0x00, 0x00,
+        0x00, 0x00, 0x31, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xaa, 0xa1, 0x1a, 0xaa, 0xaa, 0xaa,
+        0xfe, 0x10, 0x00, 0x00, 0x00, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x6f, 0x70, 0x00, 0x00, 0x00,
+        0x3f, 0xa0, 0x00, 0x00, 0x00, 0x2e, 0xc1, 0x00, 0x00, 0x00, 0x0c, 0xe2, 0x00, 0x00, 0x00,
+        0x09, 0xf4, 0x00, 0x00, 0x00, 0x06, 0xf7, 0x00, 0x00, 0x00, 0x04, 0xf9, 0x00, 0x00, 0x00,
+        0x02, 0xee, 0x55, 0x55, 0x55, 0x51, 0x4f, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x00, 0x09,
+        0xa0, 0x00, 0x00, 0x6d, 0x10, 0x00, 0x03, 0xe3, 0x00, 0x00, 0x01, 0x10, 0x00, 0x1b, 0xbb,
+        0xbb, 0xb8, 0x16, 0x66, 0x68, 0xf9, 0x00, 0x00, 0x1c, 0xd1, 0x00, 0x00, 0xbe, 0x20, 0x00,
+        0x09, 0xf3, 0x00, 0x00, 0x6f, 0x50, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x2e, 0xb2, 0x22, 0x22,
+        0x5f, 0xff, 0xff, 0xfb, 0x00, 0x00, 0x39, 0x30, 0x00, 0x00, 0x00, 0x05, 0xf4, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xaa, 0xa1, 0x1a, 0xaa, 0xaa, 0xaa,
+        0xfe, 0x10, 0x00, 0x00, 0x00, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x6f, 0x70, 0x00, 0x00, 0x00,
+        0x3f, 0xa0, 0x00, 0x00, 0x00, 0x2e, 0xc1, 0x00, 0x00, 0x00, 0x0c, 0xe2, 0x00, 0x00, 0x00,
+        0x09, 0xf4, 0x00, 0x00, 0x00, 0x06, 0xf7, 0x00, 0x00, 0x00, 0x04, 0xf9, 0x00, 0x00, 0x00,
+        0x02, 0xee, 0x55, 0x55, 0x55, 0x51, 0x4f, 0xff, 0xff, 0xff, 0xff, 0x40, 0x00, 0x01, 0x20,
+        0x00, 0x00, 0x08, 0xd0, 0x00, 0x00, 0x06, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1b, 0xbb,
+        0xbb, 0xb8, 0x16, 0x66, 0x68, 0xf9, 0x00, 0x00, 0x1c, 0xd1, 0x00, 0x00, 0xbe, 0x20, 0x00,
+        0x09, 0xf3, 0x00, 0x00, 0x6f, 0x50, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x2e, 0xb2, 0x22, 0x22,
+        0x5f, 0xff, 0xff, 0xfb, 0x00, 0x07, 0x70, 0x87, 0x00, 0x00, 0x00, 0x0c, 0xbc, 0x00, 0x00,
+        0x00, 0x00, 0x13, 0x10, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xaa, 0xa1, 0x1a, 0xaa, 0xaa, 0xaa,
+        0xfe, 0x10, 0x00, 0x00, 0x00, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x6f, 0x70, 0x00, 0x00, 0x00,
+        0x3f, 0xa0, 0x00, 0x00, 0x00, 0x2e, 0xc1, 0x00, 0x00, 0x00, 0x0c, 0xe2, 0x00, 0x00, 0x00,
+        0x09, 0xf4, 0x00, 0x00, 0x00, 0x06, 0xf7, 0x00, 0x00, 0x00, 0x04, 0xf9, 0x00, 0x00, 0x00,
+        0x02, 0xee, 0x55, 0x55, 0x55, 0x51, 0x4f, 0xff, 0xff, 0xff, 0xff, 0x40, 0x01, 0xb2, 0x08,
+        0x50, 0x00, 0x7b, 0x5c, 0x00, 0x00, 0x0c, 0xf3, 0x00, 0x00, 0x01, 0x20, 0x00, 0x1b, 0xbb,
+        0xbb, 0xb8, 0x16, 0x66, 0x68, 0xf9, 0x00, 0x00, 0x1c, 0xd1, 0x00, 0x00, 0xbe, 0x20, 0x00,
+        0x09, 0xf3, 0x00, 0x00, 0x6f, 0x50, 0x00, 0x05, 0xf7, 0x00, 0x00, 0x2e, 0xb2, 0x22, 0x22,
+        0x5f, 0xff, 0xff, 0xfb, 0x00, 0x01, 0x22, 0x00, 0x7f, 0xfe, 0x02, 0xf7, 0x11, 0x04, 0xf3,
+        0x00, 0x7c, 0xf3, 0x00, 0x38, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3,
+        0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x04, 0xf3, 0x00, 0x3e, 0xee,
+        0xee, 0xe3, 0x13, 0x33, 0x33, 0x31, 0x3e, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xe3, 0x13,
+        0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x31, 0x05, 0x61, 0xe5, 0x7f, 0x1a, 0xf0, 0x23, 0x00,
+        0x0a, 0x70, 0xf9, 0x3f, 0x37, 0xa0, 0x00, 0x00, 0x0f, 0xa1, 0xf8, 0x4e, 0x17, 0x80, 0x6a,
+        0x08, 0xf1, 0x2f, 0x40, 0x98, 0x00, 0x00, 0x05, 0x60, 0x38, 0x1e, 0x50, 0xb8, 0x7f, 0x14,
+        0xf4, 0xaf, 0x07, 0xf3, 0x23, 0x01, 0x31, 0x0a, 0x60, 0x88, 0x0f, 0x90, 0xcc, 0x3f, 0x31,
+        0xf6, 0x7b, 0x04, 0xd0, 0x21, 0x01, 0x20, 0x0f, 0xa0, 0xcd, 0x1f, 0x80, 0xdb, 0x4e, 0x11,
+        0xf4, 0x78, 0x04, 0xa0, 0x00, 0x07, 0x70, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0,
+        0x00, 0x36, 0x6c, 0xc6, 0x63, 0x7c, 0xce, 0xec, 0xc7, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a,
+        0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00,
+        0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x06, 0x60, 0x00,
+        0x00, 0x07, 0x70, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x36, 0x6c, 0xc6,
+        0x63, 0x7c, 0xce, 0xec, 0xc7, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x0a,
+        0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x8e, 0xef, 0xfe, 0xe8, 0x24, 0x4c, 0xc4, 0x42, 0x00,
+        0x0a, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x00, 0x00, 0x06, 0x60, 0x00, 0x00, 0x32, 0x00, 0x1d,
+        0xff, 0x60, 0x8f, 0xff, 0xe0, 0x8f, 0xff, 0xe0, 0x2e, 0xff, 0x70, 0x01, 0x53, 0x00, 0x2f,
+        0x70, 0x00, 0xcc, 0x00, 0x07, 0xf2, 0x2f, 0x80, 0x00, 0xcc, 0x00, 0x08, 0xf2, 0x00, 0x01,
+        0x8c, 0xda, 0x30, 0x00, 0x2d, 0xd7, 0x6a, 0xf1, 0x00, 0xbe, 0x20, 0x00, 0x51, 0x02, 0xf7,
+        0x00, 0x00, 0x00, 0x6b, 0xfb, 0x99, 0x95, 0x00, 0x5a, 0xf6, 0x55, 0x52, 0x00, 0x08, 0xf2,
+        0x00, 0x00, 0x00, 0xbe, 0xfe, 0xee, 0x50, 0x00, 0x03, 0xf6, 0x00, 0x00, 0x00, 0x00, 0xdc,
+        0x00, 0x00, 0x11, 0x00, 0x5f, 0xa2, 0x14, 0xd2, 0x00, 0x05, 0xdf, 0xfe, 0x80, 0x00, 0x00,
+        0x02, 0x31, 0x00, 0x00, 0x00, 0x00, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0xcf, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xcf, 0xff, 0x50, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0xb8, 0x42, 0x4a, 0x50, 0x00, 0x00, 0x00, 0x01, 0xc7, 0x27, 0x84, 0x0a, 0x50, 0x00,
+        0x00, 0x01, 0xcf, 0xdf, 0xff, 0xf1, 0x5f, 0x50, 0x00, 0x01, 0xcf, 0xff, 0xff, 0xfe, 0x16,
+        0xff, 0x50, 0x01, 0xcf, 0xff, 0xff, 0xff, 0x51, 0xdf, 0xff, 0x50, 0x7f, 0xff, 0xff, 0xff,
+        0x51, 0xcf, 0xff, 0xfd, 0x00, 0x9f, 0xff, 0xff, 0xa0, 0xbf, 0xff, 0xfd, 0x20, 0x00, 0x9f,
+        0xff, 0xf7, 0x1f, 0xff, 0xfd, 0x20, 0x00, 0x00, 0x9f, 0xff, 0x83, 0xff, 0xfd, 0x20, 0x00,
+        0x00, 0x00, 0x9f, 0xff, 0xff, 0xfd, 0x20, 0x00, 0x00, 0x00, 0x00, 0x9f, 0x60, 0xfd, 0x20,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x96, 0x0d, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9d,
+        0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+    ],
+};
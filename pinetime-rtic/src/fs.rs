@@ -0,0 +1,1411 @@
+//! A small power-loss resilient file system for NOR flash, in the spirit of
+//! littlefs.
+//!
+//! The file system is made of blocks of 4 KiB (a flash sector):
+//!
+//! - Every directory is a log of entries in a pair of blocks. The root
+//!   directory uses the blocks 0 and 1. Adding or changing an entry appends a
+//!   record, removing an entry clears the "live" flag of its record
+//!   (programming can clear bits without erasing). When the active block of
+//!   a pair is full, the live records are copied to the other block, which
+//!   becomes the active one once its header with a higher revision is
+//!   written.
+//! - Files are stored in blocks that are linked backwards. The block with
+//!   index `i` starts with pointers to the blocks `i - 1`, `i - 2`, `i - 4`,
+//!   …, `i - 2^ctz(i)`, like the CTZ skip lists of littlefs. A file entry
+//!   points to the last block, from which every block of the file can be
+//!   found in O(log n) steps.
+//!
+//! New data is only written to erased space, and it is only referenced by a
+//! directory entry once it is complete. Records and headers are protected by
+//! a CRC. If power is lost, an operation has therefore either happened
+//! completely or not at all. There is no allocation table: free blocks are
+//! found by walking the directories and files.
+//!
+//! Files are written sequentially, either replacing their contents (which
+//! takes effect when the file is closed) or appending to them. Only one file
+//! should be written at a time.
+//!
+//! All operations take the block device as an argument, so that it only has
+//! to be borrowed while the file system is used.
+
+use crate::kvstore::crc16;
+
+pub mod ram;
+
+/// Size of a block, the smallest unit that can be erased.
+pub const BLOCK_SIZE: u32 = 4096;
+
+/// Maximum number of blocks of a device.
+pub const MAX_BLOCKS: u32 = 1024;
+
+/// Maximum length of a file or directory name in bytes.
+pub const MAX_NAME_LEN: usize = 32;
+
+/// Maximum depth of nested directories.
+pub const MAX_DEPTH: usize = 8;
+
+const MAGIC: [u8; 4] = *b"PTFS";
+const VERSION: u16 = 1;
+const HEADER_LEN: u32 = 12;
+
+/// The blocks of the root directory.
+const ROOT: [u32; 2] = [0, 1];
+
+// Record: flag (1) | kind (1) | name length (1) | name | a (4) | b (4) | CRC (2)
+//
+// Files: a = last block, b = size. Directories: a, b = block pair.
+const RECORD_OVERHEAD: u32 = 13;
+const MAX_RECORD_LEN: usize = RECORD_OVERHEAD as usize + MAX_NAME_LEN;
+const LIVE: u8 = 0xFF;
+const KIND_FILE: u8 = 1;
+const KIND_DIR: u8 = 2;
+
+/// Last block of an empty file.
+const NO_BLOCK: u32 = 0xFFFF_FFFF;
+
+type Bitmap = [u32; (MAX_BLOCKS / 32) as usize];
+
+/// NOR flash (or anything behaving like it) divided into blocks of
+/// `BLOCK_SIZE`.
+pub trait BlockDevice {
+    type Error;
+
+    /// Number of blocks.
+    fn block_count(&self) -> u32;
+
+    /// Read data starting at `offset` within `block`.
+    fn read(&mut self, block: u32, offset: u32, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Program data. Programming can only clear bits.
+    fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Erase a block (set all bytes to 0xFF).
+    fn erase(&mut self, block: u32) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// Accessing the block device failed.
+    Device(E),
+    /// No file system was found.
+    NotFormatted,
+    /// The file system is damaged, or was written by an incompatible version.
+    Corrupt,
+    /// The device has fewer than two or more than `MAX_BLOCKS` blocks.
+    UnsupportedDevice,
+    NotFound,
+    AlreadyExists,
+    NotADirectory,
+    IsADirectory,
+    DirectoryNotEmpty,
+    /// A name is empty or too long, or directories are nested too deep.
+    InvalidPath,
+    /// No free blocks are left, or the directory is full.
+    NoSpace,
+    /// The operation is not allowed in the mode the file was opened with.
+    BadMode,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Device(e)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    File,
+    Dir,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub kind: Kind,
+    /// Size in bytes, 0 for directories.
+    pub size: u32,
+}
+
+/// How a file is opened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Read,
+    /// Replace the contents. The file is created if it doesn't exist.
+    Write,
+    /// Append to the contents. The file is created if it doesn't exist.
+    Append,
+}
+
+/// A mounted file system.
+pub struct Fs {
+    block_count: u32,
+    /// Blocks that are in use, as of the last walk through the file system,
+    /// and blocks that were allocated since.
+    used: Bitmap,
+    /// Blocks that were allocated since the last commit. They are not
+    /// referenced yet, but must not be handed out again.
+    fresh: Bitmap,
+    /// Where to look for the next free block.
+    cursor: u32,
+}
+
+impl Fs {
+    /// Mount the file system.
+    pub fn mount<D: BlockDevice>(dev: &mut D) -> Result<Self, Error<D::Error>> {
+        let block_count = dev.block_count();
+        if !(2..=MAX_BLOCKS).contains(&block_count) {
+            return Err(Error::UnsupportedDevice);
+        }
+        let root = open_dir(dev, ROOT)?;
+        let mut fs = Self {
+            block_count,
+            used: [0; (MAX_BLOCKS / 32) as usize],
+            fresh: [0; (MAX_BLOCKS / 32) as usize],
+            cursor: 0,
+        };
+        // Clean up after an update that was interrupted by a power loss
+        fs.used = fs.walk(dev, true)?;
+        // Start allocating somewhere else after every mount, so that blocks
+        // wear evenly
+        fs.cursor = root.revision.wrapping_add(end_of_log(dev, &root)?) % block_count;
+        Ok(fs)
+    }
+
+    /// Create an empty file system. Everything stored on the device is lost.
+    pub fn format<D: BlockDevice>(dev: &mut D) -> Result<(), Error<D::Error>> {
+        if !(2..=MAX_BLOCKS).contains(&dev.block_count()) {
+            return Err(Error::UnsupportedDevice);
+        }
+        dev.erase(ROOT[0])?;
+        dev.erase(ROOT[1])?;
+        dev.program(ROOT[0], 0, &encode_header(1))?;
+        Ok(())
+    }
+
+    /// Mount the file system, formatting the device if there is none.
+    pub fn mount_or_format<D: BlockDevice>(dev: &mut D) -> Result<Self, Error<D::Error>> {
+        match Self::mount(dev) {
+            Err(Error::NotFormatted) => {
+                Self::format(dev)?;
+                Self::mount(dev)
+            }
+            result => result,
+        }
+    }
+
+    /// Return the number of free bytes.
+    pub fn free_space<D: BlockDevice>(&mut self, dev: &mut D) -> Result<u32, Error<D::Error>> {
+        self.used = self.walk(dev, false)?;
+        let used: u32 = self.used.iter().map(|word| word.count_ones()).sum();
+        Ok((self.block_count - used) * BLOCK_SIZE)
+    }
+
+    /// Return the kind and size of a file or directory.
+    pub fn metadata<D: BlockDevice>(
+        &self,
+        dev: &mut D,
+        path: &str,
+    ) -> Result<Metadata, Error<D::Error>> {
+        if components(path).next().is_none() {
+            return Ok(Metadata {
+                kind: Kind::Dir,
+                size: 0,
+            });
+        }
+        let (dir, name) = parent(dev, path)?;
+        let record = lookup(dev, &dir, &name)?.ok_or(Error::NotFound)?;
+        Ok(record.metadata())
+    }
+
+    /// Call `f` with the name and metadata of every entry of a directory.
+    pub fn read_dir<D: BlockDevice>(
+        &self,
+        dev: &mut D,
+        path: &str,
+        mut f: impl FnMut(&str, Metadata),
+    ) -> Result<(), Error<D::Error>> {
+        let dir = resolve(dev, components(path))?;
+        let mut offset = HEADER_LEN;
+        while let Some(record) = read_record(dev, dir.block(), offset)? {
+            offset += record.len();
+            if record.live {
+                f(record.name.as_str(), record.metadata());
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a directory. The parent directory must exist.
+    pub fn create_dir<D: BlockDevice>(
+        &mut self,
+        dev: &mut D,
+        path: &str,
+    ) -> Result<(), Error<D::Error>> {
+        if components(path).count() > MAX_DEPTH {
+            return Err(Error::InvalidPath);
+        }
+        let (parent, name) = parent(dev, path)?;
+        if lookup(dev, &parent, &name)?.is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        let first = self.alloc(dev)?;
+        let second = self.alloc(dev)?;
+        dev.program(first, 0, &encode_header(1))?;
+        commit(dev, &parent, Some((KIND_DIR, &name, first, second)), None)?;
+        self.fresh = [0; (MAX_BLOCKS / 32) as usize];
+        Ok(())
+    }
+
+    /// Remove a file or an empty directory.
+    pub fn remove<D: BlockDevice>(
+        &mut self,
+        dev: &mut D,
+        path: &str,
+    ) -> Result<(), Error<D::Error>> {
+        let (parent, name) = parent(dev, path)?;
+        let record = lookup(dev, &parent, &name)?.ok_or(Error::NotFound)?;
+        if record.kind == Kind::Dir {
+            let dir = open_subdir(dev, [record.a, record.b])?;
+            let mut offset = HEADER_LEN;
+            while let Some(entry) = read_record(dev, dir.block(), offset)? {
+                if entry.live {
+                    return Err(Error::DirectoryNotEmpty);
+                }
+                offset += entry.len();
+            }
+        }
+        // The blocks are reclaimed by the next walk
+        commit(dev, &parent, None, Some(&record))
+    }
+
+    /// Open a file.
+    pub fn open<D: BlockDevice>(
+        &mut self,
+        dev: &mut D,
+        path: &str,
+        mode: Mode,
+    ) -> Result<File, Error<D::Error>> {
+        let (parent, name) = parent(dev, path)?;
+        let (head, size) = match lookup(dev, &parent, &name)? {
+            Some(record) if record.kind == Kind::Dir => return Err(Error::IsADirectory),
+            Some(_) if mode == Mode::Write => (NO_BLOCK, 0),
+            Some(record) => (record.a, record.b),
+            None if mode == Mode::Read => return Err(Error::NotFound),
+            None => {
+                commit(dev, &parent, Some((KIND_FILE, &name, NO_BLOCK, 0)), None)?;
+                (NO_BLOCK, 0)
+            }
+        };
+        Ok(File {
+            dir: parent.pair,
+            name,
+            mode,
+            head,
+            size,
+            pos: if mode == Mode::Append { size } else { 0 },
+            // Replacing the contents must be committed even if nothing is
+            // written
+            dirty: mode == Mode::Write,
+            tail_erased: false,
+            cached: None,
+        })
+    }
+
+    /// Return an erased block that is not in use.
+    fn alloc<D: BlockDevice>(&mut self, dev: &mut D) -> Result<u32, Error<D::Error>> {
+        for attempt in 0..2 {
+            for i in 0..self.block_count {
+                let block = (self.cursor + i) % self.block_count;
+                if !is_set(&self.used, block) {
+                    set(&mut self.used, block);
+                    set(&mut self.fresh, block);
+                    self.cursor = (block + 1) % self.block_count;
+                    dev.erase(block)?;
+                    return Ok(block);
+                }
+            }
+            if attempt == 0 {
+                // Reclaim blocks that are no longer referenced
+                self.used = self.walk(dev, false)?;
+            }
+        }
+        Err(Error::NoSpace)
+    }
+
+    /// Walk through the file system and return the blocks in use, including
+    /// the fresh ones. If `repair` is set, older duplicates of entries are
+    /// removed, which are left behind if power is lost during an update.
+    fn walk<D: BlockDevice>(&self, dev: &mut D, repair: bool) -> Result<Bitmap, Error<D::Error>> {
+        let mut used = self.fresh;
+        for block in ROOT.iter() {
+            set(&mut used, *block);
+        }
+        walk_dir(dev, &mut used, ROOT, 0, repair)?;
+        Ok(used)
+    }
+}
+
+/// An open file.
+pub struct File {
+    /// The directory containing the file.
+    dir: [u32; 2],
+    name: Name,
+    mode: Mode,
+
+    /// The last block, or `NO_BLOCK` if the file is empty.
+    head: u32,
+    size: u32,
+    /// Position for reading.
+    pos: u32,
+
+    /// Whether there are changes that were not committed.
+    dirty: bool,
+    /// Whether the last block is known to be erased behind the data.
+    tail_erased: bool,
+    /// Index and number of the block that was read last.
+    cached: Option<(u32, u32)>,
+}
+
+impl File {
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Set the position for reading.
+    pub fn seek(&mut self, pos: u32) {
+        self.pos = pos.min(self.size);
+    }
+
+    /// Read from the current position. Returns the number of bytes read,
+    /// which is only less than the length of `buf` at the end of the file.
+    pub fn read<D: BlockDevice>(
+        &mut self,
+        dev: &mut D,
+        buf: &mut [u8],
+    ) -> Result<usize, Error<D::Error>> {
+        if self.mode != Mode::Read {
+            return Err(Error::BadMode);
+        }
+        let mut done = 0;
+        while done < buf.len() && self.pos < self.size {
+            let (index, offset) = locate(self.pos);
+            let block = self.block(dev, index)?;
+            let len = (buf.len() - done)
+                .min((BLOCK_SIZE - offset) as usize)
+                .min((self.size - self.pos) as usize);
+            dev.read(block, offset, &mut buf[done..done + len])?;
+            done += len;
+            self.pos += len as u32;
+        }
+        Ok(done)
+    }
+
+    /// Append data. It becomes visible once the file is closed.
+    pub fn write<D: BlockDevice>(
+        &mut self,
+        fs: &mut Fs,
+        dev: &mut D,
+        mut data: &[u8],
+    ) -> Result<(), Error<D::Error>> {
+        if self.mode == Mode::Read {
+            return Err(Error::BadMode);
+        }
+        while !data.is_empty() {
+            let (index, offset) = locate(self.size);
+            if index == self.block_count() {
+                self.add_block(fs, dev, index)?;
+            } else if !self.tail_erased {
+                // The last block may contain leftovers of an interrupted
+                // write. If so, continue in a copy.
+                if !is_erased(dev, self.head, offset)? {
+                    self.copy_head(fs, dev, offset)?;
+                }
+                self.tail_erased = true;
+            }
+            let len = data.len().min((BLOCK_SIZE - offset) as usize);
+            dev.program(self.head, offset, &data[..len])?;
+            self.size += len as u32;
+            self.dirty = true;
+            data = &data[len..];
+        }
+        Ok(())
+    }
+
+    /// Commit the changes.
+    pub fn close<D: BlockDevice>(
+        mut self,
+        fs: &mut Fs,
+        dev: &mut D,
+    ) -> Result<(), Error<D::Error>> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let dir = open_subdir(dev, self.dir)?;
+        let old = lookup(dev, &dir, &self.name)?;
+        commit(
+            dev,
+            &dir,
+            Some((KIND_FILE, &self.name, self.head, self.size)),
+            old.as_ref(),
+        )?;
+        self.dirty = false;
+        fs.fresh = [0; (MAX_BLOCKS / 32) as usize];
+        Ok(())
+    }
+
+    /// Number of blocks of the file.
+    fn block_count(&self) -> u32 {
+        if self.size == 0 {
+            0
+        } else {
+            locate(self.size - 1).0 + 1
+        }
+    }
+
+    /// Return the number of the block with the specified index.
+    fn block<D: BlockDevice>(&mut self, dev: &mut D, index: u32) -> Result<u32, Error<D::Error>> {
+        match self.cached {
+            Some((cached, block)) if cached == index => Ok(block),
+            _ => {
+                let mut block = self.head;
+                let mut current = self.block_count() - 1;
+                while current > index {
+                    // Take the largest step that doesn't skip the target
+                    let step = current
+                        .trailing_zeros()
+                        .min(31 - (current - index).leading_zeros());
+                    block = read_pointer(dev, block, step)?;
+                    current -= 1 << step;
+                }
+                self.cached = Some((index, block));
+                Ok(block)
+            }
+        }
+    }
+
+    /// Allocate the block with the specified index and write its pointers.
+    fn add_block<D: BlockDevice>(
+        &mut self,
+        fs: &mut Fs,
+        dev: &mut D,
+        index: u32,
+    ) -> Result<(), Error<D::Error>> {
+        let block = fs.alloc(dev)?;
+        if index > 0 {
+            let mut pointers = [0; 4 * 32];
+            let mut pointer = self.head;
+            for level in 0..=index.trailing_zeros() {
+                if level > 0 {
+                    // The block 2^(level - 1) back points 2^level back
+                    pointer = read_pointer(dev, pointer, level - 1)?;
+                }
+                let start = 4 * level as usize;
+                pointers[start..start + 4].copy_from_slice(&pointer.to_le_bytes());
+            }
+            dev.program(block, 0, &pointers[..header_len(index) as usize])?;
+        }
+        self.head = block;
+        self.tail_erased = true;
+        Ok(())
+    }
+
+    /// Replace the last block by a copy of its first `len` bytes.
+    fn copy_head<D: BlockDevice>(
+        &mut self,
+        fs: &mut Fs,
+        dev: &mut D,
+        len: u32,
+    ) -> Result<(), Error<D::Error>> {
+        let block = fs.alloc(dev)?;
+        let mut buf = [0; 64];
+        let mut offset = 0;
+        while offset < len {
+            let chunk = &mut buf[..(len - offset).min(64) as usize];
+            dev.read(self.head, offset, chunk)?;
+            dev.program(block, offset, chunk)?;
+            offset += chunk.len() as u32;
+        }
+        self.head = block;
+        self.cached = None;
+        Ok(())
+    }
+}
+
+/// A file or directory name.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Name {
+    bytes: [u8; MAX_NAME_LEN],
+    len: u8,
+}
+
+impl Name {
+    fn new(name: &str) -> Option<Self> {
+        if name.is_empty() || name.len() > MAX_NAME_LEN || name == "." || name == ".." {
+            return None;
+        }
+        let mut bytes = [0; MAX_NAME_LEN];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Some(Self {
+            bytes,
+            len: name.len() as u8,
+        })
+    }
+
+    fn as_str(&self) -> &str {
+        // Names are only created from valid strings
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap()
+    }
+}
+
+/// An entry in the log of a directory.
+#[derive(Clone, Copy)]
+struct Record {
+    offset: u32,
+    live: bool,
+    kind: Kind,
+    name: Name,
+    a: u32,
+    b: u32,
+}
+
+impl Record {
+    fn len(&self) -> u32 {
+        RECORD_OVERHEAD + self.name.len as u32
+    }
+
+    fn metadata(&self) -> Metadata {
+        match self.kind {
+            Kind::File => Metadata {
+                kind: Kind::File,
+                size: self.b,
+            },
+            Kind::Dir => Metadata {
+                kind: Kind::Dir,
+                size: 0,
+            },
+        }
+    }
+}
+
+/// The state of a directory.
+#[derive(Clone, Copy)]
+struct Dir {
+    pair: [u32; 2],
+    /// Index of the active block in the pair.
+    active: usize,
+    revision: u32,
+}
+
+impl Dir {
+    fn block(&self) -> u32 {
+        self.pair[self.active]
+    }
+}
+
+fn components(path: &str) -> impl DoubleEndedIterator<Item = &str> + '_ {
+    path.split('/').filter(|component| !component.is_empty())
+}
+
+/// Open the directory with the specified path.
+fn resolve<'a, D: BlockDevice>(
+    dev: &mut D,
+    components: impl Iterator<Item = &'a str>,
+) -> Result<Dir, Error<D::Error>> {
+    let mut dir = open_dir(dev, ROOT)?;
+    for component in components {
+        let name = Name::new(component).ok_or(Error::InvalidPath)?;
+        dir = match lookup(dev, &dir, &name)? {
+            Some(record) if record.kind == Kind::Dir => open_subdir(dev, [record.a, record.b])?,
+            Some(_) => return Err(Error::NotADirectory),
+            None => return Err(Error::NotFound),
+        };
+    }
+    Ok(dir)
+}
+
+/// Open the parent directory of a path and return it with the last name.
+fn parent<D: BlockDevice>(dev: &mut D, path: &str) -> Result<(Dir, Name), Error<D::Error>> {
+    let mut components = components(path);
+    let name = components.next_back().ok_or(Error::InvalidPath)?;
+    let name = Name::new(name).ok_or(Error::InvalidPath)?;
+    Ok((resolve(dev, components)?, name))
+}
+
+/// Open a directory. Returns `Error::NotFormatted` if neither block of the
+/// pair has a valid header.
+fn open_dir<D: BlockDevice>(dev: &mut D, pair: [u32; 2]) -> Result<Dir, Error<D::Error>> {
+    let mut found: Option<Dir> = None;
+    for (active, block) in pair.iter().enumerate() {
+        if *block >= dev.block_count() {
+            return Err(Error::Corrupt);
+        }
+        let mut header = [0; HEADER_LEN as usize];
+        dev.read(*block, 0, &mut header)?;
+        let revision = match parse_header(&header) {
+            Some(Ok(revision)) => revision,
+            Some(Err(())) => return Err(Error::Corrupt),
+            None => continue,
+        };
+        match found {
+            Some(dir) if dir.revision >= revision => {}
+            _ => {
+                found = Some(Dir {
+                    pair,
+                    active,
+                    revision,
+                })
+            }
+        }
+    }
+    found.ok_or(Error::NotFormatted)
+}
+
+fn open_subdir<D: BlockDevice>(dev: &mut D, pair: [u32; 2]) -> Result<Dir, Error<D::Error>> {
+    open_dir(dev, pair).map_err(|e| match e {
+        Error::NotFormatted => Error::Corrupt,
+        e => e,
+    })
+}
+
+/// Read the record at `offset`. Returns `None` at the end of the log.
+fn read_record<D: BlockDevice>(
+    dev: &mut D,
+    block: u32,
+    offset: u32,
+) -> Result<Option<Record>, D::Error> {
+    if offset + RECORD_OVERHEAD > BLOCK_SIZE {
+        return Ok(None);
+    }
+    // Read as much as the longest record in one go
+    let mut buf = [0; MAX_RECORD_LEN];
+    let available = (BLOCK_SIZE - offset).min(MAX_RECORD_LEN as u32) as usize;
+    dev.read(block, offset, &mut buf[..available])?;
+    let kind = match buf[1] {
+        KIND_FILE => Kind::File,
+        KIND_DIR => Kind::Dir,
+        // Erased, or a partially written record
+        _ => return Ok(None),
+    };
+    let name_len = buf[2] as usize;
+    let len = RECORD_OVERHEAD as usize + name_len;
+    if name_len > MAX_NAME_LEN || len > available {
+        return Ok(None);
+    }
+    let crc = u16::from_le_bytes([buf[len - 2], buf[len - 1]]);
+    if crc16(&buf[1..len - 2]) != crc {
+        return Ok(None);
+    }
+    let name = match core::str::from_utf8(&buf[3..3 + name_len])
+        .ok()
+        .and_then(Name::new)
+    {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    let field = |start: usize| {
+        u32::from_le_bytes([buf[start], buf[start + 1], buf[start + 2], buf[start + 3]])
+    };
+    Ok(Some(Record {
+        offset,
+        live: buf[0] == LIVE,
+        kind,
+        name,
+        a: field(3 + name_len),
+        b: field(7 + name_len),
+    }))
+}
+
+fn encode_record<'a>(
+    kind: u8,
+    name: &Name,
+    a: u32,
+    b: u32,
+    buf: &'a mut [u8; MAX_RECORD_LEN],
+) -> &'a [u8] {
+    let name_len = name.len as usize;
+    let len = RECORD_OVERHEAD as usize + name_len;
+    buf[0] = LIVE;
+    buf[1] = kind;
+    buf[2] = name.len;
+    buf[3..3 + name_len].copy_from_slice(name.as_str().as_bytes());
+    buf[3 + name_len..7 + name_len].copy_from_slice(&a.to_le_bytes());
+    buf[7 + name_len..11 + name_len].copy_from_slice(&b.to_le_bytes());
+    let crc = crc16(&buf[1..len - 2]);
+    buf[len - 2..len].copy_from_slice(&crc.to_le_bytes());
+    &buf[..len]
+}
+
+/// Return the live entry with the specified name, if any.
+fn lookup<D: BlockDevice>(dev: &mut D, dir: &Dir, name: &Name) -> Result<Option<Record>, D::Error> {
+    lookup_from(dev, dir, HEADER_LEN, name)
+}
+
+/// Return the last live entry with the specified name after `offset`.
+fn lookup_from<D: BlockDevice>(
+    dev: &mut D,
+    dir: &Dir,
+    mut offset: u32,
+    name: &Name,
+) -> Result<Option<Record>, D::Error> {
+    let mut found = None;
+    while let Some(record) = read_record(dev, dir.block(), offset)? {
+        offset += record.len();
+        if record.live && record.name == *name {
+            found = Some(record);
+        }
+    }
+    Ok(found)
+}
+
+/// Return the offset behind the last record of a directory, or `BLOCK_SIZE`
+/// if the space behind it isn't erased, i.e. if a record was only partially
+/// written.
+fn end_of_log<D: BlockDevice>(dev: &mut D, dir: &Dir) -> Result<u32, D::Error> {
+    let mut offset = HEADER_LEN;
+    while let Some(record) = read_record(dev, dir.block(), offset)? {
+        offset += record.len();
+    }
+    Ok(if is_erased(dev, dir.block(), offset)? {
+        offset
+    } else {
+        BLOCK_SIZE
+    })
+}
+
+/// Add an entry to a directory and/or remove the live entry `old`.
+fn commit<D: BlockDevice>(
+    dev: &mut D,
+    dir: &Dir,
+    new: Option<(u8, &Name, u32, u32)>,
+    old: Option<&Record>,
+) -> Result<(), Error<D::Error>> {
+    if let Some((kind, name, a, b)) = new {
+        let mut buf = [0; MAX_RECORD_LEN];
+        let record = encode_record(kind, name, a, b, &mut buf);
+        let end = end_of_log(dev, dir)?;
+        if end + record.len() as u32 > BLOCK_SIZE {
+            return compact(dev, dir, record, name);
+        }
+        dev.program(dir.block(), end, record)?;
+    }
+    // If power is lost before this, the older entry is removed when the
+    // file system is mounted again
+    if let Some(old) = old {
+        dev.program(dir.block(), old.offset, &[0])?;
+    }
+    Ok(())
+}
+
+/// Copy the live entries (except the one named `name`) and `record` to the
+/// other block of the pair, and make it the active one.
+fn compact<D: BlockDevice>(
+    dev: &mut D,
+    dir: &Dir,
+    record: &[u8],
+    name: &Name,
+) -> Result<(), Error<D::Error>> {
+    let copied = |entry: &Record| entry.live && entry.name != *name;
+
+    // Check that everything fits before erasing anything
+    let mut total = HEADER_LEN + record.len() as u32;
+    let mut offset = HEADER_LEN;
+    while let Some(entry) = read_record(dev, dir.block(), offset)? {
+        offset += entry.len();
+        if copied(&entry) {
+            total += entry.len();
+        }
+    }
+    if total > BLOCK_SIZE {
+        return Err(Error::NoSpace);
+    }
+
+    let target = dir.pair[1 - dir.active];
+    dev.erase(target)?;
+    let mut buf = [0; MAX_RECORD_LEN];
+    let mut offset = HEADER_LEN;
+    let mut target_offset = HEADER_LEN;
+    while let Some(entry) = read_record(dev, dir.block(), offset)? {
+        offset += entry.len();
+        if copied(&entry) {
+            let raw = &mut buf[..entry.len() as usize];
+            dev.read(dir.block(), entry.offset, raw)?;
+            dev.program(target, target_offset, raw)?;
+            target_offset += entry.len();
+        }
+    }
+    dev.program(target, target_offset, record)?;
+    // Writing the header makes the block the active one
+    dev.program(target, 0, &encode_header(dir.revision.wrapping_add(1)))?;
+    Ok(())
+}
+
+/// Mark the blocks used by a directory and everything in it.
+fn walk_dir<D: BlockDevice>(
+    dev: &mut D,
+    used: &mut Bitmap,
+    pair: [u32; 2],
+    depth: usize,
+    repair: bool,
+) -> Result<(), Error<D::Error>> {
+    if depth > MAX_DEPTH {
+        return Err(Error::Corrupt);
+    }
+    let dir = open_subdir(dev, pair)?;
+    let mut offset = HEADER_LEN;
+    while let Some(record) = read_record(dev, dir.block(), offset)? {
+        offset += record.len();
+        if !record.live {
+            continue;
+        }
+        if repair && lookup_from(dev, &dir, offset, &record.name)?.is_some() {
+            dev.program(dir.block(), record.offset, &[0])?;
+            continue;
+        }
+        match record.kind {
+            Kind::File => walk_file(dev, used, record.a, record.b)?,
+            Kind::Dir => {
+                mark(dev, used, record.a)?;
+                mark(dev, used, record.b)?;
+                walk_dir(dev, used, [record.a, record.b], depth + 1, repair)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Mark the blocks of a file.
+fn walk_file<D: BlockDevice>(
+    dev: &mut D,
+    used: &mut Bitmap,
+    head: u32,
+    size: u32,
+) -> Result<(), Error<D::Error>> {
+    if size == 0 {
+        return Ok(());
+    }
+    let mut block = head;
+    for index in (0..=locate(size - 1).0).rev() {
+        mark(dev, used, block)?;
+        if index > 0 {
+            block = read_pointer(dev, block, 0)?;
+        }
+    }
+    Ok(())
+}
+
+fn mark<D: BlockDevice>(dev: &D, used: &mut Bitmap, block: u32) -> Result<(), Error<D::Error>> {
+    if block >= dev.block_count() {
+        return Err(Error::Corrupt);
+    }
+    set(used, block);
+    Ok(())
+}
+
+fn set(bitmap: &mut Bitmap, block: u32) {
+    bitmap[(block / 32) as usize] |= 1 << (block % 32);
+}
+
+fn is_set(bitmap: &Bitmap, block: u32) -> bool {
+    bitmap[(block / 32) as usize] & (1 << (block % 32)) != 0
+}
+
+/// Read the pointer to the block `2^level` blocks back.
+fn read_pointer<D: BlockDevice>(
+    dev: &mut D,
+    block: u32,
+    level: u32,
+) -> Result<u32, Error<D::Error>> {
+    let mut pointer = [0; 4];
+    dev.read(block, 4 * level, &mut pointer)?;
+    let pointer = u32::from_le_bytes(pointer);
+    if pointer >= dev.block_count() {
+        return Err(Error::Corrupt);
+    }
+    Ok(pointer)
+}
+
+/// Length of the pointers at the start of the block with the specified
+/// index.
+fn header_len(index: u32) -> u32 {
+    if index == 0 {
+        0
+    } else {
+        4 * (index.trailing_zeros() + 1)
+    }
+}
+
+/// Return the index of the block containing the byte at `pos` of a file,
+/// and the offset of the byte within the block.
+fn locate(mut pos: u32) -> (u32, u32) {
+    let mut index = 0;
+    loop {
+        let header = header_len(index);
+        if pos < BLOCK_SIZE - header {
+            return (index, header + pos);
+        }
+        pos -= BLOCK_SIZE - header;
+        index += 1;
+    }
+}
+
+/// Return whether a block is erased from `offset` to its end.
+fn is_erased<D: BlockDevice>(dev: &mut D, block: u32, offset: u32) -> Result<bool, D::Error> {
+    let mut buf = [0; 256];
+    let mut offset = offset;
+    while offset < BLOCK_SIZE {
+        let chunk = &mut buf[..(BLOCK_SIZE - offset).min(256) as usize];
+        dev.read(block, offset, chunk)?;
+        if chunk.iter().any(|byte| *byte != 0xFF) {
+            return Ok(false);
+        }
+        offset += chunk.len() as u32;
+    }
+    Ok(true)
+}
+
+fn encode_header(revision: u32) -> [u8; HEADER_LEN as usize] {
+    let mut header = [0; HEADER_LEN as usize];
+    header[..4].copy_from_slice(&MAGIC);
+    header[4..8].copy_from_slice(&revision.to_le_bytes());
+    header[8..10].copy_from_slice(&VERSION.to_le_bytes());
+    let crc = crc16(&header[..10]);
+    header[10..].copy_from_slice(&crc.to_le_bytes());
+    header
+}
+
+/// Return the revision of a valid header, or `Err` if it has an unsupported
+/// version.
+fn parse_header(header: &[u8; HEADER_LEN as usize]) -> Option<Result<u32, ()>> {
+    let crc = u16::from_le_bytes([header[10], header[11]]);
+    if header[..4] != MAGIC || crc16(&header[..10]) != crc {
+        return None;
+    }
+    if u16::from_le_bytes([header[8], header[9]]) != VERSION {
+        return Some(Err(()));
+    }
+    Some(Ok(u32::from_le_bytes([
+        header[4], header[5], header[6], header[7],
+    ])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ram::RamDevice;
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::convert::Infallible;
+
+    const BLOCKS: usize = 32;
+
+    fn erased() -> Vec<u8> {
+        vec![0xFF; BLOCKS * BLOCK_SIZE as usize]
+    }
+
+    fn write<D: BlockDevice>(
+        fs: &mut Fs,
+        dev: &mut D,
+        path: &str,
+        data: &[u8],
+        mode: Mode,
+    ) -> Result<(), Error<D::Error>> {
+        let mut file = fs.open(dev, path, mode)?;
+        // Write in uneven chunks
+        for chunk in data.chunks(1000) {
+            file.write(fs, dev, chunk)?;
+        }
+        file.close(fs, dev)
+    }
+
+    fn read<D: BlockDevice>(
+        fs: &mut Fs,
+        dev: &mut D,
+        path: &str,
+    ) -> Result<Vec<u8>, Error<D::Error>> {
+        let mut file = fs.open(dev, path, Mode::Read)?;
+        let mut data = vec![0; file.size() as usize + 10];
+        let len = file.read(dev, &mut data)?;
+        data.truncate(len);
+        Ok(data)
+    }
+
+    fn list(fs: &Fs, dev: &mut RamDevice, path: &str) -> Vec<(String, Metadata)> {
+        let mut entries = Vec::new();
+        fs.read_dir(dev, path, |name, metadata| {
+            entries.push((name.to_string(), metadata))
+        })
+        .unwrap();
+        entries
+    }
+
+    fn content(seed: u32, len: usize) -> Vec<u8> {
+        (0..len as u32)
+            .map(|i| (i.wrapping_mul(31) ^ seed.wrapping_mul(97)) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn format_on_first_mount() {
+        let mut memory = erased();
+        let mut dev = RamDevice::new(&mut memory);
+        assert_eq!(Fs::mount(&mut dev).err(), Some(Error::NotFormatted));
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        assert_eq!(
+            fs.free_space(&mut dev),
+            Ok((BLOCKS as u32 - 2) * BLOCK_SIZE)
+        );
+        assert!(list(&fs, &mut dev, "/").is_empty());
+
+        // Mounting again keeps the contents
+        write(&mut fs, &mut dev, "/hello", b"world", Mode::Write).unwrap();
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        assert_eq!(read(&mut fs, &mut dev, "/hello"), Ok(b"world".to_vec()));
+    }
+
+    #[test]
+    fn unsupported_device() {
+        for blocks in [1, MAX_BLOCKS + 1].iter() {
+            let mut memory = vec![0xFF; (blocks * BLOCK_SIZE) as usize];
+            let mut dev = RamDevice::new(&mut memory);
+            assert_eq!(
+                Fs::mount_or_format(&mut dev).err(),
+                Some(Error::UnsupportedDevice)
+            );
+            assert_eq!(Fs::format(&mut dev), Err(Error::UnsupportedDevice));
+        }
+    }
+
+    #[test]
+    fn files_and_directories() {
+        let mut memory = erased();
+        let mut dev = RamDevice::new(&mut memory);
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        fs.create_dir(&mut dev, "/fonts").unwrap();
+        fs.create_dir(&mut dev, "/fonts/large").unwrap();
+        assert_eq!(fs.create_dir(&mut dev, "/fonts"), Err(Error::AlreadyExists));
+        assert_eq!(
+            fs.create_dir(&mut dev, "/missing/dir"),
+            Err(Error::NotFound)
+        );
+        write(
+            &mut fs,
+            &mut dev,
+            "/fonts/large/digits",
+            b"0123456789",
+            Mode::Write,
+        )
+        .unwrap();
+        write(&mut fs, &mut dev, "/fonts/small", b"abc", Mode::Write).unwrap();
+
+        assert_eq!(
+            list(&fs, &mut dev, "/fonts"),
+            vec![
+                (
+                    "large".to_string(),
+                    Metadata {
+                        kind: Kind::Dir,
+                        size: 0
+                    }
+                ),
+                (
+                    "small".to_string(),
+                    Metadata {
+                        kind: Kind::File,
+                        size: 3
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            fs.metadata(&mut dev, "fonts/large/digits"),
+            Ok(Metadata {
+                kind: Kind::File,
+                size: 10
+            })
+        );
+        assert_eq!(fs.metadata(&mut dev, "/").map(|m| m.kind), Ok(Kind::Dir));
+        assert_eq!(read(&mut fs, &mut dev, "/fonts/small"), Ok(b"abc".to_vec()));
+
+        assert_eq!(
+            fs.open(&mut dev, "/fonts", Mode::Read).err(),
+            Some(Error::IsADirectory)
+        );
+        assert_eq!(
+            fs.open(&mut dev, "/fonts/small/x", Mode::Read).err(),
+            Some(Error::NotADirectory)
+        );
+        assert_eq!(
+            fs.open(&mut dev, "/nothing", Mode::Read).err(),
+            Some(Error::NotFound)
+        );
+        let name = "x".repeat(MAX_NAME_LEN + 1);
+        assert_eq!(fs.create_dir(&mut dev, &name), Err(Error::InvalidPath));
+
+        assert_eq!(
+            fs.remove(&mut dev, "/fonts/large"),
+            Err(Error::DirectoryNotEmpty)
+        );
+        fs.remove(&mut dev, "/fonts/large/digits").unwrap();
+        fs.remove(&mut dev, "/fonts/large").unwrap();
+        fs.remove(&mut dev, "/fonts/small").unwrap();
+        fs.remove(&mut dev, "/fonts").unwrap();
+        assert!(list(&fs, &mut dev, "/").is_empty());
+        assert_eq!(
+            fs.free_space(&mut dev),
+            Ok((BLOCKS as u32 - 2) * BLOCK_SIZE)
+        );
+    }
+
+    #[test]
+    fn large_files() {
+        let mut memory = erased();
+        let mut dev = RamDevice::new(&mut memory);
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        let data = content(1, 20 * BLOCK_SIZE as usize);
+        write(&mut fs, &mut dev, "/big", &data, Mode::Write).unwrap();
+        assert_eq!(read(&mut fs, &mut dev, "/big"), Ok(data.clone()));
+        assert_eq!(fs.free_space(&mut dev), Ok(9 * BLOCK_SIZE));
+
+        // Random access
+        let mut file = fs.open(&mut dev, "/big", Mode::Read).unwrap();
+        for pos in [70_000, 5, 40_000, 81_000, 4096 * 7 - 3].iter() {
+            let mut buf = [0; 100];
+            file.seek(*pos);
+            let len = file.read(&mut dev, &mut buf).unwrap();
+            assert_eq!(&buf[..len], &data[*pos as usize..][..len]);
+        }
+
+        // Replacing the contents frees the old blocks
+        write(&mut fs, &mut dev, "/big", b"small", Mode::Write).unwrap();
+        assert_eq!(read(&mut fs, &mut dev, "/big"), Ok(b"small".to_vec()));
+        assert_eq!(fs.free_space(&mut dev), Ok(29 * BLOCK_SIZE));
+    }
+
+    #[test]
+    fn append() {
+        let mut memory = erased();
+        let mut dev = RamDevice::new(&mut memory);
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        let mut expected = Vec::new();
+        for i in 0..400 {
+            let data = content(i, 77);
+            write(&mut fs, &mut dev, "/log", &data, Mode::Append).unwrap();
+            expected.extend_from_slice(&data);
+        }
+        assert_eq!(read(&mut fs, &mut dev, "/log"), Ok(expected));
+        // Many small updates of the directory compact its log
+        assert!(open_dir(&mut dev, ROOT).unwrap().revision > 1);
+    }
+
+    #[test]
+    fn no_space() {
+        let mut memory = erased();
+        let mut dev = RamDevice::new(&mut memory);
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+        write(
+            &mut fs,
+            &mut dev,
+            "/a",
+            &content(1, 20 * BLOCK_SIZE as usize),
+            Mode::Write,
+        )
+        .unwrap();
+        assert_eq!(
+            write(
+                &mut fs,
+                &mut dev,
+                "/b",
+                &content(2, 20 * BLOCK_SIZE as usize),
+                Mode::Write
+            ),
+            Err(Error::NoSpace)
+        );
+        // The failed write didn't change anything
+        assert_eq!(fs.metadata(&mut dev, "/b").map(|m| m.size), Ok(0));
+        fs.remove(&mut dev, "/a").unwrap();
+        write(
+            &mut fs,
+            &mut dev,
+            "/b",
+            &content(2, 20 * BLOCK_SIZE as usize),
+            Mode::Write,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn skip_list() {
+        assert_eq!(locate(0), (0, 0));
+        assert_eq!(locate(BLOCK_SIZE), (1, 4));
+        assert_eq!(locate(2 * BLOCK_SIZE - 4), (2, 8));
+        assert_eq!(header_len(8), 16);
+    }
+
+    /// A RAM device that loses power after a limited number of program and
+    /// erase operations, in the middle of that operation.
+    struct Faulty<'a> {
+        dev: RamDevice<'a>,
+        steps_left: Option<usize>,
+        lost: bool,
+        steps: usize,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct PowerLoss;
+
+    impl<'a> Faulty<'a> {
+        fn step(&mut self) -> Result<bool, PowerLoss> {
+            if self.lost {
+                return Err(PowerLoss);
+            }
+            self.steps += 1;
+            match &mut self.steps_left {
+                Some(0) => {
+                    self.lost = true;
+                    Ok(false)
+                }
+                Some(left) => {
+                    *left -= 1;
+                    Ok(true)
+                }
+                None => Ok(true),
+            }
+        }
+    }
+
+    fn infallible<T>(result: Result<T, Infallible>) -> T {
+        match result {
+            Ok(value) => value,
+            Err(e) => match e {},
+        }
+    }
+
+    impl<'a> BlockDevice for Faulty<'a> {
+        type Error = PowerLoss;
+
+        fn block_count(&self) -> u32 {
+            self.dev.block_count()
+        }
+
+        fn read(&mut self, block: u32, offset: u32, buf: &mut [u8]) -> Result<(), PowerLoss> {
+            if self.lost {
+                return Err(PowerLoss);
+            }
+            infallible(self.dev.read(block, offset, buf));
+            Ok(())
+        }
+
+        fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), PowerLoss> {
+            if self.step()? {
+                infallible(self.dev.program(block, offset, data));
+                Ok(())
+            } else {
+                // Only the first half is programmed
+                infallible(self.dev.program(block, offset, &data[..data.len() / 2]));
+                Err(PowerLoss)
+            }
+        }
+
+        fn erase(&mut self, block: u32) -> Result<(), PowerLoss> {
+            if self.step()? {
+                infallible(self.dev.erase(block));
+                Ok(())
+            } else {
+                // Erasing was interrupted, the block contains garbage
+                let garbage = [0x55; BLOCK_SIZE as usize / 2];
+                infallible(self.dev.program(block, 0, &garbage));
+                Err(PowerLoss)
+            }
+        }
+    }
+
+    type Model = BTreeMap<&'static str, Vec<u8>>;
+
+    /// A long name fills the log of the directory quickly.
+    const LOG: &str = "/logs/a-log-file-with-a-long-name";
+
+    /// Run a sequence of operations. `model` holds the committed contents of
+    /// the files. Returns the path and new contents (or `None` if removed)
+    /// of the operation that failed.
+    fn run(dev: &mut Faulty, model: &mut Model) -> Result<(), (&'static str, Option<Vec<u8>>)> {
+        let mut fs = Fs::mount_or_format(dev).map_err(|_| ("", None))?;
+        fs.create_dir(dev, "/logs").map_err(|_| ("/logs", None))?;
+        for round in 0..3u32 {
+            // Replace a file spanning several blocks
+            let image = content(round, 3 * BLOCK_SIZE as usize + 100 * round as usize);
+            write(&mut fs, dev, "/image", &image, Mode::Write)
+                .map_err(|_| ("/image", Some(image.clone())))?;
+            model.insert("/image", image);
+
+            // Append to a log, often enough to compact the directory
+            for i in 0..40 {
+                let mut log = model.get(LOG).cloned().unwrap_or_default();
+                let entry = content(round * 100 + i, 1 + 7 * i as usize);
+                log.extend_from_slice(&entry);
+                write(&mut fs, dev, LOG, &entry, Mode::Append)
+                    .map_err(|_| (LOG, Some(log.clone())))?;
+                model.insert(LOG, log);
+            }
+
+            // Create and remove a small file
+            if round % 2 == 0 {
+                let data = content(round + 200, 10);
+                write(&mut fs, dev, "/tmp", &data, Mode::Write)
+                    .map_err(|_| ("/tmp", Some(data.clone())))?;
+                model.insert("/tmp", data);
+            } else {
+                fs.remove(dev, "/tmp").map_err(|_| ("/tmp", None))?;
+                model.remove("/tmp");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn power_loss_at_every_step() {
+        let mut memory = erased();
+        let mut dev = Faulty {
+            dev: RamDevice::new(&mut memory),
+            steps_left: None,
+            lost: false,
+            steps: 0,
+        };
+        run(&mut dev, &mut Model::new()).unwrap();
+        let total = dev.steps;
+        let logs = resolve(&mut dev, components("/logs")).unwrap();
+        assert!(logs.revision > 1);
+
+        for cut in 0..total {
+            let mut memory = erased();
+            let mut dev = Faulty {
+                dev: RamDevice::new(&mut memory),
+                steps_left: Some(cut),
+                lost: false,
+                steps: 0,
+            };
+            let mut model = Model::new();
+            let (failed, new) = run(&mut dev, &mut model).unwrap_err();
+
+            // Power is back
+            dev.steps_left = None;
+            dev.lost = false;
+            let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+            for path in ["/image", LOG, "/tmp"].iter() {
+                let actual = read(&mut fs, &mut dev, path).ok();
+                let old = model.get(path).cloned();
+                // Files created by the failed operation may exist and be
+                // empty
+                let valid = actual == old
+                    || (*path == failed && (actual == new || actual == Some(Vec::new())));
+                assert!(valid, "cut {}: unexpected contents of {}", cut, path);
+            }
+
+            // The file system keeps working, and all space is accounted for
+            write(&mut fs, &mut dev, "/new", b"new", Mode::Write).unwrap();
+            assert_eq!(read(&mut fs, &mut dev, "/new"), Ok(b"new".to_vec()));
+            let mut used = 0;
+            fs.read_dir(&mut dev, "/", |_, metadata| used += metadata.size)
+                .unwrap();
+            assert!(fs.free_space(&mut dev).unwrap() + used <= BLOCKS as u32 * BLOCK_SIZE);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! A block device in RAM, for tests and the simulator.
+
+use core::convert::Infallible;
+
+use super::{BlockDevice, BLOCK_SIZE};
+
+/// A block device backed by a slice, with the semantics of NOR flash:
+/// programming only clears bits.
+pub struct RamDevice<'a> {
+    memory: &'a mut [u8],
+}
+
+impl<'a> RamDevice<'a> {
+    /// Use `memory` as a block device. Its length must be a multiple of
+    /// `BLOCK_SIZE`. The contents are kept, so that a file system can be
+    /// mounted again.
+    pub fn new(memory: &'a mut [u8]) -> Self {
+        assert!(
+            memory.len() as u32 & (BLOCK_SIZE - 1) == 0,
+            "length must be a multiple of the block size"
+        );
+        Self { memory }
+    }
+
+    fn range(&self, block: u32, offset: u32, len: usize) -> core::ops::Range<usize> {
+        assert!(block < self.block_count() && offset as usize + len <= BLOCK_SIZE as usize);
+        let start = (block * BLOCK_SIZE + offset) as usize;
+        start..start + len
+    }
+}
+
+impl<'a> BlockDevice for RamDevice<'a> {
+    type Error = Infallible;
+
+    fn block_count(&self) -> u32 {
+        self.memory.len() as u32 / BLOCK_SIZE
+    }
+
+    fn read(&mut self, block: u32, offset: u32, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let range = self.range(block, offset, buf.len());
+        buf.copy_from_slice(&self.memory[range]);
+        Ok(())
+    }
+
+    fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), Self::Error> {
+        let range = self.range(block, offset, data.len());
+        for (byte, new) in self.memory[range].iter_mut().zip(data) {
+            *byte &= new;
+        }
+        Ok(())
+    }
+
+    fn erase(&mut self, block: u32) -> Result<(), Self::Error> {
+        let range = self.range(block, 0, BLOCK_SIZE as usize);
+        for byte in &mut self.memory[range] {
+            *byte = 0xFF;
+        }
+        Ok(())
+    }
+}
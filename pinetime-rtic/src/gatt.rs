@@ -0,0 +1,281 @@
+//! GATT attributes of the watch.
+//!
+//! Besides the battery service, the watch offers the "Current Time" service
+//! (to set the wall clock), a custom alarm service (to read and configure
+//! the alarms) and a custom notification service (to push notifications from
+//! a phone). Written values are collected here and picked up by the firmware
+//! through `take_current_time`, `take_alarms` and `take_notification`.
+
+use rubble::att::{
+    AttUuid, Attribute, AttributeAccessPermissions, AttributeProvider, Handle, HandleRange,
+};
+use rubble::link::Responder;
+use rubble::uuid::{Uuid128, Uuid16};
+use rubble::Error;
+
+use pinetime_rtic::alarm;
+use pinetime_rtic::clock::DateTime;
+use pinetime_rtic::notification::{Assembler, Notification, Notifications};
+
+use crate::AppConfig;
+
+const CURRENT_TIME_HANDLE: u16 = 0x0006;
+const ALARMS_HANDLE: u16 = 0x0009;
+const NOTIFICATION_HANDLE: u16 = 0x000C;
+
+/// UUID of the alarm characteristic (7a1a0002-3c5f-4d8e-9b2a-6f0e8d4c2b1a).
+/// The value contains all alarms in the format of `alarm::Alarms::encode`.
+const ALARMS_UUID: [u8; 16] = [
+    0x7a, 0x1a, 0x00, 0x02, 0x3c, 0x5f, 0x4d, 0x8e, 0x9b, 0x2a, 0x6f, 0x0e, 0x8d, 0x4c, 0x2b, 0x1a,
+];
+
+/// UUID of the notification characteristic
+/// (7a1a0004-3c5f-4d8e-9b2a-6f0e8d4c2b1a). Notifications are written in
+/// fragments, see `pinetime_rtic::notification`.
+const NOTIFICATION_UUID: [u8; 16] = [
+    0x7a, 0x1a, 0x00, 0x04, 0x3c, 0x5f, 0x4d, 0x8e, 0x9b, 0x2a, 0x6f, 0x0e, 0x8d, 0x4c, 0x2b, 0x1a,
+];
+
+pub struct WatchServiceAttrs {
+    /// All attributes. The values of writable attributes are stored
+    /// separately.
+    attributes: [Attribute<&'static [u8]>; 12],
+
+    /// Current value of the alarm characteristic.
+    alarms: [u8; alarm::ENCODED_LEN],
+
+    /// Time that was written but not yet picked up.
+    current_time: Option<DateTime>,
+
+    /// Whether the alarms were written but not yet picked up.
+    alarms_written: bool,
+
+    /// Reassembles the fragments written to the notification
+    /// characteristic.
+    assembler: Assembler,
+
+    /// Notifications that were received but not yet picked up, oldest
+    /// first. If there are too many, the oldest ones are dropped.
+    notifications: Notifications,
+}
+
+impl WatchServiceAttrs {
+    /// Create the attributes. `alarms` is the initial value of the alarm
+    /// characteristic.
+    pub fn new(alarms: &[u8; alarm::ENCODED_LEN]) -> Self {
+        Self {
+            attributes: [
+                Attribute::new(
+                    Uuid16(0x2800).into(), // "Primary Service"
+                    Handle::from_raw(0x0001),
+                    &[0x0F, 0x18], // "Battery Service" = 0x180F
+                ),
+                Attribute::new(
+                    Uuid16(0x2803).into(), // "Characteristic"
+                    Handle::from_raw(0x0002),
+                    &[
+                        0x02, // 1 byte properties: READ = 0x02
+                        0x03, 0x00, // 2 bytes handle = 0x0003
+                        0x19, 0x2A, // 2 bytes UUID = 0x2A19 (Battery Level)
+                    ],
+                ),
+                Attribute::new(
+                    Uuid16(0x2A19).into(), // "Battery Level"
+                    Handle::from_raw(0x0003),
+                    &[48u8],
+                ),
+                Attribute::new(
+                    Uuid16(0x2800).into(), // "Primary Service"
+                    Handle::from_raw(0x0004),
+                    &[0x05, 0x18], // "Current Time Service" = 0x1805
+                ),
+                Attribute::new(
+                    Uuid16(0x2803).into(), // "Characteristic"
+                    Handle::from_raw(0x0005),
+                    &[
+                        0x08, // 1 byte properties: WRITE = 0x08
+                        0x06, 0x00, // 2 bytes handle = 0x0006
+                        0x2B, 0x2A, // 2 bytes UUID = 0x2A2B (Current Time)
+                    ],
+                ),
+                Attribute::new(
+                    Uuid16(0x2A2B).into(), // "Current Time"
+                    Handle::from_raw(CURRENT_TIME_HANDLE),
+                    &[],
+                ),
+                Attribute::new(
+                    Uuid16(0x2800).into(), // "Primary Service"
+                    Handle::from_raw(0x0007),
+                    &[
+                        // Alarm service UUID (7a1a0001-3c5f-4d8e-9b2a-6f0e8d4c2b1a),
+                        // little endian
+                        0x1a, 0x2b, 0x4c, 0x8d, 0x0e, 0x6f, 0x2a, 0x9b, 0x8e, 0x4d, 0x5f, 0x3c,
+                        0x01, 0x00, 0x1a, 0x7a,
+                    ],
+                ),
+                Attribute::new(
+                    Uuid16(0x2803).into(), // "Characteristic"
+                    Handle::from_raw(0x0008),
+                    &[
+                        0x0A, // 1 byte properties: READ = 0x02, WRITE = 0x08
+                        0x09, 0x00, // 2 bytes handle = 0x0009
+                        // 16 bytes alarm characteristic UUID, little endian
+                        0x1a, 0x2b, 0x4c, 0x8d, 0x0e, 0x6f, 0x2a, 0x9b, 0x8e, 0x4d, 0x5f, 0x3c,
+                        0x02, 0x00, 0x1a, 0x7a,
+                    ],
+                ),
+                Attribute::new(
+                    Uuid128::from_bytes(ALARMS_UUID).into(),
+                    Handle::from_raw(ALARMS_HANDLE),
+                    &[],
+                ),
+                Attribute::new(
+                    Uuid16(0x2800).into(), // "Primary Service"
+                    Handle::from_raw(0x000A),
+                    &[
+                        // Notification service UUID
+                        // (7a1a0003-3c5f-4d8e-9b2a-6f0e8d4c2b1a), little endian
+                        0x1a, 0x2b, 0x4c, 0x8d, 0x0e, 0x6f, 0x2a, 0x9b, 0x8e, 0x4d, 0x5f, 0x3c,
+                        0x03, 0x00, 0x1a, 0x7a,
+                    ],
+                ),
+                Attribute::new(
+                    Uuid16(0x2803).into(), // "Characteristic"
+                    Handle::from_raw(0x000B),
+                    &[
+                        0x08, // 1 byte properties: WRITE = 0x08
+                        0x0C, 0x00, // 2 bytes handle = 0x000C
+                        // 16 bytes notification characteristic UUID, little
+                        // endian
+                        0x1a, 0x2b, 0x4c, 0x8d, 0x0e, 0x6f, 0x2a, 0x9b, 0x8e, 0x4d, 0x5f, 0x3c,
+                        0x04, 0x00, 0x1a, 0x7a,
+                    ],
+                ),
+                Attribute::new(
+                    Uuid128::from_bytes(NOTIFICATION_UUID).into(),
+                    Handle::from_raw(NOTIFICATION_HANDLE),
+                    &[],
+                ),
+            ],
+            alarms: *alarms,
+            current_time: None,
+            alarms_written: false,
+            assembler: Assembler::new(),
+            notifications: Notifications::new(),
+        }
+    }
+
+    /// Return the time that was written through the "Current Time"
+    /// characteristic since the last call, if any.
+    pub fn take_current_time(&mut self) -> Option<DateTime> {
+        self.current_time.take()
+    }
+
+    /// Return the value of the alarm characteristic if it was written since
+    /// the last call.
+    pub fn take_alarms(&mut self) -> Option<[u8; alarm::ENCODED_LEN]> {
+        if self.alarms_written {
+            self.alarms_written = false;
+            Some(self.alarms)
+        } else {
+            None
+        }
+    }
+
+    /// Return whether notifications were received but not yet picked up.
+    pub fn has_notifications(&self) -> bool {
+        !self.notifications.is_empty()
+    }
+
+    /// Return the oldest notification that was received but not yet picked
+    /// up, if any.
+    pub fn take_notification(&mut self) -> Option<Notification> {
+        self.notifications.pop_oldest()
+    }
+
+    /// Update the value of the alarm characteristic.
+    pub fn set_alarms(&mut self, alarms: &[u8; alarm::ENCODED_LEN]) {
+        self.alarms = *alarms;
+    }
+}
+
+/// Call `f` with the attributes served by the BLE stack.
+pub fn with_attrs<T>(
+    ble_r: &mut Responder<AppConfig>,
+    f: impl FnOnce(&mut WatchServiceAttrs) -> T,
+) -> T {
+    f(ble_r.l2cap().channel_mapper().attribute_provider())
+}
+
+impl AttributeProvider for WatchServiceAttrs {
+    fn for_attrs_in_range(
+        &mut self,
+        range: HandleRange,
+        mut f: impl FnMut(&Self, &Attribute<dyn AsRef<[u8]>>) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let count = self.attributes.len();
+        let start = usize::from(range.start().as_u16() - 1); // handles start at 1, not 0
+        let end = usize::from(range.end().as_u16() - 1);
+        if start >= count {
+            return Ok(());
+        }
+        for attr in &self.attributes[start..=end.min(count - 1)] {
+            if attr.handle.as_u16() == ALARMS_HANDLE {
+                let alarms = Attribute::new(attr.att_type, attr.handle, self.alarms);
+                f(self, &alarms)?;
+            } else {
+                f(self, attr)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_grouping_attr(&self, uuid: AttUuid) -> bool {
+        uuid == Uuid16(0x2800)
+    }
+
+    fn group_end(&self, handle: Handle) -> Option<&Attribute<dyn AsRef<[u8]>>> {
+        match handle.as_u16() {
+            0x0001 | 0x0002 => Some(&self.attributes[2]),
+            0x0004 | 0x0005 => Some(&self.attributes[5]),
+            0x0007 | 0x0008 => Some(&self.attributes[8]),
+            0x000A | 0x000B => Some(&self.attributes[11]),
+            _ => None,
+        }
+    }
+
+    fn attr_access_permissions(&self, handle: Handle) -> AttributeAccessPermissions {
+        match handle.as_u16() {
+            CURRENT_TIME_HANDLE | NOTIFICATION_HANDLE => AttributeAccessPermissions::Writeable,
+            ALARMS_HANDLE => AttributeAccessPermissions::ReadableAndWriteable,
+            _ => AttributeAccessPermissions::Readable,
+        }
+    }
+
+    fn write_attr(&mut self, handle: Handle, data: &[u8]) -> Result<(), Error> {
+        match handle.as_u16() {
+            CURRENT_TIME_HANDLE => {
+                let datetime = DateTime::from_current_time(data).ok_or(Error::InvalidValue)?;
+                self.current_time = Some(datetime);
+                Ok(())
+            }
+            ALARMS_HANDLE => {
+                // Shorter values only replace the first alarms
+                if data.len() > self.alarms.len() {
+                    return Err(Error::InvalidLength);
+                }
+                self.alarms[..data.len()].copy_from_slice(data);
+                self.alarms_written = true;
+                Ok(())
+            }
+            NOTIFICATION_HANDLE => {
+                let notification = self.assembler.push(data).map_err(|_| Error::InvalidValue)?;
+                if let Some(notification) = notification {
+                    self.notifications.push(notification);
+                }
+                Ok(())
+            }
+            _ => Err(Error::InvalidValue),
+        }
+    }
+}
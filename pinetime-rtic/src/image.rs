@@ -0,0 +1,742 @@
+//! Image assets that are streamed to the display.
+//!
+//! Images can be too large to be kept in RAM or in the internal flash. They
+//! are therefore stored as files in the external flash and decoded a few
+//! rows at a time, in the order in which the display expects the pixels.
+//!
+//! An image starts with a header of `HEADER_LEN` bytes (numbers are little
+//! endian):
+//!
+//! | Offset | Size | Content                                          |
+//! |--------|------|--------------------------------------------------|
+//! | 0      | 4    | Magic `PTIM`                                     |
+//! | 4      | 1    | Version (1)                                      |
+//! | 5      | 1    | Pixel format, see `PixelFormat`                  |
+//! | 6      | 1    | Compression, see `Compression`                   |
+//! | 7      | 1    | Number of palette colors - 1 (indexed images), 0 |
+//! | 8      | 2    | Width                                            |
+//! | 10     | 2    | Height                                           |
+//!
+//! Indexed images continue with their palette, a `u16` Rgb565 color per
+//! entry. The pixels follow row by row: a little endian Rgb565 `u16` per
+//! pixel, or a byte with the index into the palette. With run-length
+//! encoding, the pixels are split into packets that start with a control
+//! byte `c`: if the highest bit is set, the next pixel is repeated
+//! `(c & 0x7F) + 1` times, otherwise `c + 1` pixels follow as they are.
+//!
+//! Images are created from PNGs with the `imgconv` tool, which uses
+//! `encode`.
+//!
+//! The decoder doesn't own the source of the data. Like the flash drivers,
+//! it is passed to every call, so that it only has to be borrowed while
+//! pixels are decoded.
+
+use core::convert::Infallible;
+
+use embedded_graphics::{
+    drawable::Pixel,
+    geometry::Point,
+    image::{ImageDimensions, IntoPixelIter},
+    pixelcolor::{raw::RawU16, Rgb565},
+};
+
+use crate::fs::{self, BlockDevice, File};
+
+/// Length of the header in bytes.
+pub const HEADER_LEN: usize = 12;
+
+/// Maximum number of colors in the palette of an indexed image.
+pub const MAX_COLORS: usize = 256;
+
+/// Maximum number of pixels in a packet of the run-length encoding.
+pub const MAX_RUN: usize = 128;
+
+const MAGIC: [u8; 4] = *b"PTIM";
+const VERSION: u8 = 1;
+
+/// Bit of the control byte that marks a repeated pixel.
+const REPEAT: u8 = 0x80;
+
+/// Number of bytes that are read from the source at once.
+const BUFFER_LEN: usize = 64;
+
+/// A source of image data.
+pub trait Read {
+    type Error;
+
+    /// Read into `buf`. Returns the number of bytes read, which is only less
+    /// than the length of `buf` at the end of the data.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+impl Read for &[u8] {
+    type Error = Infallible;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let len = buf.len().min(self.len());
+        buf[..len].copy_from_slice(&self[..len]);
+        *self = &self[len..];
+        Ok(len)
+    }
+}
+
+/// Reads an image from a file, at the current position of the file.
+pub struct FileReader<'a, D> {
+    file: &'a mut File,
+    dev: &'a mut D,
+}
+
+impl<'a, D: BlockDevice> FileReader<'a, D> {
+    pub fn new(file: &'a mut File, dev: &'a mut D) -> Self {
+        Self { file, dev }
+    }
+}
+
+impl<'a, D: BlockDevice> Read for FileReader<'a, D> {
+    type Error = fs::Error<D::Error>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.file.read(self.dev, buf)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// The source could not be read.
+    Read(E),
+    /// The data is not an image, or one of an unknown version.
+    Invalid,
+    /// The image uses a pixel format or compression that is not supported.
+    Unsupported,
+    /// The data ended before the last pixel.
+    Truncated,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Read(e)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// 16 bit colors (5 bits red, 6 bits green, 5 bits blue).
+    Rgb565,
+    /// Indexes into a palette of up to `MAX_COLORS` Rgb565 colors.
+    Indexed { colors: u16 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    None = 0,
+    /// Run-length encoding.
+    Rle = 1,
+}
+
+/// The header of an image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub width: u16,
+    pub height: u16,
+    pub format: PixelFormat,
+    pub compression: Compression,
+}
+
+impl Header {
+    pub fn encode(&self) -> [u8; HEADER_LEN] {
+        let mut header = [0; HEADER_LEN];
+        header[0..4].copy_from_slice(&MAGIC);
+        header[4] = VERSION;
+        match self.format {
+            PixelFormat::Rgb565 => header[5] = 0,
+            PixelFormat::Indexed { colors } => {
+                header[5] = 1;
+                header[7] = (colors - 1) as u8;
+            }
+        }
+        header[6] = self.compression as u8;
+        header[8..10].copy_from_slice(&self.width.to_le_bytes());
+        header[10..12].copy_from_slice(&self.height.to_le_bytes());
+        header
+    }
+
+    pub fn decode<E>(header: &[u8; HEADER_LEN]) -> Result<Self, Error<E>> {
+        if header[0..4] != MAGIC || header[4] != VERSION {
+            return Err(Error::Invalid);
+        }
+        let format = match header[5] {
+            0 => PixelFormat::Rgb565,
+            1 => PixelFormat::Indexed {
+                colors: header[7] as u16 + 1,
+            },
+            _ => return Err(Error::Unsupported),
+        };
+        let compression = match header[6] {
+            0 => Compression::None,
+            1 => Compression::Rle,
+            _ => return Err(Error::Unsupported),
+        };
+        Ok(Self {
+            width: u16::from_le_bytes([header[8], header[9]]),
+            height: u16::from_le_bytes([header[10], header[11]]),
+            format,
+            compression,
+        })
+    }
+
+    /// Return the number of pixels of the image.
+    pub fn pixels(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+}
+
+/// The packet of the run-length encoding that is being decoded.
+#[derive(Clone, Copy)]
+enum Packet {
+    /// `count` more pixels follow as they are.
+    Literal { count: u8 },
+    /// `pixel` is repeated `count` more times.
+    Repeat { pixel: u16, count: u8 },
+}
+
+/// Decodes the pixels of an image in row-major order.
+pub struct Decoder {
+    header: Header,
+    /// Number of pixels that were not decoded yet.
+    remaining: u32,
+    packet: Packet,
+    palette: [u16; MAX_COLORS],
+    /// Data that was read from the source but not decoded yet.
+    buffer: [u8; BUFFER_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl Decoder {
+    /// Read the header (and palette) of an image and return a decoder for
+    /// its pixels.
+    pub fn new<R: Read>(reader: &mut R) -> Result<Self, Error<R::Error>> {
+        let mut header = [0; HEADER_LEN];
+        if reader.read(&mut header)? < HEADER_LEN {
+            return Err(Error::Invalid);
+        }
+        let header = Header::decode(&header)?;
+        let mut decoder = Self {
+            header,
+            remaining: header.pixels(),
+            packet: Packet::Literal { count: 0 },
+            palette: [0; MAX_COLORS],
+            buffer: [0; BUFFER_LEN],
+            pos: 0,
+            len: 0,
+        };
+        if let PixelFormat::Indexed { colors } = header.format {
+            for i in 0..colors as usize {
+                decoder.palette[i] = decoder.color(reader)?;
+            }
+        }
+        Ok(decoder)
+    }
+
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Decode the next pixels into `pixels`. Returns the number of pixels
+    /// decoded, which is only less than the length of `pixels` at the end of
+    /// the image.
+    pub fn read<R: Read>(
+        &mut self,
+        reader: &mut R,
+        pixels: &mut [u16],
+    ) -> Result<usize, Error<R::Error>> {
+        let len = pixels.len().min(self.remaining as usize);
+        for pixel in pixels[..len].iter_mut() {
+            *pixel = self.next(reader)?;
+        }
+        self.remaining -= len as u32;
+        Ok(len)
+    }
+
+    /// Decode the next pixel.
+    fn next<R: Read>(&mut self, reader: &mut R) -> Result<u16, Error<R::Error>> {
+        if self.header.compression == Compression::None {
+            return self.pixel(reader);
+        }
+        loop {
+            match self.packet {
+                Packet::Literal { count: 0 } | Packet::Repeat { count: 0, .. } => {
+                    let control = self.byte(reader)?;
+                    let count = (control & !REPEAT) + 1;
+                    self.packet = if control & REPEAT != 0 {
+                        let pixel = self.pixel(reader)?;
+                        Packet::Repeat { pixel, count }
+                    } else {
+                        Packet::Literal { count }
+                    };
+                }
+                Packet::Literal { count } => {
+                    self.packet = Packet::Literal { count: count - 1 };
+                    return self.pixel(reader);
+                }
+                Packet::Repeat { pixel, count } => {
+                    self.packet = Packet::Repeat {
+                        pixel,
+                        count: count - 1,
+                    };
+                    return Ok(pixel);
+                }
+            }
+        }
+    }
+
+    /// Read a pixel as it is stored and return its color.
+    fn pixel<R: Read>(&mut self, reader: &mut R) -> Result<u16, Error<R::Error>> {
+        match self.header.format {
+            PixelFormat::Rgb565 => self.color(reader),
+            PixelFormat::Indexed { colors } => {
+                let index = self.byte(reader)?;
+                if index as u16 >= colors {
+                    return Err(Error::Invalid);
+                }
+                Ok(self.palette[index as usize])
+            }
+        }
+    }
+
+    fn color<R: Read>(&mut self, reader: &mut R) -> Result<u16, Error<R::Error>> {
+        Ok(u16::from_le_bytes([self.byte(reader)?, self.byte(reader)?]))
+    }
+
+    fn byte<R: Read>(&mut self, reader: &mut R) -> Result<u8, Error<R::Error>> {
+        if self.pos == self.len {
+            self.len = reader.read(&mut self.buffer)?;
+            self.pos = 0;
+            if self.len == 0 {
+                return Err(Error::Truncated);
+            }
+        }
+        self.pos += 1;
+        Ok(self.buffer[self.pos - 1])
+    }
+}
+
+/// An image in memory that can be drawn with embedded-graphics:
+///
+/// ```ignore
+/// let ferris = ImageAsset::new(include_bytes!("../ferris.img")).unwrap();
+/// Image::new(&ferris, Point::new(10, 80)).draw(&mut display)?;
+/// ```
+pub struct ImageAsset<'a> {
+    header: Header,
+    data: &'a [u8],
+}
+
+impl<'a> ImageAsset<'a> {
+    /// Return an error if `data` doesn't start with a valid header. Errors
+    /// in the pixel data end the drawing early.
+    pub fn new(data: &'a [u8]) -> Result<Self, Error<Infallible>> {
+        let header = Decoder::new(&mut &data[..])?.header();
+        Ok(Self { header, data })
+    }
+
+    pub fn header(&self) -> Header {
+        self.header
+    }
+}
+
+impl<'a> ImageDimensions for ImageAsset<'a> {
+    fn width(&self) -> u32 {
+        self.header.width as u32
+    }
+
+    fn height(&self) -> u32 {
+        self.header.height as u32
+    }
+}
+
+impl<'a> IntoPixelIter<Rgb565> for &ImageAsset<'a> {
+    type PixelIterator = Pixels<'a>;
+
+    fn pixel_iter(self) -> Self::PixelIterator {
+        let mut data = self.data;
+        let decoder = Decoder::new(&mut data).ok();
+        Pixels {
+            data,
+            decoder,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the pixels of an `ImageAsset`.
+pub struct Pixels<'a> {
+    data: &'a [u8],
+    /// `None` after the last pixel.
+    decoder: Option<Decoder>,
+    index: u32,
+}
+
+impl<'a> Iterator for Pixels<'a> {
+    type Item = Pixel<Rgb565>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let decoder = self.decoder.as_mut()?;
+        let width = decoder.header().width as u32;
+        let mut color = [0];
+        if decoder.read(&mut self.data, &mut color) != Ok(1) {
+            self.decoder = None;
+            return None;
+        }
+        let point = Point::new((self.index % width) as i32, (self.index / width) as i32);
+        self.index += 1;
+        Some(Pixel(point, RawU16::new(color[0]).into()))
+    }
+}
+
+/// The colors of an indexed image.
+struct Palette {
+    colors: [u16; MAX_COLORS],
+    len: usize,
+}
+
+impl Palette {
+    /// Return `None` if the pixels have more than `MAX_COLORS` colors.
+    fn of(pixels: &[u16]) -> Option<Self> {
+        let mut palette = Self {
+            colors: [0; MAX_COLORS],
+            len: 0,
+        };
+        for pixel in pixels {
+            if palette.index(*pixel).is_none() {
+                if palette.len == MAX_COLORS {
+                    return None;
+                }
+                palette.colors[palette.len] = *pixel;
+                palette.len += 1;
+            }
+        }
+        Some(palette)
+    }
+
+    fn index(&self, color: u16) -> Option<u8> {
+        self.colors[..self.len]
+            .iter()
+            .position(|c| *c == color)
+            .map(|index| index as u8)
+    }
+}
+
+/// Encode an image in the format that takes the least space. `write` is
+/// called with the encoded data piece by piece.
+pub fn encode(width: u16, height: u16, pixels: &[u16], write: impl FnMut(&[u8])) -> Header {
+    let mut best = None;
+    for indexed in [true, false].iter() {
+        for compression in [Compression::None, Compression::Rle].iter() {
+            let mut size = 0;
+            let encoded = encode_as(width, height, pixels, *indexed, *compression, |data| {
+                size += data.len()
+            });
+            best = match (encoded, best) {
+                (Some(_), Some((best_size, _, _))) if size >= best_size => best,
+                (Some(_), _) => Some((size, *indexed, *compression)),
+                (None, _) => best,
+            };
+        }
+    }
+    let (_, indexed, compression) = best.unwrap();
+    encode_as(width, height, pixels, indexed, compression, write).unwrap()
+}
+
+/// Encode an image with the specified compression, as an indexed image if
+/// `indexed` is set. Returns `None` if the image has too many colors to be
+/// indexed.
+pub fn encode_as(
+    width: u16,
+    height: u16,
+    pixels: &[u16],
+    indexed: bool,
+    compression: Compression,
+    mut write: impl FnMut(&[u8]),
+) -> Option<Header> {
+    assert_eq!(pixels.len(), width as usize * height as usize);
+    let palette = if indexed {
+        Some(Palette::of(pixels)?)
+    } else {
+        None
+    };
+    let header = Header {
+        width,
+        height,
+        format: match &palette {
+            Some(palette) => PixelFormat::Indexed {
+                colors: palette.len as u16,
+            },
+            None => PixelFormat::Rgb565,
+        },
+        compression,
+    };
+    write(&header.encode());
+    if let Some(palette) = &palette {
+        for color in &palette.colors[..palette.len] {
+            write(&color.to_le_bytes());
+        }
+    }
+
+    let write_pixels = |pixels: &[u16], write: &mut dyn FnMut(&[u8])| {
+        for pixel in pixels {
+            match &palette {
+                Some(palette) => write(&[palette.index(*pixel).unwrap()]),
+                None => write(&pixel.to_le_bytes()),
+            }
+        }
+    };
+    if compression == Compression::None {
+        write_pixels(pixels, &mut write);
+        return Some(header);
+    }
+    let mut i = 0;
+    while i < pixels.len() {
+        let run = pixels[i..]
+            .iter()
+            .take(MAX_RUN)
+            .take_while(|pixel| **pixel == pixels[i])
+            .count();
+        if run > 1 {
+            write(&[REPEAT | (run - 1) as u8]);
+            write_pixels(&pixels[i..i + 1], &mut write);
+            i += run;
+        } else {
+            // Everything up to the next run
+            let start = i;
+            while i < pixels.len()
+                && i - start < MAX_RUN
+                && (i + 1 == pixels.len() || pixels[i + 1] != pixels[i])
+            {
+                i += 1;
+            }
+            write(&[(i - start - 1) as u8]);
+            write_pixels(&pixels[start..i], &mut write);
+        }
+    }
+    Some(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::{ram::RamDevice, Fs, Mode};
+
+    fn image(width: u16, height: u16) -> Vec<u16> {
+        // Runs longer than a packet and pixels that differ from their
+        // neighbours, 207 colors
+        (0..width as u32 * height as u32)
+            .map(|i| match i % 300 {
+                0..=199 => (i / 150 % 7) as u16 * 0x1234,
+                _ => (i % 200) as u16 + 1,
+            })
+            .collect()
+    }
+
+    fn encoded(
+        width: u16,
+        height: u16,
+        pixels: &[u16],
+        indexed: bool,
+        compression: Compression,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        encode_as(width, height, pixels, indexed, compression, |bytes| {
+            data.extend_from_slice(bytes)
+        })
+        .unwrap();
+        data
+    }
+
+    /// Decode an image in chunks of `chunk` pixels.
+    fn decode<R: Read>(reader: &mut R, chunk: usize) -> Result<Vec<u16>, Error<R::Error>> {
+        let mut decoder = Decoder::new(reader)?;
+        let mut pixels = Vec::new();
+        let mut buf = vec![0; chunk];
+        loop {
+            let len = decoder.read(reader, &mut buf)?;
+            pixels.extend_from_slice(&buf[..len]);
+            if len < chunk {
+                return Ok(pixels);
+            }
+        }
+    }
+
+    #[test]
+    fn header() {
+        let header = Header {
+            width: 86,
+            height: 64,
+            format: PixelFormat::Rgb565,
+            compression: Compression::Rle,
+        };
+        let data = header.encode();
+        assert_eq!(&data[..8], b"PTIM\x01\x00\x01\x00");
+        assert_eq!(Header::decode::<()>(&data), Ok(header));
+        assert_eq!(header.pixels(), 86 * 64);
+
+        let indexed = Header {
+            format: PixelFormat::Indexed { colors: 256 },
+            ..header
+        };
+        let data = indexed.encode();
+        assert_eq!(&data[..8], b"PTIM\x01\x01\x01\xFF");
+        assert_eq!(Header::decode::<()>(&data), Ok(indexed));
+
+        let mut invalid = data;
+        invalid[0] = b'X';
+        assert_eq!(Header::decode::<()>(&invalid), Err(Error::Invalid));
+        let mut invalid = data;
+        invalid[4] = 2;
+        assert_eq!(Header::decode::<()>(&invalid), Err(Error::Invalid));
+        let mut unsupported = data;
+        unsupported[5] = 9;
+        assert_eq!(Header::decode::<()>(&unsupported), Err(Error::Unsupported));
+        let mut unsupported = data;
+        unsupported[6] = 9;
+        assert_eq!(Header::decode::<()>(&unsupported), Err(Error::Unsupported));
+    }
+
+    #[test]
+    fn decode_in_chunks() {
+        let pixels = image(86, 64);
+        for indexed in [false, true].iter() {
+            for compression in [Compression::None, Compression::Rle].iter() {
+                let data = encoded(86, 64, &pixels, *indexed, *compression);
+                for chunk in [1, 7, 86, 1000, 86 * 64].iter() {
+                    assert_eq!(decode(&mut &data[..], *chunk), Ok(pixels.clone()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn encode_smallest() {
+        let mut data = Vec::new();
+        let header = encode(86, 64, &image(86, 64), |bytes| {
+            data.extend_from_slice(bytes)
+        });
+        assert_eq!(header.format, PixelFormat::Indexed { colors: 207 });
+        assert_eq!(header.compression, Compression::Rle);
+        assert!(data.len() < 86 * 64);
+
+        // Too many colors for a palette, literal packets cost one byte per
+        // 128 pixels
+        let noise: Vec<u16> = (0..1000u32).map(|i| (i * 7919 % 65521) as u16).collect();
+        assert_eq!(
+            encoded(1000, 1, &noise, false, Compression::Rle).len(),
+            HEADER_LEN + 2000 + 8
+        );
+        assert_eq!(
+            encode_as(1000, 1, &noise, true, Compression::None, |_| {}),
+            None
+        );
+        let header = encode(1000, 1, &noise, |_| {});
+        assert_eq!(header.format, PixelFormat::Rgb565);
+        assert_eq!(header.compression, Compression::None);
+
+        // Few colors, but no runs
+        let stripes: Vec<u16> = (0..1000).map(|i| i % 2).collect();
+        let header = encode(1000, 1, &stripes, |_| {});
+        assert_eq!(header.format, PixelFormat::Indexed { colors: 2 });
+        assert_eq!(header.compression, Compression::None);
+    }
+
+    #[test]
+    fn truncated_or_invalid() {
+        let data = encoded(10, 10, &[0xF800; 100], false, Compression::Rle);
+        assert_eq!(
+            decode(&mut &data[..data.len() - 1], 10),
+            Err(Error::Truncated)
+        );
+        assert_eq!(decode(&mut &data[..5], 10), Err(Error::Invalid));
+        assert_eq!(
+            decode(&mut &b"not an image at all"[..], 10),
+            Err(Error::Invalid)
+        );
+
+        // Truncated palette
+        let data = encoded(10, 10, &image(10, 10), true, Compression::None);
+        assert_eq!(decode(&mut &data[..20], 10), Err(Error::Truncated));
+
+        // Index outside of the palette
+        let mut data = encoded(3, 1, &[0xF800, 0x001F, 0xF800], true, Compression::None);
+        assert_eq!(data[HEADER_LEN + 4..], [0, 1, 0]);
+        data[HEADER_LEN + 5] = 2;
+        assert_eq!(decode(&mut &data[..], 10), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn pixel_iter() {
+        let pixels = image(20, 20);
+        let mut data = Vec::new();
+        encode(20, 20, &pixels, |bytes| data.extend_from_slice(bytes));
+        let asset = ImageAsset::new(&data).unwrap();
+        assert_eq!((asset.width(), asset.height()), (20, 20));
+
+        let drawn: Vec<_> = asset.pixel_iter().collect();
+        assert_eq!(drawn.len(), 400);
+        assert_eq!(
+            drawn[0],
+            Pixel(Point::new(0, 0), RawU16::new(pixels[0]).into())
+        );
+        assert_eq!(
+            drawn[43],
+            Pixel(Point::new(3, 2), RawU16::new(pixels[43]).into())
+        );
+        assert_eq!(
+            drawn[351],
+            Pixel(Point::new(11, 17), RawU16::new(pixels[351]).into())
+        );
+
+        // Drawing stops at the end of the data
+        let asset = ImageAsset::new(&data[..data.len() - 10]).unwrap();
+        assert!(asset.pixel_iter().count() < 400);
+        assert!(ImageAsset::new(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn stream_from_file() {
+        let mut memory = vec![0xFF; 64 * fs::BLOCK_SIZE as usize];
+        let mut dev = RamDevice::new(&mut memory);
+        let mut fs = Fs::mount_or_format(&mut dev).unwrap();
+
+        // Large enough for several blocks
+        let pixels = image(240, 240);
+        let data = encoded(240, 240, &pixels, false, Compression::None);
+        let mut file = fs.open(&mut dev, "/face.img", Mode::Write).unwrap();
+        file.write(&mut fs, &mut dev, &data).unwrap();
+        file.close(&mut fs, &mut dev).unwrap();
+
+        // Borrow the device only while decoding, like the firmware does
+        let mut file = fs.open(&mut dev, "/face.img", Mode::Read).unwrap();
+        let mut decoder = Decoder::new(&mut FileReader::new(&mut file, &mut dev)).unwrap();
+        assert_eq!(decoder.header().width, 240);
+        let mut decoded = Vec::new();
+        let mut rows = [0; 240 * 8];
+        for _ in 0..30 {
+            let len = decoder
+                .read(&mut FileReader::new(&mut file, &mut dev), &mut rows)
+                .unwrap();
+            assert_eq!(len, rows.len());
+            decoded.extend_from_slice(&rows);
+        }
+        assert_eq!(decoded, pixels);
+        assert_eq!(
+            decoder.read(&mut FileReader::new(&mut file, &mut dev), &mut rows),
+            Ok(0)
+        );
+
+        // Read it again
+        file.seek(0);
+        assert_eq!(
+            decode(&mut FileReader::new(&mut file, &mut dev), 500),
+            Ok(pixels)
+        );
+    }
+}
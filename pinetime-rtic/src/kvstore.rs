@@ -0,0 +1,738 @@
+//! Log-structured key/value store for small values in NOR flash.
+//!
+//! The store occupies a few flash sectors, only one of which is active at a
+//! time. Setting a value appends a record to the active sector, the latest
+//! record of a key wins. When the active sector is full, the latest values
+//! are copied to the next sector (in a round robin fashion, so that all
+//! sectors wear evenly) and that sector becomes the active one.
+//!
+//! Every record and every sector header is protected by a CRC. A sector only
+//! becomes active once its header is written, which happens after all values
+//! were copied. If power is lost in the middle of writing, the interrupted
+//! record (or copy) is ignored when mounting the store again, so the store
+//! always contains either the old or the new value of a key.
+//!
+//! Sector layout:
+//!
+//! ```text
+//! header: magic (4) | sequence number (4) | reserved (2) | CRC (2)
+//! record: key (1) | length (1) | value (length) | CRC (2)
+//! record: ...
+//! erased space (0xFF)
+//! ```
+//!
+//! A record with length 0 removes the key.
+
+use core::marker::PhantomData;
+
+/// Size of a flash sector, the smallest unit that can be erased.
+pub const SECTOR_SIZE: u32 = 4096;
+
+/// Number of keys. Keys range from 0 to `MAX_KEYS - 1`.
+pub const MAX_KEYS: usize = 32;
+
+/// Maximum length of an encoded value. Small enough that all keys fit into a
+/// single sector.
+pub const MAX_VALUE_LEN: usize = 64;
+
+const MAGIC: [u8; 4] = *b"PTKV";
+const HEADER_LEN: u32 = 12;
+
+/// Length of a record without its value.
+const RECORD_OVERHEAD: u32 = 4;
+
+const ERASED: u8 = 0xFF;
+
+/// Flash memory that the store is kept in.
+pub trait Storage {
+    type Error;
+
+    /// Read data starting at `address`.
+    fn read(&mut self, address: u32, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Program data. Programming can only clear bits.
+    fn program(&mut self, address: u32, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Erase the sector starting at `address` (set all bytes to 0xFF).
+    fn erase_sector(&mut self, address: u32) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// Accessing the storage failed.
+    Storage(E),
+    /// The encoded value is empty or longer than `MAX_VALUE_LEN`.
+    TooLarge,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Storage(e)
+    }
+}
+
+/// A value that can be stored.
+pub trait Value: Sized {
+    /// Encode the value into `buf` (of `MAX_VALUE_LEN` bytes). Return the
+    /// encoded length.
+    fn encode(&self, buf: &mut [u8]) -> usize;
+
+    /// Decode a value. Return `None` if the data is invalid.
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_value_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl Value for $int {
+                fn encode(&self, buf: &mut [u8]) -> usize {
+                    let bytes = self.to_le_bytes();
+                    buf[..bytes.len()].copy_from_slice(&bytes);
+                    bytes.len()
+                }
+
+                fn decode(data: &[u8]) -> Option<Self> {
+                    let mut bytes = [0; core::mem::size_of::<$int>()];
+                    if data.len() != bytes.len() {
+                        return None;
+                    }
+                    bytes.copy_from_slice(data);
+                    Some(<$int>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_value_for_int!(u8, u16, u32, i8, i16, i32);
+
+impl Value for bool {
+    fn encode(&self, buf: &mut [u8]) -> usize {
+        buf[0] = *self as u8;
+        1
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        match data {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Value for [u8; 16] {
+    fn encode(&self, buf: &mut [u8]) -> usize {
+        buf[..16].copy_from_slice(self);
+        16
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        let mut value = [0; 16];
+        if data.len() != value.len() {
+            return None;
+        }
+        value.copy_from_slice(data);
+        Some(value)
+    }
+}
+
+/// A key of values of type `T`.
+#[derive(Debug)]
+pub struct Key<T> {
+    id: u8,
+    _value: PhantomData<T>,
+}
+
+impl<T> Key<T> {
+    /// Create a key. Panics if `id` is not below `MAX_KEYS`.
+    pub const fn new(id: u8) -> Self {
+        assert!((id as usize) < MAX_KEYS, "invalid key");
+        Self {
+            id,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Key<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Key<T> {}
+
+/// The state of a mounted store.
+pub struct Store {
+    /// Address of the first sector.
+    base: u32,
+    sectors: u32,
+
+    /// Index of the active sector.
+    active: u32,
+    /// Sequence number of the active sector.
+    sequence: u32,
+    /// Offset of the free space in the active sector.
+    offset: u32,
+    /// Offset of the latest record of every key in the active sector, or 0
+    /// if there is none.
+    index: [u16; MAX_KEYS],
+}
+
+impl Store {
+    /// Mount the store occupying `sectors` sectors starting at `base`. An
+    /// empty store is created if no valid sector is found.
+    pub fn mount<S: Storage>(storage: &mut S, base: u32, sectors: u32) -> Result<Self, S::Error> {
+        assert!(sectors >= 2, "at least two sectors are required");
+        let mut store = Self {
+            base,
+            sectors,
+            active: 0,
+            sequence: 0,
+            offset: SECTOR_SIZE,
+            index: [0; MAX_KEYS],
+        };
+
+        // Find the sector with the highest sequence number
+        let mut found = false;
+        for sector in 0..sectors {
+            let mut header = [0; HEADER_LEN as usize];
+            storage.read(store.sector_address(sector), &mut header)?;
+            if let Some(sequence) = parse_header(&header) {
+                if !found || sequence > store.sequence {
+                    found = true;
+                    store.active = sector;
+                    store.sequence = sequence;
+                }
+            }
+        }
+
+        if found {
+            store.scan(storage)?;
+        } else {
+            // Setting the first value moves on to the first sector, which
+            // erases it and writes its header.
+            store.active = sectors - 1;
+        }
+        Ok(store)
+    }
+
+    /// Return the value of a key, or `None` if it's not set or can't be
+    /// decoded.
+    pub fn get<S: Storage, T: Value>(
+        &self,
+        storage: &mut S,
+        key: Key<T>,
+    ) -> Result<Option<T>, S::Error> {
+        let mut buf = [0; MAX_VALUE_LEN];
+        Ok(match self.read_value(storage, key.id, &mut buf)? {
+            Some(len) => T::decode(&buf[..len]),
+            None => None,
+        })
+    }
+
+    /// Set the value of a key. Nothing is written if the value didn't
+    /// change.
+    pub fn set<S: Storage, T: Value>(
+        &mut self,
+        storage: &mut S,
+        key: Key<T>,
+        value: &T,
+    ) -> Result<(), Error<S::Error>> {
+        let mut buf = [0; MAX_VALUE_LEN];
+        let len = value.encode(&mut buf);
+        if len == 0 || len > MAX_VALUE_LEN {
+            return Err(Error::TooLarge);
+        }
+        let mut current = [0; MAX_VALUE_LEN];
+        if self.read_value(storage, key.id, &mut current)? == Some(len)
+            && current[..len] == buf[..len]
+        {
+            return Ok(());
+        }
+        self.append(storage, key.id, &buf[..len])
+    }
+
+    /// Remove a key.
+    pub fn remove<S: Storage, T>(
+        &mut self,
+        storage: &mut S,
+        key: Key<T>,
+    ) -> Result<(), Error<S::Error>> {
+        if self.index[key.id as usize] == 0 {
+            return Ok(());
+        }
+        self.append(storage, key.id, &[])
+    }
+
+    fn sector_address(&self, sector: u32) -> u32 {
+        self.base + sector * SECTOR_SIZE
+    }
+
+    /// Build the index of the active sector and find its free space.
+    fn scan<S: Storage>(&mut self, storage: &mut S) -> Result<(), S::Error> {
+        let address = self.sector_address(self.active);
+        let mut offset = HEADER_LEN;
+        let mut buf = [0; MAX_VALUE_LEN + RECORD_OVERHEAD as usize];
+        while offset + RECORD_OVERHEAD <= SECTOR_SIZE {
+            let (key, len) = {
+                let head = &mut buf[..2];
+                storage.read(address + offset, head)?;
+                (head[0], head[1] as usize)
+            };
+            if key == ERASED && len == ERASED as usize {
+                // Everything behind the last record must be erased, otherwise
+                // a record was only partially written.
+                if !is_erased(storage, address + offset, SECTOR_SIZE - offset)? {
+                    offset = SECTOR_SIZE;
+                }
+                break;
+            }
+            let record_len = RECORD_OVERHEAD + len as u32;
+            if key as usize >= MAX_KEYS || len > MAX_VALUE_LEN || offset + record_len > SECTOR_SIZE
+            {
+                offset = SECTOR_SIZE;
+                break;
+            }
+            let record = &mut buf[..record_len as usize];
+            storage.read(address + offset, record)?;
+            if !check_record(record) {
+                offset = SECTOR_SIZE;
+                break;
+            }
+            self.index[key as usize] = if len == 0 { 0 } else { offset as u16 };
+            offset += record_len;
+        }
+        // If a record is corrupted, the sector is considered full. The next
+        // write then copies the valid records to a new sector.
+        self.offset = offset;
+        Ok(())
+    }
+
+    /// Read the latest value of a key into `buf` and return its length.
+    fn read_value<S: Storage>(
+        &self,
+        storage: &mut S,
+        key: u8,
+        buf: &mut [u8; MAX_VALUE_LEN],
+    ) -> Result<Option<usize>, S::Error> {
+        let offset = self.index[key as usize];
+        if offset == 0 {
+            return Ok(None);
+        }
+        let mut record = [0; MAX_VALUE_LEN + RECORD_OVERHEAD as usize];
+        let address = self.sector_address(self.active) + offset as u32;
+        storage.read(address, &mut record[..2])?;
+        let len = record[1] as usize;
+        if len > MAX_VALUE_LEN {
+            return Ok(None);
+        }
+        let record = &mut record[..len + RECORD_OVERHEAD as usize];
+        storage.read(address, record)?;
+        if !check_record(record) {
+            return Ok(None);
+        }
+        buf[..len].copy_from_slice(&record[2..2 + len]);
+        Ok(Some(len))
+    }
+
+    /// Append a record, moving to the next sector if the active one is
+    /// full.
+    fn append<S: Storage>(
+        &mut self,
+        storage: &mut S,
+        key: u8,
+        value: &[u8],
+    ) -> Result<(), Error<S::Error>> {
+        let mut record = [0; MAX_VALUE_LEN + RECORD_OVERHEAD as usize];
+        let record = encode_record(key, value, &mut record);
+        if self.offset + record.len() as u32 > SECTOR_SIZE {
+            return self.compact(storage, key, record);
+        }
+        let address = self.sector_address(self.active) + self.offset;
+        storage.program(address, record)?;
+        self.index[key as usize] = if value.is_empty() {
+            0
+        } else {
+            self.offset as u16
+        };
+        self.offset += record.len() as u32;
+        Ok(())
+    }
+
+    /// Copy the latest values to the next sector, together with a new
+    /// record, and make it the active sector.
+    fn compact<S: Storage>(
+        &mut self,
+        storage: &mut S,
+        key: u8,
+        record: &[u8],
+    ) -> Result<(), Error<S::Error>> {
+        // Everything fits into the new sector, as all keys together are
+        // smaller than a sector
+        let mut lens = [0u8; MAX_KEYS];
+        for other in 0..MAX_KEYS as u8 {
+            if other == key || self.index[other as usize] == 0 {
+                continue;
+            }
+            let address = self.sector_address(self.active) + self.index[other as usize] as u32;
+            let mut head = [0; 2];
+            storage.read(address, &mut head)?;
+            lens[other as usize] = head[1];
+        }
+
+        let next = (self.active + 1) % self.sectors;
+        let next_address = self.sector_address(next);
+        storage.erase_sector(next_address)?;
+
+        let mut index = [0; MAX_KEYS];
+        let mut offset = HEADER_LEN;
+        let mut buf = [0; MAX_VALUE_LEN + RECORD_OVERHEAD as usize];
+        for other in 0..MAX_KEYS {
+            if lens[other] == 0 {
+                continue;
+            }
+            let copy = &mut buf[..RECORD_OVERHEAD as usize + lens[other] as usize];
+            let address = self.sector_address(self.active) + self.index[other] as u32;
+            storage.read(address, copy)?;
+            storage.program(next_address + offset, copy)?;
+            index[other] = offset as u16;
+            offset += copy.len() as u32;
+        }
+        // Removing a key doesn't need a record in the new sector
+        if record.len() > RECORD_OVERHEAD as usize {
+            storage.program(next_address + offset, record)?;
+            index[key as usize] = offset as u16;
+            offset += record.len() as u32;
+        }
+
+        // Writing the header makes the new sector the active one
+        let sequence = self.sequence.wrapping_add(1);
+        storage.program(next_address, &encode_header(sequence))?;
+
+        self.active = next;
+        self.sequence = sequence;
+        self.offset = offset;
+        self.index = index;
+        Ok(())
+    }
+}
+
+/// Return whether `len` bytes starting at `address` are erased.
+fn is_erased<S: Storage>(storage: &mut S, address: u32, len: u32) -> Result<bool, S::Error> {
+    let mut buf = [0; 64];
+    let mut checked = 0;
+    while checked < len {
+        let chunk = &mut buf[..(len - checked).min(64) as usize];
+        storage.read(address + checked, chunk)?;
+        if chunk.iter().any(|byte| *byte != ERASED) {
+            return Ok(false);
+        }
+        checked += chunk.len() as u32;
+    }
+    Ok(true)
+}
+
+fn encode_header(sequence: u32) -> [u8; HEADER_LEN as usize] {
+    let mut header = [0; HEADER_LEN as usize];
+    header[..4].copy_from_slice(&MAGIC);
+    header[4..8].copy_from_slice(&sequence.to_le_bytes());
+    let crc = crc16(&header[..10]);
+    header[10..].copy_from_slice(&crc.to_le_bytes());
+    header
+}
+
+/// Return the sequence number of a valid header.
+fn parse_header(header: &[u8; HEADER_LEN as usize]) -> Option<u32> {
+    let crc = u16::from_le_bytes([header[10], header[11]]);
+    if header[..4] != MAGIC || crc16(&header[..10]) != crc {
+        return None;
+    }
+    Some(u32::from_le_bytes([
+        header[4], header[5], header[6], header[7],
+    ]))
+}
+
+fn encode_record<'a>(key: u8, value: &[u8], buf: &'a mut [u8]) -> &'a [u8] {
+    let len = value.len();
+    buf[0] = key;
+    buf[1] = len as u8;
+    buf[2..2 + len].copy_from_slice(value);
+    let crc = crc16(&buf[..2 + len]);
+    buf[2 + len..4 + len].copy_from_slice(&crc.to_le_bytes());
+    &buf[..4 + len]
+}
+
+/// Check the CRC of a complete record.
+fn check_record(record: &[u8]) -> bool {
+    let (data, crc) = record.split_at(record.len() - 2);
+    crc16(data) == u16::from_le_bytes([crc[0], crc[1]])
+}
+
+/// CRC-16/CCITT-FALSE.
+pub fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0xFFFF, |crc, byte| {
+        (crc << 8) ^ CRC16_TABLE[((crc >> 8) as u8 ^ byte) as usize]
+    })
+}
+
+/// The CRC of every byte value, so that the CRC is computed bytewise.
+const CRC16_TABLE: [u16; 256] = crc16_table();
+
+const fn crc16_table() -> [u16; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const SECTORS: u32 = 3;
+
+    /// RAM backed storage with NOR flash semantics. Power is lost after a
+    /// limited number of write steps (program or erase operations), in the
+    /// middle of that step.
+    struct RamStorage {
+        memory: Vec<u8>,
+        steps_left: Option<usize>,
+        lost: bool,
+        steps: usize,
+        erases: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct PowerLoss;
+
+    impl RamStorage {
+        fn new() -> Self {
+            Self {
+                memory: vec![0xFF; (SECTORS * SECTOR_SIZE) as usize],
+                steps_left: None,
+                lost: false,
+                steps: 0,
+                erases: vec![0; SECTORS as usize],
+            }
+        }
+
+        /// Count a write step. Returns `false` if power is lost during the
+        /// step.
+        fn step(&mut self) -> bool {
+            self.steps += 1;
+            match &mut self.steps_left {
+                Some(0) => {
+                    self.lost = true;
+                    false
+                }
+                Some(left) => {
+                    *left -= 1;
+                    true
+                }
+                None => true,
+            }
+        }
+    }
+
+    impl Storage for RamStorage {
+        type Error = PowerLoss;
+
+        fn read(&mut self, address: u32, buf: &mut [u8]) -> Result<(), PowerLoss> {
+            if self.lost {
+                return Err(PowerLoss);
+            }
+            let start = address as usize;
+            buf.copy_from_slice(&self.memory[start..start + buf.len()]);
+            Ok(())
+        }
+
+        fn program(&mut self, address: u32, data: &[u8]) -> Result<(), PowerLoss> {
+            if self.lost {
+                return Err(PowerLoss);
+            }
+            let complete = self.step();
+            // Power loss in the middle: only the first half is programmed
+            let len = if complete { data.len() } else { data.len() / 2 };
+            let start = address as usize;
+            for (byte, new) in self.memory[start..start + len].iter_mut().zip(data) {
+                *byte &= new;
+            }
+            if complete {
+                Ok(())
+            } else {
+                Err(PowerLoss)
+            }
+        }
+
+        fn erase_sector(&mut self, address: u32) -> Result<(), PowerLoss> {
+            if self.lost {
+                return Err(PowerLoss);
+            }
+            let complete = self.step();
+            let len = if complete {
+                SECTOR_SIZE
+            } else {
+                SECTOR_SIZE / 3
+            };
+            let start = address as usize;
+            for byte in &mut self.memory[start..start + len as usize] {
+                *byte = 0xFF;
+            }
+            self.erases[(address / SECTOR_SIZE) as usize] += 1;
+            if complete {
+                Ok(())
+            } else {
+                Err(PowerLoss)
+            }
+        }
+    }
+
+    const NUMBER: Key<u32> = Key::new(1);
+    const FLAG: Key<bool> = Key::new(2);
+    const BYTES: Key<[u8; 16]> = Key::new(31);
+
+    #[test]
+    fn set_get_remove() {
+        let mut storage = RamStorage::new();
+        let mut store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+        assert_eq!(store.get(&mut storage, NUMBER), Ok(None));
+
+        store.set(&mut storage, NUMBER, &42).unwrap();
+        store.set(&mut storage, FLAG, &true).unwrap();
+        store.set(&mut storage, BYTES, &[7; 16]).unwrap();
+        store.set(&mut storage, NUMBER, &43).unwrap();
+        assert_eq!(store.get(&mut storage, NUMBER), Ok(Some(43)));
+        assert_eq!(store.get(&mut storage, FLAG), Ok(Some(true)));
+
+        store.remove(&mut storage, FLAG).unwrap();
+        assert_eq!(store.get(&mut storage, FLAG), Ok(None));
+
+        // Survives a reboot
+        let store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+        assert_eq!(store.get(&mut storage, NUMBER), Ok(Some(43)));
+        assert_eq!(store.get(&mut storage, FLAG), Ok(None));
+        assert_eq!(store.get(&mut storage, BYTES), Ok(Some([7; 16])));
+    }
+
+    #[test]
+    fn unchanged_values_are_not_written() {
+        let mut storage = RamStorage::new();
+        let mut store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+        store.set(&mut storage, NUMBER, &1).unwrap();
+        let steps = storage.steps;
+        store.set(&mut storage, NUMBER, &1).unwrap();
+        assert_eq!(storage.steps, steps);
+    }
+
+    #[test]
+    fn decode_errors() {
+        let mut storage = RamStorage::new();
+        let mut store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+        // Same key, different type
+        store.set(&mut storage, Key::<u8>::new(1), &1).unwrap();
+        assert_eq!(store.get(&mut storage, NUMBER), Ok(None));
+    }
+
+    #[test]
+    fn wear_leveling() {
+        let mut storage = RamStorage::new();
+        let mut store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+        for i in 0..10_000u32 {
+            store.set(&mut storage, NUMBER, &i).unwrap();
+            store.set(&mut storage, BYTES, &[i as u8; 16]).unwrap();
+        }
+        assert_eq!(store.get(&mut storage, NUMBER), Ok(Some(9999)));
+        let min = *storage.erases.iter().min().unwrap();
+        let max = *storage.erases.iter().max().unwrap();
+        assert!(min > 10);
+        assert!(max - min <= 1);
+    }
+
+    /// Run a sequence of operations that fills the sectors several times.
+    /// Returns the values that were set before an operation failed, and the
+    /// operation that failed.
+    fn run(
+        storage: &mut RamStorage,
+        model: &mut HashMap<u8, u32>,
+    ) -> Result<(), (u8, Option<u32>)> {
+        let mut store = Store::mount(storage, 0, SECTORS).map_err(|_| (0, None))?;
+        for i in 0..1500u32 {
+            let id = (i % 5) as u8 + 1;
+            let key = Key::<u32>::new(id);
+            if i % 7 == 0 {
+                store.remove(storage, key).map_err(|_| (id, None))?;
+                model.remove(&id);
+            } else {
+                store.set(storage, key, &i).map_err(|_| (id, Some(i)))?;
+                model.insert(id, i);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn power_loss_at_every_step() {
+        let mut storage = RamStorage::new();
+        run(&mut storage, &mut HashMap::new()).unwrap();
+        let total = storage.steps;
+        // Every sector was used at least once
+        assert!(storage.erases.iter().all(|count| *count > 0));
+
+        for cut in 0..total {
+            let mut storage = RamStorage::new();
+            storage.steps_left = Some(cut);
+            let mut model = HashMap::new();
+            let (failed_id, failed_value) = run(&mut storage, &mut model).unwrap_err();
+
+            // Power is back
+            storage.steps_left = None;
+            storage.lost = false;
+            let mut store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+            for id in 1..=5 {
+                let value = store.get(&mut storage, Key::<u32>::new(id)).unwrap();
+                let old = model.get(&id).copied();
+                if id == failed_id {
+                    assert!(
+                        value == old || value == failed_value,
+                        "cut {}: key {} is {:?}, expected {:?} or {:?}",
+                        cut,
+                        id,
+                        value,
+                        old,
+                        failed_value
+                    );
+                } else {
+                    assert_eq!(value, old, "cut {}: key {}", cut, id);
+                }
+            }
+
+            // The store keeps working
+            store.set(&mut storage, NUMBER, &1234).unwrap();
+            store.set(&mut storage, Key::<u32>::new(3), &5678).unwrap();
+            let store = Store::mount(&mut storage, 0, SECTORS).unwrap();
+            assert_eq!(store.get(&mut storage, NUMBER), Ok(Some(1234)));
+            assert_eq!(store.get(&mut storage, Key::<u32>::new(3)), Ok(Some(5678)));
+        }
+    }
+
+    #[test]
+    fn crc() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+}
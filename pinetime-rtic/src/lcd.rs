@@ -0,0 +1,279 @@
+//! ST7789 display driver that sends pixel data through EasyDMA.
+//!
+//! Tasks don't talk to the display controller themselves. They queue windows
+//! of pixels in a ring buffer through the `LcdWriter` and return right away
+//! (unless the queue is full). The SPIM1 interrupt handler sends the queued
+//! windows in the background, one EasyDMA transfer at a time, so that drawing
+//! doesn't block other tasks.
+//!
+//! Other devices may use the SPI bus between two transfers, even in the
+//! middle of a window. Writing is then resumed with the "memory write
+//! continue" command.
+
+use embedded_graphics::pixelcolor::{
+    raw::{RawData, RawU16},
+    Rgb565,
+};
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use nrf52832_hal::gpio::{Output, Pin, PushPull};
+use nrf52832_hal::pac;
+
+use pinetime_rtic::damage::Rect;
+use pinetime_rtic::ring::{self, Consumer, Producer, RingBuffer};
+use pinetime_rtic::ui::Panel;
+
+use crate::spi_bus::{Device, SpiBus};
+
+/// Number of bytes per EasyDMA transfer. Even, so that a transfer never ends
+/// in the middle of a pixel.
+const MAX_TRANSFER: usize = 254;
+
+/// Length of the header that precedes every window in the queue: the kind
+/// (1 byte), the area (4 × 2 bytes), the fill color (2 bytes) and a padding
+/// byte, which keeps the pixels aligned to two bytes.
+const HEADER_LEN: usize = 12;
+
+/// The window is followed by its pixels.
+const KIND_PIXELS: u8 = 0;
+/// The window is filled with a single color.
+const KIND_FILL: u8 = 1;
+
+// ST7789 commands
+const SWRESET: u8 = 0x01;
+const SLPOUT: u8 = 0x11;
+const NORON: u8 = 0x13;
+const INVOFF: u8 = 0x20;
+const INVON: u8 = 0x21;
+const DISPON: u8 = 0x29;
+const CASET: u8 = 0x2A;
+const RASET: u8 = 0x2B;
+const RAMWR: u8 = 0x2C;
+const MADCTL: u8 = 0x36;
+const COLMOD: u8 = 0x3A;
+const RAMWRC: u8 = 0x3C;
+
+/// The pins of the display controller besides the SPI bus.
+pub struct Pins {
+    /// Data/command select
+    pub dc: Pin<Output<PushPull>>,
+    pub rst: Pin<Output<PushPull>>,
+}
+
+/// The interrupt driven part of the driver.
+pub struct Lcd {
+    pins: Pins,
+    queue: Consumer<'static>,
+
+    /// Number of bytes of the current window that were not sent yet.
+    remaining: usize,
+    /// Whether the current window is filled with a single color.
+    filling: bool,
+    /// Length of the running transfer, or 0 if none is running.
+    in_flight: usize,
+
+    /// The fill color, repeated. Sent with EasyDMA, so it must not be moved
+    /// while a window is filled.
+    fill: [u8; MAX_TRANSFER],
+}
+
+/// The part of the driver that is used by tasks to queue windows.
+pub struct LcdWriter {
+    queue: Producer<'static>,
+}
+
+impl Lcd {
+    /// Initialize the display controller. The display is cleared by the
+    /// caller.
+    pub fn init(
+        pins: Pins,
+        bus: &mut SpiBus,
+        queue: &'static mut RingBuffer,
+        delay: &mut impl DelayUs<u32>,
+    ) -> (Self, LcdWriter) {
+        let (producer, consumer) = queue.split();
+        let mut lcd = Self {
+            pins,
+            queue: consumer,
+            remaining: 0,
+            filling: false,
+            in_flight: 0,
+            fill: [0; MAX_TRANSFER],
+        };
+
+        // Hardware reset
+        lcd.pins.rst.set_low().unwrap();
+        delay.delay_us(10);
+        lcd.pins.rst.set_high().unwrap();
+        delay.delay_us(120_000);
+
+        lcd.command(bus, SWRESET, &[]);
+        delay.delay_us(150_000);
+        lcd.command(bus, SLPOUT, &[]);
+        delay.delay_us(10_000);
+        lcd.command(bus, INVOFF, &[]);
+        // Portrait orientation
+        lcd.command(bus, MADCTL, &[0b0000_0000]);
+        // 16 bits per pixel (RGB565)
+        lcd.command(bus, COLMOD, &[0b0101_0101]);
+        lcd.command(bus, INVON, &[]);
+        lcd.command(bus, NORON, &[]);
+        delay.delay_us(10_000);
+        lcd.command(bus, DISPON, &[]);
+        delay.delay_us(10_000);
+
+        (lcd, LcdWriter { queue: producer })
+    }
+
+    /// Handle the SPIM1 interrupt. Called when a transfer ended, or when the
+    /// writer queued a window.
+    pub fn on_interrupt(&mut self, bus: &mut SpiBus) {
+        if bus.take_finished() {
+            if !self.filling {
+                self.queue.release(self.in_flight);
+            }
+            self.remaining -= self.in_flight;
+            self.in_flight = 0;
+        } else if self.in_flight != 0 {
+            // A window was queued while a transfer is running. It will be
+            // picked up when the transfer ended.
+            return;
+        }
+
+        while self.remaining == 0 {
+            if !self.start_window(bus) {
+                // Nothing left to send
+                return;
+            }
+        }
+
+        if !bus.select(Device::Lcd) {
+            // Another device used the bus in the middle of the window
+            self.command(bus, RAMWRC, &[]);
+            self.pins.dc.set_high().unwrap();
+        }
+
+        let (ptr, len) = if self.filling {
+            (self.fill.as_ptr(), self.remaining.min(self.fill.len()))
+        } else {
+            let pixels = self.queue.peek(self.remaining.min(MAX_TRANSFER));
+            (pixels.as_ptr(), pixels.len())
+        };
+        self.in_flight = len;
+        // The pixels stay in the queue (or in `fill`) until the transfer
+        // finished.
+        unsafe { bus.start(Device::Lcd, ptr, len) };
+    }
+
+    /// Take the next window from the queue and send its commands. Return
+    /// `false` if the queue is empty.
+    fn start_window(&mut self, bus: &mut SpiBus) -> bool {
+        let mut header = [0; HEADER_LEN];
+        if !self.queue.read(&mut header) {
+            return false;
+        }
+        let field = |index: usize| u16::from_le_bytes([header[index], header[index + 1]]);
+        let area = Rect::new(field(1), field(3), field(5), field(7));
+        self.filling = header[0] == KIND_FILL;
+        if self.filling {
+            for pixel in self.fill.chunks_exact_mut(2) {
+                pixel.copy_from_slice(&header[9..11]);
+            }
+        }
+        self.remaining = area.area() as usize * 2;
+        if self.remaining != 0 {
+            self.set_window(bus, area);
+        }
+        true
+    }
+
+    /// Select the area that the following pixels are written to.
+    fn set_window(&mut self, bus: &mut SpiBus, area: Rect) {
+        let [x0h, x0l] = area.x.to_be_bytes();
+        let [x1h, x1l] = (area.right() - 1).to_be_bytes();
+        let [y0h, y0l] = area.y.to_be_bytes();
+        let [y1h, y1l] = (area.bottom() - 1).to_be_bytes();
+        self.command(bus, CASET, &[x0h, x0l, x1h, x1l]);
+        self.command(bus, RASET, &[y0h, y0l, y1h, y1l]);
+        self.command(bus, RAMWR, &[]);
+        // Everything up to the next command is pixel data
+        self.pins.dc.set_high().unwrap();
+    }
+
+    /// Send a command and its parameters, blocking until done.
+    fn command(&mut self, bus: &mut SpiBus, command: u8, params: &[u8]) {
+        bus.select(Device::Lcd);
+        self.pins.dc.set_low().unwrap();
+        bus.write(Device::Lcd, &[command]);
+        if !params.is_empty() {
+            self.pins.dc.set_high().unwrap();
+            bus.write(Device::Lcd, params);
+        }
+    }
+}
+
+impl LcdWriter {
+    /// Queue a window of pixels (row by row). Only blocks if the queue is
+    /// full.
+    pub fn write_pixels(&mut self, area: Rect, pixels: &[u16]) {
+        debug_assert_eq!(pixels.len(), area.area() as usize);
+        self.reserve(HEADER_LEN + pixels.len() * 2);
+        self.queue.write(&header(KIND_PIXELS, area, 0));
+        // The display expects big endian pixels
+        let mut bytes = [0; 64];
+        for chunk in pixels.chunks(bytes.len() / 2) {
+            for (pixel, out) in chunk.iter().zip(bytes.chunks_exact_mut(2)) {
+                out.copy_from_slice(&pixel.to_be_bytes());
+            }
+            self.queue.write(&bytes[..chunk.len() * 2]);
+        }
+        self.queue.commit();
+        self.wake();
+    }
+
+    /// Queue a window filled with a single color.
+    pub fn fill(&mut self, area: Rect, color: Rgb565) {
+        self.reserve(HEADER_LEN);
+        let color = RawU16::from(color).into_inner();
+        self.queue.write(&header(KIND_FILL, area, color));
+        self.queue.commit();
+        self.wake();
+    }
+
+    /// Wait until `len` bytes can be queued.
+    fn reserve(&mut self, len: usize) {
+        assert!(len <= ring::CAPACITY, "window too large");
+        while self.queue.free() < len {
+            // The queue is drained by the SPIM1 interrupt
+            cortex_m::asm::wfi();
+        }
+    }
+
+    /// Make the interrupt handler pick up the queued window, in case no
+    /// transfer is running.
+    fn wake(&self) {
+        rtic::pend(pac::Interrupt::SPIM1_SPIS1_TWIM1_TWIS1_SPI1_TWI1);
+    }
+}
+
+impl Panel for LcdWriter {
+    fn write_pixels(&mut self, area: Rect, pixels: &[u16]) {
+        LcdWriter::write_pixels(self, area, pixels);
+    }
+
+    fn fill(&mut self, area: Rect, color: Rgb565) {
+        LcdWriter::fill(self, area, color);
+    }
+}
+
+/// Encode the header of a queued window.
+fn header(kind: u8, area: Rect, color: u16) -> [u8; HEADER_LEN] {
+    let mut header = [0; HEADER_LEN];
+    header[0] = kind;
+    header[1..3].copy_from_slice(&area.x.to_le_bytes());
+    header[3..5].copy_from_slice(&area.y.to_le_bytes());
+    header[5..7].copy_from_slice(&area.width.to_le_bytes());
+    header[7..9].copy_from_slice(&area.height.to_le_bytes());
+    header[9..11].copy_from_slice(&color.to_be_bytes());
+    header
+}
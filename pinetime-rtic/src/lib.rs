@@ -0,0 +1,35 @@
+//! Hardware independent parts of the PineTime firmware.
+//!
+//! The firmware binary (`main.rs`) does the RTIC and HAL wiring. Everything
+//! in this library can also be compiled for the host, so that it can be unit
+//! tested:
+//!
+//! ```text
+//! $ cargo test --lib --target x86_64-unknown-linux-gnu
+//! ```
+
+#![cfg_attr(not(test), no_std)]
+
+pub mod alarm;
+pub mod apps;
+pub mod backlight;
+pub mod battery;
+pub mod button;
+pub mod clock;
+pub mod countdown;
+pub mod damage;
+pub mod flash;
+pub mod font;
+pub mod fs;
+pub mod image;
+pub mod kvstore;
+pub mod list;
+pub mod monotonic_nrf52;
+pub mod notification;
+pub mod render;
+pub mod ring;
+pub mod settings;
+pub mod stopwatch;
+pub mod touch;
+pub mod ui;
+pub mod vibration;
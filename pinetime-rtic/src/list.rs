@@ -0,0 +1,381 @@
+//! A scrollable list, for menus and settings.
+//!
+//! Every item has a label, and optionally an icon (a character of the symbol
+//! font) and a value, which is shown right aligned. The selected item is
+//! highlighted. A press of the button selects the next item, and a long press
+//! (or a tap) activates the selected one. Swiping scrolls the list with
+//! inertia, and the selection follows so that it stays visible.
+//!
+//! The list doesn't own its items. They are provided by `Items` whenever
+//! rows are drawn. Only the rows that changed are redrawn: the ones whose
+//! selection changed, and the ones marked with `List::changed`. Scrolling
+//! redraws the whole list, there is no framebuffer to move the pixels in.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{pixelcolor::Rgb565, primitives::Rectangle, style::PrimitiveStyleBuilder};
+
+use crate::button::Gesture;
+use crate::damage::Rect;
+use crate::font;
+use crate::render::Strip;
+use crate::touch::TouchGesture;
+use crate::ui::{Context, Input, BACKGROUND_COLOR, LCD_H, LCD_W, MARGIN};
+
+/// Height of a row.
+pub const ROW_H: u16 = 40;
+
+/// Maximum length of a value in bytes.
+pub const VALUE_LEN: usize = 20;
+
+/// Maximum number of items.
+pub const MAX_ITEMS: usize = 128;
+
+/// Background color of the selected item.
+pub const HIGHLIGHT_COLOR: Rgb565 = Rgb565::new(0, 0b011000, 0b00110);
+
+/// Width reserved for the icons.
+const ICON_W: u16 = 24;
+
+/// Scroll speed after a swipe, in pixels per tick.
+const FLING_SPEED: i32 = 24;
+
+/// Maximum scroll speed, reached by swiping repeatedly.
+const MAX_SPEED: i32 = 72;
+
+/// Deceleration of the scrolling, in pixels per tick per tick.
+const FRICTION: i32 = 2;
+
+/// The items of a list.
+pub trait Items {
+    /// Return the number of items, at most `MAX_ITEMS`.
+    fn count(&self) -> usize;
+
+    fn label(&self, index: usize) -> &str;
+
+    /// Return the icon of an item, a character of `font::SYMBOLS_16`.
+    fn icon(&self, _index: usize) -> Option<char> {
+        None
+    }
+
+    /// Return the value of an item, e.g. the state of a setting. It can be
+    /// formatted into `buf`.
+    fn value<'a>(&'a self, _index: usize, _buf: &'a mut [u8; VALUE_LEN]) -> Option<&'a str> {
+        None
+    }
+}
+
+/// What is shown on the display.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Shown {
+    offset: i32,
+    selected: usize,
+    count: usize,
+}
+
+/// The state of a list. It covers the display from `top` to the bottom.
+pub struct List {
+    top: u16,
+    selected: usize,
+    /// Distance from the top of the first row to the top of the list.
+    offset: i32,
+    /// Scroll speed in pixels per tick, positive when scrolling towards the
+    /// end of the list.
+    velocity: i32,
+    shown: Option<Shown>,
+    /// The rows that must be redrawn, one bit per item.
+    changed: u128,
+}
+
+impl List {
+    pub const fn new(top: u16) -> Self {
+        Self {
+            top,
+            selected: 0,
+            offset: 0,
+            velocity: 0,
+            shown: None,
+            changed: 0,
+        }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select an item and scroll it into view.
+    pub fn select(&mut self, index: usize, count: usize) {
+        self.selected = index.min(count.saturating_sub(1));
+        let top = self.selected as i32 * ROW_H as i32;
+        if top < self.offset {
+            self.offset = top;
+        } else if top + ROW_H as i32 > self.offset + self.height() {
+            self.offset = top + ROW_H as i32 - self.height();
+        }
+        self.velocity = 0;
+    }
+
+    /// Mark an item as changed, e.g. because its value changed.
+    pub fn changed(&mut self, index: usize) {
+        if index < MAX_ITEMS {
+            self.changed |= 1 << index;
+        }
+    }
+
+    /// Forget what is shown, so that the whole list is drawn by the next
+    /// `tick`. Must be called when the display was cleared.
+    pub fn invalidate(&mut self) {
+        self.shown = None;
+    }
+
+    /// Return whether the list is still scrolling.
+    pub fn is_scrolling(&self) -> bool {
+        self.velocity != 0
+    }
+
+    /// Handle a button or touch gesture, `count` is the number of items.
+    /// Return the index of the item that was activated, if any.
+    pub fn input(&mut self, input: Input, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        match input {
+            Input::Button(event) => match event.gesture {
+                Gesture::Press => self.select((self.selected + 1) % count, count),
+                Gesture::LongPress => return Some(self.selected),
+                Gesture::DoublePress => {}
+            },
+            Input::Touch(gesture) => match gesture {
+                TouchGesture::SlideUp => self.fling(1),
+                TouchGesture::SlideDown => self.fling(-1),
+                TouchGesture::SingleTap | TouchGesture::LongPress if !self.is_scrolling() => {
+                    return Some(self.selected)
+                }
+                // A tap stops the scrolling
+                TouchGesture::SingleTap => self.velocity = 0,
+                _ => {}
+            },
+        }
+        None
+    }
+
+    /// Scroll with inertia, `direction` is 1 towards the end of the list.
+    fn fling(&mut self, direction: i32) {
+        let velocity = if self.velocity.signum() == direction {
+            self.velocity + direction * FLING_SPEED
+        } else {
+            direction * FLING_SPEED
+        };
+        self.velocity = velocity.clamp(-MAX_SPEED, MAX_SPEED);
+    }
+
+    /// Scroll by one step and slow down. Once the speed drops to zero, the
+    /// list keeps moving to the next row boundary.
+    fn scroll(&mut self, count: usize) {
+        if self.velocity == 0 || count == 0 {
+            return;
+        }
+        let row = ROW_H as i32;
+        let max_offset = (count as i32 * row - self.height()).max(0);
+        let mut offset = self.offset + self.velocity;
+        let speed = self.velocity.abs() - FRICTION;
+        if speed > 0 {
+            self.velocity = self.velocity.signum() * speed;
+        } else {
+            offset = if self.velocity > 0 {
+                (offset + row - 1).div_euclid(row) * row
+            } else {
+                offset.div_euclid(row) * row
+            };
+            self.velocity = 0;
+        }
+        if offset <= 0 || offset >= max_offset {
+            offset = offset.clamp(0, max_offset);
+            self.velocity = 0;
+        }
+        self.offset = offset;
+
+        // Keep the selection on one of the fully visible rows
+        let first = (offset + row - 1) / row;
+        let last = ((offset + self.height()) / row - 1).max(first);
+        let selected = (self.selected as i32).max(first).min(last) as usize;
+        self.selected = selected.min(count - 1);
+    }
+
+    fn height(&self) -> i32 {
+        (LCD_H - self.top) as i32
+    }
+
+    /// Return the area of the list covered by an item, if it is visible.
+    fn row_area(&self, index: usize) -> Option<Rect> {
+        let top = self.top as i32 + index as i32 * ROW_H as i32 - self.offset;
+        let (y0, y1) = (
+            top.max(self.top as i32),
+            (top + ROW_H as i32).min(LCD_H as i32),
+        );
+        if y0 >= y1 {
+            return None;
+        }
+        Some(Rect::new(0, y0 as u16, LCD_W, (y1 - y0) as u16))
+    }
+
+    /// Scroll and redraw what changed.
+    pub fn tick(&mut self, cx: &mut Context, items: &(impl Items + ?Sized)) {
+        let count = items.count().min(MAX_ITEMS);
+        self.scroll(count);
+
+        let shown = Shown {
+            offset: self.offset,
+            selected: self.selected,
+            count,
+        };
+        match self.shown {
+            Some(previous) if previous.offset == shown.offset && previous.count == count => {
+                let mut changed = self.changed;
+                if previous.selected != shown.selected {
+                    changed |= 1 << previous.selected | 1 << shown.selected;
+                }
+                for index in (0..count).filter(|index| changed & 1 << index != 0) {
+                    if let Some(area) = self.row_area(index) {
+                        cx.draw_area(area, |strip| self.draw_row(strip, items, index));
+                    }
+                }
+            }
+            _ => {
+                let area = Rect::new(0, self.top, LCD_W, LCD_H - self.top);
+                cx.draw_area(area, |strip| {
+                    for index in 0..count {
+                        self.draw_row(strip, items, index);
+                    }
+                });
+            }
+        }
+        self.shown = Some(shown);
+        self.changed = 0;
+    }
+
+    /// Draw an item, if it is visible in the strip.
+    fn draw_row(&self, strip: &mut Strip, items: &(impl Items + ?Sized), index: usize) {
+        let area = match self.row_area(index) {
+            Some(area) => area,
+            None => return,
+        };
+        if area.intersection(&strip.area()).is_none() {
+            return;
+        }
+        let top = self.top as i32 + index as i32 * ROW_H as i32 - self.offset;
+
+        let background = if index == self.selected {
+            let style = PrimitiveStyleBuilder::new()
+                .fill_color(HIGHLIGHT_COLOR)
+                .build();
+            Rectangle::new(
+                Point::new(0, top),
+                Point::new(LCD_W as i32 - 1, top + ROW_H as i32 - 1),
+            )
+            .into_styled(style)
+            .draw(strip)
+            .unwrap();
+            HIGHLIGHT_COLOR
+        } else {
+            BACKGROUND_COLOR
+        };
+        let style = font::TextStyle {
+            fonts: &[&font::TEXT_16, &font::SYMBOLS_16],
+            color: Rgb565::WHITE,
+            background,
+        };
+        let y = top + (ROW_H as i32 - style.line_height() as i32) / 2;
+
+        let mut x = MARGIN as i32;
+        if let Some(icon) = items.icon(index) {
+            let mut buf = [0u8; 4];
+            let icon = icon.encode_utf8(&mut buf);
+            let icon_style = font::TextStyle {
+                fonts: &[&font::SYMBOLS_16, &font::TEXT_16],
+                ..style
+            };
+            font::Text::new(icon, Point::new(x, y), icon_style)
+                .draw(strip)
+                .unwrap();
+            x += ICON_W as i32;
+        }
+        font::Text::new(items.label(index), Point::new(x, y), style)
+            .draw(strip)
+            .unwrap();
+
+        let mut buf = [0u8; VALUE_LEN];
+        if let Some(value) = items.value(index, &mut buf) {
+            let x = (LCD_W - MARGIN) as i32 - style.text_width(value) as i32;
+            font::Text::new(value, Point::new(x, y), style)
+                .draw(strip)
+                .unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::ButtonEvent;
+    use crate::monotonic_nrf52::Instant;
+
+    fn press(gesture: Gesture) -> Input {
+        Input::Button(ButtonEvent {
+            gesture,
+            at: Instant::from_counts(0),
+        })
+    }
+
+    #[test]
+    fn buttons() {
+        // Five rows are visible below the title
+        let mut list = List::new(40);
+        assert_eq!(list.input(press(Gesture::LongPress), 7), Some(0));
+        for _ in 0..5 {
+            list.input(press(Gesture::Press), 7);
+        }
+        // The sixth item is scrolled into view
+        assert_eq!(list.selected(), 5);
+        assert_eq!(list.offset, ROW_H as i32);
+        list.input(press(Gesture::Press), 7);
+        list.input(press(Gesture::Press), 7);
+        assert_eq!((list.selected(), list.offset), (0, 0));
+        assert_eq!(list.input(press(Gesture::Press), 0), None);
+    }
+
+    #[test]
+    fn inertia() {
+        let mut list = List::new(40);
+        list.input(Input::Touch(TouchGesture::SlideUp), 20);
+        let mut offsets = Vec::new();
+        while list.is_scrolling() {
+            list.scroll(20);
+            offsets.push(list.offset);
+        }
+        // Slows down and stops at the next row boundary
+        assert_eq!(offsets[..4], [24, 46, 66, 84]);
+        assert_eq!(offsets[offsets.len() - 2..], [154, 4 * ROW_H as i32]);
+        // The selection follows
+        assert_eq!(list.selected(), 4);
+        // A tap activates the selected item once the list stopped
+        assert_eq!(
+            list.input(Input::Touch(TouchGesture::SingleTap), 20),
+            Some(list.selected())
+        );
+
+        // Stops at the ends
+        for _ in 0..10 {
+            list.input(Input::Touch(TouchGesture::SlideDown), 20);
+        }
+        assert_eq!(list.velocity, -MAX_SPEED);
+        while list.is_scrolling() {
+            list.scroll(20);
+        }
+        // The selection stays on the last visible row
+        assert_eq!((list.offset, list.selected()), (0, 4));
+        list.input(Input::Touch(TouchGesture::SlideUp), 3);
+        list.scroll(3);
+        assert!(!list.is_scrolling());
+        assert_eq!(list.offset, 0);
+    }
+}
@@ -5,23 +5,13 @@
 #[cfg(not(test))]
 use panic_rtt_target as _;
 
-use debouncr::{debounce_6, Debouncer, Edge, Repeat6};
-use embedded_graphics::prelude::*;
-use embedded_graphics::{
-    fonts::{Font12x16, Text},
-    image::{Image, ImageRawLE},
-    pixelcolor::Rgb565,
-    primitives::rectangle::Rectangle,
-    style::{PrimitiveStyleBuilder, TextStyleBuilder},
-};
-use nrf52832_hal::gpio::{p0, Floating, Input, Level, Output, Pin, PushPull};
+use debouncr::{debounce_6, Debouncer, Repeat6};
+use nrf52832_hal::gpio::Level;
 use nrf52832_hal::prelude::*;
 use nrf52832_hal::{self as hal, pac};
-use numtoa::NumToA;
 use rtic::app;
 use rtt_target::{rprintln, rtt_init_print};
 use rubble::config::Config;
-use rubble::gatt::BatteryServiceAttrs;
 use rubble::l2cap::{BleChannelMap, L2CAPState};
 use rubble::link::ad_structure::AdStructure;
 use rubble::link::queue::{PacketQueue, SimpleQueue};
@@ -31,70 +21,114 @@ use rubble::time::{Duration as RubbleDuration, Timer};
 use rubble_nrf5x::radio::{BleRadio, PacketBuffer};
 use rubble_nrf5x::timer::BleTimer;
 use rubble_nrf5x::utils::get_device_address;
-use st7789::{self, Orientation};
 
-mod backlight;
-mod battery;
+mod board;
 mod delay;
-mod monotonic_nrf52;
-
-use monotonic_nrf52::U32Ext;
-
-const LCD_W: u16 = 240;
-const LCD_H: u16 = 240;
-
-const FERRIS_W: u16 = 86;
-const FERRIS_H: u16 = 64;
-
-const MARGIN: u16 = 10;
-
-const BACKGROUND_COLOR: Rgb565 = Rgb565::new(0, 0b000111, 0);
+mod gatt;
+mod lcd;
+mod spi_bus;
+
+use pinetime_rtic::alarm::{self, Alarms};
+use pinetime_rtic::button::GestureDetector;
+use pinetime_rtic::clock::{DateTime, WallClock};
+use pinetime_rtic::flash;
+use pinetime_rtic::fs::{self, Fs, Mode};
+use pinetime_rtic::image::{Decoder, FileReader};
+use pinetime_rtic::kvstore::{self, Store};
+use pinetime_rtic::monotonic_nrf52::{self, Instant, U32Ext};
+use pinetime_rtic::notification::Notifications;
+use pinetime_rtic::ring::RingBuffer;
+use pinetime_rtic::settings::{self, Settings};
+use pinetime_rtic::touch::{Cst816s, TouchGesture};
+use pinetime_rtic::ui::{self, Ui};
+use pinetime_rtic::vibration::{self, Pattern, Player, Step, VibrationMotor};
+
+/// Path of the ferris image in the file system.
+const FERRIS_PATH: &str = "/ferris.img";
+
+/// The built-in ferris image, created from `ferris.png` with `imgconv`. It
+/// is installed in the file system on first boot, and drawn from here if the
+/// file system can't be used.
+const FERRIS_IMAGE: &[u8] = include_bytes!("../ferris.img");
+
+/// Vibration pattern when the countdown expires.
+const COUNTDOWN_PATTERN: Pattern<'static> = Pattern::new(&[200, 200], 3);
+
+/// Backlight brightness when an alarm or a notification wakes up the
+/// display.
+const ALARM_BRIGHTNESS: u8 = 3;
+
+/// Number of flash sectors used by the file system: all of them up to the
+/// settings.
+const FS_SECTORS: u32 = settings::FLASH_ADDRESS / flash::SECTOR_SIZE;
 
 pub struct AppConfig {}
 
 impl Config for AppConfig {
     type Timer = BleTimer<hal::target::TIMER2>;
     type Transmitter = BleRadio;
-    type ChannelMapper = BleChannelMap<BatteryServiceAttrs, NoSecurity>;
+    type ChannelMapper = BleChannelMap<gatt::WatchServiceAttrs, NoSecurity>;
     type PacketQueue = &'static mut SimpleQueue;
 }
 
-#[app(device = nrf52832_hal::pac, peripherals = true, monotonic = crate::monotonic_nrf52::Tim1)]
+#[app(device = nrf52832_hal::pac, peripherals = true, monotonic = pinetime_rtic::monotonic_nrf52::Tim1)]
 const APP: () = {
     struct Resources {
+        // SPI bus, shared by the LCD and the flash
+        spi_bus: spi_bus::SpiBus,
+
+        // Settings, stored in the flash
+        settings: Settings,
+        settings_store: Option<Store>,
+
         // LCD
-        lcd: st7789::ST7789<
-            hal::spim::Spim<pac::SPIM1>,
-            p0::P0_18<Output<PushPull>>,
-            p0::P0_26<Output<PushPull>>,
-            delay::TimerDelay,
-        >,
-        backlight: backlight::Backlight,
+        lcd: lcd::LcdWriter,
+        lcd_dma: lcd::Lcd,
+        #[init(RingBuffer::new())]
+        lcd_queue: RingBuffer,
+        backlight: board::Backlight,
 
         // Battery
-        battery: battery::BatteryStatus,
+        battery: board::BatteryStatus,
 
         // Button
-        button: Pin<Input<Floating>>,
+        button: board::Button,
         button_debouncer: Debouncer<u8, Repeat6>,
-
-        // Styles
-        text_style: TextStyleBuilder<Rgb565, Font12x16>,
-
-        // Counter resources
-        #[init(0)]
-        counter: usize,
-
-        // Ferris resources
-        ferris: ImageRawLE<'static, Rgb565>,
-        #[init(10)]
-        ferris_x_offset: i32,
-        #[init(80)]
-        ferris_y_offset: i32,
-        #[init(2)]
-        ferris_step_size: i32,
+        #[init(GestureDetector::new())]
+        button_gestures: GestureDetector,
+
+        // Touch
+        touch: board::Touch,
+        gpiote: pac::GPIOTE,
+
+        // Vibration motor
+        vibration: VibrationMotor,
+        #[init(Player::new())]
+        vibration_player: Player,
+
+        // User interface: the apps and the navigation between them
+        #[init(Ui::new())]
+        ui: Ui,
+
+        // The ferris image in the file system, `None` if the built-in one is
+        // drawn
+        ferris: Option<fs::File>,
+
+        // Clock and alarm resources
+        #[init(WallClock::new(Instant::from_counts(0)))]
+        wall_clock: WallClock,
+        // Minute of the wall clock that was last handled
+        #[init(None)]
+        clock_minute: Option<u64>,
+        #[init(Alarms::new())]
+        alarms: Alarms,
+
+        // Notifications received over BLE
+        #[init(Notifications::new())]
+        notifications: Notifications,
 
         // BLE
+        ble_address: [u8; 6],
         #[init([0; MIN_PDU_BUF])]
         ble_tx_buf: PacketBuffer,
         #[init([0; MIN_PDU_BUF])]
@@ -109,21 +143,24 @@ const APP: () = {
     }
 
     #[init(
-        resources = [ble_tx_buf, ble_rx_buf, tx_queue, rx_queue],
-        spawn = [write_counter, write_ferris, poll_button, show_battery_status, update_battery_status],
+        resources = [ble_tx_buf, ble_rx_buf, tx_queue, rx_queue, lcd_queue, alarms],
+        spawn = [poll_button, ui_tick, update_battery_status, clock_tick],
     )]
     fn init(cx: init::Context) -> init::LateResources {
         // Destructure device peripherals
         let pac::Peripherals {
             CLOCK,
             FICR,
+            GPIOTE,
             P0,
+            PWM0,
             RADIO,
             SAADC,
             SPIM1,
             TIMER0,
             TIMER1,
             TIMER2,
+            TWIM0,
             ..
         } = cx.device;
 
@@ -137,7 +174,7 @@ const APP: () = {
         let _clocks = hal::clocks::Clocks::new(CLOCK).enable_ext_hfosc();
 
         // Set up delay provider on TIMER0
-        let delay = delay::TimerDelay::new(TIMER0);
+        let mut delay = delay::TimerDelay::new(TIMER0);
 
         // Initialize monotonic timer on TIMER1 (for RTIC)
         monotonic_nrf52::Tim1::initialize(TIMER1);
@@ -148,25 +185,101 @@ const APP: () = {
         // Set up GPIO peripheral
         let gpio = hal::gpio::p0::Parts::new(P0);
 
+        // Set up the SPI bus. Chip select must be held low while driving the
+        // display. It must be high when using other SPI devices on the same
+        // bus (such as external flash storage) so that the display controller
+        // won't respond to the wrong commands. This is taken care of by the
+        // bus.
+        let spi_pins = spi_bus::Pins {
+            sck: gpio.p0_02.into_push_pull_output(Level::Low).degrade(),
+            mosi: gpio.p0_03.into_push_pull_output(Level::Low).degrade(),
+            miso: gpio.p0_04.into_floating_input().degrade(),
+            // LCD_CS (P0.25): LCD chip select
+            lcd_cs: gpio.p0_25.into_push_pull_output(Level::High).degrade(),
+            // SPI-CE# (P0.05): Flash chip select
+            flash_cs: gpio.p0_05.into_push_pull_output(Level::High).degrade(),
+        };
+        let mut spi_bus = spi_bus::SpiBus::new(SPIM1, spi_pins);
+
+        // Check that the flash responds on the shared bus
+        match spi_bus.with_flash(|flash| flash.read_jedec_id()) {
+            Ok(flash::JEDEC_ID) => rprintln!("Flash detected"),
+            Ok(id) => rprintln!("Unexpected flash JEDEC ID: {:02x?}", id),
+            Err(e) => rprintln!("Could not read flash JEDEC ID: {:?}", e),
+        }
+
+        // Load the settings. If that fails, start with the defaults, the
+        // store is mounted again when they are saved.
+        let loaded = spi_bus.with_flash(|flash| {
+            let mut store = Store::mount(flash, settings::FLASH_ADDRESS, settings::SECTORS)?;
+            let settings = Settings::load(&mut store, flash)?;
+            Ok::<_, kvstore::Error<_>>((store, settings))
+        });
+        let (settings_store, settings) = match loaded {
+            Ok((store, settings)) => (Some(store), settings),
+            Err(e) => {
+                rprintln!("Could not load settings: {:?}", e);
+                (None, Settings::default())
+            }
+        };
+        cx.resources.alarms.decode(&settings.alarms);
+
+        // Mount the file system, it is created on first boot. Then open the
+        // ferris image, which is installed unless it is there already.
+        let ferris = spi_bus.with_flash(|flash| {
+            let mut partition = flash.partition(0, FS_SECTORS);
+            let mut fs = Fs::mount_or_format(&mut partition)?;
+            let free = fs.free_space(&mut partition)?;
+            rprintln!("File system mounted, {} KiB free", free / 1024);
+            open_image(&mut fs, &mut partition, FERRIS_PATH, FERRIS_IMAGE)
+        });
+        let ferris = match ferris {
+            Ok(file) => Some(file),
+            Err(e) => {
+                rprintln!("Could not open the ferris image: {:?}", e);
+                None
+            }
+        };
+
         // Enable backlight
-        let backlight = backlight::Backlight::init(
-            gpio.p0_14.into_push_pull_output(Level::High).degrade(),
-            gpio.p0_22.into_push_pull_output(Level::High).degrade(),
-            gpio.p0_23.into_push_pull_output(Level::High).degrade(),
-            1,
-        );
+        let backlight = board::backlight(gpio.p0_14, gpio.p0_22, gpio.p0_23, settings.brightness);
 
         // Battery status
-        let battery = battery::BatteryStatus::init(
-            gpio.p0_12.into_floating_input(),
-            gpio.p0_31.into_floating_input(),
-            SAADC,
-        );
+        let battery = board::battery(gpio.p0_12, gpio.p0_31, SAADC);
 
         // Enable button
         gpio.p0_15.into_push_pull_output(Level::High);
         let button = gpio.p0_13.into_floating_input().degrade();
 
+        // Vibration motor (active low)
+        let mut vibration = VibrationMotor::init(
+            PWM0,
+            gpio.p0_16.into_push_pull_output(Level::High).degrade(),
+        );
+        vibration.set_intensity(settings.vibration);
+
+        // Reset touch controller
+        let mut touch_rst = gpio.p0_10.into_push_pull_output(Level::High);
+        touch_rst.set_low().unwrap();
+        cortex_m::asm::delay(64_000 * 5); // 5 ms
+        touch_rst.set_high().unwrap();
+        cortex_m::asm::delay(64_000 * 50); // 50 ms
+
+        // Initialize touch controller on TWIM0 (SDA P0.06, SCL P0.07)
+        let twim_pins = hal::twim::Pins {
+            scl: gpio.p0_07.into_floating_input().degrade(),
+            sda: gpio.p0_06.into_floating_input().degrade(),
+        };
+        let twim = hal::twim::Twim::new(TWIM0, twim_pins, hal::twim::Frequency::K400);
+        let touch = Cst816s::new(twim);
+
+        // The touch controller pulls TP_INT (P0.28) low when a touch event is
+        // available. Generate an interrupt on the falling edge.
+        gpio.p0_28.into_pullup_input();
+        GPIOTE.config[0]
+            .write(|w| unsafe { w.mode().event().psel().bits(28).polarity().hi_to_lo() });
+        GPIOTE.intenset.write(|w| w.in0().set());
+
         // Get bluetooth device address
         let device_address = get_device_address();
         rprintln!("Bluetooth device address: {:?}", device_address);
@@ -188,14 +301,16 @@ const APP: () = {
         let ble_r = Responder::<AppConfig>::new(
             tx,
             rx,
-            L2CAPState::new(BleChannelMap::with_attributes(BatteryServiceAttrs::new())),
+            L2CAPState::new(BleChannelMap::with_attributes(
+                gatt::WatchServiceAttrs::new(&cx.resources.alarms.encode()),
+            )),
         );
 
         // Send advertisement and set up regular interrupt
         let next_update = ble_ll
             .start_advertise(
                 RubbleDuration::from_millis(200),
-                &[AdStructure::CompleteLocalName("Rusty PineTime")],
+                &[AdStructure::CompleteLocalName(settings.ble_name.as_str())],
                 &mut radio,
                 tx_cons,
                 rx_prod,
@@ -203,96 +318,63 @@ const APP: () = {
             .unwrap();
         ble_ll.timer().configure_interrupt(next_update);
 
-        // Set up SPI pins
-        let spi_clk = gpio.p0_02.into_push_pull_output(Level::Low).degrade();
-        let spi_mosi = gpio.p0_03.into_push_pull_output(Level::Low).degrade();
-        let spi_miso = gpio.p0_04.into_floating_input().degrade();
-        let spi_pins = hal::spim::Pins {
-            sck: spi_clk,
-            miso: Some(spi_miso),
-            mosi: Some(spi_mosi),
-        };
-
         // Set up LCD pins
-        // LCD_CS (P0.25): Chip select
-        let mut lcd_cs = gpio.p0_25.into_push_pull_output(Level::Low);
-        // LCD_RS (P0.18): Data/clock pin
-        let lcd_dc = gpio.p0_18.into_push_pull_output(Level::Low);
-        // LCD_RESET (P0.26): Display reset
-        let lcd_rst = gpio.p0_26.into_push_pull_output(Level::Low);
-
-        // Initialize SPI
-        let spi = hal::Spim::new(
-            SPIM1,
-            spi_pins,
-            // Use SPI at 8MHz (the fastest clock available on the nRF52832)
-            // because otherwise refreshing will be super slow.
-            hal::spim::Frequency::M8,
-            // SPI must be used in mode 3. Mode 0 (the default) won't work.
-            hal::spim::MODE_3,
-            0,
-        );
-
-        // Chip select must be held low while driving the display. It must be high
-        // when using other SPI devices on the same bus (such as external flash
-        // storage) so that the display controller won't respond to the wrong
-        // commands.
-        lcd_cs.set_low().unwrap();
-
-        // Initialize LCD
-        let mut lcd = st7789::ST7789::new(spi, lcd_dc, lcd_rst, LCD_W, LCD_H, delay);
-        lcd.init().unwrap();
-        lcd.set_orientation(&Orientation::Portrait).unwrap();
-
-        // Draw something onto the LCD
-        let backdrop_style = PrimitiveStyleBuilder::new()
-            .fill_color(BACKGROUND_COLOR)
-            .build();
-        Rectangle::new(Point::new(0, 0), Point::new(LCD_W as i32, LCD_H as i32))
-            .into_styled(backdrop_style)
-            .draw(&mut lcd)
-            .unwrap();
-
-        // Choose text style
-        let text_style = TextStyleBuilder::new(Font12x16)
-            .text_color(Rgb565::WHITE)
-            .background_color(BACKGROUND_COLOR);
-
-        // Draw text
-        Text::new("PineTime", Point::new(10, 10))
-            .into_styled(text_style.build())
-            .draw(&mut lcd)
-            .unwrap();
+        let lcd_pins = lcd::Pins {
+            // LCD_RS (P0.18): Data/clock pin
+            dc: gpio.p0_18.into_push_pull_output(Level::Low).degrade(),
+            // LCD_RESET (P0.26): Display reset
+            rst: gpio.p0_26.into_push_pull_output(Level::Low).degrade(),
+        };
 
-        // Load ferris image data
-        let ferris = ImageRawLE::new(
-            include_bytes!("../ferris.raw"),
-            FERRIS_W as u32,
-            FERRIS_H as u32,
-        );
+        // Initialize LCD. Pixel data is sent in the background by the SPIM1
+        // interrupt.
+        let (lcd_dma, lcd) =
+            lcd::Lcd::init(lcd_pins, &mut spi_bus, cx.resources.lcd_queue, &mut delay);
 
         // Schedule tasks immediately
-        cx.spawn.write_counter().unwrap();
-        cx.spawn.write_ferris().unwrap();
         cx.spawn.poll_button().unwrap();
-        cx.spawn.show_battery_status().unwrap();
+        cx.spawn.ui_tick().unwrap();
         cx.spawn.update_battery_status().unwrap();
+        cx.spawn.clock_tick().unwrap();
 
         init::LateResources {
+            spi_bus,
+            settings,
+            settings_store,
             lcd,
+            lcd_dma,
             battery,
             backlight,
             button,
+            touch,
+            gpiote: GPIOTE,
+            vibration,
             button_debouncer: debounce_6(),
-            text_style,
             ferris,
 
+            ble_address: *device_address.raw(),
             radio,
             ble_ll,
             ble_r,
         }
     }
 
+    /// Count TIMER1 overflows to extend the monotonic timer to 64 bits.
+    #[task(binds = TIMER1, priority = 3)]
+    fn timer1(_cx: timer1::Context) {
+        monotonic_nrf52::Tim1::on_interrupt();
+    }
+
+    /// Send the queued LCD windows. Tasks that use other devices on the SPI
+    /// bus lock it, which keeps this from running in the meantime.
+    ///
+    /// Runs below the BLE interrupts: waiting for the flash (e.g. for an
+    /// erase when saving settings) with the bus locked must not delay them.
+    #[task(binds = SPIM1_SPIS1_TWIM1_TWIS1_SPI1_TWI1, resources = [lcd_dma, spi_bus], priority = 2)]
+    fn spim1(cx: spim1::Context) {
+        cx.resources.lcd_dma.on_interrupt(cx.resources.spi_bus);
+    }
+
     /// Hook up the RADIO interrupt to the Rubble BLE stack.
     #[task(binds = RADIO, resources = [radio, ble_ll], spawn = [ble_worker], priority = 3)]
     fn radio(cx: radio::Context) {
@@ -338,160 +420,369 @@ const APP: () = {
     }
 
     /// Lower-priority task spawned from RADIO and TIMER2 interrupts.
-    #[task(resources = [ble_r], priority = 2)]
+    #[task(
+        resources = [ble_r],
+        spawn = [set_time, set_alarms, receive_notifications],
+        priority = 2,
+    )]
     fn ble_worker(cx: ble_worker::Context) {
         // Fully drain the packet queue
         while cx.resources.ble_r.has_work() {
             cx.resources.ble_r.process_one().unwrap();
         }
+
+        // Apply written attributes
+        let spawn = cx.spawn;
+        gatt::with_attrs(cx.resources.ble_r, |attrs| {
+            if let Some(datetime) = attrs.take_current_time() {
+                spawn.set_time(datetime).ok();
+            }
+            if let Some(data) = attrs.take_alarms() {
+                spawn.set_alarms(data).ok();
+            }
+            if attrs.has_notifications() {
+                // If this fails, the task is pending and picks them up anyway
+                spawn.receive_notifications().ok();
+            }
+        });
     }
 
-    #[task(resources = [lcd, ferris, ferris_x_offset, ferris_y_offset, ferris_step_size], schedule = [write_ferris])]
-    fn write_ferris(cx: write_ferris::Context) {
-        // Draw ferris
-        Image::new(
-            &cx.resources.ferris,
-            Point::new(*cx.resources.ferris_x_offset, *cx.resources.ferris_y_offset),
-        )
-        .draw(cx.resources.lcd)
-        .unwrap();
-
-        // Clean up behind ferris
-        let backdrop_style = PrimitiveStyleBuilder::new()
-            .fill_color(BACKGROUND_COLOR)
-            .build();
-        let (p1, p2) = if *cx.resources.ferris_step_size > 0 {
-            // Clean up to the left
-            (
-                Point::new(
-                    *cx.resources.ferris_x_offset - *cx.resources.ferris_step_size,
-                    *cx.resources.ferris_y_offset,
-                ),
-                Point::new(
-                    *cx.resources.ferris_x_offset,
-                    *cx.resources.ferris_y_offset + (FERRIS_H as i32),
-                ),
-            )
-        } else {
-            // Clean up to the right
-            (
-                Point::new(
-                    *cx.resources.ferris_x_offset + FERRIS_W as i32,
-                    *cx.resources.ferris_y_offset,
-                ),
-                Point::new(
-                    *cx.resources.ferris_x_offset + FERRIS_W as i32
-                        - *cx.resources.ferris_step_size,
-                    *cx.resources.ferris_y_offset + (FERRIS_H as i32),
-                ),
-            )
+    /// Poll the button. Edges are only considered once the button was
+    /// pressed or released without bouncing for 12 (6 * 2) ms.
+    #[task(resources = [button, button_debouncer, button_gestures], spawn = [ui_event], schedule = [poll_button])]
+    fn poll_button(cx: poll_button::Context) {
+        // Poll button
+        let pressed = cx.resources.button.is_high().unwrap();
+        let edge = cx.resources.button_debouncer.update(pressed);
+
+        // Detect gestures and dispatch event
+        let now = monotonic_nrf52::Instant::now();
+        if let Some(event) = cx.resources.button_gestures.update(edge, now) {
+            cx.spawn.ui_event(ui::Event::Button(event)).ok();
+        }
+
+        // Re-schedule the timer interrupt in 2ms
+        cx.schedule.poll_button(cx.scheduled + 2.millis()).unwrap();
+    }
+
+    /// Hook up the GPIOTE interrupt to the touch controller.
+    #[task(binds = GPIOTE, resources = [gpiote], spawn = [read_touch], priority = 2)]
+    fn gpiote(cx: gpiote::Context) {
+        let gpiote = cx.resources.gpiote;
+        if gpiote.events_in[0].read().bits() != 0 {
+            gpiote.events_in[0].write(|w| unsafe { w.bits(0) });
+            // If this fails, the touch data will be read anyway.
+            cx.spawn.read_touch().ok();
+        }
+    }
+
+    /// Read the touch event from the touch controller.
+    #[task(resources = [touch], spawn = [ui_event])]
+    fn read_touch(cx: read_touch::Context) {
+        match cx.resources.touch.read_event() {
+            Ok(Some(event)) => {
+                if event.gesture != TouchGesture::None {
+                    cx.spawn.ui_event(ui::Event::Touch(event.gesture)).ok();
+                }
+            }
+            Ok(None) => {}
+            Err(e) => rprintln!("Could not read touch event: {:?}", e),
+        }
+    }
+
+    /// Let the UI handle an event and draw to the LCD, then take care of the
+    /// effects of the event.
+    #[task(
+        resources = [
+            lcd,
+            ui,
+            alarms,
+            wall_clock,
+            battery,
+            backlight,
+            spi_bus,
+            ferris,
+            settings,
+            vibration,
+            ble_address,
+            notifications,
+        ],
+        spawn = [vibrate, stop_vibration, sync_ble_alarms, save_settings],
+        schedule = [countdown_expired],
+        capacity = 8,
+    )]
+    fn ui_event(cx: ui_event::Context, event: ui::Event) {
+        let battery = cx.resources.battery;
+        let mut images = FlashImages {
+            spi_bus: cx.resources.spi_bus,
+            ferris: cx.resources.ferris,
         };
-        Rectangle::new(p1, p2)
-            .into_styled(backdrop_style)
-            .draw(cx.resources.lcd)
-            .unwrap();
+        let env = ui::Env {
+            panel: cx.resources.lcd,
+            images: &mut images,
+            now: monotonic_nrf52::Instant::now(),
+            alarms: cx.resources.alarms,
+            wall_clock: cx.resources.wall_clock,
+            battery: ui::Battery {
+                voltage: battery.voltage(),
+                charging: battery.is_charging(),
+            },
+            settings: cx.resources.settings,
+            ble_address: *cx.resources.ble_address,
+            notifications: cx.resources.notifications,
+        };
+        let effects = cx.resources.ui.handle(event, env);
 
-        // Reset step size
-        if *cx.resources.ferris_x_offset as u16 > LCD_W - FERRIS_W - MARGIN {
-            *cx.resources.ferris_step_size = -*cx.resources.ferris_step_size;
-        } else if (*cx.resources.ferris_x_offset as u16) < MARGIN {
-            *cx.resources.ferris_step_size = -*cx.resources.ferris_step_size;
+        let backlight = cx.resources.backlight;
+        let settings = cx.resources.settings;
+        if effects.screen_off {
+            backlight.off();
         }
-        *cx.resources.ferris_x_offset += *cx.resources.ferris_step_size;
+        if effects.screen_on {
+            backlight.set(settings.brightness);
+        }
+        if effects.settings_changed {
+            backlight.set(settings.brightness);
+            cx.resources.vibration.set_intensity(settings.vibration);
+            cx.spawn.save_settings().ok();
+        }
+        if effects.cycle_brightness {
+            if backlight.get_brightness() < 7 {
+                backlight.brighter();
+            } else {
+                backlight.off();
+            }
+            cx.spawn.save_settings().ok();
+        }
+        if effects.stop_vibration {
+            cx.spawn.stop_vibration().ok();
+        }
+        if effects.alarms_changed {
+            cx.spawn.sync_ble_alarms().ok();
+        }
+        if let Some((ends, generation)) = effects.countdown_expiry {
+            // If this fails, the UI notices the expiry on the next tick
+            cx.schedule.countdown_expired(ends, generation).ok();
+        }
+        match effects.alert {
+            Some(ui::Alert::Countdown) => {
+                rprintln!("Countdown expired");
+                cx.spawn.vibrate(COUNTDOWN_PATTERN).ok();
+            }
+            Some(ui::Alert::Alarm(index)) => {
+                rprintln!("Alarm {} is ringing", index);
+                // Wake up the display
+                if backlight.get_brightness() == 0 {
+                    backlight.set(ALARM_BRIGHTNESS);
+                }
+                cx.spawn.vibrate(vibration::ALARM).ok();
+            }
+            Some(ui::Alert::Notification) => {
+                rprintln!("Showing notification");
+                if backlight.get_brightness() == 0 {
+                    backlight.set(ALARM_BRIGHTNESS);
+                }
+                cx.spawn.vibrate(vibration::NOTIFICATION).ok();
+            }
+            None => {}
+        }
+    }
 
-        // Re-schedule the timer interrupt
-        cx.schedule.write_ferris(cx.scheduled + 25.hz()).unwrap();
+    /// Pass a countdown expiry to the UI. Expiries are queued separately, so
+    /// that outdated ones (the countdown was paused or reset since) don't
+    /// take up the space of other UI events.
+    #[task(spawn = [ui_event], capacity = 4)]
+    fn countdown_expired(cx: countdown_expired::Context, generation: u32) {
+        // If this fails, the UI notices the expiry on the next tick
+        cx.spawn
+            .ui_event(ui::Event::CountdownExpired(generation))
+            .ok();
     }
 
-    #[task(resources = [lcd, text_style, counter], schedule = [write_counter])]
-    fn write_counter(cx: write_counter::Context) {
-        rprintln!("Counter is {}", cx.resources.counter);
+    /// Send a tick to the UI `ui::TICK_HZ` times per second, so that the
+    /// app on top updates what changed.
+    #[task(spawn = [ui_event], schedule = [ui_tick])]
+    fn ui_tick(cx: ui_tick::Context) {
+        // If this fails, the UI is busy and skips a tick
+        cx.spawn.ui_event(ui::Event::Tick).ok();
 
-        // Write counter to the display
-        let mut buf = [0u8; 20];
-        let text = cx.resources.counter.numtoa_str(10, &mut buf);
-        Text::new(text, Point::new(10, LCD_H as i32 - 10 - 16))
-            .into_styled(cx.resources.text_style.build())
-            .draw(cx.resources.lcd)
+        // Re-schedule the timer interrupt
+        cx.schedule
+            .ui_tick(cx.scheduled + ui::TICK_HZ.hz())
             .unwrap();
+    }
 
-        // Increment counter
-        *cx.resources.counter += 1;
+    /// Play a vibration pattern, replacing the one that is currently played.
+    #[task(resources = [vibration_player], spawn = [vibration_step], capacity = 2)]
+    fn vibrate(cx: vibrate::Context, pattern: Pattern<'static>) {
+        let generation = cx.resources.vibration_player.play(&pattern);
+        cx.spawn.vibration_step(generation).ok();
+    }
 
-        // Re-schedule the timer interrupt
-        cx.schedule.write_counter(cx.scheduled + 1.secs()).unwrap();
+    /// Stop playing the current vibration pattern.
+    #[task(resources = [vibration, vibration_player])]
+    fn stop_vibration(cx: stop_vibration::Context) {
+        cx.resources.vibration_player.stop();
+        cx.resources.vibration.off();
     }
 
-    #[task(resources = [button, button_debouncer], spawn = [button_pressed], schedule = [poll_button])]
-    fn poll_button(cx: poll_button::Context) {
-        // Poll button
-        let pressed = cx.resources.button.is_high().unwrap();
-        let edge = cx.resources.button_debouncer.update(pressed);
+    /// Apply the next step of a vibration pattern. Re-schedules itself until
+    /// the pattern is finished or replaced.
+    #[task(
+        resources = [vibration, vibration_player],
+        schedule = [vibration_step],
+        capacity = 4,
+    )]
+    fn vibration_step(cx: vibration_step::Context, generation: u32) {
+        let duration = match cx.resources.vibration_player.advance(generation) {
+            Some(Step::On(duration)) => {
+                cx.resources.vibration.on();
+                duration
+            }
+            Some(Step::Off(duration)) => {
+                cx.resources.vibration.off();
+                duration
+            }
+            Some(Step::Done) => {
+                cx.resources.vibration.off();
+                return;
+            }
+            // Another pattern was started since, which is handled by its own
+            // chain of steps.
+            None => return,
+        };
+        cx.schedule
+            .vibration_step(cx.scheduled + (duration as u32).millis(), generation)
+            .ok();
+    }
 
-        // Dispatch event
-        if edge == Some(Edge::Rising) {
-            cx.spawn.button_pressed().unwrap();
+    /// Check the alarms whenever a new minute of the wall clock starts.
+    #[task(
+        resources = [alarms, clock_minute, wall_clock],
+        spawn = [ui_event],
+        schedule = [clock_tick],
+    )]
+    fn clock_tick(cx: clock_tick::Context) {
+        let wall_clock = cx.resources.wall_clock;
+        let secs = wall_clock.secs(monotonic_nrf52::Instant::now());
+        let minute = secs / 60;
+        if *cx.resources.clock_minute != Some(minute) {
+            *cx.resources.clock_minute = Some(minute);
+            if wall_clock.is_set() {
+                if let Some(index) = cx.resources.alarms.due(&DateTime::from_secs(secs)) {
+                    cx.spawn.ui_event(ui::Event::AlarmRinging(index)).ok();
+                }
+            }
         }
 
-        // Re-schedule the timer interrupt in 2ms
-        cx.schedule.poll_button(cx.scheduled + 2.millis()).unwrap();
+        // Re-schedule the timer interrupt in 1s
+        cx.schedule.clock_tick(cx.scheduled + 1.secs()).unwrap();
     }
 
-    /// Called when button is pressed without bouncing for 12 (6 * 2) ms.
-    #[task(resources = [backlight])]
-    fn button_pressed(cx: button_pressed::Context) {
-        if cx.resources.backlight.get_brightness() < 7 {
-            cx.resources.backlight.brighter();
-        } else {
-            cx.resources.backlight.off();
+    /// Set the wall clock to the time written over BLE.
+    #[task(resources = [wall_clock])]
+    fn set_time(cx: set_time::Context, datetime: DateTime) {
+        if let Some(secs) = datetime.to_secs() {
+            rprintln!("Setting time to {:?}", datetime);
+            let now = monotonic_nrf52::Instant::now();
+            cx.resources.wall_clock.set(now, secs);
         }
     }
 
-    /// Fetch the battery status from the hardware. Update the text if
-    /// something changed.
-    #[task(resources = [battery], spawn = [show_battery_status], schedule = [update_battery_status])]
-    fn update_battery_status(cx: update_battery_status::Context) {
-        rprintln!("Update battery status");
+    /// Replace the alarms with the ones written over BLE.
+    #[task(resources = [alarms], spawn = [sync_ble_alarms])]
+    fn set_alarms(cx: set_alarms::Context, data: [u8; alarm::ENCODED_LEN]) {
+        if !cx.resources.alarms.decode(&data) {
+            rprintln!("Ignoring invalid alarms");
+        }
 
-        let changed = cx.resources.battery.update();
-        if changed {
-            rprintln!("Battery status changed");
-            cx.spawn.show_battery_status().unwrap();
+        // Make sure that the characteristic reflects the actual alarms
+        cx.spawn.sync_ble_alarms().ok();
+    }
+
+    /// Keep the notifications received over BLE and show the newest one.
+    /// Notifications without a timestamp get the time of the wall clock, if
+    /// it is set.
+    #[task(resources = [ble_r, notifications, wall_clock], spawn = [ui_event])]
+    fn receive_notifications(mut cx: receive_notifications::Context) {
+        let wall_clock = cx.resources.wall_clock;
+        let mut received = false;
+        while let Some(mut notification) = cx
+            .resources
+            .ble_r
+            .lock(|ble_r| gatt::with_attrs(ble_r, |attrs| attrs.take_notification()))
+        {
+            if notification.timestamp == 0 && wall_clock.is_set() {
+                let secs = wall_clock.secs(monotonic_nrf52::Instant::now());
+                notification.timestamp = secs as u32;
+            }
+            cx.resources.notifications.push(notification);
+            received = true;
+        }
+        if received {
+            cx.spawn.ui_event(ui::Event::Notification).ok();
         }
+    }
 
-        // Re-schedule the timer interrupt in 1s
-        cx.schedule
-            .update_battery_status(cx.scheduled + 1.secs())
-            .unwrap();
+    /// Update the BLE alarm characteristic with the current alarms and save
+    /// them.
+    #[task(resources = [alarms, ble_r], spawn = [save_settings])]
+    fn sync_ble_alarms(mut cx: sync_ble_alarms::Context) {
+        let data = cx.resources.alarms.encode();
+        cx.resources
+            .ble_r
+            .lock(|ble_r| gatt::with_attrs(ble_r, |attrs| attrs.set_alarms(&data)));
+        cx.spawn.save_settings().ok();
     }
 
-    /// Show the battery status on the LCD.
-    #[task(resources = [battery, lcd, text_style])]
-    fn show_battery_status(cx: show_battery_status::Context) {
-        let voltage = cx.resources.battery.voltage();
-        let charging = cx.resources.battery.is_charging();
+    /// Write the settings that changed to the flash. If the settings store
+    /// couldn't be mounted at boot, try again.
+    #[task(resources = [settings, settings_store, spi_bus, alarms, backlight])]
+    fn save_settings(mut cx: save_settings::Context) {
+        let settings = cx.resources.settings;
+        // Don't start with the backlight turned off after a reboot
+        let brightness = cx.resources.backlight.get_brightness();
+        if brightness != 0 {
+            settings.brightness = brightness;
+        }
+        settings.alarms = cx.resources.alarms.encode();
+
+        let store = cx.resources.settings_store;
+        let result = cx.resources.spi_bus.lock(|bus| {
+            bus.with_flash(|flash| {
+                if store.is_none() {
+                    let base = settings::FLASH_ADDRESS;
+                    *store = Some(Store::mount(flash, base, settings::SECTORS)?);
+                }
+                settings.save(store.as_mut().unwrap(), flash)
+            })
+        });
+        if let Err(e) = result {
+            rprintln!("Could not save settings: {:?}", e);
+        }
+    }
 
-        rprintln!(
-            "Battery status: {} ({})",
-            voltage,
-            if charging { "charging" } else { "discharging" },
-        );
+    /// Fetch the battery status from the hardware. The watch face shows it
+    /// on the next tick.
+    #[task(resources = [battery], schedule = [update_battery_status])]
+    fn update_battery_status(cx: update_battery_status::Context) {
+        let battery = cx.resources.battery;
+        if battery.update() {
+            rprintln!(
+                "Battery status: {} ({})",
+                battery.voltage(),
+                if battery.is_charging() {
+                    "charging"
+                } else {
+                    "discharging"
+                },
+            );
+        }
 
-        // Show battery status in top right corner
-        let mut buf = [0u8; 6];
-        (voltage / 10).numtoa(10, &mut buf[0..1]);
-        buf[1] = b'.';
-        (voltage % 10).numtoa(10, &mut buf[2..3]);
-        buf[3] = b'V';
-        buf[4] = b'/';
-        buf[5] = if charging { b'C' } else { b'D' };
-        let status = core::str::from_utf8(&buf).unwrap();
-        let text = Text::new(status, Point::zero()).into_styled(cx.resources.text_style.build());
-        let translation = Point::new(
-            LCD_W as i32 - text.size().width as i32 - MARGIN as i32,
-            MARGIN as i32,
-        );
-        text.translate(translation).draw(cx.resources.lcd).unwrap();
+        // Re-schedule the timer interrupt in 1s
+        cx.schedule
+            .update_battery_status(cx.scheduled + 1.secs())
+            .unwrap();
     }
 
     // Provide unused interrupts to RTIC for its scheduling
@@ -504,3 +795,77 @@ const APP: () = {
         fn SWI5_EGU5();
     }
 };
+
+/// Open an image in the file system. If it doesn't exist, it is created with
+/// `data` first.
+fn open_image<D: fs::BlockDevice>(
+    fs: &mut Fs,
+    dev: &mut D,
+    path: &str,
+    data: &[u8],
+) -> Result<fs::File, fs::Error<D::Error>> {
+    match fs.open(dev, path, Mode::Read) {
+        Err(fs::Error::NotFound) => {
+            let mut file = fs.open(dev, path, Mode::Write)?;
+            file.write(fs, dev, data)?;
+            file.close(fs, dev)?;
+            fs.open(dev, path, Mode::Read)
+        }
+        result => result,
+    }
+}
+
+/// Draws the images from the file system. If that fails, the built-in ones
+/// are drawn instead.
+struct FlashImages<'a, B> {
+    spi_bus: B,
+    ferris: &'a mut Option<fs::File>,
+}
+
+impl<B: rtic::Mutex<T = spi_bus::SpiBus>> ui::Images for FlashImages<'_, B> {
+    fn draw(&mut self, image: ui::Image, panel: &mut dyn ui::Panel, x: u16, y: u16) {
+        let (file, mut data) = match image {
+            ui::Image::Ferris => (&mut *self.ferris, FERRIS_IMAGE),
+        };
+
+        let spi_bus = &mut self.spi_bus;
+        let streamed = match file.as_mut() {
+            Some(file) => {
+                file.seek(0);
+                let decoder = spi_bus.lock(|bus| {
+                    bus.with_flash(|flash| {
+                        let mut partition = flash.partition(0, FS_SECTORS);
+                        Decoder::new(&mut FileReader::new(file, &mut partition))
+                    })
+                });
+                let result = decoder.and_then(|decoder| {
+                    ui::stream_image(panel, x, y, decoder, |decoder, pixels| {
+                        // Only lock the bus while reading, the LCD queue is
+                        // drained by the SPIM1 interrupt
+                        spi_bus.lock(|bus| {
+                            bus.with_flash(|flash| {
+                                let mut partition = flash.partition(0, FS_SECTORS);
+                                decoder.read(&mut FileReader::new(file, &mut partition), pixels)
+                            })
+                        })
+                    })
+                });
+                if let Err(e) = &result {
+                    rprintln!("Could not draw the {:?} image: {:?}", image, e);
+                }
+                result.is_ok()
+            }
+            None => false,
+        };
+        if !streamed {
+            *file = None;
+            Decoder::new(&mut data)
+                .and_then(|decoder| {
+                    ui::stream_image(panel, x, y, decoder, |decoder, pixels| {
+                        decoder.read(&mut data, pixels)
+                    })
+                })
+                .unwrap();
+        }
+    }
+}
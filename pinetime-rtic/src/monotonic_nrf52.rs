@@ -2,72 +2,142 @@
 //!
 //! Source:
 //! https://github.com/rtic-rs/rtic-examples/blob/master/rtic_v5/monotonic_nrf52/src/monotonic_nrf52.rs
+//!
+//! The hardware timer only has 32 bits, which at 1 MHz overflows after about
+//! 71 minutes. To get a monotonic timer that doesn't wrap around in practice,
+//! the number of elapsed half periods is counted in the TIMER1 interrupt (see
+//! `Tim1::on_interrupt`) and combined with the counter value into a 64 bit
+//! `Instant`.
+//!
+//! Only `Instant::now` and `Tim1` access the hardware. Everything else is pure
+//! arithmetic and compiles on the host as well, so that it can be unit tested.
 
-use core::u32;
+#[cfg(target_arch = "arm")]
+use core::sync::atomic::{compiler_fence, AtomicU32, Ordering};
 use core::{
-    cmp::Ordering,
     convert::{Infallible, TryInto},
     fmt, ops,
 };
+#[cfg(target_arch = "arm")]
 use nrf52832_hal::target;
-use rtic::Monotonic;
 
-/// A measurement of the counter. Opaque and useful only with `Duration`
+/// Numerator of the ratio between the system timer (SysTick) frequency and
+/// the monotonic timer frequency.
 ///
-/// # Correctness
+/// TIMER1 is being initialized with 1 MHz. If we multiply by 64, we get the
+/// sys clock of 64 MHz.
+const RATIO_NUMERATOR: u32 = 64;
+
+/// Denominator of the ratio between the system timer (SysTick) frequency and
+/// the monotonic timer frequency.
+const RATIO_DENOMINATOR: u32 = 1;
+
+/// Number of half periods (2^31 ticks) the 32 bit hardware counter went
+/// through since the last reset.
+#[cfg(target_arch = "arm")]
+static PERIOD: AtomicU32 = AtomicU32::new(0);
+
+/// Combine the half period count and the 32 bit hardware counter value into
+/// a 64 bit tick count.
 ///
-/// Adding or subtracting a `Duration` of more than `(1 << 31)` cycles to an `Instant` effectively
-/// makes it "wrap around" and creates an incorrect value. This is also true if the operation is
-/// done in steps, e.g. `(instant + dur) + dur` where `dur` is `(1 << 30)` ticks.
-#[derive(Clone, Copy, Eq, PartialEq)]
+/// The period is incremented both when the counter overflows and when it
+/// reaches the half way point. If the period is odd, the counter is expected
+/// to be in the upper half of its range. The XOR maps the counter value onto
+/// the half period that is being counted, which means that a period value that
+/// lags behind the hardware counter by less than half a period (about 35
+/// minutes) still results in a correct timestamp.
+#[cfg_attr(not(target_arch = "arm"), allow(dead_code))]
+fn calc_now(period: u32, counter: u32) -> u64 {
+    ((period as u64) << 31) + ((counter ^ ((period & 1) << 31)) as u64)
+}
+
+/// A measurement of the counter. Opaque and useful only with `Duration`
+///
+/// The instant is stored as a 64 bit tick count, which won't overflow during
+/// the lifetime of the device. All arithmetic saturates instead of wrapping.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Instant {
-    inner: i32,
+    inner: u64,
 }
 
 impl Instant {
     /// Returns an instant corresponding to "now"
+    #[cfg(target_arch = "arm")]
     pub fn now() -> Self {
-        let now = {
+        // The period must be read before the counter, see `calc_now`.
+        let period = PERIOD.load(Ordering::Relaxed);
+        compiler_fence(Ordering::Acquire);
+        let counter = {
             let timer = unsafe { &*target::TIMER1::ptr() };
             timer.tasks_capture[0].write(|w| unsafe { w.bits(1) });
             timer.cc[0].read().bits()
         };
 
-        Instant { inner: now as i32 }
+        Instant {
+            inner: calc_now(period, counter),
+        }
     }
 
     /// Returns the amount of time elapsed since this instant was created.
+    #[cfg(target_arch = "arm")]
     pub fn elapsed(&self) -> Duration {
         Instant::now() - *self
     }
 
+    /// Creates an instant from the underlying count
+    pub const fn from_counts(counts: u64) -> Self {
+        Instant { inner: counts }
+    }
+
     /// Returns the underlying count
-    pub fn counts(&self) -> u32 {
-        self.inner as u32
+    pub fn counts(&self) -> u64 {
+        self.inner
+    }
+
+    /// Returns the number of ticks elapsed from another instant to this one,
+    /// or 0 if `earlier` is later than `self`.
+    ///
+    /// Unlike `duration_since`, this can represent arbitrarily long spans.
+    pub fn ticks_since(&self, earlier: Instant) -> u64 {
+        self.inner.saturating_sub(earlier.inner)
     }
 
     /// Returns the amount of time elapsed from another instant to this one.
+    ///
+    /// If `earlier` is later than `self`, a zero duration is returned. If the
+    /// difference is larger than what a `Duration` can hold, the maximum
+    /// duration is returned.
     pub fn duration_since(&self, earlier: Instant) -> Duration {
-        let diff = self.inner - earlier.inner;
-        assert!(diff >= 0, "second instant is later than self");
-        Duration { inner: diff as u32 }
+        let diff = self.inner.saturating_sub(earlier.inner);
+        Duration {
+            inner: if diff > u32::MAX as u64 {
+                u32::MAX
+            } else {
+                diff as u32
+            },
+        }
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or `None` if `earlier` is later than `self`.
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        if earlier.inner > self.inner {
+            None
+        } else {
+            Some(self.duration_since(earlier))
+        }
     }
 }
 
 impl fmt::Debug for Instant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Instant")
-            .field(&(self.inner as u32))
-            .finish()
+        f.debug_tuple("Instant").field(&self.inner).finish()
     }
 }
 
 impl ops::AddAssign<Duration> for Instant {
     fn add_assign(&mut self, dur: Duration) {
-        // NOTE this is a debug assertion because there's no foolproof way to detect a wrap around;
-        // the user may write `(instant + dur) + dur` where `dur` is `(1<<31)-1` ticks.
-        debug_assert!(dur.inner < (1 << 31));
-        self.inner = self.inner.wrapping_add(dur.inner as i32);
+        self.inner = self.inner.saturating_add(dur.inner as u64);
     }
 }
 
@@ -82,9 +152,7 @@ impl ops::Add<Duration> for Instant {
 
 impl ops::SubAssign<Duration> for Instant {
     fn sub_assign(&mut self, dur: Duration) {
-        // NOTE see the NOTE in `<Instant as AddAssign<Duration>>::add_assign`
-        debug_assert!(dur.inner < (1 << 31));
-        self.inner = self.inner.wrapping_sub(dur.inner as i32);
+        self.inner = self.inner.saturating_sub(dur.inner as u64);
     }
 }
 
@@ -105,42 +173,124 @@ impl ops::Sub<Instant> for Instant {
     }
 }
 
-impl Ord for Instant {
-    fn cmp(&self, rhs: &Self) -> Ordering {
-        self.inner.wrapping_sub(rhs.inner).cmp(&0)
-    }
-}
-
-impl PartialOrd for Instant {
-    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        Some(self.cmp(rhs))
-    }
-}
-
 /// A `Duration` type to represent a span of time.
 ///
-/// This data type is only available on ARMv7-M
-///
 /// # Correctness
 ///
-/// This type is *not* appropriate for representing time spans in the order of, or larger than,
-/// seconds because it can hold a maximum of `(1 << 31)` "ticks" where each tick is the inverse of
-/// the CPU frequency, which usually is dozens of MHz.
-#[derive(Clone, Copy, Default, Eq, Ord, PartialEq, PartialOrd)]
+/// A duration can hold a maximum of `u32::MAX` ticks of the 1 MHz timer,
+/// which is about 71 minutes. All arithmetic saturates at zero and at the
+/// maximum instead of wrapping around.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Duration {
     inner: u32,
 }
 
+/// Number of ticks of the monotonic timer per second.
+pub const TICKS_PER_SECOND: u64 = micros_to_ticks(1_000_000);
+
+/// Converts microseconds into ticks of the monotonic timer.
+const fn micros_to_ticks(micros: u64) -> u64 {
+    (micros * (64 * RATIO_DENOMINATOR) as u64) / RATIO_NUMERATOR as u64
+}
+
 impl Duration {
+    /// The zero duration.
+    pub const ZERO: Duration = Duration { inner: 0 };
+
+    /// The longest duration that can be represented (about 71 minutes).
+    pub const MAX: Duration = Duration { inner: u32::MAX };
+
     /// Creates a new `Duration` from the specified number of clock cycles
-    pub fn from_cycles(cycles: u32) -> Self {
+    pub const fn from_cycles(cycles: u32) -> Self {
         Duration { inner: cycles }
     }
 
     /// Returns the total number of clock cycles contained by this `Duration`
-    pub fn as_cycles(&self) -> u32 {
+    pub const fn as_cycles(&self) -> u32 {
         self.inner
     }
+
+    /// Creates a `Duration` from a 64 bit tick count, or returns `None` if it
+    /// doesn't fit.
+    const fn try_from_ticks(ticks: u64) -> Option<Self> {
+        if ticks > u32::MAX as u64 {
+            None
+        } else {
+            Some(Duration {
+                inner: ticks as u32,
+            })
+        }
+    }
+
+    /// Creates a `Duration` from a 64 bit tick count, saturating at
+    /// `Duration::MAX`.
+    const fn from_ticks_saturating(ticks: u64) -> Self {
+        match Self::try_from_ticks(ticks) {
+            Some(dur) => dur,
+            None => Self::MAX,
+        }
+    }
+
+    /// Creates a `Duration` from the specified number of microseconds,
+    /// saturating at `Duration::MAX`.
+    pub const fn from_micros(micros: u32) -> Self {
+        Self::from_ticks_saturating(micros_to_ticks(micros as u64))
+    }
+
+    /// Creates a `Duration` from the specified number of milliseconds,
+    /// saturating at `Duration::MAX`.
+    pub const fn from_millis(millis: u32) -> Self {
+        Self::from_ticks_saturating(micros_to_ticks(millis as u64 * 1_000))
+    }
+
+    /// Creates a `Duration` from the specified number of seconds, saturating
+    /// at `Duration::MAX`.
+    pub const fn from_secs(secs: u32) -> Self {
+        Self::from_ticks_saturating(micros_to_ticks(secs as u64 * 1_000_000))
+    }
+
+    /// Creates a `Duration` from the period of the specified frequency. A
+    /// frequency of 0 Hz results in `Duration::MAX`.
+    pub const fn from_hz(hz: u32) -> Self {
+        match 1_000_000u32.checked_div(hz) {
+            Some(micros) => Self::from_micros(micros),
+            None => Self::MAX,
+        }
+    }
+
+    /// Creates a `Duration` from the specified number of microseconds, or
+    /// returns `None` if it can't be represented.
+    pub const fn try_from_micros(micros: u32) -> Option<Self> {
+        Self::try_from_ticks(micros_to_ticks(micros as u64))
+    }
+
+    /// Creates a `Duration` from the specified number of milliseconds, or
+    /// returns `None` if it can't be represented.
+    pub const fn try_from_millis(millis: u32) -> Option<Self> {
+        Self::try_from_ticks(micros_to_ticks(millis as u64 * 1_000))
+    }
+
+    /// Creates a `Duration` from the specified number of seconds, or returns
+    /// `None` if it can't be represented.
+    pub const fn try_from_secs(secs: u32) -> Option<Self> {
+        Self::try_from_ticks(micros_to_ticks(secs as u64 * 1_000_000))
+    }
+
+    /// Adds two durations, or returns `None` on overflow.
+    pub fn checked_add(self, rhs: Duration) -> Option<Self> {
+        self.inner.checked_add(rhs.inner).map(Duration::from_cycles)
+    }
+
+    /// Subtracts a duration, or returns `None` if the result would be
+    /// negative.
+    pub fn checked_sub(self, rhs: Duration) -> Option<Self> {
+        self.inner.checked_sub(rhs.inner).map(Duration::from_cycles)
+    }
+
+    /// Multiplies the duration, or returns `None` on overflow.
+    pub fn checked_mul(self, rhs: u32) -> Option<Self> {
+        self.inner.checked_mul(rhs).map(Duration::from_cycles)
+    }
 }
 
 // Used internally by RTIC to convert the duration into a known type
@@ -154,7 +304,7 @@ impl TryInto<u32> for Duration {
 
 impl ops::AddAssign for Duration {
     fn add_assign(&mut self, dur: Duration) {
-        self.inner += dur.inner;
+        *self = *self + dur;
     }
 }
 
@@ -163,7 +313,7 @@ impl ops::Add<Duration> for Duration {
 
     fn add(self, other: Self) -> Self {
         Duration {
-            inner: self.inner + other.inner,
+            inner: self.inner.saturating_add(other.inner),
         }
     }
 }
@@ -173,7 +323,7 @@ impl ops::Mul<u32> for Duration {
 
     fn mul(self, other: u32) -> Self {
         Duration {
-            inner: self.inner * other,
+            inner: self.inner.saturating_mul(other),
         }
     }
 }
@@ -186,7 +336,7 @@ impl ops::MulAssign<u32> for Duration {
 
 impl ops::SubAssign for Duration {
     fn sub_assign(&mut self, rhs: Duration) {
-        self.inner -= rhs.inner;
+        *self = *self - rhs;
     }
 }
 
@@ -195,14 +345,15 @@ impl ops::Sub<Duration> for Duration {
 
     fn sub(self, rhs: Self) -> Self {
         Duration {
-            inner: self.inner - rhs.inner,
+            inner: self.inner.saturating_sub(rhs.inner),
         }
     }
 }
 
 /// Adds the `millis` and `micros` methods to the `u32` type
 ///
-/// This trait is only available on ARMv7-M
+/// The plain conversions saturate at `Duration::MAX` (about 71 minutes), the
+/// `try_` variants return `None` instead.
 pub trait U32Ext {
     /// Converts the `u32` value as seconds into ticks
     fn secs(self) -> Duration;
@@ -215,26 +366,44 @@ pub trait U32Ext {
 
     /// Converts the `u32` value as hertz into ticks
     fn hz(self) -> Duration;
+
+    /// Converts the `u32` value as seconds into ticks, if representable
+    fn try_secs(self) -> Option<Duration>;
+
+    /// Converts the `u32` value as milliseconds into ticks, if representable
+    fn try_millis(self) -> Option<Duration>;
+
+    /// Converts the `u32` value as microseconds into ticks, if representable
+    fn try_micros(self) -> Option<Duration>;
 }
 
 impl U32Ext for u32 {
     fn secs(self) -> Duration {
-        self.millis() * 1_000
+        Duration::from_secs(self)
     }
 
     fn millis(self) -> Duration {
-        self.micros() * 1_000
+        Duration::from_millis(self)
     }
 
     fn micros(self) -> Duration {
-        let frac = Tim1::ratio();
-        Duration {
-            inner: (64 * frac.denominator * self) / frac.numerator,
-        }
+        Duration::from_micros(self)
     }
 
     fn hz(self) -> Duration {
-        (1_000_000 / self).micros()
+        Duration::from_hz(self)
+    }
+
+    fn try_secs(self) -> Option<Duration> {
+        Duration::try_from_secs(self)
+    }
+
+    fn try_millis(self) -> Option<Duration> {
+        Duration::try_from_millis(self)
+    }
+
+    fn try_micros(self) -> Option<Duration> {
+        Duration::try_from_micros(self)
     }
 }
 
@@ -242,29 +411,15 @@ impl U32Ext for u32 {
 /// to not allow for erroneous configuration.
 ///
 /// The timer must be initialized through `initialize()`.
+#[cfg(target_arch = "arm")]
 pub struct Tim1;
 
+#[cfg(target_arch = "arm")]
 impl Tim1 {
     pub fn initialize(timer: target::TIMER1) {
-        // Auto restart, make sure the entire timer won't stop for any event
-        timer.shorts.write(|w| {
-            w.compare0_clear()
-                .enabled()
-                .compare0_stop()
-                .disabled()
-                .compare1_clear()
-                .enabled()
-                .compare1_stop()
-                .disabled()
-                .compare2_clear()
-                .enabled()
-                .compare2_stop()
-                .disabled()
-                .compare3_clear()
-                .enabled()
-                .compare3_stop()
-                .disabled()
-        });
+        // Free running counter, make sure the timer won't stop or clear for
+        // any event
+        timer.shorts.reset();
 
         // 1 MHz mode
         timer.prescaler.write(|w| unsafe { w.prescaler().bits(4) });
@@ -272,31 +427,49 @@ impl Tim1 {
         // 32 bit mode
         timer.bitmode.write(|w| w.bitmode()._32bit());
 
-        // Set compare value to max, not sure if this is needed
-        timer.cc[0].write(|w| unsafe { w.cc().bits(u32::MAX) });
+        // CC[0] is used to capture the current counter value. CC[1] and CC[2]
+        // generate an interrupt on overflow and half way through the counter
+        // range, so that the period count can be updated.
+        timer.cc[1].write(|w| unsafe { w.cc().bits(0) });
+        timer.cc[2].write(|w| unsafe { w.cc().bits(1 << 31) });
+        timer
+            .intenset
+            .write(|w| w.compare1().set().compare2().set());
 
         // Clear the counter value
         timer.tasks_clear.write(|w| unsafe { w.bits(1) });
+        PERIOD.store(0, Ordering::Relaxed);
 
         // Start the timer
         timer.tasks_start.write(|w| unsafe { w.bits(1) });
+    }
 
-        // Throw away the timer, it is now setup and consumed
-        drop(timer);
+    /// Handle the TIMER1 interrupt.
+    ///
+    /// This must be called from the interrupt handler bound to TIMER1 at
+    /// least once every half period (about 35 minutes), otherwise the
+    /// monotonic timer will jump backwards.
+    pub fn on_interrupt() {
+        let timer = unsafe { &*target::TIMER1::ptr() };
+        for i in 1..=2 {
+            if timer.events_compare[i].read().bits() != 0 {
+                timer.events_compare[i].write(|w| unsafe { w.bits(0) });
+                PERIOD.fetch_add(1, Ordering::Relaxed);
+            }
+        }
     }
 }
 
+#[cfg(target_arch = "arm")]
 impl rtic::Monotonic for Tim1 {
     type Instant = Instant;
 
     /// The ratio between the system timer (SysTick) frequency and this clock
     /// frequency, i.e. `Monotonic clock * Fraction = System clock`.
     fn ratio() -> rtic::Fraction {
-        // The TIM1 timer is being initialized with 1 MHz. If we multiply by 64,
-        // we get the sys clock of 64 MHz.
         rtic::Fraction {
-            numerator: 64,
-            denominator: 1,
+            numerator: RATIO_NUMERATOR,
+            denominator: RATIO_DENOMINATOR,
         }
     }
 
@@ -307,11 +480,197 @@ impl rtic::Monotonic for Tim1 {
     unsafe fn reset() {
         let timer = &*target::TIMER1::ptr();
 
-        // Clear the counter value
+        // Clear the counter value and the period count
         timer.tasks_clear.write(|w| w.bits(1));
+        timer.events_compare[1].write(|w| w.bits(0));
+        timer.events_compare[2].write(|w| w.bits(0));
+        PERIOD.store(0, Ordering::Relaxed);
     }
 
     fn zero() -> Self::Instant {
         Instant { inner: 0 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: u32 = 1 << 31;
+
+    fn instant(ticks: u64) -> Instant {
+        Instant { inner: ticks }
+    }
+
+    #[test]
+    fn calc_now_first_period() {
+        assert_eq!(calc_now(0, 0), 0);
+        assert_eq!(calc_now(0, 12345), 12345);
+        assert_eq!(calc_now(1, HALF), HALF as u64);
+        assert_eq!(calc_now(1, u32::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn calc_now_across_overflow() {
+        // Counter wrapped around to 0, period updated by the interrupt
+        assert_eq!(calc_now(2, 0), 1 << 32);
+        assert_eq!(calc_now(2, 5), (1 << 32) + 5);
+        assert_eq!(calc_now(3, HALF + 5), (1 << 32) + HALF as u64 + 5);
+        assert_eq!(calc_now(4, 5), (2 << 32) + 5);
+    }
+
+    #[test]
+    fn calc_now_lagging_period() {
+        // Counter passed the half way point, interrupt not yet handled
+        assert_eq!(calc_now(0, HALF + 5), HALF as u64 + 5);
+        // Counter wrapped around, interrupt not yet handled
+        assert_eq!(calc_now(1, 5), (1 << 32) + 5);
+        assert_eq!(calc_now(3, 5), (2 << 32) + 5);
+    }
+
+    #[test]
+    fn instant_ordering_across_overflow() {
+        let before = instant(calc_now(1, u32::MAX - 10));
+        let after = instant(calc_now(2, 10));
+        assert!(before < after);
+        assert!(after > before);
+        assert_eq!(after - before, Duration::from_cycles(21));
+    }
+
+    #[test]
+    fn instant_add_across_overflow() {
+        let start = instant(u32::MAX as u64 - 10);
+        let later = start + Duration::from_cycles(20);
+        assert_eq!(later.counts(), u32::MAX as u64 + 10);
+        assert!(later > start);
+        assert_eq!(later - Duration::from_cycles(20), start);
+    }
+
+    #[test]
+    fn instant_add_large_duration() {
+        // Durations of more than (1 << 31) ticks no longer wrap around
+        let start = instant(1000);
+        let later = start + Duration::from_cycles(u32::MAX);
+        assert!(later > start);
+        assert_eq!(later.duration_since(start), Duration::from_cycles(u32::MAX));
+    }
+
+    #[test]
+    fn instant_arithmetic_saturates() {
+        assert_eq!(instant(10) - Duration::from_cycles(20), instant(0));
+        assert_eq!(
+            instant(u64::MAX) + Duration::from_cycles(1),
+            instant(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn duration_since_later_instant_saturates() {
+        // This used to panic with "second instant is later than self"
+        let earlier = instant(100);
+        let later = instant(200);
+        assert_eq!(earlier.duration_since(later), Duration::from_cycles(0));
+        assert_eq!(earlier - later, Duration::from_cycles(0));
+        assert_eq!(earlier.checked_duration_since(later), None);
+        assert_eq!(
+            later.checked_duration_since(earlier),
+            Some(Duration::from_cycles(100))
+        );
+    }
+
+    #[test]
+    fn duration_since_too_long_saturates() {
+        let earlier = instant(0);
+        let later = instant(1 << 40);
+        assert_eq!(
+            later.duration_since(earlier),
+            Duration::from_cycles(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn duration_arithmetic_saturates() {
+        let max = Duration::from_cycles(u32::MAX);
+        let one = Duration::from_cycles(1);
+        assert_eq!(max + one, max);
+        assert_eq!(one - max, Duration::from_cycles(0));
+        assert_eq!(max * 2, max);
+
+        let mut dur = one;
+        dur -= max;
+        assert_eq!(dur, Duration::from_cycles(0));
+        dur += max;
+        dur += max;
+        assert_eq!(dur, max);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(1.micros(), Duration::from_cycles(1));
+        assert_eq!(1.millis(), Duration::from_cycles(1_000));
+        assert_eq!(1.secs(), Duration::from_cycles(1_000_000));
+        assert_eq!(1_000.millis(), 1.secs());
+        assert_eq!(60.secs(), Duration::from_cycles(60_000_000));
+    }
+
+    #[test]
+    fn hz_precision() {
+        assert_eq!(1.hz(), 1.secs());
+        assert_eq!(25.hz(), 40.millis());
+        assert_eq!(1_000.hz(), 1.millis());
+        // Periods that aren't a whole number of ticks are truncated
+        assert_eq!(3.hz(), Duration::from_cycles(333_333));
+        assert_eq!(7.hz(), Duration::from_cycles(142_857));
+        assert_eq!(1_000_000.hz(), Duration::from_cycles(1));
+        assert_eq!(2_000_000.hz(), Duration::from_cycles(0));
+    }
+
+    #[test]
+    fn large_conversions() {
+        // These used to overflow in the intermediate u32 calculation
+        assert_eq!(100_000_000.micros(), 100.secs());
+        assert_eq!(100.secs(), Duration::from_cycles(100_000_000));
+        assert_eq!(4_000_000.millis(), 4_000.secs());
+        assert_eq!(4_294.secs(), Duration::from_cycles(4_294_000_000));
+        assert_eq!(u32::MAX.micros(), Duration::MAX);
+    }
+
+    #[test]
+    fn conversions_saturate() {
+        assert_eq!(4_295.secs(), Duration::MAX);
+        assert_eq!(u32::MAX.secs(), Duration::MAX);
+        assert_eq!(u32::MAX.millis(), Duration::MAX);
+        assert_eq!(0.hz(), Duration::MAX);
+    }
+
+    #[test]
+    fn try_conversions() {
+        assert_eq!(4_294.try_secs(), Some(4_294.secs()));
+        assert_eq!(4_295.try_secs(), None);
+        assert_eq!(4_294_967.try_millis(), Some(4_294_967.millis()));
+        assert_eq!(4_294_968.try_millis(), None);
+        assert_eq!(u32::MAX.try_micros(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn const_constructors() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        const FRAME: Duration = Duration::from_hz(25);
+        const TOO_LONG: Option<Duration> = Duration::try_from_secs(10_000);
+        assert_eq!(TIMEOUT, 10.secs());
+        assert_eq!(FRAME, 40.millis());
+        assert_eq!(TOO_LONG, None);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = Duration::MAX;
+        let one = 1.micros();
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(one.checked_add(one), Some(2.micros()));
+        assert_eq!(one.checked_sub(max), None);
+        assert_eq!(max.checked_sub(max), Some(Duration::ZERO));
+        assert_eq!(1.secs().checked_mul(4_294), Some(4_294.secs()));
+        assert_eq!(1.secs().checked_mul(4_295), None);
+    }
+}
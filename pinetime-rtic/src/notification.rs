@@ -0,0 +1,389 @@
+//! Notifications pushed by a phone over BLE.
+//!
+//! A write to the notification characteristic carries at most 20 bytes with
+//! the default ATT MTU, so notifications are written in fragments. Every
+//! fragment starts with a header byte: the index of the fragment (0 for the
+//! first one) in bits 0–6, and bit 7 set on the last one. The reassembled
+//! message is:
+//!
+//! - 1 byte category, see `Category`
+//! - 4 bytes timestamp: local time in seconds since 1970-01-01, little
+//!   endian, or 0 if it is unknown
+//! - the app name, the title and the body, each as a length byte followed by
+//!   UTF-8
+//!
+//! Texts that don't fit are truncated. Received notifications are kept in
+//! `Notifications`, which drops the oldest one when it is full.
+
+/// Number of notifications that are kept.
+pub const MAX_NOTIFICATIONS: usize = 8;
+
+/// Maximum length of a message in bytes. Longer messages are truncated.
+pub const MAX_MESSAGE_LEN: usize = 256;
+
+/// Space for the texts of a notification in bytes.
+pub const TEXT_LEN: usize = 240;
+
+/// Bit of the fragment header that marks the last fragment.
+const LAST_FRAGMENT: u8 = 0x80;
+
+/// Categories of notifications, with the IDs of Apple's Notification Center
+/// Service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Other,
+    IncomingCall,
+    MissedCall,
+    Voicemail,
+    Social,
+    Schedule,
+    Email,
+    News,
+    HealthAndFitness,
+    BusinessAndFinance,
+    Location,
+    Entertainment,
+}
+
+impl Category {
+    /// Return the category with the specified ID. Unknown IDs are `Other`.
+    pub fn from_id(id: u8) -> Self {
+        match id {
+            1 => Category::IncomingCall,
+            2 => Category::MissedCall,
+            3 => Category::Voicemail,
+            4 => Category::Social,
+            5 => Category::Schedule,
+            6 => Category::Email,
+            7 => Category::News,
+            8 => Category::HealthAndFitness,
+            9 => Category::BusinessAndFinance,
+            10 => Category::Location,
+            11 => Category::Entertainment,
+            _ => Category::Other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Notification {
+    pub category: Category,
+    /// Local time in seconds since 1970-01-01, 0 if it is unknown.
+    pub timestamp: u32,
+    /// The app name, the title and the body.
+    text: [u8; TEXT_LEN],
+    /// Ends of the app name, the title and the body in `text`.
+    ends: [u8; 3],
+}
+
+impl Notification {
+    const EMPTY: Self = Self {
+        category: Category::Other,
+        timestamp: 0,
+        text: [0; TEXT_LEN],
+        ends: [0; 3],
+    };
+
+    /// Create a notification. Texts that don't fit are truncated, the body
+    /// first.
+    pub fn new(category: Category, timestamp: u32, app: &str, title: &str, body: &str) -> Self {
+        let mut notification = Self {
+            category,
+            timestamp,
+            ..Self::EMPTY
+        };
+        let mut len = 0;
+        for (i, text) in [app, title, body].iter().enumerate() {
+            let text = truncate(text, TEXT_LEN - len);
+            notification.text[len..len + text.len()].copy_from_slice(text.as_bytes());
+            len += text.len();
+            notification.ends[i] = len as u8;
+        }
+        notification
+    }
+
+    /// Decode a reassembled message. Return `None` if it is too short.
+    pub fn decode(message: &[u8]) -> Option<Self> {
+        if message.len() < 5 {
+            return None;
+        }
+        let mut timestamp = [0; 4];
+        timestamp.copy_from_slice(&message[1..5]);
+
+        // Strings that were cut off by the end of the message are truncated
+        let mut texts = [""; 3];
+        let mut rest = &message[5..];
+        for text in texts.iter_mut() {
+            let (&len, tail) = match rest.split_first() {
+                Some(split) => split,
+                None => break,
+            };
+            let (bytes, tail) = tail.split_at((len as usize).min(tail.len()));
+            *text = match core::str::from_utf8(bytes) {
+                Ok(text) => text,
+                Err(e) => core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap(),
+            };
+            rest = tail;
+        }
+        Some(Self::new(
+            Category::from_id(message[0]),
+            u32::from_le_bytes(timestamp),
+            texts[0],
+            texts[1],
+            texts[2],
+        ))
+    }
+
+    fn text(&self, index: usize) -> &str {
+        let start = if index == 0 {
+            0
+        } else {
+            self.ends[index - 1] as usize
+        };
+        // Only created from valid strings, truncated at character boundaries
+        core::str::from_utf8(&self.text[start..self.ends[index] as usize]).unwrap()
+    }
+
+    /// Return the name of the app that sent the notification.
+    pub fn app(&self) -> &str {
+        self.text(0)
+    }
+
+    pub fn title(&self) -> &str {
+        self.text(1)
+    }
+
+    pub fn body(&self) -> &str {
+        self.text(2)
+    }
+}
+
+/// Return the longest prefix of `text` that fits into `len` bytes.
+fn truncate(text: &str, len: usize) -> &str {
+    if text.len() <= len {
+        return text;
+    }
+    let mut end = len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A fragment without a header.
+    Empty,
+    /// A fragment that doesn't follow the previous one.
+    OutOfOrder,
+    /// The message is too short to be a notification.
+    Invalid,
+}
+
+/// Reassembles notifications from fragments.
+pub struct Assembler {
+    message: [u8; MAX_MESSAGE_LEN],
+    len: usize,
+    /// Index of the next fragment, `None` until a first fragment is added.
+    next: Option<u8>,
+}
+
+impl Assembler {
+    pub const fn new() -> Self {
+        Self {
+            message: [0; MAX_MESSAGE_LEN],
+            len: 0,
+            next: None,
+        }
+    }
+
+    /// Add a fragment. Return the notification once its last fragment was
+    /// added. A first fragment discards an incomplete notification.
+    pub fn push(&mut self, fragment: &[u8]) -> Result<Option<Notification>, Error> {
+        let (&header, data) = fragment.split_first().ok_or(Error::Empty)?;
+        let index = header & !LAST_FRAGMENT;
+        if index == 0 {
+            self.len = 0;
+        } else if self.next != Some(index) {
+            self.next = None;
+            return Err(Error::OutOfOrder);
+        }
+        let len = data.len().min(MAX_MESSAGE_LEN - self.len);
+        self.message[self.len..self.len + len].copy_from_slice(&data[..len]);
+        self.len += len;
+
+        if header & LAST_FRAGMENT == 0 {
+            self.next = index
+                .checked_add(1)
+                .filter(|next| next & LAST_FRAGMENT == 0);
+            return Ok(None);
+        }
+        self.next = None;
+        Notification::decode(&self.message[..self.len])
+            .map(Some)
+            .ok_or(Error::Invalid)
+    }
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The notifications that were received, newest first.
+pub struct Notifications {
+    /// A ring buffer, `start` is the index of the oldest notification.
+    items: [Notification; MAX_NOTIFICATIONS],
+    start: usize,
+    len: usize,
+}
+
+impl Notifications {
+    pub const fn new() -> Self {
+        Self {
+            items: [Notification::EMPTY; MAX_NOTIFICATIONS],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Add a notification. If there are `MAX_NOTIFICATIONS` already, the
+    /// oldest one is dropped.
+    pub fn push(&mut self, notification: Notification) {
+        if self.len == MAX_NOTIFICATIONS {
+            self.start = (self.start + 1) % MAX_NOTIFICATIONS;
+            self.len -= 1;
+        }
+        self.items[(self.start + self.len) % MAX_NOTIFICATIONS] = notification;
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return a notification, 0 being the newest one.
+    pub fn get(&self, index: usize) -> Option<&Notification> {
+        if index >= self.len {
+            return None;
+        }
+        Some(&self.items[(self.start + self.len - 1 - index) % MAX_NOTIFICATIONS])
+    }
+
+    /// Remove and return the oldest notification.
+    pub fn pop_oldest(&mut self) -> Option<Notification> {
+        if self.len == 0 {
+            return None;
+        }
+        let notification = self.items[self.start];
+        self.start = (self.start + 1) % MAX_NOTIFICATIONS;
+        self.len -= 1;
+        Some(notification)
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode a notification and split it into fragments of 20 bytes.
+    fn split(category: u8, timestamp: u32, texts: [&str; 3]) -> Vec<Vec<u8>> {
+        let mut message = vec![category];
+        message.extend_from_slice(&timestamp.to_le_bytes());
+        for text in texts.iter() {
+            message.push(text.len() as u8);
+            message.extend_from_slice(text.as_bytes());
+        }
+        let count = message.chunks(19).count();
+        message
+            .chunks(19)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let last = if i == count - 1 { LAST_FRAGMENT } else { 0 };
+                let mut fragment = vec![i as u8 | last];
+                fragment.extend_from_slice(chunk);
+                fragment
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reassemble() {
+        let mut assembler = Assembler::new();
+        let fragments = split(
+            4,
+            1_600_000_000,
+            ["Chat", "Ferris", "Lunch at 12, at the usual place? 🦀"],
+        );
+        assert_eq!(fragments.len(), 3);
+        assert_eq!(assembler.push(&fragments[0]), Ok(None));
+        assert_eq!(assembler.push(&fragments[1]), Ok(None));
+        let notification = assembler.push(&fragments[2]).unwrap().unwrap();
+        assert_eq!(notification.category, Category::Social);
+        assert_eq!(notification.timestamp, 1_600_000_000);
+        assert_eq!(notification.app(), "Chat");
+        assert_eq!(notification.title(), "Ferris");
+        assert_eq!(notification.body(), "Lunch at 12, at the usual place? 🦀");
+
+        // Missing fragments discard the notification until the next one
+        assert_eq!(assembler.push(&fragments[0]), Ok(None));
+        assert_eq!(assembler.push(&fragments[2]), Err(Error::OutOfOrder));
+        assert_eq!(assembler.push(&fragments[1]), Err(Error::OutOfOrder));
+        assert_eq!(assembler.push(&[]), Err(Error::Empty));
+        assert_eq!(assembler.push(&[LAST_FRAGMENT, 4]), Err(Error::Invalid));
+        let single = split(99, 0, ["", "Hi", ""]);
+        let notification = assembler.push(&single[0]).unwrap().unwrap();
+        assert_eq!(notification.category, Category::Other);
+        assert_eq!((notification.app(), notification.title()), ("", "Hi"));
+    }
+
+    #[test]
+    fn truncate_texts() {
+        let body = "ä".repeat(200);
+        let notification = Notification::new(Category::Email, 0, "Mail", "Re: ü", &body);
+        assert_eq!(notification.title(), "Re: ü");
+        // 240 bytes, minus 4 and 6 bytes, leave room for 115 "ä"
+        assert_eq!(notification.body(), "ä".repeat(115));
+
+        // Cut off in the middle of a character
+        let message = [0, 0, 0, 0, 0, 3, b'A', b'p', b'p', 4, b'x', 0xC3];
+        let notification = Notification::decode(&message).unwrap();
+        assert_eq!(notification.app(), "App");
+        assert_eq!(notification.title(), "x");
+        assert_eq!(notification.body(), "");
+    }
+
+    #[test]
+    fn queue() {
+        let mut notifications = Notifications::new();
+        assert!(notifications.is_empty());
+        for i in 0..MAX_NOTIFICATIONS as u32 + 2 {
+            notifications.push(Notification::new(Category::News, i, "", "", ""));
+        }
+        assert_eq!(notifications.len(), MAX_NOTIFICATIONS);
+        assert_eq!(notifications.get(0).unwrap().timestamp, 9);
+        assert_eq!(notifications.get(7).unwrap().timestamp, 2);
+        assert_eq!(notifications.get(8), None);
+        assert_eq!(notifications.pop_oldest().unwrap().timestamp, 2);
+        assert_eq!(notifications.len(), MAX_NOTIFICATIONS - 1);
+        assert_eq!(notifications.get(6).unwrap().timestamp, 3);
+        notifications.clear();
+        assert_eq!(notifications.get(0), None);
+        assert_eq!(notifications.pop_oldest(), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Framebuffer-less rendering of damaged regions.
+//!
+//! There is not enough RAM for a framebuffer of the whole screen. Instead,
+//! damaged regions are rendered in horizontal strips that fit into a small
+//! buffer: for every strip, the buffer is filled with the background color,
+//! the scene is drawn into it (pixels outside of the strip are discarded) and
+//! the strip is pushed to the display as a single window.
+//!
+//! Widgets therefore only need to invalidate the area they used to cover and
+//! the area they cover now, without erasing anything themselves.
+
+use core::convert::Infallible;
+
+use embedded_graphics::{
+    drawable::Pixel,
+    geometry::Size,
+    pixelcolor::{
+        raw::{RawData, RawU16},
+        Rgb565,
+    },
+    DrawTarget,
+};
+
+use crate::damage::{Damage, Rect};
+
+/// Size of the strip buffer in pixels. Must be at least the screen width.
+pub const BUFFER_PIXELS: usize = 2048;
+
+/// A draw target covering a strip of a damaged region. Pixels outside of the
+/// strip are ignored.
+pub struct Strip<'a> {
+    area: Rect,
+    pixels: &'a mut [u16],
+    screen: Size,
+}
+
+impl<'a> Strip<'a> {
+    /// Return the area of the screen covered by the strip.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl<'a> DrawTarget<Rgb565> for Strip<'a> {
+    type Error = Infallible;
+
+    fn draw_pixel(&mut self, Pixel(point, color): Pixel<Rgb565>) -> Result<(), Self::Error> {
+        if point.x < 0 || point.y < 0 {
+            return Ok(());
+        }
+        let (x, y) = (point.x as u16, point.y as u16);
+        if self.area.contains(x, y) {
+            let index =
+                (y - self.area.y) as usize * self.area.width as usize + (x - self.area.x) as usize;
+            self.pixels[index] = RawU16::from(color).into_inner();
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> Size {
+        self.screen
+    }
+}
+
+pub struct Renderer {
+    damage: Damage,
+    screen: Rect,
+    background: Rgb565,
+    buffer: [u16; BUFFER_PIXELS],
+}
+
+impl Renderer {
+    /// Create a renderer for a screen of the specified size.
+    pub const fn new(width: u16, height: u16, background: Rgb565) -> Self {
+        Self {
+            damage: Damage::new(),
+            screen: Rect::new(0, 0, width, height),
+            background,
+            buffer: [0; BUFFER_PIXELS],
+        }
+    }
+
+    /// Mark an area of the screen as damaged. It will be redrawn by the next
+    /// call to `flush`.
+    pub fn invalidate(&mut self, rect: Rect) {
+        if let Some(rect) = rect.intersection(&self.screen) {
+            self.damage.add(rect);
+        }
+    }
+
+    /// Mark the whole screen as damaged.
+    pub fn invalidate_all(&mut self) {
+        self.damage.add(self.screen);
+    }
+
+    /// Return whether any area is damaged.
+    pub fn is_dirty(&self) -> bool {
+        !self.damage.is_empty()
+    }
+
+    /// Redraw all damaged regions.
+    ///
+    /// `draw` is called once per strip and must draw everything that is
+    /// visible in the damaged regions, except for the background. `push` is
+    /// called with the area and the pixels (row by row) of every strip and
+    /// must send them to the display. The buffer is reused for the next
+    /// strip as soon as `push` returns.
+    pub fn flush(&mut self, mut draw: impl FnMut(&mut Strip), mut push: impl FnMut(Rect, &[u16])) {
+        let background = RawU16::from(self.background).into_inner();
+        let screen = Size::new(self.screen.width as u32, self.screen.height as u32);
+        for region in self.damage.regions() {
+            let rows = (BUFFER_PIXELS / region.width as usize) as u16;
+            let mut y = region.y;
+            while y < region.bottom() {
+                let area = Rect::new(region.x, y, region.width, rows.min(region.bottom() - y));
+                let pixels = &mut self.buffer[..area.area() as usize];
+                for pixel in pixels.iter_mut() {
+                    *pixel = background;
+                }
+                draw(&mut Strip {
+                    area,
+                    pixels,
+                    screen,
+                });
+                push(area, &self.buffer[..area.area() as usize]);
+                y = area.bottom();
+            }
+        }
+        self.damage.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{prelude::*, primitives::Rectangle, style::PrimitiveStyle};
+
+    #[test]
+    fn flush_damaged_regions_in_strips() {
+        let mut renderer = Renderer::new(240, 240, Rgb565::BLACK);
+        renderer.invalidate(Rect::new(0, 0, 240, 20));
+        renderer.invalidate(Rect::new(230, 230, 20, 20));
+        assert!(renderer.is_dirty());
+
+        let mut windows = Vec::new();
+        let mut white = 0;
+        renderer.flush(
+            |strip| {
+                Rectangle::new(Point::new(0, 0), Point::new(9, 9))
+                    .into_styled(PrimitiveStyle::with_fill(Rgb565::WHITE))
+                    .draw(strip)
+                    .unwrap();
+            },
+            |area, pixels| {
+                assert_eq!(pixels.len(), area.area() as usize);
+                white += pixels.iter().filter(|p| **p == 0xFFFF).count();
+                windows.push(area);
+            },
+        );
+
+        // 2048 / 240 = 8 rows per strip, the second region is clipped
+        assert_eq!(
+            windows,
+            [
+                Rect::new(0, 0, 240, 8),
+                Rect::new(0, 8, 240, 8),
+                Rect::new(0, 16, 240, 4),
+                Rect::new(230, 230, 10, 10),
+            ]
+        );
+        assert_eq!(white, 100);
+        assert!(!renderer.is_dirty());
+    }
+}
@@ -0,0 +1,208 @@
+//! Single-producer single-consumer byte ring buffer.
+//!
+//! The producer (a task) appends data, the consumer (an interrupt handler)
+//! hands contiguous parts of it to a DMA peripheral and releases them once
+//! the transfer completed. Both ends only share the read and write counters,
+//! so no locking is required.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Capacity of the ring buffer in bytes. Must be a power of two, so that the
+/// counters can wrap around.
+pub const CAPACITY: usize = 16 * 1024;
+
+pub struct RingBuffer {
+    buffer: UnsafeCell<[u8; CAPACITY]>,
+    /// Total number of bytes written (wrapping).
+    head: AtomicUsize,
+    /// Total number of bytes released (wrapping).
+    tail: AtomicUsize,
+}
+
+// The buffer is only accessed through a single `Producer` and a single
+// `Consumer`, which never access the same bytes at the same time.
+unsafe impl Sync for RingBuffer {}
+
+impl RingBuffer {
+    pub const fn new() -> Self {
+        Self {
+            buffer: UnsafeCell::new([0; CAPACITY]),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Split the buffer into its producer and consumer ends.
+    pub fn split(&mut self) -> (Producer<'_>, Consumer<'_>) {
+        let ring = &*self;
+        let head = ring.head.load(Ordering::Relaxed);
+        (Producer { ring, head }, Consumer { ring })
+    }
+
+    fn len(&self) -> usize {
+        self.head
+            .load(Ordering::Acquire)
+            .wrapping_sub(self.tail.load(Ordering::Acquire))
+    }
+
+    fn ptr(&self) -> *mut u8 {
+        self.buffer.get() as *mut u8
+    }
+}
+
+impl Default for RingBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The writing end of a ring buffer.
+pub struct Producer<'a> {
+    ring: &'a RingBuffer,
+    /// Write position, including bytes that were not committed yet.
+    head: usize,
+}
+
+impl<'a> Producer<'a> {
+    /// Return the number of bytes that can be written.
+    pub fn free(&self) -> usize {
+        let tail = self.ring.tail.load(Ordering::Acquire);
+        CAPACITY - self.head.wrapping_sub(tail)
+    }
+
+    /// Append data without making it visible to the consumer yet.
+    ///
+    /// Panics if there is not enough free space.
+    pub fn write(&mut self, data: &[u8]) {
+        assert!(data.len() <= self.free(), "ring buffer overflow");
+        let start = self.head % CAPACITY;
+        let first = data.len().min(CAPACITY - start);
+        // The consumer never touches bytes between the committed and the
+        // pending head.
+        unsafe {
+            let ptr = self.ring.ptr();
+            core::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(start), first);
+            core::ptr::copy_nonoverlapping(data[first..].as_ptr(), ptr, data.len() - first);
+        }
+        self.head = self.head.wrapping_add(data.len());
+    }
+
+    /// Make all written data visible to the consumer.
+    pub fn commit(&mut self) {
+        self.ring.head.store(self.head, Ordering::Release);
+    }
+}
+
+/// The reading end of a ring buffer.
+pub struct Consumer<'a> {
+    ring: &'a RingBuffer,
+}
+
+impl<'a> Consumer<'a> {
+    /// Return the number of committed bytes that were not released yet.
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Return whether there is nothing to read.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the oldest unreleased bytes, at most `max`. The slice ends at
+    /// the end of the buffer, the rest is returned after releasing it.
+    ///
+    /// The bytes stay valid until they are released.
+    pub fn peek(&self, max: usize) -> &[u8] {
+        let start = self.ring.tail.load(Ordering::Relaxed) % CAPACITY;
+        let len = self.len().min(max).min(CAPACITY - start);
+        unsafe { core::slice::from_raw_parts(self.ring.ptr().add(start), len) }
+    }
+
+    /// Release the oldest `count` bytes, so that they can be overwritten by
+    /// the producer.
+    pub fn release(&mut self, count: usize) {
+        assert!(count <= self.len(), "releasing unread data");
+        let tail = self.ring.tail.load(Ordering::Relaxed);
+        self.ring
+            .tail
+            .store(tail.wrapping_add(count), Ordering::Release);
+    }
+
+    /// Copy the oldest bytes into `buf` and release them. Returns `false`
+    /// without reading anything if fewer bytes are available.
+    pub fn read(&mut self, buf: &mut [u8]) -> bool {
+        if self.len() < buf.len() {
+            return false;
+        }
+        let first = self.peek(buf.len()).len();
+        buf[..first].copy_from_slice(self.peek(first));
+        self.release(first);
+        let rest = buf.len() - first;
+        buf[first..].copy_from_slice(self.peek(rest));
+        self.release(rest);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_commit_read() {
+        let mut ring = Box::new(RingBuffer::new());
+        let (mut producer, mut consumer) = ring.split();
+        assert_eq!(producer.free(), CAPACITY);
+
+        producer.write(&[1, 2, 3]);
+        assert!(consumer.is_empty());
+        producer.commit();
+        assert_eq!(consumer.len(), 3);
+        assert_eq!(producer.free(), CAPACITY - 3);
+
+        let mut buf = [0; 2];
+        assert!(consumer.read(&mut buf));
+        assert_eq!(buf, [1, 2]);
+        assert!(!consumer.read(&mut buf));
+        assert_eq!(consumer.peek(10), &[3]);
+        consumer.release(1);
+        assert!(consumer.is_empty());
+        assert_eq!(producer.free(), CAPACITY);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut ring = Box::new(RingBuffer::new());
+        let (mut producer, mut consumer) = ring.split();
+        let filler = [0xAA; CAPACITY - 4];
+        producer.write(&filler);
+        producer.commit();
+        consumer.release(filler.len());
+
+        producer.write(&[1, 2, 3, 4, 5, 6]);
+        producer.commit();
+
+        // Peeking stops at the end of the buffer
+        assert_eq!(consumer.peek(100), &[1, 2, 3, 4]);
+        consumer.release(4);
+        assert_eq!(consumer.peek(100), &[5, 6]);
+
+        // Reading copies across the end
+        producer.write(&[7, 8]);
+        producer.commit();
+        let mut buf = [0; 4];
+        assert!(consumer.read(&mut buf));
+        assert_eq!(buf, [5, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ring buffer overflow")]
+    fn overflow() {
+        let mut ring = Box::new(RingBuffer::new());
+        let (mut producer, _consumer) = ring.split();
+        producer.write(&[0; CAPACITY]);
+        producer.write(&[0]);
+    }
+}
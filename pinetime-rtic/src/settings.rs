@@ -0,0 +1,234 @@
+//! Settings that persist across reboots.
+//!
+//! Every setting is stored under its own key in the key/value store, so that
+//! changing a setting only writes a small record. A key always keeps the
+//! type of its value. If the encoding of a setting changes, it gets a new key
+//! and `SCHEMA_VERSION` is increased; `load` then converts the old value.
+
+use crate::alarm::{self, Alarms};
+use crate::flash;
+use crate::kvstore::{self, Key, Storage, Store, Value};
+
+/// Version of the stored settings.
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// Number of flash sectors used for the settings.
+pub const SECTORS: u32 = 4;
+
+/// Address of the settings in the external flash (the last sectors).
+pub const FLASH_ADDRESS: u32 = flash::CAPACITY - SECTORS * flash::SECTOR_SIZE;
+
+/// Maximum length of the device name in bytes.
+pub const MAX_NAME_LEN: usize = 20;
+
+/// The screen timeouts that can be chosen, in seconds. 0 keeps the screen on.
+pub const SCREEN_TIMEOUTS: &[u8] = &[0, 5, 10, 15, 30, 60];
+
+/// The vibration intensities that can be chosen, in percent.
+pub const VIBRATION_LEVELS: &[u8] = &[20, 40, 60, 80, 100];
+
+const VERSION: Key<u16> = Key::new(0);
+const BRIGHTNESS: Key<u8> = Key::new(1);
+const ALARMS: Key<[u8; alarm::ENCODED_LEN]> = Key::new(2);
+const BLE_NAME: Key<DeviceName> = Key::new(3);
+const SCREEN_TIMEOUT: Key<u8> = Key::new(4);
+const CLOCK_12H: Key<bool> = Key::new(5);
+const VIBRATION: Key<u8> = Key::new(6);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Settings {
+    /// Backlight brightness level (1–7).
+    pub brightness: u8,
+    /// The alarms, encoded with `Alarms::encode`.
+    pub alarms: [u8; alarm::ENCODED_LEN],
+    /// The name in BLE advertisements.
+    pub ble_name: DeviceName,
+    /// Seconds without input until the screen is turned off, 0 for never.
+    pub screen_timeout: u8,
+    /// Whether the time is shown on a 12-hour clock.
+    pub clock_12h: bool,
+    /// Vibration intensity in percent (1–100).
+    pub vibration: u8,
+}
+
+impl Settings {
+    /// Read the settings from the store. Settings that are missing or can't
+    /// be decoded have their default value.
+    pub fn load<S: Storage>(
+        store: &mut Store,
+        storage: &mut S,
+    ) -> Result<Self, kvstore::Error<S::Error>> {
+        match store.get(storage, VERSION)? {
+            // Settings stored by a newer version are read as they are, keys
+            // never change their type
+            Some(version) if version >= SCHEMA_VERSION => {}
+            // This is the first version, there is nothing to convert yet
+            _ => store.set(storage, VERSION, &SCHEMA_VERSION)?,
+        }
+
+        let defaults = Self::default();
+        Ok(Self {
+            brightness: store
+                .get(storage, BRIGHTNESS)?
+                .filter(|brightness| (1..=7).contains(brightness))
+                .unwrap_or(defaults.brightness),
+            alarms: store.get(storage, ALARMS)?.unwrap_or(defaults.alarms),
+            ble_name: store.get(storage, BLE_NAME)?.unwrap_or(defaults.ble_name),
+            screen_timeout: store
+                .get(storage, SCREEN_TIMEOUT)?
+                .unwrap_or(defaults.screen_timeout),
+            clock_12h: store.get(storage, CLOCK_12H)?.unwrap_or(defaults.clock_12h),
+            vibration: store
+                .get(storage, VIBRATION)?
+                .filter(|vibration| (1..=100).contains(vibration))
+                .unwrap_or(defaults.vibration),
+        })
+    }
+
+    /// Write the settings to the store. Only settings that changed are
+    /// written.
+    pub fn save<S: Storage>(
+        &self,
+        store: &mut Store,
+        storage: &mut S,
+    ) -> Result<(), kvstore::Error<S::Error>> {
+        store.set(storage, BRIGHTNESS, &self.brightness)?;
+        store.set(storage, ALARMS, &self.alarms)?;
+        store.set(storage, BLE_NAME, &self.ble_name)?;
+        store.set(storage, SCREEN_TIMEOUT, &self.screen_timeout)?;
+        store.set(storage, CLOCK_12H, &self.clock_12h)?;
+        store.set(storage, VIBRATION, &self.vibration)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            brightness: 1,
+            alarms: Alarms::new().encode(),
+            ble_name: DeviceName::new("Rusty PineTime").unwrap(),
+            screen_timeout: 0,
+            clock_12h: false,
+            vibration: 80,
+        }
+    }
+}
+
+/// A device name of up to `MAX_NAME_LEN` bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeviceName {
+    bytes: [u8; MAX_NAME_LEN],
+    len: u8,
+}
+
+impl DeviceName {
+    /// Return `None` if the name is empty or too long.
+    pub fn new(name: &str) -> Option<Self> {
+        if name.is_empty() || name.len() > MAX_NAME_LEN {
+            return None;
+        }
+        let mut bytes = [0; MAX_NAME_LEN];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Some(Self {
+            bytes,
+            len: name.len() as u8,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only created from valid strings
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap()
+    }
+}
+
+impl Value for DeviceName {
+    fn encode(&self, buf: &mut [u8]) -> usize {
+        buf[..self.len as usize].copy_from_slice(self.as_str().as_bytes());
+        self.len as usize
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        core::str::from_utf8(data).ok().and_then(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flash::{sim::SimFlash, Xt25f32b};
+
+    #[test]
+    fn defaults() {
+        let sim = SimFlash::new();
+        let mut flash = Xt25f32b::new(sim.spi(), sim.cs());
+        let mut store = Store::mount(&mut flash, FLASH_ADDRESS, SECTORS).unwrap();
+        let settings = Settings::load(&mut store, &mut flash).unwrap();
+        assert_eq!(settings, Settings::default());
+        assert_eq!(settings.ble_name.as_str(), "Rusty PineTime");
+        assert_eq!(store.get(&mut flash, VERSION), Ok(Some(SCHEMA_VERSION)));
+    }
+
+    #[test]
+    fn save_and_load() {
+        let sim = SimFlash::new();
+        let mut flash = Xt25f32b::new(sim.spi(), sim.cs());
+        let mut store = Store::mount(&mut flash, FLASH_ADDRESS, SECTORS).unwrap();
+        let mut settings = Settings::load(&mut store, &mut flash).unwrap();
+        settings.brightness = 5;
+        settings.alarms[0] = 7;
+        settings.ble_name = DeviceName::new("Ferris").unwrap();
+        settings.screen_timeout = 15;
+        settings.clock_12h = true;
+        settings.vibration = 40;
+        settings.save(&mut store, &mut flash).unwrap();
+
+        // Reboot
+        let mut flash = Xt25f32b::new(sim.spi(), sim.cs());
+        let mut store = Store::mount(&mut flash, FLASH_ADDRESS, SECTORS).unwrap();
+        assert_eq!(Settings::load(&mut store, &mut flash), Ok(settings));
+
+        // Nothing but the settings area was touched
+        assert_eq!(
+            sim.erase_count((FLASH_ADDRESS / flash::SECTOR_SIZE) as usize),
+            1
+        );
+        assert_eq!(sim.memory(0, 16), [0xFF; 16]);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let sim = SimFlash::new();
+        let mut flash = Xt25f32b::new(sim.spi(), sim.cs());
+        let mut store = Store::mount(&mut flash, FLASH_ADDRESS, SECTORS).unwrap();
+        store.set(&mut flash, BRIGHTNESS, &0).unwrap();
+        store.set(&mut flash, VIBRATION, &101).unwrap();
+        // Not valid UTF-8
+        store.set(&mut flash, Key::<u16>::new(3), &0xFFFF).unwrap();
+        let settings = Settings::load(&mut store, &mut flash).unwrap();
+        assert_eq!(settings.brightness, 1);
+        assert_eq!(settings.vibration, 80);
+        assert_eq!(settings.ble_name.as_str(), "Rusty PineTime");
+    }
+
+    #[test]
+    fn newer_schema_version() {
+        let sim = SimFlash::new();
+        let mut flash = Xt25f32b::new(sim.spi(), sim.cs());
+        let mut store = Store::mount(&mut flash, FLASH_ADDRESS, SECTORS).unwrap();
+        store
+            .set(&mut flash, VERSION, &(SCHEMA_VERSION + 1))
+            .unwrap();
+        store.set(&mut flash, BRIGHTNESS, &3).unwrap();
+        let settings = Settings::load(&mut store, &mut flash).unwrap();
+        assert_eq!(settings.brightness, 3);
+        assert_eq!(store.get(&mut flash, VERSION), Ok(Some(SCHEMA_VERSION + 1)));
+    }
+
+    #[test]
+    fn device_name() {
+        assert_eq!(DeviceName::new(""), None);
+        assert_eq!(DeviceName::new("123456789012345678901"), None);
+        let name = DeviceName::new("PineTime ⌚").unwrap();
+        assert_eq!(name.as_str(), "PineTime ⌚");
+    }
+}
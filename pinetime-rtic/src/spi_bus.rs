@@ -0,0 +1,260 @@
+//! The SPI bus (SPIM1) shared by the LCD and the external NOR flash.
+//!
+//! Every device has its own chip select line. At most one of them is pulled
+//! low at a time, so that a device never responds to data meant for another
+//! one. The LCD stays selected between its transfers until another device
+//! needs the bus, because deselecting it in the middle of a window would
+//! require re-sending the window.
+//!
+//! The bus is an RTIC resource, so tasks get exclusive access by locking it.
+//! Transfers started with `start` run in the background and signal their end
+//! through the SPIM1 interrupt. All other transfers block until done; they
+//! first wait for a background transfer to end.
+
+use core::cell::RefCell;
+use core::convert::Infallible;
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+use nrf52832_hal::gpio::{Floating, Input, Output, Pin, PushPull};
+use nrf52832_hal::pac::{self, SPIM1};
+
+use pinetime_rtic::flash::Xt25f32b;
+
+/// Maximum number of bytes per EasyDMA transfer. MAXCNT is only 8 bits wide
+/// on the nRF52832.
+pub const MAX_TRANSFER: usize = 255;
+
+/// A device on the bus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Device {
+    Lcd,
+    Flash,
+}
+
+/// The pins of the bus.
+pub struct Pins {
+    pub sck: Pin<Output<PushPull>>,
+    pub mosi: Pin<Output<PushPull>>,
+    pub miso: Pin<Input<Floating>>,
+    /// Chip select of the LCD (active low)
+    pub lcd_cs: Pin<Output<PushPull>>,
+    /// Chip select of the flash (active low)
+    pub flash_cs: Pin<Output<PushPull>>,
+}
+
+pub struct SpiBus {
+    spim: SPIM1,
+    pins: Pins,
+
+    /// The device whose chip select is low.
+    selected: Option<Device>,
+    /// Whether a background transfer is running.
+    running: bool,
+    /// Whether a background transfer ended while another transfer waited
+    /// for it. The end is reported by the next call to `take_finished`.
+    finished: bool,
+
+    /// Data to be sent. EasyDMA can only read from RAM, so data of blocking
+    /// transfers is copied here first.
+    buffer: [u8; MAX_TRANSFER],
+}
+
+impl SpiBus {
+    /// Initialize SPIM1. The chip select pins must be high.
+    pub fn new(spim: SPIM1, pins: Pins) -> Self {
+        spim.psel
+            .sck
+            .write(|w| unsafe { w.bits(pins.sck.pin as u32) });
+        spim.psel
+            .mosi
+            .write(|w| unsafe { w.bits(pins.mosi.pin as u32) });
+        spim.psel
+            .miso
+            .write(|w| unsafe { w.bits(pins.miso.pin as u32) });
+        // Use SPI at 8MHz (the fastest clock available on the nRF52832)
+        // because otherwise refreshing will be super slow.
+        spim.frequency.write(|w| w.frequency().m8());
+        // SPI must be used in mode 3 for the LCD. Mode 0 (the default) won't
+        // work. The flash supports both.
+        spim.config
+            .write(|w| w.order().msb_first().cpol().active_low().cpha().trailing());
+        spim.orc.write(|w| unsafe { w.orc().bits(0xFF) });
+        spim.enable.write(|w| w.enable().enabled());
+        Self {
+            spim,
+            pins,
+            selected: None,
+            running: false,
+            finished: false,
+            buffer: [0; MAX_TRANSFER],
+        }
+    }
+
+    /// Pull the chip select of `device` low and the others high. Return
+    /// whether the device was already selected.
+    pub fn select(&mut self, device: Device) -> bool {
+        if self.selected == Some(device) {
+            return true;
+        }
+        self.wait();
+        self.deselect();
+        match device {
+            Device::Lcd => self.pins.lcd_cs.set_low().unwrap(),
+            Device::Flash => self.pins.flash_cs.set_low().unwrap(),
+        }
+        self.selected = Some(device);
+        false
+    }
+
+    /// Pull all chip select lines high.
+    pub fn deselect(&mut self) {
+        self.wait();
+        self.pins.lcd_cs.set_high().unwrap();
+        self.pins.flash_cs.set_high().unwrap();
+        self.selected = None;
+    }
+
+    /// Send `data` to the device, blocking until done.
+    pub fn write(&mut self, device: Device, data: &[u8]) {
+        self.select(device);
+        for chunk in data.chunks(MAX_TRANSFER) {
+            self.buffer[..chunk.len()].copy_from_slice(chunk);
+            self.transfer_blocking(chunk.len(), None);
+        }
+    }
+
+    /// Send `words` to the device and replace them with the received data,
+    /// blocking until done.
+    pub fn transfer(&mut self, device: Device, words: &mut [u8]) {
+        self.select(device);
+        for chunk in words.chunks_mut(MAX_TRANSFER) {
+            self.buffer[..chunk.len()].copy_from_slice(chunk);
+            self.transfer_blocking(chunk.len(), Some(chunk));
+        }
+    }
+
+    /// Run `f` with a driver for the flash.
+    pub fn with_flash<R>(&mut self, f: impl FnOnce(&mut Flash<'_, '_>) -> R) -> R {
+        let bus = RefCell::new(self);
+        let mut flash = Xt25f32b::new(FlashSpi(&bus), FlashCs(&bus));
+        f(&mut flash)
+    }
+
+    /// Start sending `len` bytes at `ptr` to the device in the background.
+    /// The end is signalled by the SPIM1 interrupt, see `take_finished`.
+    ///
+    /// # Safety
+    ///
+    /// The data must be located in RAM and stay valid until the transfer
+    /// finished. `len` must not exceed `MAX_TRANSFER`.
+    pub unsafe fn start(&mut self, device: Device, ptr: *const u8, len: usize) {
+        self.select(device);
+        self.running = true;
+        self.spim.intenset.write(|w| w.end().set());
+        self.start_transfer(ptr, len, None);
+    }
+
+    /// Return whether a background transfer finished since the last call.
+    /// Called from the SPIM1 interrupt.
+    pub fn take_finished(&mut self) -> bool {
+        if self.running && self.spim.events_end.read().bits() != 0 {
+            self.end_background();
+        }
+        core::mem::replace(&mut self.finished, false)
+    }
+
+    /// Wait for a running background transfer to end.
+    fn wait(&mut self) {
+        if self.running {
+            while self.spim.events_end.read().bits() == 0 {}
+            self.end_background();
+            // The interrupt was disabled before it was handled
+            rtic::pend(pac::Interrupt::SPIM1_SPIS1_TWIM1_TWIS1_SPI1_TWI1);
+        }
+    }
+
+    fn end_background(&mut self) {
+        self.spim.intenclr.write(|w| w.end().clear());
+        self.spim.events_end.reset();
+        compiler_fence(Ordering::SeqCst);
+        self.running = false;
+        self.finished = true;
+    }
+
+    /// Send the first `len` bytes of the buffer and receive into `rx`.
+    fn transfer_blocking(&mut self, len: usize, rx: Option<&mut [u8]>) {
+        self.start_transfer(self.buffer.as_ptr(), len, rx);
+        while self.spim.events_end.read().bits() == 0 {}
+        self.spim.events_end.reset();
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    fn start_transfer(&mut self, ptr: *const u8, len: usize, rx: Option<&mut [u8]>) {
+        compiler_fence(Ordering::SeqCst);
+        self.spim
+            .txd
+            .ptr
+            .write(|w| unsafe { w.ptr().bits(ptr as u32) });
+        self.spim
+            .txd
+            .maxcnt
+            .write(|w| unsafe { w.maxcnt().bits(len as u8) });
+        let (rx_ptr, rx_len) = match rx {
+            Some(rx) => (rx.as_mut_ptr(), rx.len()),
+            None => (core::ptr::null_mut(), 0),
+        };
+        self.spim
+            .rxd
+            .ptr
+            .write(|w| unsafe { w.ptr().bits(rx_ptr as u32) });
+        self.spim
+            .rxd
+            .maxcnt
+            .write(|w| unsafe { w.maxcnt().bits(rx_len as u8) });
+        self.spim.tasks_start.write(|w| unsafe { w.bits(1) });
+    }
+}
+
+/// The flash driver, borrowing the bus.
+pub type Flash<'a, 'b> = Xt25f32b<FlashSpi<'a, 'b>, FlashCs<'a, 'b>>;
+
+/// The bus as seen by the flash driver.
+pub struct FlashSpi<'a, 'b>(&'a RefCell<&'b mut SpiBus>);
+
+impl<'a, 'b> Transfer<u8> for FlashSpi<'a, 'b> {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.0.borrow_mut().transfer(Device::Flash, words);
+        Ok(words)
+    }
+}
+
+impl<'a, 'b> Write<u8> for FlashSpi<'a, 'b> {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.0.borrow_mut().write(Device::Flash, words);
+        Ok(())
+    }
+}
+
+/// The chip select of the flash. Selecting the flash deselects all other
+/// devices.
+pub struct FlashCs<'a, 'b>(&'a RefCell<&'b mut SpiBus>);
+
+impl<'a, 'b> OutputPin for FlashCs<'a, 'b> {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().select(Device::Flash);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().deselect();
+        Ok(())
+    }
+}
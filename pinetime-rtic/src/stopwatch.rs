@@ -0,0 +1,184 @@
+//! Stopwatch with lap times.
+//!
+//! All timing is based on instants of the monotonic timer, so the stopwatch
+//! keeps running correctly no matter how often (or rarely) it is displayed.
+
+use crate::monotonic_nrf52::{Instant, TICKS_PER_SECOND};
+
+/// Maximum number of laps that are recorded.
+pub const MAX_LAPS: usize = 99;
+
+pub struct Stopwatch {
+    /// The instant the stopwatch was last started, if it is running.
+    started: Option<Instant>,
+
+    /// Ticks accumulated before the stopwatch was last started.
+    accumulated: u64,
+
+    /// Total elapsed ticks at the end of the last lap.
+    last_lap_end: u64,
+
+    /// Lap times in ticks, oldest first.
+    laps: [u64; MAX_LAPS],
+
+    /// Number of recorded laps.
+    lap_count: usize,
+}
+
+impl Stopwatch {
+    pub const fn new() -> Self {
+        Self {
+            started: None,
+            accumulated: 0,
+            last_lap_end: 0,
+            laps: [0; MAX_LAPS],
+            lap_count: 0,
+        }
+    }
+
+    /// Return whether the stopwatch is currently running.
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Start the stopwatch. Does nothing if it's already running.
+    pub fn start(&mut self, at: Instant) {
+        if self.started.is_none() {
+            self.started = Some(at);
+        }
+    }
+
+    /// Stop the stopwatch. Does nothing if it's not running.
+    pub fn stop(&mut self, at: Instant) {
+        if let Some(started) = self.started.take() {
+            self.accumulated += at.ticks_since(started);
+        }
+    }
+
+    /// Start the stopwatch if it's stopped, stop it if it's running.
+    pub fn toggle(&mut self, at: Instant) {
+        if self.is_running() {
+            self.stop(at);
+        } else {
+            self.start(at);
+        }
+    }
+
+    /// Record a lap. Laps beyond `MAX_LAPS` are ignored.
+    pub fn lap(&mut self, at: Instant) {
+        if self.lap_count >= MAX_LAPS {
+            return;
+        }
+        let total = self.elapsed_ticks(at);
+        self.laps[self.lap_count] = total - self.last_lap_end;
+        self.last_lap_end = total;
+        self.lap_count += 1;
+    }
+
+    /// Stop the stopwatch and clear the elapsed time and all laps.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Return the total elapsed time in ticks.
+    pub fn elapsed_ticks(&self, now: Instant) -> u64 {
+        match self.started {
+            Some(started) => self.accumulated + now.ticks_since(started),
+            None => self.accumulated,
+        }
+    }
+
+    /// Return the total elapsed time in centiseconds.
+    pub fn elapsed_centis(&self, now: Instant) -> u64 {
+        ticks_to_centis(self.elapsed_ticks(now))
+    }
+
+    /// Return the number of recorded laps.
+    pub fn lap_count(&self) -> usize {
+        self.lap_count
+    }
+
+    /// Return the time of the lap with the specified index (starting at 0)
+    /// in centiseconds.
+    pub fn lap_centis(&self, index: usize) -> Option<u64> {
+        self.laps[..self.lap_count]
+            .get(index)
+            .map(|ticks| ticks_to_centis(*ticks))
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn ticks_to_centis(ticks: u64) -> u64 {
+    ticks * 100 / TICKS_PER_SECOND
+}
+
+/// Format a time in centiseconds as `MM:SS.cc`, or as `HH:MM:SS` once it
+/// reaches one hour. The output is always 8 characters long.
+pub fn format_centis(centis: u64, buf: &mut [u8; 8]) -> &str {
+    let secs = centis / 100;
+    let (a, b, c, sep) = if secs < 3600 {
+        (secs / 60, secs % 60, centis % 100, b'.')
+    } else {
+        ((secs / 3600) % 100, (secs / 60) % 60, secs % 60, b':')
+    };
+    for (i, value) in [a, b, c].iter().enumerate() {
+        buf[i * 3] = b'0' + (value / 10) as u8;
+        buf[i * 3 + 1] = b'0' + (value % 10) as u8;
+    }
+    buf[2] = b':';
+    buf[5] = sep;
+    core::str::from_utf8(buf).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Instant {
+        Instant::from_counts(millis * 1_000)
+    }
+
+    #[test]
+    fn start_stop_accumulates() {
+        let mut sw = Stopwatch::new();
+        sw.start(ms(1_000));
+        assert_eq!(sw.elapsed_centis(ms(1_500)), 50);
+        sw.stop(ms(2_000));
+        assert_eq!(sw.elapsed_centis(ms(10_000)), 100);
+        sw.toggle(ms(20_000));
+        assert!(sw.is_running());
+        assert_eq!(sw.elapsed_centis(ms(20_250)), 125);
+    }
+
+    #[test]
+    fn laps() {
+        let mut sw = Stopwatch::new();
+        sw.start(ms(0));
+        sw.lap(ms(1_230));
+        sw.stop(ms(2_000));
+        sw.start(ms(5_000));
+        sw.lap(ms(5_500));
+        assert_eq!(sw.lap_count(), 2);
+        assert_eq!(sw.lap_centis(0), Some(123));
+        assert_eq!(sw.lap_centis(1), Some(127));
+        assert_eq!(sw.lap_centis(2), None);
+        sw.reset();
+        assert_eq!(sw.lap_count(), 0);
+        assert!(!sw.is_running());
+    }
+
+    #[test]
+    fn format() {
+        let mut buf = [0; 8];
+        assert_eq!(format_centis(0, &mut buf), "00:00.00");
+        assert_eq!(format_centis(12_345, &mut buf), "02:03.45");
+        assert_eq!(format_centis(359_999, &mut buf), "59:59.99");
+        assert_eq!(format_centis(360_000, &mut buf), "01:00:00");
+        assert_eq!(format_centis(4_523_400, &mut buf), "12:33:54");
+    }
+}
@@ -0,0 +1,138 @@
+//! Driver for the CST816S capacitive touch controller.
+//!
+//! The controller is connected through I²C. It pulls its interrupt line low
+//! whenever a touch event is available, which can then be read with
+//! `Cst816s::read_event`.
+
+use embedded_hal::blocking::i2c::WriteRead;
+
+/// I²C address of the touch controller.
+const ADDRESS: u8 = 0x15;
+
+/// First register of the touch data (gesture ID).
+const REG_GESTURE_ID: u8 = 0x01;
+
+/// A gesture as detected by the touch controller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchGesture {
+    None,
+    SlideDown,
+    SlideUp,
+    SlideLeft,
+    SlideRight,
+    SingleTap,
+    DoubleTap,
+    LongPress,
+}
+
+impl TouchGesture {
+    fn from_id(id: u8) -> Self {
+        match id {
+            0x01 => TouchGesture::SlideDown,
+            0x02 => TouchGesture::SlideUp,
+            0x03 => TouchGesture::SlideLeft,
+            0x04 => TouchGesture::SlideRight,
+            0x05 => TouchGesture::SingleTap,
+            0x0B => TouchGesture::DoubleTap,
+            0x0C => TouchGesture::LongPress,
+            _ => TouchGesture::None,
+        }
+    }
+}
+
+/// The kind of a touch event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchAction {
+    /// The finger touched the screen.
+    Down,
+    /// The finger was lifted.
+    Up,
+    /// The finger is (still) touching the screen.
+    Contact,
+}
+
+/// A touch event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TouchEvent {
+    pub gesture: TouchGesture,
+    pub action: TouchAction,
+    pub x: u16,
+    pub y: u16,
+}
+
+impl TouchEvent {
+    /// Parse the touch data registers, starting with the gesture ID. Return
+    /// `None` if the data doesn't contain a touch point.
+    pub fn parse(data: &[u8; 6]) -> Option<Self> {
+        let action = match data[2] >> 6 {
+            0 => TouchAction::Down,
+            1 => TouchAction::Up,
+            2 => TouchAction::Contact,
+            _ => return None,
+        };
+        if data[1] == 0 && action != TouchAction::Up {
+            // No finger on the screen
+            return None;
+        }
+        Some(Self {
+            gesture: TouchGesture::from_id(data[0]),
+            action,
+            x: (((data[2] & 0x0F) as u16) << 8) | data[3] as u16,
+            y: (((data[4] & 0x0F) as u16) << 8) | data[5] as u16,
+        })
+    }
+}
+
+pub struct Cst816s<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C, E> Cst816s<I2C>
+where
+    I2C: WriteRead<Error = E>,
+{
+    /// Initialize the driver. The controller must have been reset through
+    /// its reset pin before.
+    pub fn new(i2c: I2C) -> Self {
+        Self { i2c }
+    }
+
+    /// Read the current touch event from the controller.
+    pub fn read_event(&mut self) -> Result<Option<TouchEvent>, E> {
+        let mut data = [0u8; 6];
+        self.i2c.write_read(ADDRESS, &[REG_GESTURE_ID], &mut data)?;
+        Ok(TouchEvent::parse(&data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tap() {
+        let event = TouchEvent::parse(&[0x05, 1, 0x40, 120, 0x00, 200]).unwrap();
+        assert_eq!(
+            event,
+            TouchEvent {
+                gesture: TouchGesture::SingleTap,
+                action: TouchAction::Up,
+                x: 120,
+                y: 200,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_slide() {
+        let event = TouchEvent::parse(&[0x02, 1, 0x80, 10, 0x01, 2]).unwrap();
+        assert_eq!(event.gesture, TouchGesture::SlideUp);
+        assert_eq!(event.action, TouchAction::Contact);
+        assert_eq!((event.x, event.y), (10, 258));
+    }
+
+    #[test]
+    fn parse_no_finger() {
+        assert_eq!(TouchEvent::parse(&[0x00, 0, 0x00, 0, 0, 0]), None);
+    }
+}
@@ -0,0 +1,816 @@
+//! The user interface: a stack of apps that own the display in turn.
+//!
+//! Nothing in here knows about RTIC or the HAL. The firmware feeds the UI
+//! with `Event`s (input, a periodic tick, alarms) and takes care of the
+//! `Effects` of an event (e.g. scheduling the countdown expiry). Drawing goes
+//! to a `Panel`, which is the LCD on the watch and a framebuffer in the
+//! desktop simulator.
+//!
+//! Only the app on top of the navigation stack gets input and ticks, and
+//! only it draws. A double press of the button goes back, or opens the
+//! launcher on the watch face. Alerts are an app as well, which is pushed on
+//! top of whatever is shown and draws a banner (or a received notification)
+//! over it.
+//!
+//! Without input for the screen timeout of the settings, the screen is
+//! turned off and the apps no longer get ticks. The next input only turns it
+//! on again, and isn't passed to the app.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::{
+    fonts::{Font, Font12x16, Text},
+    pixelcolor::Rgb565,
+    style::{Styled, TextStyle, TextStyleBuilder},
+};
+
+use crate::alarm::Alarms;
+use crate::apps::Apps;
+use crate::button::{ButtonEvent, Gesture};
+use crate::clock::WallClock;
+use crate::countdown::Countdown;
+use crate::damage::Rect;
+use crate::image::{self, Decoder};
+use crate::monotonic_nrf52::{Instant, TICKS_PER_SECOND};
+use crate::notification::Notifications;
+use crate::render::{Renderer, Strip};
+use crate::settings::Settings;
+use crate::touch::TouchGesture;
+
+pub const LCD_W: u16 = 240;
+pub const LCD_H: u16 = 240;
+
+pub const MARGIN: u16 = 10;
+
+pub const BACKGROUND_COLOR: Rgb565 = Rgb565::new(0, 0b000111, 0);
+
+/// Frequency of `Event::Tick` in Hz.
+pub const TICK_HZ: u32 = 25;
+
+/// Number of pixels of an image that are streamed to the panel at once.
+const IMAGE_CHUNK: usize = 512;
+
+/// Maximum number of apps on the navigation stack.
+const STACK_DEPTH: usize = 4;
+
+/// Where the UI is drawn: the LCD, or a framebuffer in the simulator.
+pub trait Panel {
+    /// Write a window of pixels (row by row).
+    fn write_pixels(&mut self, area: Rect, pixels: &[u16]);
+
+    /// Fill a window with a single color.
+    fn fill(&mut self, area: Rect, color: Rgb565);
+}
+
+/// The images that apps can draw.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Image {
+    Ferris,
+}
+
+/// Where images come from. On the watch, they are streamed from the file
+/// system with `stream_image`, because they are too large to be rendered in
+/// strips: they would have to be decoded again for every strip.
+pub trait Images {
+    /// Draw an image with its top left corner at `x`, `y`.
+    fn draw(&mut self, image: Image, panel: &mut dyn Panel, x: u16, y: u16);
+}
+
+/// The battery status.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Battery {
+    /// Voltage in tenths of a volt.
+    pub voltage: u8,
+    pub charging: bool,
+}
+
+/// What the UI reacts to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    Button(ButtonEvent),
+    Touch(TouchGesture),
+    /// Sent `TICK_HZ` times per second to update the display.
+    Tick,
+    /// The alarm with the specified index is due.
+    AlarmRinging(usize),
+    /// The countdown expiry with the specified generation is due.
+    CountdownExpired(u32),
+    /// A notification was received, it is the newest one in
+    /// `Env::notifications`.
+    Notification,
+}
+
+/// Input for the app on top of the stack.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Input {
+    Button(ButtonEvent),
+    Touch(TouchGesture),
+}
+
+/// An alert shown on top of the current app.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alert {
+    /// The countdown expired.
+    Countdown,
+    /// The alarm with the specified index is ringing.
+    Alarm(usize),
+    /// The newest notification.
+    Notification,
+}
+
+/// What has to be done after an event, besides updating the display.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Effects {
+    /// An alert was ended, the vibration must be stopped.
+    pub stop_vibration: bool,
+    /// The alarms changed and must be synced over BLE and saved.
+    pub alarms_changed: bool,
+    /// Step to the next backlight brightness (or turn it off after the
+    /// brightest one).
+    pub cycle_brightness: bool,
+    /// The countdown was started. Its expiry (instant and generation) must be
+    /// scheduled.
+    pub countdown_expiry: Option<(Instant, u32)>,
+    /// An alert was shown. The watch must vibrate and wake up the display.
+    pub alert: Option<Alert>,
+    /// The settings were changed and must be applied and saved.
+    pub settings_changed: bool,
+    /// The screen timed out and must be turned off.
+    pub screen_off: bool,
+    /// The screen must be turned on again.
+    pub screen_on: bool,
+}
+
+/// The state the UI depends on, but doesn't own.
+pub struct Env<'a> {
+    pub panel: &'a mut dyn Panel,
+    pub images: &'a mut dyn Images,
+    pub now: Instant,
+    pub alarms: &'a mut Alarms,
+    pub wall_clock: &'a WallClock,
+    pub battery: Battery,
+    pub settings: &'a mut Settings,
+    /// The BLE device address, least significant byte first.
+    pub ble_address: [u8; 6],
+    pub notifications: &'a mut Notifications,
+}
+
+/// The apps, as they are referred to on the navigation stack.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AppId {
+    WatchFace,
+    Launcher,
+    Stopwatch,
+    Countdown,
+    Alarms,
+    Settings,
+    Notifications,
+    Alert,
+}
+
+/// Where to navigate after an input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    /// Stay in the current app.
+    None,
+    /// Show an app on top of the current one.
+    Push(AppId),
+    /// Go back to the app below the current one.
+    Pop,
+    /// Replace the current app.
+    Replace(AppId),
+}
+
+/// An app, which owns the display while it is on top of the navigation
+/// stack.
+pub trait App {
+    /// Called when the app gets on top of the stack, before it is drawn.
+    fn enter(&mut self, _cx: &mut Context) {}
+
+    /// Called when the app is removed from the top of the stack, i.e. popped,
+    /// replaced or covered by another app.
+    fn exit(&mut self, _cx: &mut Context) {}
+
+    /// Handle a button or touch gesture.
+    fn input(&mut self, input: Input, cx: &mut Context) -> Action;
+
+    /// Update what changed on the display. Called `TICK_HZ` times per
+    /// second, and right after `draw`.
+    fn tick(&mut self, _cx: &mut Context) {}
+
+    /// Draw the app from scratch. Whatever `tick` updates can be left to it.
+    fn draw(&mut self, cx: &mut Context);
+}
+
+/// What an app can use while it handles an event.
+pub struct Context<'a, 'e> {
+    pub env: &'a mut Env<'e>,
+    pub effects: &'a mut Effects,
+    renderer: &'a mut Renderer,
+}
+
+impl Context<'_, '_> {
+    pub fn now(&self) -> Instant {
+        self.env.now
+    }
+
+    /// Clear the display and draw a title in the top left corner.
+    pub fn clear(&mut self, title: &str) {
+        self.env
+            .panel
+            .fill(Rect::new(0, 0, LCD_W, LCD_H), BACKGROUND_COLOR);
+        self.draw_text(
+            Text::new(title, Point::new(MARGIN as i32, MARGIN as i32))
+                .into_styled(text_style(BACKGROUND_COLOR)),
+        );
+    }
+
+    /// Redraw an area of the display. Everything in the area that isn't
+    /// drawn by `draw` is cleared.
+    pub fn draw_area(&mut self, area: Rect, draw: impl FnMut(&mut Strip)) {
+        let panel = &mut *self.env.panel;
+        self.renderer.invalidate(area);
+        self.renderer
+            .flush(draw, |area, pixels| panel.write_pixels(area, pixels));
+    }
+
+    /// Draw a text, replacing whatever was shown in its bounding box.
+    pub fn draw_text<F: Font + Copy>(&mut self, text: Styled<Text, TextStyle<Rgb565, F>>) {
+        let top_left = text.top_left();
+        let size = text.size();
+        let area = Rect::new(
+            top_left.x as u16,
+            top_left.y as u16,
+            size.width as u16,
+            size.height as u16,
+        );
+        self.draw_area(area, |strip| text.draw(strip).unwrap());
+    }
+
+    /// Draw an image with its top left corner at `x`, `y`.
+    pub fn draw_image(&mut self, image: Image, x: u16, y: u16) {
+        let env = &mut *self.env;
+        env.images.draw(image, env.panel, x, y);
+    }
+}
+
+/// The navigation stack. The app at the bottom is never popped.
+struct Stack {
+    apps: [AppId; STACK_DEPTH],
+    len: usize,
+}
+
+impl Stack {
+    const fn new(root: AppId) -> Self {
+        Self {
+            apps: [root; STACK_DEPTH],
+            len: 1,
+        }
+    }
+
+    fn top(&self) -> AppId {
+        self.apps[self.len - 1]
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Push an app. If the stack is full, the app on top is replaced.
+    fn push(&mut self, app: AppId) {
+        if self.len == STACK_DEPTH {
+            self.replace(app);
+        } else {
+            self.apps[self.len] = app;
+            self.len += 1;
+        }
+    }
+
+    /// Pop the app on top, unless it is the one at the bottom. Return whether
+    /// it was popped.
+    fn pop(&mut self) -> bool {
+        if self.len > 1 {
+            self.len -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn replace(&mut self, app: AppId) {
+        self.apps[self.len - 1] = app;
+    }
+}
+
+/// State of the user interface.
+pub struct Ui {
+    stack: Stack,
+    pub(crate) apps: Apps,
+    renderer: Renderer,
+    /// Whether the app on top has yet to be entered, i.e. nothing was drawn
+    /// since boot.
+    started: bool,
+    /// When the last input was handled.
+    last_input: Instant,
+    /// Whether the screen timed out.
+    asleep: bool,
+}
+
+impl Ui {
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(AppId::WatchFace),
+            apps: Apps::new(),
+            renderer: Renderer::new(LCD_W, LCD_H, BACKGROUND_COLOR),
+            started: false,
+            last_input: Instant::from_counts(0),
+            asleep: false,
+        }
+    }
+
+    /// Return the app on top of the navigation stack.
+    pub fn top(&self) -> AppId {
+        self.stack.top()
+    }
+
+    pub fn countdown(&self) -> &Countdown {
+        &self.apps.countdown.countdown
+    }
+
+    /// Return the alert that is shown, if any.
+    pub fn alert(&self) -> Option<Alert> {
+        if self.top() == AppId::Alert {
+            self.apps.alert.alert
+        } else {
+            None
+        }
+    }
+
+    /// Handle an event. The first one draws the app on top of the stack.
+    pub fn handle(&mut self, event: Event, mut env: Env) -> Effects {
+        // A tick delivers a countdown expiry that got lost, e.g. because the
+        // firmware's event queue was full when it was due
+        let event = match (event, self.apps.countdown.countdown.expiry()) {
+            (Event::Tick, Some((ends, generation))) if env.now >= ends => {
+                Event::CountdownExpired(generation)
+            }
+            _ => event,
+        };
+
+        let mut effects = Effects::default();
+        let mut cx = Context {
+            env: &mut env,
+            effects: &mut effects,
+            renderer: &mut self.renderer,
+        };
+        let (stack, apps) = (&mut self.stack, &mut self.apps);
+        if !self.started {
+            self.started = true;
+            self.last_input = cx.now();
+            enter(apps, stack.top(), &mut cx);
+        }
+
+        let top = stack.top();
+        match event {
+            Event::Button(_) | Event::Touch(_) => {
+                self.last_input = cx.now();
+                if self.asleep {
+                    self.asleep = false;
+                    cx.effects.screen_on = true;
+                    return effects;
+                }
+            }
+            Event::AlarmRinging(_) | Event::CountdownExpired(_) | Event::Notification => {}
+            Event::Tick => {
+                if self.asleep {
+                    return effects;
+                }
+                let timeout = cx.env.settings.screen_timeout as u64 * TICKS_PER_SECOND;
+                let idle = cx.now().ticks_since(self.last_input);
+                // Alerts stay on the screen until they are ended
+                if timeout != 0 && idle >= timeout && top != AppId::Alert {
+                    self.asleep = true;
+                    cx.effects.screen_off = true;
+                    return effects;
+                }
+            }
+        }
+
+        match event {
+            // Alerts must be ended explicitly
+            Event::Button(ButtonEvent {
+                gesture: Gesture::DoublePress,
+                ..
+            }) if top != AppId::Alert => {
+                let action = if stack.len() == 1 {
+                    Action::Push(AppId::Launcher)
+                } else {
+                    Action::Pop
+                };
+                navigate(stack, apps, action, &mut cx);
+            }
+            Event::Button(event) => {
+                let action = apps.get(top).input(Input::Button(event), &mut cx);
+                navigate(stack, apps, action, &mut cx);
+            }
+            Event::Touch(gesture) => {
+                let action = apps.get(top).input(Input::Touch(gesture), &mut cx);
+                navigate(stack, apps, action, &mut cx);
+            }
+            Event::Tick => apps.get(top).tick(&mut cx),
+            Event::AlarmRinging(index) => show_alert(stack, apps, Alert::Alarm(index), &mut cx),
+            Event::Notification => {
+                // A ringing alarm or an expired countdown isn't covered, the
+                // notification is kept anyway
+                let alerting = top == AppId::Alert && apps.alert.alert != Some(Alert::Notification);
+                if !alerting && !cx.env.notifications.is_empty() {
+                    show_alert(stack, apps, Alert::Notification, &mut cx);
+                }
+            }
+            Event::CountdownExpired(generation) => {
+                let countdown = &mut apps.countdown.countdown;
+                if countdown.expire(generation) {
+                    show_alert(stack, apps, Alert::Countdown, &mut cx);
+                } else if let Some((ends, current)) = countdown.expiry() {
+                    // The countdown was paused and resumed since this was
+                    // scheduled. Make sure the current expiry is scheduled,
+                    // in case scheduling failed because the queue was full
+                    // of outdated expiries. Otherwise only a tick would
+                    // notice it.
+                    if current != generation {
+                        cx.effects.countdown_expiry = Some((ends, current));
+                    }
+                }
+            }
+        }
+
+        // Alerts wake up the screen
+        if effects.alert.is_some() && self.asleep {
+            self.asleep = false;
+            self.last_input = env.now;
+            effects.screen_on = true;
+        }
+        effects
+    }
+}
+
+impl Default for Ui {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Enter and draw the app that got on top of the stack.
+fn enter(apps: &mut Apps, id: AppId, cx: &mut Context) {
+    let app = apps.get(id);
+    app.enter(cx);
+    app.draw(cx);
+    app.tick(cx);
+}
+
+/// Navigate as requested by the app on top of the stack.
+fn navigate(stack: &mut Stack, apps: &mut Apps, action: Action, cx: &mut Context) {
+    let top = stack.top();
+    match action {
+        Action::None => return,
+        Action::Push(id) => {
+            apps.get(top).exit(cx);
+            stack.push(id);
+        }
+        Action::Pop => {
+            if !stack.pop() {
+                return;
+            }
+            apps.get(top).exit(cx);
+        }
+        Action::Replace(id) => {
+            apps.get(top).exit(cx);
+            stack.replace(id);
+        }
+    }
+    enter(apps, stack.top(), cx);
+}
+
+/// Show an alert on top of the current app. If another alert is shown, it is
+/// replaced.
+fn show_alert(stack: &mut Stack, apps: &mut Apps, alert: Alert, cx: &mut Context) {
+    apps.alert.alert = Some(alert);
+    cx.effects.alert = Some(alert);
+    if stack.top() == AppId::Alert {
+        enter(apps, AppId::Alert, cx);
+    } else {
+        navigate(stack, apps, Action::Push(AppId::Alert), cx);
+    }
+}
+
+/// Stream an image to the panel with its top left corner at `x`, `y`. The
+/// pixels are decoded with `read`, a few rows at a time.
+pub fn stream_image<E>(
+    panel: &mut (impl Panel + ?Sized),
+    x: u16,
+    y: u16,
+    mut decoder: Decoder,
+    mut read: impl FnMut(&mut Decoder, &mut [u16]) -> Result<usize, image::Error<E>>,
+) -> Result<(), image::Error<E>> {
+    let header = decoder.header();
+    if header.width as usize > IMAGE_CHUNK {
+        return Err(image::Error::Unsupported);
+    }
+    let mut buf = [0; IMAGE_CHUNK];
+    let mut row = 0;
+    while row < header.height {
+        let rows = (IMAGE_CHUNK / header.width.max(1) as usize).min((header.height - row) as usize);
+        let pixels = &mut buf[..rows * header.width as usize];
+        read(&mut decoder, pixels)?;
+        panel.write_pixels(Rect::new(x, y + row, header.width, rows as u16), pixels);
+        row += rows as u16;
+    }
+    Ok(())
+}
+
+/// The style of the small white text.
+pub fn text_style(background: Rgb565) -> TextStyle<Rgb565, Font12x16> {
+    TextStyleBuilder::new(Font12x16)
+        .text_color(Rgb565::WHITE)
+        .background_color(background)
+        .build()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::monotonic_nrf52::Duration;
+
+    /// Records the windows written to it.
+    pub struct Windows(pub Vec<Rect>);
+
+    impl Panel for Windows {
+        fn write_pixels(&mut self, area: Rect, pixels: &[u16]) {
+            assert_eq!(pixels.len(), area.area() as usize);
+            self.0.push(area);
+        }
+
+        fn fill(&mut self, area: Rect, _color: Rgb565) {
+            self.0.push(area);
+        }
+    }
+
+    /// Records where images are drawn.
+    pub struct Drawn(pub Vec<(Image, u16, u16)>);
+
+    impl Images for Drawn {
+        fn draw(&mut self, image: Image, _panel: &mut dyn Panel, x: u16, y: u16) {
+            self.0.push((image, x, y));
+        }
+    }
+
+    /// The state the UI depends on, owned by the test.
+    pub struct TestEnv {
+        pub panel: Windows,
+        pub images: Drawn,
+        pub now: Instant,
+        pub alarms: Alarms,
+        pub wall_clock: WallClock,
+        pub settings: Settings,
+        pub notifications: Notifications,
+    }
+
+    impl TestEnv {
+        pub fn new() -> Self {
+            Self {
+                panel: Windows(Vec::new()),
+                images: Drawn(Vec::new()),
+                now: Instant::from_counts(0),
+                alarms: Alarms::new(),
+                wall_clock: WallClock::new(Instant::from_counts(0)),
+                settings: Settings::default(),
+                notifications: Notifications::new(),
+            }
+        }
+
+        pub fn env(&mut self) -> Env<'_> {
+            Env {
+                panel: &mut self.panel,
+                images: &mut self.images,
+                now: self.now,
+                alarms: &mut self.alarms,
+                wall_clock: &self.wall_clock,
+                battery: Battery::default(),
+                settings: &mut self.settings,
+                ble_address: [0x56, 0x34, 0x12, 0xEF, 0xCD, 0xAB],
+                notifications: &mut self.notifications,
+            }
+        }
+
+        pub fn handle(&mut self, ui: &mut Ui, event: Event) -> Effects {
+            ui.handle(event, self.env())
+        }
+
+        pub fn press(&mut self, ui: &mut Ui, gesture: Gesture) -> Effects {
+            let event = ButtonEvent {
+                gesture,
+                at: self.now,
+            };
+            self.handle(ui, Event::Button(event))
+        }
+
+        pub fn touch(&mut self, ui: &mut Ui, gesture: TouchGesture) -> Effects {
+            self.handle(ui, Event::Touch(gesture))
+        }
+    }
+
+    #[test]
+    fn stack() {
+        let mut stack = Stack::new(AppId::WatchFace);
+        assert!(!stack.pop());
+        stack.push(AppId::Launcher);
+        stack.replace(AppId::Stopwatch);
+        assert_eq!((stack.top(), stack.len()), (AppId::Stopwatch, 2));
+
+        // A full stack replaces the app on top
+        for _ in 0..STACK_DEPTH {
+            stack.push(AppId::Alert);
+        }
+        assert_eq!(stack.len(), STACK_DEPTH);
+        assert!(stack.pop());
+        assert_eq!(stack.top(), AppId::Alert);
+        assert!(stack.pop() && stack.pop());
+        assert_eq!(stack.top(), AppId::WatchFace);
+    }
+
+    #[test]
+    fn navigation() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+
+        let effects = env.press(&mut ui, Gesture::Press);
+        assert!(effects.cycle_brightness);
+        assert_eq!(ui.top(), AppId::WatchFace);
+
+        // Open the stopwatch from the launcher
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::Launcher);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Stopwatch);
+
+        // Start the stopwatch, take a lap
+        env.press(&mut ui, Gesture::Press);
+        env.now += Duration::from_secs(3);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.apps.stopwatch.stopwatch.lap_count(), 1);
+
+        // Back to the launcher, start the countdown
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::Launcher);
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Countdown);
+        let effects = env.touch(&mut ui, TouchGesture::SingleTap);
+        let (ends, generation) = effects.countdown_expiry.unwrap();
+        let duration = ui.countdown().duration_secs();
+        assert_eq!(ends, env.now + Duration::from_secs(duration));
+        let effects = env.handle(&mut ui, Event::CountdownExpired(generation));
+        assert_eq!(effects.alert, Some(Alert::Countdown));
+        assert_eq!(ui.alert(), Some(Alert::Countdown));
+
+        // Any gesture ends the alert, even a double press
+        let effects = env.press(&mut ui, Gesture::DoublePress);
+        assert!(effects.stop_vibration);
+        assert_eq!(ui.alert(), None);
+        assert_eq!(ui.top(), AppId::Countdown);
+        assert!(ui.countdown().is_stopped());
+
+        // Edit the second alarm
+        env.press(&mut ui, Gesture::DoublePress);
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Alarms);
+        env.press(&mut ui, Gesture::Press);
+        let effects = env.touch(&mut ui, TouchGesture::SingleTap);
+        assert!(effects.alarms_changed);
+        assert!(env.alarms.get(1).enabled);
+        assert!(!env.alarms.get(0).enabled);
+
+        // The alarm rings on top of the alarms, a long press dismisses it
+        env.handle(&mut ui, Event::AlarmRinging(1));
+        assert_eq!(ui.alert(), Some(Alert::Alarm(1)));
+        let effects = env.press(&mut ui, Gesture::LongPress);
+        assert!(effects.alarms_changed && effects.stop_vibration);
+        // It repeats on weekends, so it stays enabled
+        assert!(env.alarms.get(1).enabled);
+        assert_eq!(ui.top(), AppId::Alarms);
+
+        // Back to the watch face, which is never popped
+        env.press(&mut ui, Gesture::DoublePress);
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::WatchFace);
+        env.touch(&mut ui, TouchGesture::SlideUp);
+        assert_eq!(ui.top(), AppId::WatchFace);
+    }
+
+    #[test]
+    fn outdated_expiry() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.press(&mut ui, Gesture::DoublePress);
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        assert_eq!(ui.top(), AppId::Countdown);
+
+        // Start, pause and resume the countdown
+        let first = env.touch(&mut ui, TouchGesture::SingleTap);
+        env.touch(&mut ui, TouchGesture::SingleTap);
+        let second = env.touch(&mut ui, TouchGesture::SingleTap);
+        let (_, generation) = first.countdown_expiry.unwrap();
+        let effects = env.handle(&mut ui, Event::CountdownExpired(generation));
+        assert_eq!(effects.alert, None);
+        assert_eq!(effects.countdown_expiry, second.countdown_expiry);
+    }
+
+    #[test]
+    fn lost_expiry() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.settings.screen_timeout = 5;
+        env.press(&mut ui, Gesture::DoublePress);
+        env.press(&mut ui, Gesture::Press);
+        env.press(&mut ui, Gesture::LongPress);
+        let effects = env.touch(&mut ui, TouchGesture::SingleTap);
+        let (ends, _) = effects.countdown_expiry.unwrap();
+
+        // The screen times out, the expiry never arrives
+        env.now += Duration::from_secs(5);
+        assert!(env.handle(&mut ui, Event::Tick).screen_off);
+        env.now = ends;
+        let effects = env.handle(&mut ui, Event::Tick);
+        assert_eq!(effects.alert, Some(Alert::Countdown));
+        assert!(effects.screen_on);
+        assert_eq!(env.handle(&mut ui, Event::Tick).alert, None);
+    }
+
+    #[test]
+    fn screen_timeout() {
+        let mut ui = Ui::new();
+        let mut env = TestEnv::new();
+        env.settings.screen_timeout = 5;
+        env.handle(&mut ui, Event::Tick);
+        env.now += Duration::from_secs(4);
+        assert!(!env.handle(&mut ui, Event::Tick).screen_off);
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::Launcher);
+
+        // The timeout starts over with every input
+        env.now += Duration::from_secs(4);
+        assert!(!env.handle(&mut ui, Event::Tick).screen_off);
+        env.now += Duration::from_secs(1);
+        assert!(env.handle(&mut ui, Event::Tick).screen_off);
+
+        // Nothing is drawn until the screen is on again
+        env.panel.0.clear();
+        env.handle(&mut ui, Event::Tick);
+        assert!(env.panel.0.is_empty());
+        // The input that turns it on isn't passed to the app
+        let effects = env.press(&mut ui, Gesture::DoublePress);
+        assert!(effects.screen_on);
+        assert_eq!(ui.top(), AppId::Launcher);
+        env.press(&mut ui, Gesture::DoublePress);
+        assert_eq!(ui.top(), AppId::WatchFace);
+
+        // Alerts turn it on and keep it on
+        env.now += Duration::from_secs(5);
+        assert!(env.handle(&mut ui, Event::Tick).screen_off);
+        let effects = env.handle(&mut ui, Event::AlarmRinging(0));
+        assert!(effects.screen_on);
+        env.now += Duration::from_secs(60);
+        assert!(!env.handle(&mut ui, Event::Tick).screen_off);
+        assert_eq!(ui.alert(), Some(Alert::Alarm(0)));
+
+        // A timeout of 0 keeps it on
+        env.press(&mut ui, Gesture::Press);
+        env.settings.screen_timeout = 0;
+        env.now += Duration::from_secs(3600);
+        assert!(!env.handle(&mut ui, Event::Tick).screen_off);
+    }
+
+    #[test]
+    fn stream() {
+        let pixels: Vec<u16> = (0..30 * 40).collect();
+        let mut data = Vec::new();
+        image::encode(30, 40, &pixels, |bytes| data.extend_from_slice(bytes));
+        let mut data = &data[..];
+        let decoder = Decoder::new(&mut data).unwrap();
+        let mut panel = Windows(Vec::new());
+        stream_image(&mut panel, 5, 6, decoder, |decoder, pixels| {
+            decoder.read(&mut data, pixels)
+        })
+        .unwrap();
+        // 512 / 30 = 17 rows at a time
+        assert_eq!(
+            panel.0,
+            [
+                Rect::new(5, 6, 30, 17),
+                Rect::new(5, 23, 30, 17),
+                Rect::new(5, 40, 30, 6),
+            ]
+        );
+    }
+}
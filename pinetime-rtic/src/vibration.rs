@@ -0,0 +1,280 @@
+//! Vibration motor with a non-blocking pattern player.
+//!
+//! A pattern is a sequence of alternating on and off durations in
+//! milliseconds, starting with "on". Patterns that are repeated must end with
+//! "off", so that the motor pauses between the repetitions.
+//!
+//! The `Player` doesn't wait itself: every call to `Player::advance` returns
+//! the next step, and the firmware schedules a task for the end of that step.
+//! Like with the countdown, starting or stopping a pattern bumps a generation
+//! counter, so that steps scheduled for an older pattern are ignored.
+//!
+//! The motor is driven through PWM0, which allows reducing its intensity.
+
+#[cfg(target_arch = "arm")]
+use nrf52832_hal::gpio::{Output, Pin, PushPull};
+#[cfg(target_arch = "arm")]
+use nrf52832_hal::target::PWM0;
+
+/// Maximum number of steps in a pattern. Longer patterns are truncated.
+pub const MAX_STEPS: usize = 16;
+
+/// A short buzz, e.g. as feedback for an input.
+pub const SHORT_BUZZ: Pattern<'static> = Pattern::new(&[50], 1);
+
+/// Two short buzzes.
+pub const DOUBLE_BUZZ: Pattern<'static> = Pattern::new(&[80, 120, 80], 1);
+
+/// Pattern for incoming notifications.
+pub const NOTIFICATION: Pattern<'static> = Pattern::new(&[200, 100, 80], 1);
+
+/// Pattern for a ringing alarm.
+pub const ALARM: Pattern<'static> = Pattern::new(&[400, 200, 400, 800], 10);
+
+/// A vibration pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pattern<'a> {
+    /// Alternating on and off durations in milliseconds, starting with on.
+    steps: &'a [u16],
+    /// How many times the steps are played.
+    repeat: u8,
+}
+
+impl<'a> Pattern<'a> {
+    /// Create a pattern that is played `repeat` times.
+    ///
+    /// Panics if the pattern is repeated and has an odd number of steps,
+    /// i.e. if it ends with "on".
+    pub const fn new(steps: &'a [u16], repeat: u8) -> Self {
+        assert!(
+            repeat <= 1 || steps.len() % 2 == 0,
+            "a repeated pattern must end with an off step"
+        );
+        Self { steps, repeat }
+    }
+}
+
+/// A step of a pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Turn the motor on for the specified number of milliseconds.
+    On(u16),
+    /// Turn the motor off for the specified number of milliseconds.
+    Off(u16),
+    /// The pattern is finished, turn the motor off.
+    Done,
+}
+
+pub struct Player {
+    steps: [u16; MAX_STEPS],
+    len: usize,
+    repeat: u8,
+
+    /// Index of the next step.
+    index: usize,
+    /// Number of completed repetitions.
+    round: u8,
+
+    /// Generation of the current pattern.
+    generation: u32,
+}
+
+impl Player {
+    pub const fn new() -> Self {
+        Self {
+            steps: [0; MAX_STEPS],
+            len: 0,
+            repeat: 0,
+            index: 0,
+            round: 0,
+            generation: 0,
+        }
+    }
+
+    /// Start playing a pattern, replacing the current one. Return the
+    /// generation that must be passed to `advance`.
+    pub fn play(&mut self, pattern: &Pattern) -> u32 {
+        self.len = pattern.steps.len().min(MAX_STEPS);
+        self.steps[..self.len].copy_from_slice(&pattern.steps[..self.len]);
+        self.repeat = pattern.repeat;
+        self.index = 0;
+        self.round = 0;
+        self.generation = self.generation.wrapping_add(1);
+        self.generation
+    }
+
+    /// Stop playing. The motor must be turned off by the caller.
+    pub fn stop(&mut self) {
+        self.len = 0;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Return the next step of the pattern of the specified generation, or
+    /// `None` if another pattern was started (or playing was stopped) since.
+    pub fn advance(&mut self, generation: u32) -> Option<Step> {
+        if generation != self.generation {
+            return None;
+        }
+        if self.index >= self.len {
+            self.index = 0;
+            self.round = self.round.saturating_add(1);
+        }
+        if self.len == 0 || self.round >= self.repeat {
+            self.len = 0;
+            return Some(Step::Done);
+        }
+        let duration = self.steps[self.index];
+        let step = if self.index & 1 == 0 {
+            Step::On(duration)
+        } else {
+            Step::Off(duration)
+        };
+        self.index += 1;
+        Some(step)
+    }
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// PWM counter top value. With a 1 MHz PWM clock, this results in a PWM
+/// frequency of 10 kHz.
+#[cfg(target_arch = "arm")]
+const COUNTERTOP: u16 = 100;
+
+/// The PWM duty cycle. It is read by the PWM peripheral through EasyDMA and
+/// must therefore be located in RAM.
+#[cfg(target_arch = "arm")]
+static mut DUTY: [u16; 1] = [0];
+
+/// The vibration motor (active low).
+#[cfg(target_arch = "arm")]
+pub struct VibrationMotor {
+    pwm: PWM0,
+    _pin: Pin<Output<PushPull>>,
+    intensity: u8,
+}
+
+#[cfg(target_arch = "arm")]
+impl VibrationMotor {
+    /// Initialize the motor. The pin must be set high (motor off). While the
+    /// PWM is disabled, the pin keeps that level.
+    pub fn init(pwm: PWM0, pin: Pin<Output<PushPull>>) -> Self {
+        pwm.psel.out[0].write(|w| unsafe { w.pin().bits(pin.pin).connect().connected() });
+        pwm.mode.write(|w| w.updown().up());
+        pwm.prescaler.write(|w| w.prescaler().div_16());
+        pwm.countertop
+            .write(|w| unsafe { w.countertop().bits(COUNTERTOP) });
+        pwm.decoder
+            .write(|w| w.load().common().mode().refresh_count());
+        pwm.loop_.write(|w| w.cnt().disabled());
+        pwm.seq0.cnt.write(|w| unsafe { w.cnt().bits(1) });
+        pwm.seq0.refresh.write(|w| unsafe { w.bits(0) });
+        pwm.seq0.enddelay.write(|w| unsafe { w.bits(0) });
+        Self {
+            pwm,
+            _pin: pin,
+            intensity: 100,
+        }
+    }
+
+    /// Set the intensity in percent (0–100). Higher values are clamped to
+    /// 100. Takes effect the next time the motor is turned on.
+    pub fn set_intensity(&mut self, intensity: u8) {
+        self.intensity = intensity.min(100);
+    }
+
+    /// Return the intensity in percent.
+    pub fn intensity(&self) -> u8 {
+        self.intensity
+    }
+
+    /// Turn the motor on.
+    pub fn on(&mut self) {
+        // With the default polarity, the output is low (motor on) until the
+        // counter reaches the compare value.
+        let compare = COUNTERTOP as u32 * self.intensity as u32 / 100;
+        unsafe {
+            DUTY[0] = compare as u16;
+            let duty = core::ptr::addr_of!(DUTY) as u32;
+            self.pwm.seq0.ptr.write(|w| w.bits(duty));
+        }
+        self.pwm.enable.write(|w| w.enable().enabled());
+        // The last value of the sequence is kept until the PWM is stopped
+        self.pwm.tasks_seqstart[0].write(|w| unsafe { w.bits(1) });
+    }
+
+    /// Turn the motor off.
+    pub fn off(&mut self) {
+        self.pwm.tasks_stop.write(|w| unsafe { w.bits(1) });
+        self.pwm.enable.write(|w| w.enable().disabled());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn play_pattern() {
+        let mut player = Player::new();
+        let generation = player.play(&DOUBLE_BUZZ);
+        assert_eq!(player.advance(generation), Some(Step::On(80)));
+        assert_eq!(player.advance(generation), Some(Step::Off(120)));
+        assert_eq!(player.advance(generation), Some(Step::On(80)));
+        assert_eq!(player.advance(generation), Some(Step::Done));
+        assert_eq!(player.advance(generation), Some(Step::Done));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut player = Player::new();
+        let generation = player.play(&Pattern::new(&[10, 20], 2));
+        let steps: Vec<_> = (0..5).map(|_| player.advance(generation)).collect();
+        assert_eq!(
+            steps,
+            [
+                Some(Step::On(10)),
+                Some(Step::Off(20)),
+                Some(Step::On(10)),
+                Some(Step::Off(20)),
+                Some(Step::Done),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeat_odd_pattern() {
+        Pattern::new(&[10, 20, 10], 2);
+    }
+
+    #[test]
+    fn replace_and_stop() {
+        let mut player = Player::new();
+        let first = player.play(&ALARM);
+        assert_eq!(player.advance(first), Some(Step::On(400)));
+        let second = player.play(&SHORT_BUZZ);
+        assert_eq!(player.advance(first), None);
+        assert_eq!(player.advance(second), Some(Step::On(50)));
+        player.stop();
+        assert_eq!(player.advance(second), None);
+    }
+
+    #[test]
+    fn empty_and_long_patterns() {
+        let mut player = Player::new();
+        let generation = player.play(&Pattern::new(&[], 3));
+        assert_eq!(player.advance(generation), Some(Step::Done));
+
+        let steps = [1; MAX_STEPS + 4];
+        let generation = player.play(&Pattern::new(&steps, 1));
+        let count = (0..)
+            .take_while(|_| player.advance(generation) != Some(Step::Done))
+            .count();
+        assert_eq!(count, MAX_STEPS);
+    }
+}
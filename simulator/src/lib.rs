@@ -0,0 +1,684 @@
+//! Run the watch UI on the desktop.
+//!
+//! The `Simulator` drives `pinetime_rtic::ui::Ui` the way the firmware does:
+//! the ticks of the UI and the clock run on a simulated clock, and input
+//! events are fed from a script. The UI is drawn
+//! into a `Framebuffer`, which can be saved as PNG or PPM.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use embedded_graphics::{
+    drawable::Pixel,
+    geometry::Size,
+    pixelcolor::{
+        raw::{RawData, RawU16},
+        Rgb565,
+    },
+    DrawTarget,
+};
+
+use pinetime_rtic::alarm::Alarms;
+use pinetime_rtic::button::{ButtonEvent, Gesture};
+use pinetime_rtic::clock::{DateTime, WallClock};
+use pinetime_rtic::damage::Rect;
+use pinetime_rtic::image::Decoder;
+use pinetime_rtic::monotonic_nrf52::{Duration, Instant};
+use pinetime_rtic::notification::{Category, Notification, Notifications};
+use pinetime_rtic::settings::Settings;
+use pinetime_rtic::touch::TouchGesture;
+use pinetime_rtic::ui::{self, Alert, Battery, Env, Event, Image, Images, Panel, Ui, LCD_H, LCD_W};
+
+/// The built-in ferris image of the firmware.
+const FERRIS_IMAGE: &[u8] = include_bytes!("../../pinetime-rtic/ferris.img");
+
+/// The BLE device address of the simulated watch, least significant byte
+/// first.
+const BLE_ADDRESS: [u8; 6] = [0x56, 0x34, 0x12, 0xEF, 0xCD, 0xAB];
+
+/// The LCD contents, one RGB565 pixel per `u16`.
+#[derive(Clone, PartialEq)]
+pub struct Framebuffer {
+    pixels: Vec<u16>,
+}
+
+impl Framebuffer {
+    /// Create a black framebuffer of the size of the LCD.
+    pub fn new() -> Self {
+        Self {
+            pixels: vec![0; LCD_W as usize * LCD_H as usize],
+        }
+    }
+
+    /// Return the pixel at `x`, `y`.
+    pub fn pixel(&self, x: u16, y: u16) -> u16 {
+        self.pixels[y as usize * LCD_W as usize + x as usize]
+    }
+
+    /// Return all pixels, row by row.
+    pub fn pixels(&self) -> &[u16] {
+        &self.pixels
+    }
+
+    /// Return the pixels as 8 bit RGB triples, row by row.
+    pub fn to_rgb8(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&pixel| rgb888(pixel))
+            .collect()
+    }
+
+    /// Write the framebuffer as PNG.
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, LCD_W as u32, LCD_H as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgb8())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Read a framebuffer from a PNG of the size of the LCD, as written by
+    /// `write_png`.
+    pub fn read_png(reader: impl io::Read) -> io::Result<Self> {
+        let invalid =
+            |e: &dyn std::fmt::Display| io::Error::new(io::ErrorKind::InvalidData, e.to_string());
+        let mut decoder = png::Decoder::new(reader);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(|e| invalid(&e))?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).map_err(|e| invalid(&e))?;
+        if (info.width, info.height) != (LCD_W as u32, LCD_H as u32) {
+            return Err(invalid(&"image size differs from the LCD"));
+        }
+        if info.color_type != png::ColorType::Rgb {
+            return Err(invalid(&"image is not RGB"));
+        }
+        let pixels = buf[..info.buffer_size()]
+            .chunks(3)
+            .map(|rgb| rgb565([rgb[0], rgb[1], rgb[2]]))
+            .collect();
+        Ok(Self { pixels })
+    }
+
+    /// Write the framebuffer as binary PPM.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", LCD_W, LCD_H)?;
+        writer.write_all(&self.to_rgb8())
+    }
+
+    /// Compare with another framebuffer. Return the number of pixels that
+    /// differ and an image that shows them in red, on top of a dimmed copy
+    /// of `self`.
+    pub fn diff(&self, other: &Framebuffer) -> (usize, Framebuffer) {
+        let mut count = 0;
+        let pixels = self
+            .pixels
+            .iter()
+            .zip(&other.pixels)
+            .map(|(&a, &b)| {
+                if a == b {
+                    let [r, g, b] = rgb888(a);
+                    let gray = ((r as u16 + g as u16 + b as u16) / 3 / 3) as u8;
+                    rgb565([gray; 3])
+                } else {
+                    count += 1;
+                    0xF800
+                }
+            })
+            .collect();
+        (count, Framebuffer { pixels })
+    }
+
+    fn put(&mut self, x: u16, y: u16, pixel: u16) {
+        if x < LCD_W && y < LCD_H {
+            self.pixels[y as usize * LCD_W as usize + x as usize] = pixel;
+        }
+    }
+}
+
+impl Default for Framebuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Panel for Framebuffer {
+    fn write_pixels(&mut self, area: Rect, pixels: &[u16]) {
+        assert_eq!(pixels.len(), area.area() as usize, "window size mismatch");
+        for (i, &pixel) in pixels.iter().enumerate() {
+            let (dx, dy) = (i % area.width as usize, i / area.width as usize);
+            self.put(area.x + dx as u16, area.y + dy as u16, pixel);
+        }
+    }
+
+    fn fill(&mut self, area: Rect, color: Rgb565) {
+        let pixel = RawU16::from(color).into_inner();
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                self.put(x, y, pixel);
+            }
+        }
+    }
+}
+
+impl DrawTarget<Rgb565> for Framebuffer {
+    type Error = core::convert::Infallible;
+
+    fn draw_pixel(&mut self, Pixel(point, color): Pixel<Rgb565>) -> Result<(), Self::Error> {
+        if point.x >= 0 && point.y >= 0 {
+            self.put(
+                point.x as u16,
+                point.y as u16,
+                RawU16::from(color).into_inner(),
+            );
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> Size {
+        Size::new(LCD_W as u32, LCD_H as u32)
+    }
+}
+
+/// Expand an RGB565 pixel to 8 bits per channel.
+fn rgb888(pixel: u16) -> [u8; 3] {
+    let (r, g, b) = (pixel >> 11, (pixel >> 5) & 0x3F, pixel & 0x1F);
+    [
+        (r << 3 | r >> 2) as u8,
+        (g << 2 | g >> 4) as u8,
+        (b << 3 | b >> 2) as u8,
+    ]
+}
+
+/// Reduce an 8 bit RGB pixel to RGB565.
+fn rgb565([r, g, b]: [u8; 3]) -> u16 {
+    (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3
+}
+
+/// A line of a script.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// A button gesture.
+    Button(Gesture),
+    /// A touch gesture.
+    Touch(TouchGesture),
+    /// Let the simulated time pass, in milliseconds.
+    Wait(u32),
+    /// Set the wall clock to a time of day.
+    Time(u8, u8),
+    /// Set the battery voltage (in tenths of a volt) and whether it is
+    /// charging.
+    Battery(u8, bool),
+    /// Receive a notification over BLE. Its timestamp is set when it is
+    /// received.
+    Notify(Box<Notification>),
+    /// Save the framebuffer under a name.
+    Frame(String),
+}
+
+impl Command {
+    /// Parse a script line. Return `Ok(None)` for empty lines and comments
+    /// (starting with `#`).
+    pub fn parse(line: &str) -> Result<Option<Self>, String> {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+        let args: Vec<&str> = words.collect();
+        let invalid = || format!("Invalid command: {}", line);
+        let command = match (command, args.as_slice()) {
+            ("press", []) => Command::Button(Gesture::Press),
+            ("double-press", []) => Command::Button(Gesture::DoublePress),
+            ("long-press", []) => Command::Button(Gesture::LongPress),
+            ("tap", []) => Command::Touch(TouchGesture::SingleTap),
+            ("double-tap", []) => Command::Touch(TouchGesture::DoubleTap),
+            ("long-touch", []) => Command::Touch(TouchGesture::LongPress),
+            ("swipe", [direction]) => Command::Touch(match *direction {
+                "up" => TouchGesture::SlideUp,
+                "down" => TouchGesture::SlideDown,
+                "left" => TouchGesture::SlideLeft,
+                "right" => TouchGesture::SlideRight,
+                _ => return Err(invalid()),
+            }),
+            ("wait", [millis]) => Command::Wait(parse_millis(millis).ok_or_else(invalid)?),
+            ("time", [time]) => {
+                let (hour, minute) = parse_hm(time).ok_or_else(invalid)?;
+                Command::Time(hour, minute)
+            }
+            ("battery", [voltage, state @ ..]) => {
+                let charging = match state {
+                    [] => false,
+                    ["charging"] => true,
+                    _ => return Err(invalid()),
+                };
+                Command::Battery(parse_voltage(voltage).ok_or_else(invalid)?, charging)
+            }
+            ("frame", [name]) => Command::Frame((*name).into()),
+            ("notify", [category, ..]) => {
+                let category = parse_category(category).ok_or_else(invalid)?;
+                // The texts may contain spaces and are separated by `|`
+                let texts = line["notify".len()..].trim_start();
+                let texts = texts.split_once(' ').map_or("", |(_, texts)| texts);
+                let mut texts = texts.split('|').map(str::trim);
+                let app = texts.next().unwrap_or("");
+                let title = texts.next().ok_or_else(invalid)?;
+                let body = texts.next().unwrap_or("");
+                if texts.next().is_some() {
+                    return Err(invalid());
+                }
+                Command::Notify(Box::new(Notification::new(category, 0, app, title, body)))
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(Some(command))
+    }
+}
+
+/// Parse a duration like `250` or `250ms`, `3s`.
+fn parse_millis(duration: &str) -> Option<u32> {
+    if let Some(millis) = duration.strip_suffix("ms") {
+        millis.parse().ok()
+    } else if let Some(secs) = duration.strip_suffix('s') {
+        secs.parse::<u32>().ok()?.checked_mul(1000)
+    } else {
+        duration.parse().ok()
+    }
+}
+
+/// Parse a notification category like `social`.
+fn parse_category(name: &str) -> Option<Category> {
+    let category = match name {
+        "other" => Category::Other,
+        "call" => Category::IncomingCall,
+        "missed-call" => Category::MissedCall,
+        "voicemail" => Category::Voicemail,
+        "social" => Category::Social,
+        "schedule" => Category::Schedule,
+        "email" => Category::Email,
+        "news" => Category::News,
+        "health" => Category::HealthAndFitness,
+        "finance" => Category::BusinessAndFinance,
+        "location" => Category::Location,
+        "entertainment" => Category::Entertainment,
+        _ => return None,
+    };
+    Some(category)
+}
+
+/// Parse a time of day like `13:37`.
+fn parse_hm(time: &str) -> Option<(u8, u8)> {
+    let (hour, minute) = time.split_once(':')?;
+    let (hour, minute) = (hour.parse().ok()?, minute.parse().ok()?);
+    if hour < 24 && minute < 60 {
+        Some((hour, minute))
+    } else {
+        None
+    }
+}
+
+/// Parse a voltage like `3.9` into tenths of a volt.
+fn parse_voltage(voltage: &str) -> Option<u8> {
+    let (volts, tenths) = voltage.split_once('.')?;
+    let (volts, tenths): (u8, u8) = (volts.parse().ok()?, tenths.parse().ok()?);
+    if volts < 10 && tenths < 10 {
+        Some(volts * 10 + tenths)
+    } else {
+        None
+    }
+}
+
+/// The periodic tasks of the firmware that feed the UI.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Task {
+    Tick,
+    ClockTick,
+}
+
+impl Task {
+    fn period(self) -> Duration {
+        match self {
+            Task::Tick => Duration::from_hz(ui::TICK_HZ),
+            Task::ClockTick => Duration::from_secs(1),
+        }
+    }
+}
+
+/// Draws the built-in images of the firmware.
+struct BuiltinImages;
+
+impl Images for BuiltinImages {
+    fn draw(&mut self, image: Image, panel: &mut dyn Panel, x: u16, y: u16) {
+        let mut data = match image {
+            Image::Ferris => FERRIS_IMAGE,
+        };
+        Decoder::new(&mut data)
+            .and_then(|decoder| {
+                ui::stream_image(panel, x, y, decoder, |decoder, pixels| {
+                    decoder.read(&mut data, pixels)
+                })
+            })
+            .unwrap();
+    }
+}
+
+/// The UI and the state it depends on, driven by simulated time.
+pub struct Simulator {
+    pub ui: Ui,
+    pub alarms: Alarms,
+    pub wall_clock: WallClock,
+    pub framebuffer: Framebuffer,
+    pub battery: Battery,
+    pub settings: Settings,
+    pub notifications: Notifications,
+    now: Instant,
+    /// Periodic tasks and when they run next.
+    tasks: Vec<(Instant, Task)>,
+    /// Events scheduled by the UI, i.e. countdown expiries.
+    scheduled: Vec<(Instant, Event)>,
+    /// Minute of the wall clock that was last handled.
+    clock_minute: Option<u64>,
+    /// What happened, for the log of the simulator.
+    events: String,
+}
+
+impl Simulator {
+    /// Boot the simulated watch: start the tasks, which draw the watch face.
+    pub fn new() -> Self {
+        let now = Instant::from_counts(0);
+        let mut simulator = Self {
+            ui: Ui::new(),
+            alarms: Alarms::new(),
+            wall_clock: WallClock::new(now),
+            framebuffer: Framebuffer::new(),
+            battery: Battery {
+                voltage: 39,
+                charging: false,
+            },
+            settings: Settings::default(),
+            notifications: Notifications::new(),
+            now,
+            tasks: vec![(now, Task::Tick), (now, Task::ClockTick)],
+            scheduled: Vec::new(),
+            clock_minute: None,
+            events: String::new(),
+        };
+        simulator.advance(Duration::ZERO);
+        simulator
+    }
+
+    /// Return the simulated time since boot.
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    /// Take what happened since the last call, one line per event.
+    pub fn take_log(&mut self) -> String {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Run a command. Frames are passed to `frame`.
+    pub fn run(&mut self, command: &Command, mut frame: impl FnMut(&str, &Framebuffer)) {
+        match *command {
+            Command::Button(gesture) => {
+                let event = ButtonEvent {
+                    gesture,
+                    at: self.now,
+                };
+                self.handle(Event::Button(event));
+                // Apps update the display on the next tick, which is at most
+                // a frame away on the watch
+                self.handle(Event::Tick);
+            }
+            Command::Touch(gesture) => {
+                self.handle(Event::Touch(gesture));
+                self.handle(Event::Tick);
+            }
+            Command::Wait(millis) => self.advance(Duration::from_millis(millis)),
+            Command::Time(hour, minute) => {
+                let datetime = DateTime::from_secs(self.wall_clock.secs(self.now));
+                let secs = DateTime {
+                    hour,
+                    minute,
+                    second: 0,
+                    ..datetime
+                }
+                .to_secs()
+                .unwrap();
+                self.wall_clock.set(self.now, secs);
+                // Show it right away instead of on the next tick
+                self.handle(Event::Tick);
+            }
+            Command::Battery(voltage, charging) => {
+                self.battery = Battery { voltage, charging };
+                self.handle(Event::Tick);
+            }
+            Command::Notify(ref notification) => {
+                let mut notification = **notification;
+                // Like the firmware, which timestamps notifications on
+                // arrival if the wall clock is set
+                if self.wall_clock.is_set() {
+                    notification.timestamp = self.wall_clock.secs(self.now) as u32;
+                }
+                self.notifications.push(notification);
+                self.handle(Event::Notification);
+                self.handle(Event::Tick);
+            }
+            Command::Frame(ref name) => frame(name, &self.framebuffer),
+        }
+    }
+
+    /// Let the simulated time pass, running the tasks and events that become
+    /// due.
+    pub fn advance(&mut self, duration: Duration) {
+        let until = self.now + duration;
+        loop {
+            let task = self
+                .tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, (at, _))| *at <= until)
+                .min_by_key(|(_, (at, _))| *at)
+                .map(|(index, &(at, _))| (index, at));
+            let scheduled = self
+                .scheduled
+                .iter()
+                .enumerate()
+                .filter(|(_, (at, _))| *at <= until)
+                .min_by_key(|(_, (at, _))| *at)
+                .map(|(index, &(at, _))| (index, at));
+            match (task, scheduled) {
+                (Some((_, task_at)), Some((index, at))) if at <= task_at => {
+                    self.run_scheduled(index, at)
+                }
+                (None, Some((index, at))) => self.run_scheduled(index, at),
+                (Some((index, at)), _) => {
+                    self.now = at;
+                    let task = self.tasks[index].1;
+                    self.tasks[index].0 = at + task.period();
+                    self.run_task(task);
+                }
+                (None, None) => break,
+            }
+        }
+        self.now = until;
+    }
+
+    fn run_task(&mut self, task: Task) {
+        match task {
+            Task::Tick => self.handle(Event::Tick),
+            Task::ClockTick => {
+                let secs = self.wall_clock.secs(self.now);
+                let minute = secs / 60;
+                if self.clock_minute != Some(minute) {
+                    self.clock_minute = Some(minute);
+                    if self.wall_clock.is_set() {
+                        if let Some(index) = self.alarms.due(&DateTime::from_secs(secs)) {
+                            self.handle(Event::AlarmRinging(index));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn run_scheduled(&mut self, index: usize, at: Instant) {
+        self.now = at;
+        let (_, event) = self.scheduled.remove(index);
+        self.handle(event);
+    }
+
+    /// Let the UI handle an event, and take care of its effects like the
+    /// firmware does.
+    fn handle(&mut self, event: Event) {
+        let env = Env {
+            panel: &mut self.framebuffer,
+            images: &mut BuiltinImages,
+            now: self.now,
+            alarms: &mut self.alarms,
+            wall_clock: &self.wall_clock,
+            battery: self.battery,
+            settings: &mut self.settings,
+            ble_address: BLE_ADDRESS,
+            notifications: &mut self.notifications,
+        };
+        let effects = self.ui.handle(event, env);
+
+        if effects.stop_vibration {
+            self.log(format_args!("vibration stopped"));
+        }
+        if effects.cycle_brightness {
+            self.log(format_args!("backlight brightness changed"));
+        }
+        if effects.settings_changed {
+            self.log(format_args!("settings changed"));
+        }
+        if effects.screen_off {
+            self.log(format_args!("screen off"));
+        }
+        if effects.screen_on {
+            self.log(format_args!("screen on"));
+        }
+        if let Some((at, generation)) = effects.countdown_expiry {
+            self.scheduled
+                .push((at, Event::CountdownExpired(generation)));
+        }
+        match effects.alert {
+            Some(Alert::Countdown) => self.log(format_args!("countdown expired")),
+            Some(Alert::Alarm(index)) => self.log(format_args!("alarm {} rings", index)),
+            Some(Alert::Notification) => self.log(format_args!("notification shown")),
+            None => {}
+        }
+    }
+
+    fn log(&mut self, event: std::fmt::Arguments) {
+        let millis = self.now.counts() * 1000 / pinetime_rtic::monotonic_nrf52::TICKS_PER_SECOND;
+        writeln!(self.events, "{:>8} ms: {}", millis, event).unwrap();
+    }
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pinetime_rtic::ui::AppId;
+
+    #[test]
+    fn commands() {
+        assert_eq!(Command::parse("  # comment"), Ok(None));
+        assert_eq!(
+            Command::parse("press # snooze"),
+            Ok(Some(Command::Button(Gesture::Press)))
+        );
+        assert_eq!(
+            Command::parse("swipe left"),
+            Ok(Some(Command::Touch(TouchGesture::SlideLeft)))
+        );
+        assert_eq!(Command::parse("wait 2s"), Ok(Some(Command::Wait(2000))));
+        assert_eq!(Command::parse("wait 40ms"), Ok(Some(Command::Wait(40))));
+        assert_eq!(Command::parse("time 7:05"), Ok(Some(Command::Time(7, 5))));
+        assert_eq!(
+            Command::parse("battery 3.7 charging"),
+            Ok(Some(Command::Battery(37, true)))
+        );
+        assert_eq!(
+            Command::parse("notify social Chat | Ferris | Lunch at 12?"),
+            Ok(Some(Command::Notify(Box::new(Notification::new(
+                Category::Social,
+                0,
+                "Chat",
+                "Ferris",
+                "Lunch at 12?"
+            )))))
+        );
+        assert!(Command::parse("notify email Mail").is_err());
+        assert!(Command::parse("time 24:00").is_err());
+        assert!(Command::parse("swipe sideways").is_err());
+        assert!(Command::parse("press twice").is_err());
+    }
+
+    #[test]
+    fn framebuffer() {
+        let mut framebuffer = Framebuffer::new();
+        framebuffer.fill(Rect::new(1, 2, 3, 4), Rgb565::new(31, 0, 31));
+        framebuffer.write_pixels(Rect::new(238, 0, 2, 1), &[0xFFFF, 0x07E0]);
+        assert_eq!(framebuffer.pixel(3, 5), 0xF81F);
+        assert_eq!(framebuffer.pixel(4, 5), 0);
+        assert_eq!(framebuffer.pixel(239, 0), 0x07E0);
+        assert_eq!(rgb888(0xFFFF), [255, 255, 255]);
+        assert_eq!(rgb888(0x07E0), [0, 255, 0]);
+        for pixel in [0, 0x1234, 0x07E0, 0xF81F, 0xFFFF].iter() {
+            assert_eq!(rgb565(rgb888(*pixel)), *pixel);
+        }
+
+        let mut png = Vec::new();
+        framebuffer.write_png(&mut png).unwrap();
+        let read = Framebuffer::read_png(&png[..]).unwrap();
+        assert!(read == framebuffer);
+
+        let (count, diff) = framebuffer.diff(&Framebuffer::new());
+        assert_eq!(count, 3 * 4 + 2);
+        assert_eq!(diff.pixel(3, 5), 0xF800);
+        assert_eq!(diff.pixel(4, 5), 0);
+
+        let mut ppm = Vec::new();
+        framebuffer.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n240 240\n255\n"));
+        assert_eq!(ppm.len(), 15 + 240 * 240 * 3);
+    }
+
+    #[test]
+    fn countdown() {
+        let mut simulator = Simulator::new();
+        let background = RawU16::from(ui::BACKGROUND_COLOR).into_inner();
+        assert_eq!(simulator.framebuffer.pixel(0, 239), background);
+
+        simulator.run(&Command::Button(Gesture::DoublePress), |_, _| {});
+        simulator.run(&Command::Button(Gesture::Press), |_, _| {});
+        simulator.run(&Command::Button(Gesture::LongPress), |_, _| {});
+        assert_eq!(simulator.ui.top(), AppId::Countdown);
+        simulator.run(&Command::Touch(TouchGesture::SlideDown), |_, _| {});
+        simulator.run(&Command::Button(Gesture::Press), |_, _| {});
+        simulator.run(&Command::Wait(239_000), |_, _| {});
+        assert!(simulator.ui.alert().is_none());
+        simulator.run(&Command::Wait(1000), |_, _| {});
+        assert!(simulator.ui.alert().is_some());
+        assert!(simulator
+            .take_log()
+            .ends_with("240000 ms: countdown expired\n"));
+
+        // The alert banner is red
+        assert_eq!(simulator.framebuffer.pixel(0, 120), 0xF800);
+        simulator.run(&Command::Button(Gesture::Press), |_, _| {});
+        assert!(simulator.ui.alert().is_none());
+        assert_eq!(simulator.framebuffer.pixel(0, 120), background);
+    }
+}
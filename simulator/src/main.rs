@@ -0,0 +1,74 @@
+//! Run the watch UI on the desktop, driven by a script:
+//!
+//! ```text
+//! $ cargo run --release -- scripts/tour.txt frames/
+//! ```
+//!
+//! Every `frame NAME` line of the script saves the display as
+//! `frames/NAME.png` (or `NAME.ppm` with `--ppm`). See `Command::parse` for
+//! the other commands.
+
+use std::{env, fs, io, path::Path, process};
+
+use simulator::{Command, Framebuffer, Simulator};
+
+const USAGE: &str = "Usage: simulator [--ppm] <script> <output directory>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut ppm = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--ppm" => ppm = true,
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => paths.push(arg),
+        }
+    }
+    let (script, output) = match paths.as_slice() {
+        [script, output] => (script, Path::new(output)),
+        _ => return Err(USAGE.into()),
+    };
+
+    let script =
+        fs::read_to_string(script).map_err(|e| format!("Could not read {}: {}", script, e))?;
+    let mut commands = Vec::new();
+    for (number, line) in script.lines().enumerate() {
+        let command = Command::parse(line).map_err(|e| format!("Line {}: {}", number + 1, e))?;
+        commands.extend(command);
+    }
+
+    fs::create_dir_all(output)
+        .map_err(|e| format!("Could not create {}: {}", output.display(), e))?;
+    let mut simulator = Simulator::new();
+    let mut result = Ok(());
+    for command in &commands {
+        simulator.run(command, |name, framebuffer| {
+            let extension = if ppm { "ppm" } else { "png" };
+            let path = output.join(name).with_extension(extension);
+            if let Err(e) = save(&path, framebuffer, ppm) {
+                result = Err(format!("Could not write {}: {}", path.display(), e));
+            }
+            println!("{}", path.display());
+        });
+        print!("{}", simulator.take_log());
+        result.clone()?;
+    }
+    Ok(())
+}
+
+fn save(path: &Path, framebuffer: &Framebuffer, ppm: bool) -> io::Result<()> {
+    let file = io::BufWriter::new(fs::File::create(path)?);
+    if ppm {
+        framebuffer.write_ppm(file)
+    } else {
+        framebuffer.write_png(file)
+    }
+}
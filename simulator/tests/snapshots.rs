@@ -0,0 +1,135 @@
+//! Compare the screens against the reference images in `tests/snapshots`.
+//!
+//! Every scene is a simulator script. If a screen differs from its reference,
+//! the actual screen and an image with the differing pixels in red are saved
+//! to `target/snapshots`. After intended changes of the UI, update the
+//! references with:
+//!
+//! ```text
+//! $ UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+//!
+//! This also records the references of new scenes. Without it, a missing
+//! reference is a failure.
+
+use std::{env, fs, io, path::PathBuf};
+
+use simulator::{Command, Framebuffer, Simulator};
+
+/// The scenes and the scripts that lead to them.
+const SCENES: &[(&str, &str)] = &[
+    ("main-unset", "wait 1s"),
+    ("main", "time 13:37\nwait 2s"),
+    ("battery-empty", "battery 3.0"),
+    ("battery-charging", "battery 3.7 charging"),
+    ("battery-full", "battery 4.2"),
+    ("battery-full-charging", "battery 4.2 charging"),
+    ("launcher", "double-press\npress"),
+    (
+        "stopwatch",
+        "double-press\nlong-press\npress\nwait 1500ms\nlong-press\nwait 2200ms\nlong-press\nwait 300ms",
+    ),
+    (
+        "countdown",
+        "double-press\npress\nlong-press\nswipe down\ntap\nwait 2s",
+    ),
+    (
+        "countdown-expired",
+        "double-press\npress\nlong-press\nswipe right\ntap\nwait 311s",
+    ),
+    (
+        "alarms",
+        "double-press\npress\npress\nlong-press\npress\nswipe up\ntap",
+    ),
+    (
+        "alarms-clock-set",
+        "time 6:00\ndouble-press\npress\npress\nlong-press",
+    ),
+    (
+        "alarm-ringing",
+        "time 6:59\ndouble-press\npress\npress\nlong-press\nlong-press\nwait 61s",
+    ),
+    (
+        "settings",
+        "double-press\npress\npress\npress\nlong-press\npress\npress\nlong-press",
+    ),
+    (
+        "settings-info",
+        "double-press\npress\npress\npress\nlong-press\nswipe up\nwait 2s",
+    ),
+    (
+        "main-12h",
+        "time 13:37\ndouble-press\npress\npress\npress\nlong-press\npress\npress\nlong-press\ndouble-press\ndouble-press\nwait 1s",
+    ),
+    (
+        "notification",
+        "time 12:05\nnotify social Chat | Ferris | Lunch at 12, at the usual place? 😀",
+    ),
+    (
+        "notifications",
+        "time 12:05\nnotify email Mail | Release 0.2 | Out now\nnotify call Phone | Mum\npress\ndouble-press\npress\npress\npress\npress\nlong-press",
+    ),
+];
+
+#[test]
+fn snapshots() {
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let references = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let failures_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/snapshots");
+
+    let mut failures = Vec::new();
+    for (name, script) in SCENES {
+        let actual = render(script);
+        let reference = references.join(format!("{}.png", name));
+        let expected = match fs::File::open(&reference) {
+            Ok(file) => Some(Framebuffer::read_png(io::BufReader::new(file)).unwrap()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => panic!("Could not read {}: {}", reference.display(), e),
+        };
+        match expected {
+            Some(ref expected) if *expected == actual => {}
+            _ if update => {
+                fs::create_dir_all(&references).unwrap();
+                save(&reference, &actual);
+                println!("Recorded {}", reference.display());
+            }
+            Some(expected) => {
+                let (count, diff) = expected.diff(&actual);
+                fs::create_dir_all(&failures_dir).unwrap();
+                let actual_path = failures_dir.join(format!("{}.actual.png", name));
+                let diff_path = failures_dir.join(format!("{}.diff.png", name));
+                save(&actual_path, &actual);
+                save(&diff_path, &diff);
+                failures.push(format!(
+                    "{}: {} pixels differ, see {} and {}",
+                    name,
+                    count,
+                    actual_path.display(),
+                    diff_path.display(),
+                ));
+            }
+            None => failures.push(format!("{}: {} is missing", name, reference.display())),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Screens differ from their references (set UPDATE_SNAPSHOTS=1 to update them):\n{}",
+        failures.join("\n"),
+    );
+}
+
+/// Run a script and return the screen at its end.
+fn render(script: &str) -> Framebuffer {
+    let mut simulator = Simulator::new();
+    for line in script.lines() {
+        if let Some(command) = Command::parse(line).unwrap() {
+            simulator.run(&command, |_, _| {});
+        }
+    }
+    simulator.framebuffer.clone()
+}
+
+fn save(path: &std::path::Path, framebuffer: &Framebuffer) {
+    let file = fs::File::create(path).unwrap();
+    framebuffer.write_png(io::BufWriter::new(file)).unwrap();
+}